@@ -34,6 +34,7 @@ use databend_common_tracing::set_panic_hook;
 use databend_enterprise_background_service::get_background_service_handler;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::local;
+use databend_query::pipes::PipeScheduler;
 use databend_query::servers::admin::AdminService;
 use databend_query::servers::flight::FlightService;
 use databend_query::servers::metrics::MetricService;
@@ -42,8 +43,10 @@ use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::tasks::TaskScheduler;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -175,6 +178,32 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         );
     }
 
+    // PostgreSQL handler.
+    if conf.query.postgres_handler_enabled {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.postgres_handler_tcp_keepalive_timeout_secs;
+        let tls_config = MySQLTlsConfig::new(
+            conf.query.postgres_tls_server_cert.clone(),
+            conf.query.postgres_tls_server_key.clone(),
+        );
+
+        let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs, tls_config)
+            .with_context(make_error)?;
+        let listening = handler
+            .start(listening.parse().with_context(make_error)?)
+            .await
+            .with_context(make_error)?;
+        shutdown_handle.add_service("PostgresHandler", handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -h{} -p{} -Uroot",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -257,6 +286,12 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         info!("Listening for FlightSQL API: {}", listening);
     }
 
+    // Pipe scheduler.
+    PipeScheduler::start(conf);
+
+    // Task scheduler, used when cloud control is not configured.
+    TaskScheduler::start(conf).with_context(make_error)?;
+
     // Print information to users.
     println!("Databend Query");
 
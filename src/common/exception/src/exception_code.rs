@@ -363,6 +363,29 @@ build_exceptions! {
     // dynamic error codes.
     IllegalDynamicTable(2740),
 
+    // Pipe error codes.
+    UnknownPipe(2750),
+    PipeAlreadyExists(2751),
+    IllegalPipe(2752),
+
+    // Task error codes.
+    UnknownTask(2760),
+    TaskAlreadyExists(2761),
+    IllegalTask(2762),
+
+    // Table TTL error codes.
+    IllegalTableTtl(2770),
+
+    // Workload group error codes.
+    UnknownWorkloadGroup(2780),
+    WorkloadGroupAlreadyExists(2781),
+    IllegalWorkloadGroup(2782),
+    WorkloadGroupIsInUse(2783),
+
+    // Secret error codes.
+    UnknownSecret(2790),
+    SecretAlreadyExists(2791),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -410,6 +433,8 @@ build_exceptions! {
     CurrentTransactionIsAborted(4002),
     TransactionTimeout(4003),
     InvalidSessionState(4004),
+    UnknownSavepoint(4005),
+    NoActiveTransaction(4006),
 
     // recluster error codes
     NoNeedToRecluster(4011),
@@ -17,10 +17,13 @@ use std::str::FromStr;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use geo::BoundingRect;
 use geo::Geometry;
 use geozero::wkb::Ewkb;
 use geozero::CoordDimensions;
 use geozero::GeozeroGeometry;
+use geozero::ToGeo;
+use geozero::ToGeos;
 use geozero::ToJson;
 use geozero::ToWkb;
 use geozero::ToWkt;
@@ -95,6 +98,31 @@ pub fn parse_to_ewkb(buf: &str, srid: Option<i32>) -> Result<Vec<u8>> {
         .map_err(ErrorCode::from)
 }
 
+/// Decode an EWKB buffer into a GEOS geometry, the SRID is kept on the geometry.
+pub fn ewkb_to_geos(buf: &[u8]) -> Result<geos::Geometry> {
+    Ewkb(buf)
+        .to_geos()
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()))
+}
+
+/// Encode a GEOS geometry into EWKB with the given SRID.
+pub fn geos_to_ewkb(geometry: &geos::Geometry, srid: Option<i32>) -> Result<Vec<u8>> {
+    geometry
+        .to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(ErrorCode::from)
+}
+
+/// Returns the bounding box `[min_x, min_y, max_x, max_y]` of an EWKB geometry,
+/// or `None` if the geometry is empty.
+pub fn ewkb_bounding_box(buf: &[u8]) -> Result<Option<[f64; 4]>> {
+    let geometry = Ewkb(buf)
+        .to_geo()
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
+    Ok(geometry
+        .bounding_rect()
+        .map(|rect| [rect.min().x, rect.min().y, rect.max().x, rect.max().y]))
+}
+
 pub fn cut_srid(ewkt: &str) -> Result<(Option<i32>, &str)> {
     match ewkt.find(';') {
         None => Ok((None, ewkt)),
@@ -52,7 +52,10 @@ pub use decimal::display_decimal_128;
 pub use decimal::display_decimal_256;
 pub use escape::escape_string;
 pub use escape::escape_string_with_quote;
+pub use geometry::ewkb_bounding_box;
+pub use geometry::ewkb_to_geos;
 pub use geometry::geometry_format;
+pub use geometry::geos_to_ewkb;
 pub use geometry::parse_bytes_to_ewkb;
 pub use geometry::parse_to_ewkb;
 pub use geometry::parse_to_subtype;
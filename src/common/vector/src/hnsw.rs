@@ -0,0 +1,483 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+const HNSW_INDEX_FORMAT_VERSION: u8 = 1;
+const MAX_LEVEL: usize = 16;
+const RNG_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+/// The distance function used to build and search a vector index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DistanceType {
+    Cosine,
+    L2,
+}
+
+impl DistanceType {
+    pub fn distance(&self, from: &[f32], to: &[f32]) -> f32 {
+        match self {
+            DistanceType::Cosine => {
+                let mut dot = 0.0;
+                let mut aa_sum = 0.0;
+                let mut bb_sum = 0.0;
+                for (a, b) in from.iter().zip(to.iter()) {
+                    dot += a * b;
+                    aa_sum += a * a;
+                    bb_sum += b * b;
+                }
+                1.0 - dot / (aa_sum.sqrt() * bb_sum.sqrt())
+            }
+            DistanceType::L2 => from
+                .iter()
+                .zip(to.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f32>()
+                .sqrt(),
+        }
+    }
+
+    /// The scalar function computing the same distance.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            DistanceType::Cosine => "cosine_distance",
+            DistanceType::L2 => "l2_distance",
+        }
+    }
+}
+
+impl FromStr for DistanceType {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(DistanceType::Cosine),
+            "l2" => Ok(DistanceType::L2),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "unsupported vector distance '{}', expected 'cosine' or 'l2'",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for DistanceType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DistanceType::Cosine => write!(f, "cosine"),
+            DistanceType::L2 => write!(f, "l2"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+/// A Hierarchical Navigable Small World graph over the vectors of a block.
+///
+/// Each indexed vector is a node of the graph, and remembers the row offset
+/// of the vector in the block, null rows are not indexed.
+/// Node `i` owns `vectors[i * dimension..(i + 1) * dimension]`, and
+/// `neighbors[i][layer]` are its links in the layer.
+pub struct HnswIndex {
+    dimension: usize,
+    distance_type: DistanceType,
+    m: usize,
+    ef_construction: usize,
+    rows: Vec<u32>,
+    vectors: Vec<f32>,
+    neighbors: Vec<Vec<Vec<u32>>>,
+    entry_point: Option<u32>,
+    rng_state: u64,
+}
+
+impl HnswIndex {
+    /// Create an empty index, `m` is the number of links of a node in each layer
+    /// (doubled in the bottom layer), `ef_construction` is the size of the
+    /// dynamic candidate list used when inserting a vector.
+    pub fn new(
+        dimension: usize,
+        distance_type: DistanceType,
+        m: usize,
+        ef_construction: usize,
+    ) -> Self {
+        HnswIndex {
+            dimension,
+            distance_type,
+            m: m.max(2),
+            ef_construction: ef_construction.max(1),
+            rows: vec![],
+            vectors: vec![],
+            neighbors: vec![],
+            entry_point: None,
+            rng_state: RNG_SEED,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn distance_type(&self) -> DistanceType {
+        self.distance_type
+    }
+
+    /// Insert the vector of the row at offset `row` of the block.
+    pub fn insert(&mut self, row: u32, vector: &[f32]) -> Result<()> {
+        self.check_dimension(vector)?;
+
+        let node = self.rows.len() as u32;
+        let level = self.random_level();
+        self.rows.push(row);
+        self.vectors.extend_from_slice(vector);
+        self.neighbors.push(vec![vec![]; level + 1]);
+
+        let Some(entry_point) = self.entry_point else {
+            self.entry_point = Some(node);
+            return Ok(());
+        };
+        let max_level = self.neighbors[entry_point as usize].len() - 1;
+
+        let mut entry_point = entry_point;
+        for layer in (level + 1..=max_level).rev() {
+            entry_point = self.search_layer(vector, &[entry_point], 1, layer)[0].node;
+        }
+
+        let mut entry_points = vec![entry_point];
+        for layer in (0..=level.min(max_level)).rev() {
+            let candidates = self.search_layer(vector, &entry_points, self.ef_construction, layer);
+            let selected = candidates
+                .iter()
+                .take(self.m)
+                .map(|c| c.node)
+                .collect::<Vec<_>>();
+            for neighbor in selected.iter() {
+                self.connect(*neighbor, node, layer);
+            }
+            self.neighbors[node as usize][layer] = selected;
+            entry_points = candidates.into_iter().map(|c| c.node).collect();
+        }
+
+        if level > max_level {
+            self.entry_point = Some(node);
+        }
+        Ok(())
+    }
+
+    /// Search the approximate `k` nearest vectors of `query`, `ef` is the size
+    /// of the dynamic candidate list, a larger `ef` gives a better recall.
+    ///
+    /// Returns the row offsets and the distances, ordered by the distance.
+    pub fn search(&self, query: &[f32], k: usize, ef: usize) -> Result<Vec<(u32, f32)>> {
+        self.check_dimension(query)?;
+
+        let Some(mut entry_point) = self.entry_point else {
+            return Ok(vec![]);
+        };
+        if k == 0 {
+            return Ok(vec![]);
+        }
+
+        let max_level = self.neighbors[entry_point as usize].len() - 1;
+        for layer in (1..=max_level).rev() {
+            entry_point = self.search_layer(query, &[entry_point], 1, layer)[0].node;
+        }
+        let candidates = self.search_layer(query, &[entry_point], ef.max(k), 0);
+        Ok(candidates
+            .into_iter()
+            .take(k)
+            .map(|c| (self.rows[c.node as usize], c.distance))
+            .collect())
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.push(HNSW_INDEX_FORMAT_VERSION);
+        buf.push(match self.distance_type {
+            DistanceType::Cosine => 0,
+            DistanceType::L2 => 1,
+        });
+        put_u32(&mut buf, self.dimension as u32);
+        put_u32(&mut buf, self.m as u32);
+        put_u32(&mut buf, self.ef_construction as u32);
+        put_u32(&mut buf, self.rows.len() as u32);
+        put_u32(&mut buf, self.entry_point.unwrap_or(u32::MAX));
+        for row in self.rows.iter() {
+            put_u32(&mut buf, *row);
+        }
+        for v in self.vectors.iter() {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for layers in self.neighbors.iter() {
+            put_u32(&mut buf, layers.len() as u32);
+            for links in layers.iter() {
+                put_u32(&mut buf, links.len() as u32);
+                for link in links.iter() {
+                    put_u32(&mut buf, *link);
+                }
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader { data, pos: 0 };
+        let version = reader.u8()?;
+        if version != HNSW_INDEX_FORMAT_VERSION {
+            return Err(invalid(format!("unsupported format version {}", version)));
+        }
+        let distance_type = match reader.u8()? {
+            0 => DistanceType::Cosine,
+            1 => DistanceType::L2,
+            v => return Err(invalid(format!("unknown distance type {}", v))),
+        };
+        let dimension = reader.u32()? as usize;
+        let m = reader.u32()? as usize;
+        let ef_construction = reader.u32()? as usize;
+        let len = reader.u32()? as usize;
+        let entry_point = match reader.u32()? {
+            u32::MAX => None,
+            v if (v as usize) < len => Some(v),
+            v => return Err(invalid(format!("entry point {} out of bounds", v))),
+        };
+
+        let mut rows = Vec::with_capacity(len);
+        for _ in 0..len {
+            rows.push(reader.u32()?);
+        }
+        let mut vectors = Vec::with_capacity(len * dimension);
+        for _ in 0..len * dimension {
+            vectors.push(f32::from_bits(reader.u32()?));
+        }
+        let mut neighbors = Vec::with_capacity(len);
+        for _ in 0..len {
+            let levels = reader.u32()? as usize;
+            if levels == 0 || levels > MAX_LEVEL + 1 {
+                return Err(invalid(format!("invalid number of layers {}", levels)));
+            }
+            let mut layers = Vec::with_capacity(levels);
+            for _ in 0..levels {
+                let n = reader.u32()? as usize;
+                let mut links = Vec::with_capacity(n);
+                for _ in 0..n {
+                    let link = reader.u32()?;
+                    if link as usize >= len {
+                        return Err(invalid(format!("link {} out of bounds", link)));
+                    }
+                    links.push(link);
+                }
+                layers.push(links);
+            }
+            neighbors.push(layers);
+        }
+
+        Ok(HnswIndex {
+            dimension,
+            distance_type,
+            m,
+            ef_construction,
+            rows,
+            vectors,
+            neighbors,
+            entry_point,
+            rng_state: RNG_SEED,
+        })
+    }
+
+    fn check_dimension(&self, vector: &[f32]) -> Result<()> {
+        if vector.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                vector.len(),
+                self.dimension,
+            )));
+        }
+        Ok(())
+    }
+
+    fn vector(&self, node: u32) -> &[f32] {
+        let start = node as usize * self.dimension;
+        &self.vectors[start..start + self.dimension]
+    }
+
+    fn distance(&self, query: &[f32], node: u32) -> f32 {
+        self.distance_type.distance(query, self.vector(node))
+    }
+
+    // Draw the level of a new node from an exponentially decaying distribution,
+    // with a deterministic xorshift generator to make the index reproducible.
+    fn random_level(&mut self) -> usize {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+
+        let r = ((x >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+        let level = -r.ln() / (self.m as f64).ln();
+        (level as usize).min(MAX_LEVEL)
+    }
+
+    fn max_links(&self, layer: usize) -> usize {
+        if layer == 0 { self.m * 2 } else { self.m }
+    }
+
+    // Link `from` to `to` in the layer, and keep the nearest links if there are too many.
+    fn connect(&mut self, from: u32, to: u32, layer: usize) {
+        let max_links = self.max_links(layer);
+        let mut links = std::mem::take(&mut self.neighbors[from as usize][layer]);
+        links.push(to);
+        if links.len() > max_links {
+            let base = self.vector(from);
+            let mut candidates = links
+                .iter()
+                .map(|node| Candidate {
+                    distance: self.distance_type.distance(base, self.vector(*node)),
+                    node: *node,
+                })
+                .collect::<Vec<_>>();
+            candidates.sort();
+            links = candidates
+                .into_iter()
+                .take(max_links)
+                .map(|c| c.node)
+                .collect();
+        }
+        self.neighbors[from as usize][layer] = links;
+    }
+
+    // Returns the `ef` nearest nodes found from the entry points in the layer,
+    // ordered by the distance.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: &[u32],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited = HashSet::with_capacity(ef * 8);
+        let mut candidates = BinaryHeap::with_capacity(ef);
+        let mut results = BinaryHeap::with_capacity(ef + 1);
+        for node in entry_points {
+            if !visited.insert(*node) {
+                continue;
+            }
+            let candidate = Candidate {
+                distance: self.distance(query, *node),
+                node: *node,
+            };
+            candidates.push(Reverse(candidate));
+            results.push(candidate);
+            if results.len() > ef {
+                results.pop();
+            }
+        }
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            let furthest = results.peek().map(|c: &Candidate| c.distance);
+            if results.len() >= ef && furthest.is_some_and(|d| current.distance > d) {
+                break;
+            }
+            for node in self.neighbors[current.node as usize][layer].iter() {
+                if !visited.insert(*node) {
+                    continue;
+                }
+                let distance = self.distance(query, *node);
+                let furthest = results.peek().map(|c: &Candidate| c.distance);
+                if results.len() < ef || furthest.is_some_and(|d| distance < d) {
+                    let candidate = Candidate {
+                        distance,
+                        node: *node,
+                    };
+                    candidates.push(Reverse(candidate));
+                    results.push(candidate);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+        results.into_sorted_vec()
+    }
+}
+
+fn put_u32(buf: &mut Vec<u8>, v: u32) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+
+fn invalid(reason: String) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Invalid hnsw index: {}", reason))
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn u8(&mut self) -> Result<u8> {
+        let v = *self
+            .data
+            .get(self.pos)
+            .ok_or_else(|| invalid("unexpected end of data".to_string()))?;
+        self.pos += 1;
+        Ok(v)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| invalid("unexpected end of data".to_string()))?;
+        self.pos += 4;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_vector::l2_distance;
+use databend_common_vector::DistanceType;
+use databend_common_vector::HnswIndex;
+
+fn random_vectors(n: usize, dimension: usize) -> Vec<Vec<f32>> {
+    let mut state: u64 = 42;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as f32 / (1u64 << 31) as f32 - 0.5
+    };
+    (0..n)
+        .map(|_| (0..dimension).map(|_| next()).collect())
+        .collect()
+}
+
+#[test]
+fn test_hnsw_search() {
+    let vectors = random_vectors(1100, 8);
+    let (data, queries) = vectors.split_at(1000);
+
+    let mut index = HnswIndex::new(8, DistanceType::L2, 16, 100);
+    for (row, vector) in data.iter().enumerate() {
+        index.insert(row as u32, vector).unwrap();
+    }
+    assert_eq!(index.len(), 1000);
+
+    let mut hits = 0;
+    for query in queries {
+        let result = index.search(query, 10, 64).unwrap();
+        assert_eq!(result.len(), 10);
+        assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        let mut exact = data
+            .iter()
+            .enumerate()
+            .map(|(row, vector)| (row as u32, l2_distance(query, vector).unwrap()))
+            .collect::<Vec<_>>();
+        exact.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let expected = exact[..10].iter().map(|v| v.0).collect::<HashSet<_>>();
+        hits += result.iter().filter(|v| expected.contains(&v.0)).count();
+    }
+    // the search is approximate, but the recall should be high enough.
+    assert!(hits * 10 >= queries.len() * 10 * 9);
+}
+
+#[test]
+fn test_hnsw_serialization() {
+    let vectors = random_vectors(200, 4);
+    let mut index = HnswIndex::new(4, DistanceType::Cosine, 8, 32);
+    // rows with null vectors are skipped
+    for (row, vector) in vectors.iter().enumerate().filter(|(row, _)| row % 3 != 0) {
+        index.insert(row as u32, vector).unwrap();
+    }
+
+    let bytes = index.to_bytes();
+    let loaded = HnswIndex::from_bytes(&bytes).unwrap();
+    assert_eq!(loaded.len(), index.len());
+    assert_eq!(loaded.distance_type(), DistanceType::Cosine);
+    assert_eq!(loaded.to_bytes(), bytes);
+
+    let query = [0.1, 0.2, -0.3, 0.4];
+    let result = loaded.search(&query, 5, 32).unwrap();
+    assert_eq!(result, index.search(&query, 5, 32).unwrap());
+    assert!(result.iter().all(|(row, _)| row % 3 != 0));
+
+    assert!(HnswIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_hnsw_invalid() {
+    let mut index = HnswIndex::new(3, DistanceType::L2, 16, 100);
+    assert!(index.search(&[1.0, 2.0, 3.0], 10, 10).unwrap().is_empty());
+    assert!(index.insert(0, &[1.0, 2.0]).is_err());
+
+    index.insert(0, &[1.0, 2.0, 3.0]).unwrap();
+    assert!(index.search(&[1.0, 2.0], 10, 10).is_err());
+    assert_eq!(index.search(&[1.0, 2.0, 3.0], 10, 10).unwrap(), vec![(
+        0, 0.0
+    )]);
+
+    assert_eq!(
+        "COSINE".parse::<DistanceType>().unwrap(),
+        DistanceType::Cosine
+    );
+    assert!("dot".parse::<DistanceType>().is_err());
+}
@@ -252,6 +252,12 @@ pub trait SchemaApi: Send + Sync {
 
     async fn get_table_name_by_id(&self, table_id: MetaId) -> Result<Option<String>, MetaError>;
 
+    /// Return the id of the database that the table belongs to and the name of the table.
+    async fn get_db_id_table_name_by_id(
+        &self,
+        table_id: MetaId,
+    ) -> Result<Option<DBIdTableName>, MetaError>;
+
     async fn get_table_copied_file_info(
         &self,
         req: GetTableCopiedFileReq,
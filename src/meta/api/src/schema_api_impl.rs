@@ -1766,6 +1766,21 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         Ok(table_name)
     }
 
+    #[logcall::logcall]
+    #[fastrace::trace]
+    async fn get_db_id_table_name_by_id(
+        &self,
+        table_id: MetaId,
+    ) -> Result<Option<DBIdTableName>, MetaError> {
+        debug!(req :? =(&table_id); "SchemaApi: {}", func_name!());
+
+        let table_id_to_name_key = TableIdToName { table_id };
+
+        let seq_table_name = self.get_pb(&table_id_to_name_key).await?;
+
+        Ok(seq_table_name.map(|s| s.data))
+    }
+
     #[logcall::logcall("debug")]
     #[fastrace::trace]
     async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply, KVAppError> {
@@ -2478,9 +2493,9 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 }
             }
 
-            // column_id can not be duplicated
+            // column_id can not be duplicated in the indexes of the same type
             for (name, index) in indexes.iter() {
-                if *name == req.name {
+                if *name == req.name || index.index_type != req.index_type {
                     continue;
                 }
                 for column_id in &req.column_ids {
@@ -2496,13 +2511,17 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             // use the old index version, otherwise create a new index version.
             let mut old_version = None;
             if let Some(old_index) = indexes.get(&req.name) {
-                if old_index.column_ids == req.column_ids && old_index.options == req.options {
+                if old_index.index_type == req.index_type
+                    && old_index.column_ids == req.column_ids
+                    && old_index.options == req.options
+                {
                     old_version = Some(old_index.version.clone());
                 }
             }
             let version = old_version.unwrap_or(Uuid::new_v4().simple().to_string());
 
             let index = TableIndex {
+                index_type: req.index_type,
                 name: req.name.clone(),
                 column_ids: req.column_ids.clone(),
                 sync_creation: req.sync_creation,
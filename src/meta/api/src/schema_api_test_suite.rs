@@ -45,6 +45,7 @@ use databend_common_meta_app::schema::index_id_ident::IndexIdIdent;
 use databend_common_meta_app::schema::index_id_to_name_ident::IndexIdToNameIdent;
 use databend_common_meta_app::schema::least_visible_time_ident::LeastVisibleTimeIdent;
 use databend_common_meta_app::schema::table_niv::TableNIV;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogMeta;
 use databend_common_meta_app::schema::CatalogNameIdent;
 use databend_common_meta_app::schema::CatalogOption;
@@ -95,6 +96,7 @@ use databend_common_meta_app::schema::ListDictionaryReq;
 use databend_common_meta_app::schema::ListDroppedTableReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
+use databend_common_meta_app::schema::ListSequencesReq;
 use databend_common_meta_app::schema::ListTableReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockKey;
@@ -110,6 +112,7 @@ use databend_common_meta_app::schema::TableIdHistoryIdent;
 use databend_common_meta_app::schema::TableIdList;
 use databend_common_meta_app::schema::TableIdToName;
 use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
@@ -5538,6 +5541,9 @@ impl SchemaApiTestSuite {
                 ident: SequenceIdent::new(&tenant, sequence_name),
                 create_on,
                 comment: Some("seq".to_string()),
+                start: 1,
+                step: 1,
+                cache: 1,
             };
 
             let _resp = mt.create_sequence(req).await?;
@@ -5579,6 +5585,9 @@ impl SchemaApiTestSuite {
                 ident: SequenceIdent::new(&tenant, sequence_name),
                 create_on,
                 comment: Some("seq1".to_string()),
+                start: 1,
+                step: 1,
+                cache: 1,
             };
 
             let _resp = mt.create_sequence(req).await?;
@@ -5591,6 +5600,67 @@ impl SchemaApiTestSuite {
             assert_eq!(resp.current, 1);
         }
 
+        info!("--- alter sequence");
+        {
+            let req = AlterSequenceReq {
+                if_exists: false,
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                update_on: Utc::now(),
+                restart: Some(Some(100)),
+                step: Some(5),
+                cache: Some(20),
+                comment: None,
+            };
+            mt.alter_sequence(req).await?;
+
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                count: 3,
+            };
+            let resp = mt.get_sequence_next_value(req).await?;
+            assert_eq!(resp.start, 100);
+            assert_eq!(resp.step, 5);
+            assert_eq!(resp.end, 110);
+
+            let req = SequenceIdent::new(&tenant, sequence_name);
+            let resp = mt.get_sequence(&req).await?.unwrap().data;
+            assert_eq!(resp.comment, Some("seq1".to_string()));
+            assert_eq!(resp.current, 115);
+            assert_eq!(resp.cache, 20);
+        }
+
+        info!("--- alter unknown sequence");
+        {
+            let req = AlterSequenceReq {
+                if_exists: true,
+                ident: SequenceIdent::new(&tenant, "unknown_seq"),
+                update_on: Utc::now(),
+                restart: Some(None),
+                step: None,
+                cache: None,
+                comment: None,
+            };
+            mt.alter_sequence(req.clone()).await?;
+
+            let res = mt
+                .alter_sequence(AlterSequenceReq {
+                    if_exists: false,
+                    ..req
+                })
+                .await;
+            assert!(res.is_err());
+        }
+
+        info!("--- list sequences");
+        {
+            let req = ListSequencesReq {
+                tenant: tenant.clone(),
+            };
+            let resp = mt.list_sequences(req).await?;
+            assert_eq!(resp.sequences.len(), 1);
+            assert_eq!(resp.sequences[0].0, sequence_name);
+        }
+
         {
             let req = DropSequenceReq {
                 ident: SequenceIdent::new(&tenant, sequence_name),
@@ -5996,6 +6066,7 @@ impl SchemaApiTestSuite {
                 create_option: CreateOption::Create,
                 tenant: tenant.clone(),
                 table_id,
+                index_type: TableIndexType::Inverted,
                 name: index_name_1.clone(),
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
@@ -6009,6 +6080,7 @@ impl SchemaApiTestSuite {
                 create_option: CreateOption::Create,
                 table_id,
                 tenant: tenant.clone(),
+                index_type: TableIndexType::Inverted,
                 name: index_name_2.clone(),
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
@@ -6022,6 +6094,7 @@ impl SchemaApiTestSuite {
                 create_option: CreateOption::Create,
                 table_id,
                 tenant: tenant.clone(),
+                index_type: TableIndexType::Inverted,
                 name: index_name_2.clone(),
                 column_ids: index_column_ids_2.clone(),
                 sync_creation: true,
@@ -6037,6 +6110,7 @@ impl SchemaApiTestSuite {
                 create_option: CreateOption::Create,
                 table_id,
                 tenant: tenant.clone(),
+                index_type: TableIndexType::Inverted,
                 name: index_name_1.clone(),
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
@@ -6057,6 +6131,7 @@ impl SchemaApiTestSuite {
                 create_option: CreateOption::CreateIfNotExists,
                 table_id,
                 tenant: tenant.clone(),
+                index_type: TableIndexType::Inverted,
                 name: index_name_1.clone(),
                 column_ids: index_column_ids_1.clone(),
                 sync_creation: true,
@@ -6073,6 +6148,7 @@ impl SchemaApiTestSuite {
                 create_option: CreateOption::Create,
                 table_id,
                 tenant: tenant.clone(),
+                index_type: TableIndexType::Inverted,
                 name: index_name_3.clone(),
                 column_ids: index_column_ids_3.clone(),
                 sync_creation: true,
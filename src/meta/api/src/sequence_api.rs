@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CreateSequenceReply;
 use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::DropSequenceReply;
 use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_meta_app::schema::GetSequenceNextValueReply;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::ListSequencesReply;
+use databend_common_meta_app::schema::ListSequencesReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_types::MetaError;
@@ -42,5 +46,10 @@ pub trait SequenceApi: Send + Sync {
         req: GetSequenceNextValueReq,
     ) -> Result<GetSequenceNextValueReply, KVAppError>;
 
+    async fn alter_sequence(&self, req: AlterSequenceReq)
+    -> Result<AlterSequenceReply, KVAppError>;
+
+    async fn list_sequences(&self, req: ListSequencesReq) -> Result<ListSequencesReply, MetaError>;
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError>;
 }
@@ -17,6 +17,8 @@ use databend_common_meta_app::app_error::AppError;
 use databend_common_meta_app::app_error::OutofSequenceRange;
 use databend_common_meta_app::app_error::SequenceError;
 use databend_common_meta_app::app_error::WrongSequenceCount;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateSequenceReply;
 use databend_common_meta_app::schema::CreateSequenceReq;
@@ -24,9 +26,12 @@ use databend_common_meta_app::schema::DropSequenceReply;
 use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_meta_app::schema::GetSequenceNextValueReply;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::ListSequencesReply;
+use databend_common_meta_app::schema::ListSequencesReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SequenceMeta;
 use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::DirName;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::MetaError;
 use databend_common_meta_types::SeqV;
@@ -121,7 +126,11 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
 
             let start = sequence_meta.current;
             let count = req.count;
-            if u64::MAX - sequence_meta.current < count {
+            let step = sequence_meta.step as u64;
+            let next = count
+                .checked_mul(step)
+                .and_then(|delta| start.checked_add(delta));
+            let Some(next) = next else {
                 return Err(KVAppError::AppError(AppError::SequenceError(
                     SequenceError::OutofSequenceRange(OutofSequenceRange::new(
                         sequence_name,
@@ -131,10 +140,10 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
                         ),
                     )),
                 )));
-            }
+            };
 
             // update meta
-            sequence_meta.current += count;
+            sequence_meta.current = next;
             sequence_meta.update_on = Utc::now();
 
             let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
@@ -160,12 +169,86 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
                 return Ok(GetSequenceNextValueReply {
                     start,
                     step: sequence_meta.step,
-                    end: sequence_meta.current - 1,
+                    end: next - step,
+                    cache: sequence_meta.cache,
                 });
             }
         }
     }
 
+    async fn alter_sequence(
+        &self,
+        req: AlterSequenceReq,
+    ) -> Result<AlterSequenceReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let ident = req.ident.clone();
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+            let seq_meta = self.get_pb(&ident).await?;
+            let Some(seq_meta) = seq_meta else {
+                if req.if_exists {
+                    return Ok(AlterSequenceReply {});
+                }
+                return Err(AppError::SequenceError(SequenceError::UnknownSequence(
+                    ident.unknown_error(func_name!()),
+                ))
+                .into());
+            };
+            let sequence_seq = seq_meta.seq;
+            let mut sequence_meta = seq_meta.data;
+
+            if let Some(restart) = req.restart {
+                sequence_meta.current = restart.unwrap_or(sequence_meta.start);
+            }
+            if let Some(step) = req.step {
+                sequence_meta.step = step;
+            }
+            if let Some(cache) = req.cache {
+                sequence_meta.cache = cache;
+            }
+            if let Some(comment) = &req.comment {
+                sequence_meta.comment = Some(comment.clone());
+            }
+            sequence_meta.update_on = req.update_on;
+
+            let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
+            let if_then = vec![txn_op_put_pb(&ident, &sequence_meta, None)?];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident :?= (req.ident),
+                succ = succ;
+                "alter_sequence"
+            );
+            if succ {
+                return Ok(AlterSequenceReply {});
+            }
+        }
+    }
+
+    async fn list_sequences(&self, req: ListSequencesReq) -> Result<ListSequencesReply, MetaError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let dir_name = DirName::new(SequenceIdent::new(&req.tenant, "dummy"));
+        let sequences = self
+            .list_pb_vec(&dir_name)
+            .await?
+            .into_iter()
+            .map(|(ident, seq_meta)| (ident.name().to_string(), seq_meta.data))
+            .collect();
+
+        Ok(ListSequencesReply { sequences })
+    }
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError> {
         debug!(req :? =(&req); "SchemaApi: {}", func_name!());
 
@@ -31,6 +31,8 @@ pub(crate) const ID_GEN_BACKGROUND_JOB: &str = "background_job";
 
 pub(crate) const ID_GEN_PROCEDURE: &str = "procedure_id";
 
+pub(crate) const ID_GEN_TASK: &str = "task_id";
+
 /// Key for resource id generator
 ///
 /// This is a special key for an application to generate unique id with kvapi::KVApi.
@@ -120,6 +122,13 @@ impl IdGenerator {
             resource: ID_GEN_PROCEDURE.to_string(),
         }
     }
+
+    /// Create a key for generating task id with kvapi::KVApi
+    pub fn task_id() -> Self {
+        Self {
+            resource: ID_GEN_TASK.to_string(),
+        }
+    }
 }
 
 impl kvapi::KeyCodec for IdGenerator {
@@ -244,6 +253,16 @@ mod t {
             assert_eq!(g, t2);
         }
 
+        // Task id generator
+        {
+            let g = IdGenerator::task_id();
+            let k = g.to_string_key();
+            assert_eq!("__fd_id_gen/task_id", k);
+
+            let t2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g, t2);
+        }
+
         Ok(())
     }
 
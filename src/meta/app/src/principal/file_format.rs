@@ -41,6 +41,7 @@ const OPT_NULL_DISPLAY: &str = "null_display";
 const OPT_ESCAPE: &str = "escape";
 const OPT_QUOTE: &str = "quote";
 const OPT_ROW_TAG: &str = "row_tag";
+const OPT_FIELD_SOURCE: &str = "field_source";
 const OPT_ERROR_ON_COLUMN_COUNT_MISMATCH: &str = "error_on_column_count_mismatch";
 const MISSING_FIELD_AS: &str = "missing_field_as";
 const NULL_FIELD_AS: &str = "null_field_as";
@@ -59,6 +60,7 @@ pub enum FileFormatParams {
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
     Orc(OrcFileFormatParams),
+    Avro(AvroFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -71,6 +73,7 @@ impl FileFormatParams {
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
             FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
         }
     }
 
@@ -87,7 +90,11 @@ impl FileFormatParams {
             StageFileFormatType::Json => {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
+            StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
             StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -104,12 +111,14 @@ impl FileFormatParams {
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
             FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Avro(_) => StageFileCompression::None,
         }
     }
 
     pub fn need_field_default(&self) -> bool {
         match self {
             FileFormatParams::Parquet(v) => v.missing_field_as == NullAs::FieldDefault,
+            FileFormatParams::Avro(v) => v.missing_field_as == NullAs::FieldDefault,
             FileFormatParams::Csv(v) => v.empty_field_as == EmptyFieldAs::FieldDefault,
             FileFormatParams::NdJson(v) => {
                 v.null_field_as == NullAs::FieldDefault
@@ -126,9 +135,16 @@ impl FileFormatParams {
                 let default = XmlFileFormatParams::default();
                 let row_tag = reader.take_string(OPT_ROW_TAG, default.row_tag);
                 let compression = reader.take_compression()?;
+                let field_source = reader
+                    .options
+                    .remove(OPT_FIELD_SOURCE)
+                    .map(|s| XmlFieldSource::from_str(&s))
+                    .transpose()?
+                    .unwrap_or_default();
                 FileFormatParams::Xml(XmlFileFormatParams {
                     compression,
                     row_tag,
+                    field_source,
                 })
             }
             StageFileFormatType::Json => {
@@ -161,6 +177,14 @@ impl FileFormatParams {
                     missing_field_as.as_deref(),
                 )?)
             }
+            StageFileFormatType::Avro => {
+                let missing_field_as = reader.options.remove(MISSING_FIELD_AS);
+                let null_if = parse_null_if(reader.options.remove(NULL_IF))?;
+                FileFormatParams::Avro(AvroFileFormatParams::try_create(
+                    missing_field_as.as_deref(),
+                    null_if,
+                )?)
+            }
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = reader.take_compression()?;
@@ -463,6 +487,7 @@ impl TsvFileFormatParams {
 pub struct XmlFileFormatParams {
     pub compression: StageFileCompression,
     pub row_tag: String,
+    pub field_source: XmlFieldSource,
 }
 
 impl XmlFileFormatParams {
@@ -479,6 +504,44 @@ impl Default for XmlFileFormatParams {
         XmlFileFormatParams {
             compression: StageFileCompression::None,
             row_tag: "row".to_string(),
+            field_source: XmlFieldSource::default(),
+        }
+    }
+}
+
+/// Where the fields of a row are read from in the XML file format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum XmlFieldSource {
+    /// the child element with the field name, or the attribute if there is no such element
+    #[default]
+    Auto,
+    /// the child elements of the row element only
+    Element,
+    /// the attributes of the row element only
+    Attribute,
+}
+
+impl FromStr for XmlFieldSource {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "element" => Ok(Self::Element),
+            "attribute" => Ok(Self::Attribute),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid option value: FIELD_SOURCE is set to {s}. The valid values are AUTO | ELEMENT | ATTRIBUTE."
+            ))),
+        }
+    }
+}
+
+impl Display for XmlFieldSource {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "AUTO"),
+            Self::Element => write!(f, "ELEMENT"),
+            Self::Attribute => write!(f, "ATTRIBUTE"),
         }
     }
 }
@@ -696,6 +759,22 @@ impl OrcFileFormatParams {
     }
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub missing_field_as: NullAs,
+    pub null_if: Vec<String>,
+}
+
+impl AvroFileFormatParams {
+    pub fn try_create(missing_field_as: Option<&str>, null_if: Vec<String>) -> Result<Self> {
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        Ok(Self {
+            missing_field_as,
+            null_if,
+        })
+    }
+}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
@@ -740,8 +819,8 @@ impl Display for FileFormatParams {
             FileFormatParams::Xml(params) => {
                 write!(
                     f,
-                    "TYPE = XML COMPRESSION = {:?} ROW_TAG = '{}'",
-                    params.compression, params.row_tag
+                    "TYPE = XML COMPRESSION = {:?} ROW_TAG = '{}' FIELD_SOURCE = {}",
+                    params.compression, params.row_tag, params.field_source
                 )
             }
             FileFormatParams::Json(params) => {
@@ -768,6 +847,19 @@ impl Display for FileFormatParams {
                     params.missing_field_as
                 )
             }
+            FileFormatParams::Avro(params) => {
+                let null_if = params
+                    .null_if
+                    .iter()
+                    .map(|s| format!("'{}'", escape_string(s)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "TYPE = AVRO MISSING_FIELD_AS = {} NULL_IF = ({})",
+                    params.missing_field_as, null_if
+                )
+            }
         }
     }
 }
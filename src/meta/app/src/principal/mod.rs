@@ -19,10 +19,13 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod pipe;
 mod principal_identity;
 pub mod role_ident;
 mod role_info;
+mod secret;
 mod stage_file_path;
+mod task;
 pub mod udf_ident;
 mod user_auth;
 mod user_defined_file_format;
@@ -34,6 +37,7 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 mod ownership_object;
 
@@ -42,12 +46,16 @@ pub mod client_session_ident;
 pub mod connection_ident;
 pub mod network_policy_ident;
 pub mod password_policy_ident;
+pub mod pipe_ident;
 pub mod procedure;
 pub mod procedure_id_ident;
 pub mod procedure_id_to_name;
 pub mod procedure_identity;
 pub mod procedure_name_ident;
+pub mod secret_ident;
 pub mod stage_file_ident;
+pub mod task_ident;
+pub mod task_run_ident;
 pub mod tenant_ownership_object_ident;
 pub mod tenant_user_ident;
 pub mod user_defined_file_format_ident;
@@ -55,6 +63,7 @@ pub mod user_setting_ident;
 pub mod user_stage_ident;
 pub mod user_token;
 pub mod user_token_ident;
+pub mod workload_group_ident;
 
 pub use connection::*;
 pub use file_format::*;
@@ -64,6 +73,8 @@ pub use ownership_info::OwnershipInfo;
 pub use ownership_object::OwnershipObject;
 pub use password_policy::PasswordPolicy;
 pub use password_policy_ident::PasswordPolicyIdent;
+pub use pipe::PipeInfo;
+pub use pipe_ident::PipeIdent;
 pub use principal_identity::PrincipalIdentity;
 pub use procedure::CreateProcedureReply;
 pub use procedure::CreateProcedureReq;
@@ -84,8 +95,17 @@ pub use role_ident::RoleIdent;
 pub use role_ident::RoleIdentRaw;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use secret::UserDefinedSecret;
+pub use secret_ident::SecretIdent;
 pub use stage_file_ident::StageFileIdent;
 pub use stage_file_path::StageFilePath;
+pub use task::TaskInfo;
+pub use task::TaskRunInfo;
+pub use task::TaskRunState;
+pub use task::TaskSchedule;
+pub use task::TaskStatus;
+pub use task_ident::TaskIdent;
+pub use task_run_ident::TaskRunIdent;
 pub use tenant_ownership_object_ident::TenantOwnershipObjectIdent;
 pub use tenant_user_ident::TenantUserIdent;
 pub use udf_ident::UdfIdent;
@@ -114,3 +134,6 @@ pub use user_setting::UserSettingValue;
 pub use user_setting_ident::SettingIdent;
 pub use user_stage::*;
 pub use user_stage_ident::StageIdent;
+pub use workload_group::WorkloadGroup;
+pub use workload_group::DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT;
+pub use workload_group_ident::WorkloadGroupIdent;
@@ -19,7 +19,7 @@ use databend_common_meta_kvapi::kvapi::KeyCodec;
 
 /// [`OwnershipObject`] is used to maintain the grant object that support rename by id. Using ID over name
 /// have many benefits, it can avoid lost privileges after the object get renamed.
-/// But Stage and UDF do not support the concept of renaming and do not have ids, so names can be used.
+/// But Stage, UDF and Secret do not support the concept of renaming and do not have ids, so names can be used.
 ///
 /// It could be a tenant's database, a tenant's table etc.
 /// It is in form of `__fd_object_owners/<tenant>/<object>`.
@@ -30,6 +30,7 @@ use databend_common_meta_kvapi::kvapi::KeyCodec;
 /// - `table-by-catalog-id/<catalog>/<table_id>`
 /// - `stage-by-name/<stage_name>`
 /// - `udf-by-name/<udf_name>`
+/// - `secret-by-name/<secret_name>`
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum OwnershipObject {
     /// used on the fuse databases
@@ -52,6 +53,10 @@ pub enum OwnershipObject {
     UDF {
         name: String,
     },
+
+    Secret {
+        name: String,
+    },
 }
 
 impl OwnershipObject {
@@ -80,6 +85,7 @@ impl fmt::Display for OwnershipObject {
             }
             OwnershipObject::UDF { name } => write!(f, "UDF {name}"),
             OwnershipObject::Stage { name } => write!(f, "STAGE {name}"),
+            OwnershipObject::Secret { name } => write!(f, "SECRET {name}"),
         }
     }
 }
@@ -119,6 +125,7 @@ impl KeyCodec for OwnershipObject {
             }
             OwnershipObject::Stage { name } => b.push_raw("stage-by-name").push_str(name),
             OwnershipObject::UDF { name } => b.push_raw("udf-by-name").push_str(name),
+            OwnershipObject::Secret { name } => b.push_raw("secret-by-name").push_str(name),
         }
     }
 
@@ -165,9 +172,13 @@ impl KeyCodec for OwnershipObject {
                 let name = p.next_str()?;
                 Ok(OwnershipObject::UDF { name })
             }
+            "secret-by-name" => {
+                let name = p.next_str()?;
+                Ok(OwnershipObject::Secret { name })
+            }
             _ => Err(kvapi::KeyError::InvalidSegment {
                 i: p.index(),
-                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name"
+                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name|secret-by-name"
                     .to_string(),
                 got: q.to_string(),
             }),
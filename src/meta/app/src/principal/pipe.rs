@@ -0,0 +1,32 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A pipe continuously loads files from a stage into a table by running
+/// the wrapped `COPY INTO <table>` statement.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub name: String,
+    /// The wrapped `COPY INTO` statement, with the target table fully qualified.
+    pub copy_stmt: String,
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+    /// The role that created the pipe, the scheduled loads run with its privileges.
+    pub owner: String,
+}
@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A secret stored in meta, which can be referenced by name in SQL, e.g. as the key
+/// of the encryption functions, so that its value never appears in the query text.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UserDefinedSecret {
+    pub name: String,
+    pub value: String,
+    pub comment: Option<String>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl UserDefinedSecret {
+    pub fn new(name: &str, value: String, comment: Option<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+            comment,
+            created_on: Utc::now(),
+        }
+    }
+}
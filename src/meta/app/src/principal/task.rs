@@ -0,0 +1,238 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use cron::Schedule;
+
+/// When a root task is triggered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TaskSchedule {
+    Interval {
+        secs: u64,
+        millis: u64,
+    },
+    /// A cron expression, evaluated in `time_zone`, or UTC if it is absent.
+    Cron {
+        expr: String,
+        time_zone: Option<String>,
+    },
+}
+
+impl TaskSchedule {
+    /// Checks the cron expression and the time zone, a cron schedule must have an upcoming time.
+    pub fn check(&self) -> std::result::Result<(), String> {
+        match self {
+            TaskSchedule::Interval { .. } => Ok(()),
+            TaskSchedule::Cron { expr, time_zone } => {
+                let (schedule, tz) = parse_cron(expr, time_zone.as_deref())?;
+                if schedule.upcoming(tz).next().is_none() {
+                    return Err(format!("cron expression {} has no upcoming time", expr));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the next time to trigger the task after `last`.
+    ///
+    /// Returns `None` if there is no upcoming time, the schedule is checked by
+    /// [`TaskSchedule::check`] when the task is created or altered.
+    pub fn next_run_after(&self, last: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            TaskSchedule::Interval { secs, millis } => Some(
+                last + chrono::Duration::seconds(*secs as i64)
+                    + chrono::Duration::milliseconds(*millis as i64),
+            ),
+            TaskSchedule::Cron { expr, time_zone } => {
+                let (schedule, tz) = parse_cron(expr, time_zone.as_deref()).ok()?;
+                let upcoming = schedule.after(&last.with_timezone(&tz)).next()?;
+                Some(upcoming.with_timezone(&Utc))
+            }
+        }
+    }
+}
+
+fn parse_cron(
+    expr: &str,
+    time_zone: Option<&str>,
+) -> std::result::Result<(Schedule, chrono_tz::Tz), String> {
+    let schedule =
+        Schedule::from_str(expr).map_err(|e| format!("invalid cron expression {}: {}", expr, e))?;
+    let tz = match time_zone {
+        Some(tz) => chrono_tz::Tz::from_str(tz).map_err(|_| format!("invalid time zone {}", tz))?,
+        None => chrono_tz::UTC,
+    };
+    Ok((schedule, tz))
+}
+
+impl Display for TaskSchedule {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TaskSchedule::Interval { secs, millis } => {
+                write!(f, "INTERVAL {} SECOND", secs)?;
+                if *millis != 0 {
+                    write!(f, " {} MILLISECOND", millis)?;
+                }
+                Ok(())
+            }
+            TaskSchedule::Cron { expr, time_zone } => {
+                write!(f, "CRON {}", expr)?;
+                if let Some(time_zone) = time_zone {
+                    write!(f, " TIMEZONE {}", time_zone)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, num_derive::FromPrimitive)]
+pub enum TaskStatus {
+    #[default]
+    Suspended = 0,
+    Started = 1,
+}
+
+impl Display for TaskStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A task executed by the task scheduler embedded in databend-query,
+/// used when cloud control is not configured.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskInfo {
+    pub task_id: u64,
+    pub task_name: String,
+    /// The SQL of the task as it is written after `AS`.
+    pub query_text: String,
+    /// The statements of a `BEGIN ... END` script, empty if the task is a single statement.
+    pub script_sqls: Vec<String>,
+    pub when_condition: Option<String>,
+    /// The predecessors, the task is triggered once all of them succeeded.
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+    /// The role owning the task, the task is executed with the privileges of this role.
+    pub owner: String,
+    /// Only a root task, i.e. a task without predecessors, has a schedule.
+    pub schedule: Option<TaskSchedule>,
+    pub warehouse: Option<String>,
+    pub suspend_task_after_num_failures: Option<u64>,
+    pub error_integration: Option<String>,
+    pub session_params: BTreeMap<String, String>,
+    pub status: TaskStatus,
+    /// The number of runs that failed in a row.
+    pub consecutive_failures: u64,
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+    pub last_suspended_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskInfo {
+    /// The statements to execute for one run of the task.
+    pub fn statements(&self) -> Vec<String> {
+        if self.script_sqls.is_empty() {
+            vec![self.query_text.clone()]
+        } else {
+            self.script_sqls.clone()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, num_derive::FromPrimitive)]
+pub enum TaskRunState {
+    #[default]
+    Scheduled = 0,
+    Executing = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+}
+
+impl Display for TaskRunState {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TaskRunState::Scheduled => write!(f, "SCHEDULED"),
+            TaskRunState::Executing => write!(f, "EXECUTING"),
+            TaskRunState::Succeeded => write!(f, "SUCCEEDED"),
+            TaskRunState::Failed => write!(f, "FAILED"),
+            TaskRunState::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// One run of a [`TaskInfo`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskRunInfo {
+    pub run_id: String,
+    /// The definition of the task when the run is triggered.
+    pub task: TaskInfo,
+    pub query_id: String,
+    pub state: TaskRunState,
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    /// The root of the task graph which triggered this run.
+    pub root_task_id: u64,
+    pub scheduled_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono::Utc;
+
+    use super::TaskSchedule;
+
+    #[test]
+    fn test_task_schedule_next_run_after() {
+        let last = Utc.with_ymd_and_hms(2024, 9, 27, 10, 30, 0).unwrap();
+
+        let interval = TaskSchedule::Interval {
+            secs: 90,
+            millis: 500,
+        };
+        assert_eq!(
+            Some(
+                Utc.timestamp_millis_opt(last.timestamp_millis() + 90_500)
+                    .unwrap()
+            ),
+            interval.next_run_after(last)
+        );
+
+        let cron = TaskSchedule::Cron {
+            expr: "0 0 12 * * *".to_string(),
+            time_zone: Some("Asia/Shanghai".to_string()),
+        };
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2024, 9, 28, 4, 0, 0).unwrap()),
+            cron.next_run_after(last)
+        );
+
+        let invalid = TaskSchedule::Cron {
+            expr: "0 0 12 * * *".to_string(),
+            time_zone: Some("Mars/Base".to_string()),
+        };
+        assert_eq!(None, invalid.next_run_after(last));
+    }
+}
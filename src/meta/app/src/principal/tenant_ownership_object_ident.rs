@@ -48,6 +48,7 @@ impl TenantOwnershipObjectIdent {
         //     }
         //     OwnershipObject::Stage { .. } => {}
         //     OwnershipObject::UDF { .. } => {}
+        //     OwnershipObject::Secret { .. } => {}
         // }
 
         Self::new_unchecked(tenant, subject)
@@ -254,6 +255,22 @@ mod tests {
             let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
             assert_eq!(role_grantee, parsed);
         }
+
+        // secret
+        {
+            let role_grantee = TenantOwnershipObjectIdent::new_unchecked(
+                Tenant::new_literal("test"),
+                OwnershipObject::Secret {
+                    name: "foo".to_string(),
+                },
+            );
+
+            let key = role_grantee.to_string_key();
+            assert_eq!("__fd_object_owners/test/secret-by-name/foo", key);
+
+            let parsed = TenantOwnershipObjectIdent::from_str_key(&key).unwrap();
+            assert_eq!(role_grantee, parsed);
+        }
     }
 
     #[test]
@@ -189,6 +189,7 @@ pub struct UserOption {
     password_policy: Option<String>,
     disabled: Option<bool>,
     must_change_password: Option<bool>,
+    workload_group: Option<String>,
 }
 
 impl UserOption {
@@ -200,6 +201,7 @@ impl UserOption {
             password_policy: None,
             disabled: None,
             must_change_password: None,
+            workload_group: None,
         }
     }
 
@@ -237,6 +239,11 @@ impl UserOption {
         self
     }
 
+    pub fn with_workload_group(mut self, workload_group: Option<String>) -> Self {
+        self.workload_group = workload_group;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -266,6 +273,10 @@ impl UserOption {
         self.must_change_password.as_ref()
     }
 
+    pub fn workload_group(&self) -> Option<&String> {
+        self.workload_group.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -286,6 +297,10 @@ impl UserOption {
         self.must_change_password = must_change_password;
     }
 
+    pub fn set_workload_group(&mut self, workload_group: Option<String>) {
+        self.workload_group = workload_group;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -326,6 +341,8 @@ impl UserOption {
             UserOptionItem::UnsetPasswordPolicy => self.password_policy = None,
             UserOptionItem::Disabled(v) => self.disabled = Some(*v),
             UserOptionItem::MustChangePassword(v) => self.must_change_password = Some(*v),
+            UserOptionItem::SetWorkloadGroup(v) => self.workload_group = Some(v.clone()),
+            UserOptionItem::UnsetWorkloadGroup => self.workload_group = None,
         }
     }
 }
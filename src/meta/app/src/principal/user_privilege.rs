@@ -240,6 +240,10 @@ impl UserPrivilegeSet {
         }
     }
 
+    pub fn available_privileges_on_secret(available_ownership: bool) -> Self {
+        Self::available_privileges_on_udf(available_ownership)
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::DateTime;
+use chrono::Utc;
+
+/// The cpu weight of a workload group which is created without `CPU_WEIGHT`.
+pub const DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT: u64 = 100;
+
+/// A named workload group: the queries of the users and roles bound to it are admitted
+/// through a dedicated queue and share its resource quotas.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// Max number of queries of the group running at the same time, `None` means unlimited.
+    pub max_concurrency: Option<u64>,
+    /// Max memory usage in bytes of a single query of the group.
+    pub query_memory_limit: Option<u64>,
+    /// Max memory usage in bytes of all the running queries of the group.
+    pub memory_limit: Option<u64>,
+    /// Relative share of the executor cpu time against the other groups.
+    pub cpu_weight: u64,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+}
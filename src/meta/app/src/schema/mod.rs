@@ -115,6 +115,7 @@ pub use table::TableIdList;
 pub use table::TableIdToName;
 pub use table::TableIdent;
 pub use table::TableIndex;
+pub use table::TableIndexType;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
@@ -17,6 +17,7 @@ use chrono::Utc;
 pub use kvapi_impl::SequenceRsc;
 
 use super::CreateOption;
+use crate::tenant::Tenant;
 use crate::tenant_key::ident::TIdent;
 
 /// Defines the meta-service key for sequence.
@@ -30,6 +31,8 @@ pub struct SequenceMeta {
     pub start: u64,
     pub step: i64,
     pub current: u64,
+    /// How many values a query node allocates from the meta-service at once.
+    pub cache: u64,
 }
 
 impl From<CreateSequenceReq> for SequenceMeta {
@@ -38,9 +41,10 @@ impl From<CreateSequenceReq> for SequenceMeta {
             comment: p.comment.clone(),
             create_on: p.create_on,
             update_on: p.create_on,
-            start: 1,
-            step: 1,
-            current: 1,
+            start: p.start,
+            step: p.step,
+            current: p.start,
+            cache: p.cache,
         }
     }
 }
@@ -51,11 +55,39 @@ pub struct CreateSequenceReq {
     pub ident: SequenceIdent,
     pub create_on: DateTime<Utc>,
     pub comment: Option<String>,
+    pub start: u64,
+    pub step: i64,
+    pub cache: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateSequenceReply {}
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterSequenceReq {
+    pub if_exists: bool,
+    pub ident: SequenceIdent,
+    pub update_on: DateTime<Utc>,
+    /// `Some(None)` restarts the sequence from its start value.
+    pub restart: Option<Option<u64>>,
+    pub step: Option<i64>,
+    pub cache: Option<u64>,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterSequenceReply {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListSequencesReq {
+    pub tenant: Tenant,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListSequencesReply {
+    pub sequences: Vec<(String, SequenceMeta)>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GetSequenceNextValueReq {
     pub ident: SequenceIdent,
@@ -65,9 +97,10 @@ pub struct GetSequenceNextValueReq {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GetSequenceNextValueReply {
     pub start: u64,
-    // step has no use until now
     pub step: i64,
     pub end: u64,
+    /// The cache size of the sequence when the values are allocated.
+    pub cache: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
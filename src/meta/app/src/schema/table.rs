@@ -280,8 +280,35 @@ pub struct TableMeta {
     pub indexes: BTreeMap<String, TableIndex>,
 }
 
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableIndexType {
+    #[default]
+    Inverted = 0,
+    Vector = 1,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+            TableIndexType::Vector => write!(f, "VECTOR"),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TableIndex {
+    pub index_type: TableIndexType,
     pub name: String,
     pub column_ids: Vec<u32>,
     // if true, index will create after data written to databend,
@@ -846,6 +873,7 @@ pub struct CreateTableIndexReq {
     pub create_option: CreateOption,
     pub tenant: Tenant,
     pub table_id: u64,
+    pub index_type: TableIndexType,
     pub name: String,
     pub column_ids: Vec<u32>,
     pub sync_creation: bool,
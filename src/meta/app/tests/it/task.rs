@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::TaskSchedule;
+
+#[test]
+fn test_task_schedule_check() {
+    let cron = |expr: &str, time_zone: Option<&str>| TaskSchedule::Cron {
+        expr: expr.to_string(),
+        time_zone: time_zone.map(|tz| tz.to_string()),
+    };
+    assert!(cron("0 30 9 * * *", None).check().is_ok());
+    assert!(cron("0 30 9 * * *", Some("Asia/Shanghai")).check().is_ok());
+    assert!(cron("0 30 9 * *", None).check().is_err());
+    assert!(cron("0 30 9 * * *", Some("Mars/Olympus")).check().is_err());
+    // The schedule has no upcoming time.
+    assert!(cron("0 0 0 1 1 * 2000", None).check().is_err());
+}
+
+#[test]
+fn test_task_schedule_next_run_after() {
+    let last = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+    let interval = TaskSchedule::Interval {
+        secs: 10,
+        millis: 500,
+    };
+    assert_eq!(
+        interval.next_run_after(last),
+        Some(last + chrono::Duration::milliseconds(10_500))
+    );
+
+    // 09:30 in Shanghai is 01:30 in UTC.
+    let cron = TaskSchedule::Cron {
+        expr: "0 30 9 * * *".to_string(),
+        time_zone: Some("Asia/Shanghai".to_string()),
+    };
+    assert_eq!(
+        cron.next_run_after(last),
+        Some(Utc.with_ymd_and_hms(2024, 1, 1, 1, 30, 0).unwrap())
+    );
+    let cron = TaskSchedule::Cron {
+        expr: "0 30 9 * * *".to_string(),
+        time_zone: None,
+    };
+    assert_eq!(
+        cron.next_run_after(last),
+        Some(Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap())
+    );
+}
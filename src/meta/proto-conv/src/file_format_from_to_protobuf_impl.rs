@@ -21,6 +21,7 @@ use databend_common_io::GeometryDataType;
 use databend_common_meta_app as mt;
 use databend_common_meta_app::principal::BinaryFormat;
 use databend_common_meta_app::principal::EmptyFieldAs;
+use databend_common_meta_app::principal::XmlFieldSource;
 use databend_common_protos::pb;
 use num::FromPrimitive;
 
@@ -249,6 +250,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -292,6 +298,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::OrcFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -321,6 +332,31 @@ impl FromToProto for mt::principal::OrcFileFormatParams {
     }
 }
 
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        mt::principal::AvroFileFormatParams::try_create(p.missing_field_as.as_deref(), p.null_if)
+            .map_err(|e| Incompatible {
+                reason: format!("{e}"),
+            })
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+            null_if: self.null_if.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::principal::ParquetFileFormatParams {
     type PB = pb::ParquetFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -428,9 +464,18 @@ impl FromToProto for mt::principal::XmlFileFormatParams {
                 reason: format!("invalid StageFileCompression: {}", p.compression),
             })?,
         )?;
+        let field_source = p
+            .field_source
+            .map(|s| XmlFieldSource::from_str(&s))
+            .transpose()
+            .map_err(|e| Incompatible {
+                reason: format!("{:?}", e),
+            })?
+            .unwrap_or_default();
         Ok(Self {
             compression,
             row_tag: p.row_tag,
+            field_source,
         })
     }
 
@@ -442,6 +487,7 @@ impl FromToProto for mt::principal::XmlFileFormatParams {
             min_reader_ver: MIN_READER_VER,
             compression,
             row_tag: self.row_tag.clone(),
+            field_source: Some(self.field_source.to_string()),
         })
     }
 }
@@ -90,6 +90,9 @@ impl FromToProto for mt::principal::OwnershipObject {
             pb::ownership_object::Object::Stage(pb::ownership_object::OwnershipStageObject {
                 stage,
             }) => Ok(mt::principal::OwnershipObject::Stage { name: stage }),
+            pb::ownership_object::Object::Secret(pb::ownership_object::OwnershipSecretObject {
+                secret,
+            }) => Ok(mt::principal::OwnershipObject::Secret { name: secret }),
         }
     }
 
@@ -125,6 +128,11 @@ impl FromToProto for mt::principal::OwnershipObject {
                     stage: name.clone(),
                 }),
             ),
+            mt::principal::OwnershipObject::Secret { name } => Some(
+                pb::ownership_object::Object::Secret(pb::ownership_object::OwnershipSecretObject {
+                    secret: name.clone(),
+                }),
+            ),
         };
         Ok(pb::OwnershipObject {
             ver: VER,
@@ -259,6 +259,7 @@ impl FromToProto for ex::TableDataType {
                     Dt24::VariantT(_) => ex::TableDataType::Variant,
                     Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                     Dt24::GeographyT(_) => ex::TableDataType::Geography,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
                     Dt24::DecimalT(x) => {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
@@ -326,6 +327,7 @@ impl FromToProto for ex::TableDataType {
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
             TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Geography => new_pb_dt24(Dt24::GeographyT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
         };
         Ok(x)
     }
@@ -0,0 +1,165 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_protos::pb;
+use num::FromPrimitive;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::TaskInfo {
+    type PB = pb::TaskInfo;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let schedule = match (p.cron, p.interval_secs) {
+            (Some(expr), _) => Some(mt::TaskSchedule::Cron {
+                expr,
+                time_zone: p.time_zone,
+            }),
+            (None, Some(secs)) => Some(mt::TaskSchedule::Interval {
+                secs,
+                millis: p.interval_millis.unwrap_or_default(),
+            }),
+            (None, None) => None,
+        };
+
+        Ok(Self {
+            task_id: p.task_id,
+            task_name: p.task_name,
+            query_text: p.query_text,
+            script_sqls: p.script_sqls,
+            when_condition: p.when_condition,
+            after: p.after,
+            comment: p.comment,
+            owner: p.owner,
+            schedule,
+            warehouse: p.warehouse,
+            suspend_task_after_num_failures: p.suspend_task_after_num_failures,
+            error_integration: p.error_integration,
+            session_params: p.session_params,
+            status: FromPrimitive::from_i32(p.status).ok_or_else(|| Incompatible {
+                reason: format!("invalid TaskStatus: {}", p.status),
+            })?,
+            consecutive_failures: p.consecutive_failures,
+            next_scheduled_at: p
+                .next_scheduled_at
+                .map(DateTime::<Utc>::from_pb)
+                .transpose()?,
+            last_suspended_at: p
+                .last_suspended_at
+                .map(DateTime::<Utc>::from_pb)
+                .transpose()?,
+            created_at: DateTime::<Utc>::from_pb(p.created_at)?,
+            updated_at: DateTime::<Utc>::from_pb(p.updated_at)?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let (interval_secs, interval_millis, cron, time_zone) = match &self.schedule {
+            Some(mt::TaskSchedule::Interval { secs, millis }) => {
+                (Some(*secs), Some(*millis), None, None)
+            }
+            Some(mt::TaskSchedule::Cron { expr, time_zone }) => {
+                (None, None, Some(expr.clone()), time_zone.clone())
+            }
+            None => (None, None, None, None),
+        };
+
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task_id: self.task_id,
+            task_name: self.task_name.clone(),
+            query_text: self.query_text.clone(),
+            script_sqls: self.script_sqls.clone(),
+            when_condition: self.when_condition.clone(),
+            after: self.after.clone(),
+            comment: self.comment.clone(),
+            owner: self.owner.clone(),
+            interval_secs,
+            interval_millis,
+            cron,
+            time_zone,
+            warehouse: self.warehouse.clone(),
+            suspend_task_after_num_failures: self.suspend_task_after_num_failures,
+            error_integration: self.error_integration.clone(),
+            session_params: self.session_params.clone(),
+            status: self.status as i32,
+            consecutive_failures: self.consecutive_failures,
+            next_scheduled_at: self.next_scheduled_at.map(|t| t.to_pb()).transpose()?,
+            last_suspended_at: self.last_suspended_at.map(|t| t.to_pb()).transpose()?,
+            created_at: self.created_at.to_pb()?,
+            updated_at: self.updated_at.to_pb()?,
+        })
+    }
+}
+
+impl FromToProto for mt::TaskRunInfo {
+    type PB = pb::TaskRunInfo;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let task = p.task.ok_or_else(|| Incompatible {
+            reason: "TaskRunInfo.task can not be None".to_string(),
+        })?;
+
+        Ok(Self {
+            run_id: p.run_id,
+            task: mt::TaskInfo::from_pb(task)?,
+            query_id: p.query_id,
+            state: FromPrimitive::from_i32(p.state).ok_or_else(|| Incompatible {
+                reason: format!("invalid TaskRunState: {}", p.state),
+            })?,
+            error_code: p.error_code,
+            error_message: p.error_message,
+            root_task_id: p.root_task_id,
+            scheduled_at: DateTime::<Utc>::from_pb(p.scheduled_at)?,
+            completed_at: p.completed_at.map(DateTime::<Utc>::from_pb).transpose()?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            run_id: self.run_id.clone(),
+            task: Some(self.task.to_pb()?),
+            query_id: self.query_id.clone(),
+            state: self.state as i32,
+            error_code: self.error_code,
+            error_message: self.error_message.clone(),
+            root_task_id: self.root_task_id,
+            scheduled_at: self.scheduled_at.to_pb()?,
+            completed_at: self.completed_at.map(|t| t.to_pb()).transpose()?,
+        })
+    }
+}
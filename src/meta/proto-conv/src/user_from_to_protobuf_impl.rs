@@ -106,7 +106,8 @@ impl FromToProto for mt::principal::UserOption {
             .with_network_policy(p.network_policy)
             .with_password_policy(p.password_policy)
             .with_disabled(p.disabled)
-            .with_must_change_password(p.must_change_password))
+            .with_must_change_password(p.must_change_password)
+            .with_workload_group(p.workload_group))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -119,6 +120,7 @@ impl FromToProto for mt::principal::UserOption {
             password_policy: self.password_policy().cloned(),
             disabled: self.disabled().cloned(),
             must_change_password: self.must_change_password().cloned(),
+            workload_group: self.workload_group().cloned(),
         })
     }
 }
@@ -193,6 +195,9 @@ impl FromToProto for mt::principal::GrantObject {
             pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage }) => {
                 Ok(mt::principal::GrantObject::Stage(stage))
             }
+            pb::grant_object::Object::Secret(pb::grant_object::GrantSecretObject { secret }) => {
+                Ok(mt::principal::GrantObject::Secret(secret))
+            }
         }
     }
 
@@ -235,6 +240,11 @@ impl FromToProto for mt::principal::GrantObject {
                     stage: stage.clone(),
                 },
             )),
+            mt::principal::GrantObject::Secret(secret) => Some(pb::grant_object::Object::Secret(
+                pb::grant_object::GrantSecretObject {
+                    secret: secret.clone(),
+                },
+            )),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -140,6 +140,17 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (108, "2024-08-29: Add: procedure.proto: ProcedureMeta and ProcedureIdentity"),
     (109, "2024-08-29: Refactor: ProcedureMeta add arg_names"),
     (110, "2024-09-18: Add: database.proto: DatabaseMeta.gc_in_progress"),
+    (111, "2024-09-20: Add: file_format.proto: AvroFileFormatParams"),
+    (112, "2024-09-24: Add: pipe.proto: PipeInfo"),
+    (113, "2024-09-27: Add: task.proto: TaskInfo and TaskRunInfo"),
+    (114, "2024-09-29: Add: table.proto: TableIndex.index_type"),
+    (115, "2024-10-02: Add: workload_group.proto: WorkloadGroup; user.proto: UserOption.workload_group; role.proto: RoleInfo.workload_group"),
+    (116, "2024-10-05: Add: sequence.proto: SequenceMeta.cache"),
+    (117, "2024-10-08: Add: datatype.proto: DataType.interval_t"),
+    (118, "2024-10-10: Add: secret.proto: UserDefinedSecret"),
+    (119, "2024-10-12: Add: pipe.proto: PipeInfo.owner"),
+    (120, "2024-10-14: Add: user.proto: GrantObject.secret; ownership.proto: OwnershipObject.secret"),
+    (121, "2024-10-16: Add: file_format.proto: XmlFileFormatParams.field_source"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -108,3 +108,14 @@ mod v107_geography_datatype;
 mod v108_procedure;
 mod v109_procedure_with_args;
 mod v110_database_meta_gc_in_progress;
+mod v111_avro_format_params;
+mod v112_pipe;
+mod v113_task;
+mod v114_vector_index;
+mod v115_workload_group;
+mod v116_sequence_cache;
+mod v117_interval_datatype;
+mod v118_secret;
+mod v119_pipe_owner;
+mod v120_secret_grant_object;
+mod v121_xml_field_source;
@@ -83,6 +83,7 @@ fn new_sequence_meta() -> mt::SequenceMeta {
         start: 1,
         step: 1,
         current: 10,
+        cache: 1,
     }
 }
 
@@ -23,6 +23,7 @@ use crate::common;
 use crate::v032_file_format_params::mt::principal::JsonFileFormatParams;
 use crate::v032_file_format_params::mt::principal::NdJsonFileFormatParams;
 use crate::v032_file_format_params::mt::principal::ParquetFileFormatParams;
+use crate::v032_file_format_params::mt::principal::XmlFieldSource;
 use crate::v032_file_format_params::mt::principal::XmlFileFormatParams;
 
 // These bytes are built when a new version in introduced,
@@ -125,6 +126,7 @@ fn test_decode_v32_xml_file_format_params() -> anyhow::Result<()> {
         mt::principal::FileFormatParams::Xml(XmlFileFormatParams {
             compression: StageFileCompression::Gzip,
             row_tag: "row_tag".to_string(),
+            field_source: XmlFieldSource::Auto,
         })
     };
     common::test_load_old(func_name!(), file_format_params_v32.as_slice(), 0, want())?;
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v113_task_info() -> anyhow::Result<()> {
+    let task_info_v113 = vec![
+        8, 7, 18, 2, 116, 49, 26, 8, 83, 69, 76, 69, 67, 84, 32, 49, 42, 5, 49, 32, 61, 32, 49, 50,
+        2, 116, 48, 58, 2, 99, 49, 66, 5, 114, 111, 108, 101, 49, 90, 11, 48, 32, 48, 32, 42, 32,
+        42, 32, 42, 32, 42, 98, 13, 65, 115, 105, 97, 47, 83, 104, 97, 110, 103, 104, 97, 105, 112,
+        3, 130, 1, 15, 10, 8, 116, 105, 109, 101, 122, 111, 110, 101, 18, 3, 85, 84, 67, 136, 1, 1,
+        144, 1, 2, 154, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58,
+        48, 57, 32, 85, 84, 67, 170, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 178, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32,
+        49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 113, 168, 6, 24,
+    ];
+
+    let want = || mt::TaskInfo {
+        task_id: 7,
+        task_name: "t1".to_string(),
+        query_text: "SELECT 1".to_string(),
+        script_sqls: vec![],
+        when_condition: Some("1 = 1".to_string()),
+        after: vec!["t0".to_string()],
+        comment: Some("c1".to_string()),
+        owner: "role1".to_string(),
+        schedule: Some(mt::TaskSchedule::Cron {
+            expr: "0 0 * * * *".to_string(),
+            time_zone: Some("Asia/Shanghai".to_string()),
+        }),
+        warehouse: None,
+        suspend_task_after_num_failures: Some(3),
+        error_integration: None,
+        session_params: BTreeMap::from([("timezone".to_string(), "UTC".to_string())]),
+        status: mt::TaskStatus::Started,
+        consecutive_failures: 2,
+        next_scheduled_at: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+        last_suspended_at: None,
+        created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+    };
+
+    common::test_load_old(func_name!(), task_info_v113.as_slice(), 113, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v113_task_run_info() -> anyhow::Result<()> {
+    let task_run_info_v113 = vec![
+        10, 2, 114, 49, 18, 166, 1, 8, 8, 18, 2, 116, 50, 26, 38, 66, 69, 71, 73, 78, 10, 32, 32,
+        32, 32, 83, 69, 76, 69, 67, 84, 32, 49, 59, 10, 32, 32, 32, 32, 83, 69, 76, 69, 67, 84, 32,
+        50, 59, 10, 69, 78, 68, 59, 34, 8, 83, 69, 76, 69, 67, 84, 32, 49, 34, 8, 83, 69, 76, 69,
+        67, 84, 32, 50, 66, 5, 114, 111, 108, 101, 49, 72, 60, 80, 244, 3, 106, 2, 119, 104, 162,
+        1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 170, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48,
+        57, 32, 85, 84, 67, 178, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48,
+        48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 113, 168, 6, 24, 26, 2, 113, 49, 32, 3, 40, 238, 7,
+        50, 3, 98, 97, 100, 56, 8, 66, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 74, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49,
+        50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 160, 6, 113, 168, 6, 24,
+    ];
+
+    let want = || mt::TaskRunInfo {
+        run_id: "r1".to_string(),
+        task: mt::TaskInfo {
+            task_id: 8,
+            task_name: "t2".to_string(),
+            query_text: "BEGIN\n    SELECT 1;\n    SELECT 2;\nEND;".to_string(),
+            script_sqls: vec!["SELECT 1".to_string(), "SELECT 2".to_string()],
+            when_condition: None,
+            after: vec![],
+            comment: None,
+            owner: "role1".to_string(),
+            schedule: Some(mt::TaskSchedule::Interval {
+                secs: 60,
+                millis: 500,
+            }),
+            warehouse: Some("wh".to_string()),
+            suspend_task_after_num_failures: None,
+            error_integration: None,
+            session_params: BTreeMap::new(),
+            status: mt::TaskStatus::Suspended,
+            consecutive_failures: 0,
+            next_scheduled_at: None,
+            last_suspended_at: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+            created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        },
+        query_id: "q1".to_string(),
+        state: mt::TaskRunState::Failed,
+        error_code: 1006,
+        error_message: Some("bad".to_string()),
+        root_task_id: 8,
+        scheduled_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        completed_at: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap()),
+    };
+
+    common::test_load_old(func_name!(), task_run_info_v113.as_slice(), 113, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_meta_app::principal::UserGrantSet;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v115_workload_group() -> anyhow::Result<()> {
+    let workload_group_v115 = vec![
+        10, 3, 101, 116, 108, 16, 4, 24, 128, 128, 128, 128, 4, 40, 200, 1, 50, 8, 101, 116, 108,
+        32, 106, 111, 98, 115, 58, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48,
+        48, 58, 48, 57, 32, 85, 84, 67, 66, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50,
+        58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 115, 168, 6, 24,
+    ];
+
+    let want = || mt::WorkloadGroup {
+        name: "etl".to_string(),
+        max_concurrency: Some(4),
+        query_memory_limit: Some(1024 * 1024 * 1024),
+        memory_limit: None,
+        cpu_weight: 200,
+        comment: "etl jobs".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+    };
+
+    common::test_load_old(func_name!(), workload_group_v115.as_slice(), 115, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v115_user_option_workload_group() -> anyhow::Result<()> {
+    let user_option_v115 = vec![
+        18, 5, 114, 111, 108, 101, 49, 58, 3, 101, 116, 108, 160, 6, 115, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::UserOption::default()
+            .with_default_role(Some("role1".to_string()))
+            .with_workload_group(Some("etl".to_string()))
+    };
+
+    common::test_load_old(func_name!(), user_option_v115.as_slice(), 115, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v115_role_workload_group() -> anyhow::Result<()> {
+    let role_info_v115 = vec![
+        10, 2, 114, 49, 18, 6, 160, 6, 115, 168, 6, 24, 26, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+        56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 34, 23, 50, 48, 49, 52, 45, 49, 49,
+        45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 42, 2, 98, 105, 160, 6,
+        115, 168, 6, 24,
+    ];
+
+    let want = || mt::RoleInfo {
+        name: "r1".to_string(),
+        grants: UserGrantSet::new(vec![], HashSet::new()),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        workload_group: Some("bi".to_string()),
+    };
+
+    common::test_load_old(func_name!(), role_info_v115.as_slice(), 115, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v117_schema() -> anyhow::Result<()> {
+    let table_schema_v117 = vec![
+        10, 28, 10, 1, 97, 26, 17, 154, 2, 8, 34, 0, 160, 6, 117, 168, 6, 24, 160, 6, 117, 168, 6,
+        24, 160, 6, 117, 168, 6, 24, 10, 23, 10, 2, 105, 118, 26, 9, 138, 3, 0, 160, 6, 117, 168,
+        6, 24, 32, 1, 160, 6, 117, 168, 6, 24, 10, 31, 10, 1, 110, 26, 18, 178, 2, 9, 138, 3, 0,
+        160, 6, 117, 168, 6, 24, 160, 6, 117, 168, 6, 24, 32, 2, 160, 6, 117, 168, 6, 24, 24, 3,
+        160, 6, 117, 168, 6, 24,
+    ];
+
+    let fields = vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt64)),
+        TableField::new("iv", TableDataType::Interval),
+        TableField::new(
+            "n",
+            TableDataType::Nullable(Box::new(TableDataType::Interval)),
+        ),
+    ];
+
+    let want = || TableSchema::new(fields.clone());
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_schema_v117.as_slice(), 117, want())?;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::UserDefinedSecret;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v118_secret() -> anyhow::Result<()> {
+    let user_defined_secret_v118 = vec![
+        10, 9, 109, 121, 95, 115, 101, 99, 114, 101, 116, 18, 6, 115, 51, 99, 114, 51, 116, 26, 7,
+        102, 111, 114, 32, 112, 105, 105, 34, 23, 50, 48, 50, 52, 45, 49, 48, 45, 49, 48, 32, 49,
+        48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 118, 168, 6, 24,
+    ];
+
+    let want = || UserDefinedSecret {
+        name: "my_secret".to_string(),
+        value: "s3cr3t".to_string(),
+        comment: Some("for pii".to_string()),
+        created_on: Utc.with_ymd_and_hms(2024, 10, 10, 10, 0, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        user_defined_secret_v118.as_slice(),
+        118,
+        want(),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v119_pipe_owner() -> anyhow::Result<()> {
+    let pipe_info_v119 = vec![
+        10, 2, 112, 49, 18, 29, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 100, 101, 102, 97, 117,
+        108, 116, 46, 116, 49, 32, 70, 82, 79, 77, 32, 64, 115, 49, 24, 1, 42, 7, 102, 111, 111,
+        32, 98, 97, 114, 50, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48,
+        58, 48, 57, 32, 85, 84, 67, 58, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 66, 5, 114, 111, 108, 101, 49, 160, 6, 119, 168, 6, 24,
+    ];
+
+    let want = || mt::PipeInfo {
+        name: "p1".to_string(),
+        copy_stmt: "COPY INTO default.t1 FROM @s1".to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "foo bar".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        owner: "role1".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), pipe_info_v119.as_slice(), 119, want())?;
+    Ok(())
+}
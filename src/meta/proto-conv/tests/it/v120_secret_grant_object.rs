@@ -0,0 +1,70 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+
+#[test]
+fn test_decode_v120_grant_entry() -> anyhow::Result<()> {
+    let grant_entry_v120 = vec![
+        10, 12, 66, 4, 10, 2, 115, 49, 160, 6, 120, 168, 6, 24, 16, 129, 128, 8, 160, 6, 120, 168,
+        6, 24,
+    ];
+
+    let want = || {
+        mt::principal::GrantEntry::new(
+            mt::principal::GrantObject::Secret("s1".to_string()),
+            make_bitflags!(UserPrivilegeType::{Usage | Ownership}),
+        )
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), grant_entry_v120.as_slice(), 120, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v120_ownership() -> anyhow::Result<()> {
+    let ownership_info_v120 = vec![
+        10, 2, 114, 49, 18, 12, 42, 4, 10, 2, 115, 49, 160, 6, 120, 168, 6, 24, 160, 6, 120, 168,
+        6, 24,
+    ];
+
+    let want = || mt::principal::OwnershipInfo {
+        role: "r1".to_string(),
+        object: mt::principal::OwnershipObject::Secret {
+            name: "s1".to_string(),
+        },
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), ownership_info_v120.as_slice(), 120, want())?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_meta_app::principal::XmlFieldSource;
+use databend_common_meta_app::principal::XmlFileFormatParams;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+
+#[test]
+fn test_decode_v121_xml_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v121 = vec![
+        50, 28, 8, 1, 18, 7, 114, 111, 119, 95, 116, 97, 103, 26, 9, 65, 84, 84, 82, 73, 66, 85,
+        84, 69, 160, 6, 121, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::FileFormatParams::Xml(XmlFileFormatParams {
+            compression: StageFileCompression::Gzip,
+            row_tag: "row_tag".to_string(),
+            field_source: XmlFieldSource::Attribute,
+        })
+    };
+    common::test_load_old(func_name!(), file_format_params_v121.as_slice(), 0, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
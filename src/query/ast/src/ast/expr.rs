@@ -966,6 +966,7 @@ pub enum TypeName {
     Variant,
     Geometry,
     Geography,
+    Interval,
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -1088,6 +1089,9 @@ impl Display for TypeName {
             TypeName::Geography => {
                 write!(f, "GEOGRAPHY")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -93,6 +93,13 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(ttl) = stmt.ttl {
+            RcDoc::line()
+                .append(RcDoc::text("TTL "))
+                .append(pretty_expr(ttl))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.table_options.is_empty() {
             RcDoc::line()
                 .append(interweave_comma(stmt.table_options.iter().map(|(k, v)| {
@@ -224,6 +231,10 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::DropTableClusterKey => {
             RcDoc::line().append(RcDoc::text("DROP CLUSTER KEY"))
         }
+        AlterTableAction::ModifyTtl { ttl } => RcDoc::line()
+            .append(RcDoc::text("MODIFY TTL "))
+            .append(pretty_expr(ttl)),
+        AlterTableAction::DropTtl => RcDoc::line().append(RcDoc::text("DROP TTL")),
         AlterTableAction::ReclusterTable {
             is_final,
             selection,
@@ -253,6 +264,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
             TimeTravelPoint::Offset(num) => RcDoc::text(format!(" AT (OFFSET => {num})")),
+            TimeTravelPoint::Version(version) => RcDoc::text(format!(" AT (VERSION => {version})")),
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -397,6 +409,9 @@ pub(crate) fn pretty_create_stream(stmt: CreateStreamStmt) -> RcDoc<'static> {
             Some(TimeTravelPoint::Snapshot(sid)) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             Some(TimeTravelPoint::Timestamp(ts)) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
             Some(TimeTravelPoint::Offset(num)) => RcDoc::text(format!(" AT (OFFSET => {num})")),
+            Some(TimeTravelPoint::Version(version)) => {
+                RcDoc::text(format!(" AT (VERSION => {version})"))
+            }
             Some(TimeTravelPoint::Stream {
                 catalog,
                 database,
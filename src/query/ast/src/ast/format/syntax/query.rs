@@ -449,8 +449,18 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
                 JoinOperator::RightAnti => RcDoc::text("RIGHT ANTI JOIN"),
                 JoinOperator::LeftSemi => RcDoc::text("LEFT SEMI JOIN"),
                 JoinOperator::RightSemi => RcDoc::text("RIGHT SEMI JOIN"),
+                JoinOperator::Asof => RcDoc::text("ASOF JOIN"),
+                JoinOperator::LeftAsof => RcDoc::text("ASOF LEFT JOIN"),
             })
             .append(RcDoc::space().append(pretty_table(*join.right)))
+            .append(if let Some(match_condition) = &join.match_condition {
+                RcDoc::space()
+                    .append(RcDoc::text("MATCH_CONDITION ("))
+                    .append(pretty_expr(*match_condition.clone()))
+                    .append(RcDoc::text(")"))
+            } else {
+                RcDoc::nil()
+            })
             .append(match &join.condition {
                 JoinCondition::On(expr) => RcDoc::space()
                     .append(RcDoc::text("ON"))
@@ -476,6 +486,30 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             }),
+        TableReference::MatchRecognize {
+            span: _,
+            table,
+            match_recognize,
+            alias,
+        } => pretty_table(*table)
+            .append(RcDoc::text(format!(" {match_recognize}")))
+            .append(if let Some(a) = alias {
+                RcDoc::text(format!(" AS {a}"))
+            } else {
+                RcDoc::nil()
+            }),
+        TableReference::GapFill {
+            span: _,
+            table,
+            gap_fill,
+            alias,
+        } => pretty_table(*table)
+            .append(RcDoc::text(format!(" {gap_fill}")))
+            .append(if let Some(a) = alias {
+                RcDoc::text(format!(" AS {a}"))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
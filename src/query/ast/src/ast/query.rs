@@ -496,6 +496,7 @@ pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
     Offset(Box<Expr>),
+    Version(u64),
     Stream {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
@@ -515,6 +516,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Offset(num) => {
                 write!(f, "(OFFSET => {num})")?;
             }
+            TimeTravelPoint::Version(version) => {
+                write!(f, "(VERSION => {version})")?;
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -582,6 +586,240 @@ impl Display for Unpivot {
     }
 }
 
+/// `MATCH_RECOGNIZE (...)` row pattern recognition over a table reference.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct MatchRecognize {
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+    pub measures: Vec<MatchMeasure>,
+    pub rows_per_match: RowsPerMatch,
+    pub after_match_skip: AfterMatchSkip,
+    pub pattern: MatchPattern,
+    pub definitions: Vec<SymbolDefinition>,
+}
+
+impl Display for MatchRecognize {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "MATCH_RECOGNIZE (")?;
+        if !self.partition_by.is_empty() {
+            write!(f, "PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, " ")?;
+        }
+        if !self.order_by.is_empty() {
+            write!(f, "ORDER BY ")?;
+            write_comma_separated_list(f, &self.order_by)?;
+            write!(f, " ")?;
+        }
+        if !self.measures.is_empty() {
+            write!(f, "MEASURES ")?;
+            write_comma_separated_list(f, &self.measures)?;
+            write!(f, " ")?;
+        }
+        write!(
+            f,
+            "{} {} PATTERN ({})",
+            self.rows_per_match, self.after_match_skip, self.pattern
+        )?;
+        if !self.definitions.is_empty() {
+            write!(f, " DEFINE ")?;
+            write_comma_separated_list(f, &self.definitions)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct MatchMeasure {
+    pub expr: Expr,
+    pub alias: Identifier,
+}
+
+impl Display for MatchMeasure {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.expr, self.alias)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct SymbolDefinition {
+    pub symbol: Identifier,
+    pub expr: Expr,
+}
+
+impl Display for SymbolDefinition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} AS {}", self.symbol, self.expr)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Drive, DriveMut)]
+pub enum RowsPerMatch {
+    #[default]
+    OneRow,
+    AllRows,
+}
+
+impl Display for RowsPerMatch {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RowsPerMatch::OneRow => write!(f, "ONE ROW PER MATCH"),
+            RowsPerMatch::AllRows => write!(f, "ALL ROWS PER MATCH"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Drive, DriveMut)]
+pub enum AfterMatchSkip {
+    #[default]
+    PastLastRow,
+    ToNextRow,
+}
+
+impl Display for AfterMatchSkip {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AfterMatchSkip::PastLastRow => write!(f, "AFTER MATCH SKIP PAST LAST ROW"),
+            AfterMatchSkip::ToNextRow => write!(f, "AFTER MATCH SKIP TO NEXT ROW"),
+        }
+    }
+}
+
+/// The regular expression over pattern variables in `PATTERN (...)`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum MatchPattern {
+    Symbol(Identifier),
+    Concat(Vec<MatchPattern>),
+    Alternation(Vec<MatchPattern>),
+    Quantified {
+        pattern: Box<MatchPattern>,
+        #[drive(skip)]
+        min: u64,
+        #[drive(skip)]
+        max: Option<u64>,
+        /// Prefer the fewest repetitions, e.g. `A*?`.
+        #[drive(skip)]
+        reluctant: bool,
+    },
+}
+
+impl Display for MatchPattern {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MatchPattern::Symbol(symbol) => write!(f, "{symbol}"),
+            MatchPattern::Concat(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    match pattern {
+                        MatchPattern::Alternation(_) => write!(f, "({pattern})")?,
+                        _ => write!(f, "{pattern}")?,
+                    }
+                }
+                Ok(())
+            }
+            MatchPattern::Alternation(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{pattern}")?;
+                }
+                Ok(())
+            }
+            MatchPattern::Quantified {
+                pattern,
+                min,
+                max,
+                reluctant,
+            } => {
+                match pattern.as_ref() {
+                    MatchPattern::Symbol(_) => write!(f, "{pattern}")?,
+                    _ => write!(f, "({pattern})")?,
+                }
+                match (min, max) {
+                    (0, None) => write!(f, "*")?,
+                    (1, None) => write!(f, "+")?,
+                    (0, Some(1)) => write!(f, "?")?,
+                    (min, None) => write!(f, "{{{min},}}")?,
+                    (min, Some(max)) if min == max => write!(f, "{{{min}}}")?,
+                    (min, Some(max)) => write!(f, "{{{min},{max}}}")?,
+                }
+                if *reluctant {
+                    write!(f, "?")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `GAP_FILL (...)` fills the missing time buckets in each partition of a table reference.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct GapFill {
+    pub width: Expr,
+    pub time_column: Identifier,
+    pub start: Option<Expr>,
+    pub end: Option<Expr>,
+    pub partition_by: Vec<Identifier>,
+    pub fill: Vec<GapFillColumn>,
+}
+
+impl Display for GapFill {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "GAP_FILL (TIME_BUCKET_GAPFILL({}, {}",
+            self.width, self.time_column
+        )?;
+        if let (Some(start), Some(end)) = (&self.start, &self.end) {
+            write!(f, ", {start}, {end}")?;
+        }
+        write!(f, ")")?;
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+        }
+        if !self.fill.is_empty() {
+            write!(f, " FILL (")?;
+            write_comma_separated_list(f, &self.fill)?;
+            write!(f, ")")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct GapFillColumn {
+    pub column: Identifier,
+    pub method: GapFillMethod,
+}
+
+impl Display for GapFillColumn {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.column, self.method)
+    }
+}
+
+/// How the value of a column is filled in the missing buckets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum GapFillMethod {
+    /// Last observation carried forward.
+    Locf,
+    /// Linear interpolation between the previous and the next observation.
+    Interpolate,
+}
+
+impl Display for GapFillMethod {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            GapFillMethod::Locf => write!(f, "LOCF"),
+            GapFillMethod::Interpolate => write!(f, "INTERPOLATE"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Drive, DriveMut)]
 pub struct WithOptions {
     pub options: BTreeMap<String, String>,
@@ -766,6 +1004,20 @@ pub enum TableReference {
         options: SelectStageOptions,
         alias: Option<TableAlias>,
     },
+    // `<table reference> MATCH_RECOGNIZE (...) [ AS alias ]`
+    MatchRecognize {
+        span: Span,
+        table: Box<TableReference>,
+        match_recognize: Box<MatchRecognize>,
+        alias: Option<TableAlias>,
+    },
+    // `<table reference> GAP_FILL (...) [ AS alias ]`
+    GapFill {
+        span: Span,
+        table: Box<TableReference>,
+        gap_fill: Box<GapFill>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl TableReference {
@@ -931,8 +1183,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -959,6 +1220,28 @@ impl Display for TableReference {
                     write!(f, " AS {alias}")?;
                 }
             }
+            TableReference::MatchRecognize {
+                span: _,
+                table,
+                match_recognize,
+                alias,
+            } => {
+                write!(f, "{table} {match_recognize}")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+            }
+            TableReference::GapFill {
+                span: _,
+                table,
+                gap_fill,
+                alias,
+            } => {
+                write!(f, "{table} {gap_fill}")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+            }
         }
         Ok(())
     }
@@ -986,6 +1269,8 @@ impl Display for TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    // The inequality of `ASOF JOIN ... MATCH_CONDITION (...)`, only set for ASOF joins.
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -1003,6 +1288,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // ASOF joins must have a `MATCH_CONDITION`
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -118,7 +118,7 @@ impl Display for CopyIntoTableStmt {
         }
 
         if !self.validation_mode.is_empty() {
-            write!(f, "VALIDATION_MODE = {}", self.validation_mode)?;
+            write!(f, " VALIDATION_MODE = {}", self.validation_mode)?;
         }
 
         if self.size_limit != 0 {
@@ -26,6 +26,7 @@ use crate::ast::CreateOption;
 use crate::ast::CreateTableSource;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::ShowLimit;
 use crate::ast::WarehouseOptions;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -154,3 +155,82 @@ impl Display for CreateDynamicTableStmt {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum AlterDynamicTableAction {
+    Refresh,
+}
+
+impl Display for AlterDynamicTableAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterDynamicTableAction::Refresh => {
+                write!(f, "REFRESH")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum DynamicTableRef {
+    Name {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+    },
+    /// The id of the table, which stays the same when the table is renamed.
+    Id {
+        #[drive(skip)]
+        table_id: u64,
+    },
+}
+
+impl Display for DynamicTableRef {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            DynamicTableRef::Name {
+                catalog,
+                database,
+                table,
+            } => write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table))),
+            DynamicTableRef::Id { table_id } => write!(f, "{table_id}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterDynamicTableStmt {
+    pub table: DynamicTableRef,
+    pub action: AlterDynamicTableAction,
+}
+
+impl Display for AlterDynamicTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER DYNAMIC TABLE {} {}", self.table, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ShowDynamicTablesStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub limit: Option<ShowLimit>,
+}
+
+impl Display for ShowDynamicTablesStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SHOW DYNAMIC TABLES")?;
+        if let Some(database) = &self.database {
+            write!(f, " FROM ")?;
+            if let Some(catalog) = &self.catalog {
+                write!(f, "{catalog}.",)?;
+            }
+            write!(f, "{database}")?;
+        }
+        if let Some(limit) = &self.limit {
+            write!(f, " {limit}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -40,6 +40,7 @@ mod priority;
 mod procedure;
 mod replace;
 mod script;
+mod secret;
 mod sequence;
 mod set;
 mod settings;
@@ -55,6 +56,7 @@ mod update;
 mod user;
 mod view;
 mod virtual_column;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -84,6 +86,7 @@ pub use priority::*;
 pub use procedure::*;
 pub use replace::*;
 pub use script::*;
+pub use secret::*;
 pub use sequence::*;
 pub use set::*;
 pub use settings::*;
@@ -99,3 +102,4 @@ pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_column::*;
+pub use workload_group::*;
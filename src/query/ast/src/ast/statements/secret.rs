@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::quote::QuotedString;
+use crate::ast::CreateOption;
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct CreateSecretStmt {
+    pub create_option: CreateOption,
+    pub name: Identifier,
+    pub value: String,
+    pub comment: Option<String>,
+}
+
+impl CreateSecretStmt {
+    /// The statement with the secret string masked, which is the text recorded
+    /// in the query log.
+    pub fn mask(&self) -> Self {
+        Self {
+            value: "******".to_string(),
+            ..self.clone()
+        }
+    }
+}
+
+impl Display for CreateSecretStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, " OR REPLACE")?;
+        }
+        write!(f, " SECRET ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} ", self.name)?;
+        write!(f, "SECRET_STRING = {}", QuotedString(&self.value, '\''))?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = {}", QuotedString(comment, '\''))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct DropSecretStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropSecretStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP SECRET ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct ShowSecretsStmt {}
+
+impl Display for ShowSecretsStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SHOW SECRETS")
+    }
+}
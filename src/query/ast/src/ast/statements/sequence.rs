@@ -25,6 +25,9 @@ use crate::ast::Identifier;
 pub struct CreateSequenceStmt {
     pub create_option: CreateOption,
     pub sequence: Identifier,
+    pub start: Option<u64>,
+    pub increment: Option<i64>,
+    pub cache: Option<u64>,
     pub comment: Option<String>,
 }
 
@@ -39,6 +42,15 @@ impl Display for CreateSequenceStmt {
             write!(f, "IF NOT EXISTS ")?;
         }
         write!(f, " {}", self.sequence)?;
+        if let Some(start) = self.start {
+            write!(f, " START WITH {start}")?;
+        }
+        if let Some(increment) = self.increment {
+            write!(f, " INCREMENT BY {increment}")?;
+        }
+        if let Some(cache) = self.cache {
+            write!(f, " CACHE {cache}")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT = '{}'", comment)?;
         }
@@ -46,6 +58,54 @@ impl Display for CreateSequenceStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterSequenceStmt {
+    pub if_exists: bool,
+    pub sequence: Identifier,
+    /// `Some(None)` restarts the sequence from its start value.
+    #[drive(skip)]
+    pub restart: Option<Option<u64>>,
+    pub increment: Option<i64>,
+    pub cache: Option<u64>,
+    pub comment: Option<String>,
+}
+
+impl Display for AlterSequenceStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER SEQUENCE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.sequence)?;
+        match self.restart {
+            Some(Some(restart)) => write!(f, " RESTART WITH {restart}")?,
+            Some(None) => write!(f, " RESTART")?,
+            None => {}
+        }
+        if let Some(increment) = self.increment {
+            write!(f, " INCREMENT BY {increment}")?;
+        }
+        if let Some(cache) = self.cache {
+            write!(f, " CACHE {cache}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DescSequenceStmt {
+    pub sequence: Identifier,
+}
+
+impl Display for DescSequenceStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE SEQUENCE {}", self.sequence)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct DropSequenceStmt {
     pub if_exists: bool,
@@ -182,9 +182,9 @@ pub enum Statement {
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
-    CreateInvertedIndex(CreateInvertedIndexStmt),
-    DropInvertedIndex(DropInvertedIndexStmt),
-    RefreshInvertedIndex(RefreshInvertedIndexStmt),
+    CreateTableIndex(CreateTableIndexStmt),
+    DropTableIndex(DropTableIndexStmt),
+    RefreshTableIndex(RefreshTableIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -213,6 +213,7 @@ pub enum Statement {
         if_exists: bool,
         role_name: String,
     },
+    AlterRole(AlterRoleStmt),
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -253,6 +254,11 @@ pub enum Statement {
     DescribeConnection(DescribeConnectionStmt),
     ShowConnections(ShowConnectionsStmt),
 
+    // Secret
+    CreateSecret(CreateSecretStmt),
+    DropSecret(DropSecretStmt),
+    ShowSecrets(ShowSecretsStmt),
+
     // UserDefinedFileFormat
     CreateFileFormat {
         create_option: CreateOption,
@@ -287,6 +293,14 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    ShowWorkloadGroups {
+        show_options: Option<ShowOptions>,
+    },
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -296,6 +310,8 @@ pub enum Statement {
     ShowTasks(ShowTasksStmt),
 
     CreateDynamicTable(CreateDynamicTableStmt),
+    AlterDynamicTable(AlterDynamicTableStmt),
+    ShowDynamicTables(ShowDynamicTablesStmt),
 
     // pipes
     CreatePipe(CreatePipeStmt),
@@ -307,6 +323,15 @@ pub enum Statement {
     Begin,
     Commit,
     Abort,
+    Savepoint {
+        name: Identifier,
+    },
+    RollbackToSavepoint {
+        name: Identifier,
+    },
+    ReleaseSavepoint {
+        name: Identifier,
+    },
 
     // Notifications
     CreateNotification(CreateNotificationStmt),
@@ -326,7 +351,12 @@ pub enum Statement {
 
     // Sequence
     CreateSequence(CreateSequenceStmt),
+    AlterSequence(AlterSequenceStmt),
     DropSequence(DropSequenceStmt),
+    ShowSequences {
+        show_options: Option<ShowOptions>,
+    },
+    DescSequence(DescSequenceStmt),
 
     // Set priority for query
     SetPriority {
@@ -371,6 +401,7 @@ impl Statement {
                 attach_clone.uri_location.connection = attach_clone.uri_location.connection.mask();
                 format!("{}", Statement::AttachTable(attach_clone))
             }
+            Statement::CreateSecret(stmt) => format!("{}", Statement::CreateSecret(stmt.mask())),
             _ => format!("{}", self),
         }
     }
@@ -588,9 +619,9 @@ impl Display for Statement {
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateTableIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTableIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshTableIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -628,6 +659,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole(stmt) => write!(f, "{stmt}")?,
             Statement::Grant(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrants {
                 principal,
@@ -725,6 +757,15 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups { show_options } => {
+                write!(f, "SHOW WORKLOAD GROUPS")?;
+                if let Some(show_options) = show_options {
+                    write!(f, " {show_options}")?;
+                }
+            }
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -739,9 +780,15 @@ impl Display for Statement {
             Statement::DropConnection(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeConnection(stmt) => write!(f, "{stmt}")?,
             Statement::ShowConnections(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSecret(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSecret(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSecrets(stmt) => write!(f, "{stmt}")?,
             Statement::Begin => write!(f, "BEGIN")?,
             Statement::Commit => write!(f, "COMMIT")?,
             Statement::Abort => write!(f, "ABORT")?,
+            Statement::Savepoint { name } => write!(f, "SAVEPOINT {name}")?,
+            Statement::RollbackToSavepoint { name } => write!(f, "ROLLBACK TO SAVEPOINT {name}")?,
+            Statement::ReleaseSavepoint { name } => write!(f, "RELEASE SAVEPOINT {name}")?,
             Statement::CreateNotification(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNotification(stmt) => write!(f, "{stmt}")?,
             Statement::DropNotification(stmt) => write!(f, "{stmt}")?,
@@ -757,8 +804,18 @@ impl Display for Statement {
                 }
             }
             Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterSequence(stmt) => write!(f, "{stmt}")?,
             Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSequences { show_options } => {
+                write!(f, "SHOW SEQUENCES")?;
+                if let Some(show_options) = show_options {
+                    write!(f, " {show_options}")?;
+                }
+            }
+            Statement::DescSequence(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDynamicTable(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterDynamicTable(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowDynamicTables(stmt) => write!(f, "{stmt}")?,
             Statement::SetPriority {
                 priority,
                 object_id,
@@ -178,6 +178,8 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Option<ClusterOption>,
+    /// Rows are expired once the evaluated expression is earlier than the current time.
+    pub ttl: Option<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub table_type: TableType,
@@ -229,6 +231,10 @@ impl Display for CreateTableStmt {
             write!(f, " {cluster_by}")?;
         }
 
+        if let Some(ttl) = &self.ttl {
+            write!(f, " TTL {ttl}")?;
+        }
+
         // Format table options
         if !self.table_options.is_empty() {
             write!(f, " ")?;
@@ -415,6 +421,10 @@ pub enum AlterTableAction {
         cluster_by: ClusterOption,
     },
     DropTableClusterKey,
+    ModifyTtl {
+        ttl: Expr,
+    },
+    DropTtl,
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
@@ -467,6 +477,12 @@ impl Display for AlterTableAction {
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")?;
             }
+            AlterTableAction::ModifyTtl { ttl } => {
+                write!(f, "MODIFY TTL {ttl}")?;
+            }
+            AlterTableAction::DropTtl => {
+                write!(f, "DROP TTL")?;
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -829,6 +845,12 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    AutoIncrement {
+        #[drive(skip)]
+        start: u64,
+        #[drive(skip)]
+        increment: u64,
+    },
 }
 
 impl Display for ColumnExpr {
@@ -843,6 +865,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::AutoIncrement { start, increment } => {
+                write!(f, " AUTOINCREMENT START {start} INCREMENT {increment}")?;
+            }
         }
         Ok(())
     }
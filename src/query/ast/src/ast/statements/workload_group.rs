@@ -0,0 +1,165 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::CreateOption;
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateWorkloadGroupStmt {
+    pub create_option: CreateOption,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "WORKLOAD GROUP ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.set_options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterWorkloadGroupAction,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} ", self.name)?;
+        write!(f, "{}", self.action)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum AlterWorkloadGroupAction {
+    SetOptions(WorkloadGroupSetOptions),
+    UnSetOptions(WorkloadGroupUnSetOptions),
+}
+
+impl Display for AlterWorkloadGroupAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterWorkloadGroupAction::SetOptions(set_options) => {
+                write!(f, "SET{}", set_options)?;
+            }
+            AlterWorkloadGroupAction::UnSetOptions(unset_options) => {
+                write!(f, "UNSET{}", unset_options)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct WorkloadGroupSetOptions {
+    pub max_concurrency: Option<u64>,
+    pub query_memory_limit: Option<u64>,
+    pub memory_limit: Option<u64>,
+    pub cpu_weight: Option<u64>,
+    pub comment: Option<String>,
+}
+
+impl Display for WorkloadGroupSetOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(max_concurrency) = self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY = {}", max_concurrency)?;
+        }
+        if let Some(query_memory_limit) = self.query_memory_limit {
+            write!(f, " QUERY_MEMORY_LIMIT = {}", query_memory_limit)?;
+        }
+        if let Some(memory_limit) = self.memory_limit {
+            write!(f, " MEMORY_LIMIT = {}", memory_limit)?;
+        }
+        if let Some(cpu_weight) = self.cpu_weight {
+            write!(f, " CPU_WEIGHT = {}", cpu_weight)?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct WorkloadGroupUnSetOptions {
+    pub max_concurrency: bool,
+    pub query_memory_limit: bool,
+    pub memory_limit: bool,
+    pub cpu_weight: bool,
+    pub comment: bool,
+}
+
+impl Display for WorkloadGroupUnSetOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY")?;
+        }
+        if self.query_memory_limit {
+            write!(f, " QUERY_MEMORY_LIMIT")?;
+        }
+        if self.memory_limit {
+            write!(f, " MEMORY_LIMIT")?;
+        }
+        if self.cpu_weight {
+            write!(f, " CPU_WEIGHT")?;
+        }
+        if self.comment {
+            write!(f, " COMMENT")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -18,15 +18,20 @@ use nom::combinator::map;
 use nom::combinator::value;
 use nom_rule::rule;
 
+use crate::ast::AlterDynamicTableAction;
+use crate::ast::AlterDynamicTableStmt;
 use crate::ast::ClusterOption;
 use crate::ast::ClusterType;
 use crate::ast::CreateDynamicTableStmt;
+use crate::ast::DynamicTableRef;
 use crate::ast::InitializeMode;
 use crate::ast::RefreshMode;
+use crate::ast::ShowDynamicTablesStmt;
 use crate::ast::Statement;
 use crate::ast::TargetLag;
 use crate::ast::WarehouseOptions;
 use crate::parser::common::comma_separated_list1;
+use crate::parser::common::dot_separated_idents_1_to_2;
 use crate::parser::common::dot_separated_idents_1_to_3;
 use crate::parser::common::map_res;
 use crate::parser::common::IResult;
@@ -37,6 +42,7 @@ use crate::parser::query::query;
 use crate::parser::statement::cluster_type;
 use crate::parser::statement::create_table_source;
 use crate::parser::statement::parse_create_option;
+use crate::parser::statement::show_limit;
 use crate::parser::statement::table_option;
 use crate::parser::statement::warehouse_option;
 use crate::parser::token::TokenKind::*;
@@ -53,6 +59,8 @@ pub fn dynamic_table(i: Input) -> IResult<Statement> {
   [ COMMENT = '<string_literal>' ]
 AS
   <sql>`"
+        | #alter_dynamic_table : "`ALTER DYNAMIC TABLE { [<database>.]<table> | <table_id> } REFRESH`"
+        | #show_dynamic_tables : "`SHOW DYNAMIC TABLES [FROM <database>] [<show_limit>]`"
     )(i)
 }
 
@@ -105,6 +113,50 @@ fn create_dynamic_table(i: Input) -> IResult<Statement> {
     )(i)
 }
 
+fn alter_dynamic_table(i: Input) -> IResult<Statement> {
+    let table_ref = alt((
+        map(dot_separated_idents_1_to_3, |(catalog, database, table)| {
+            DynamicTableRef::Name {
+                catalog,
+                database,
+                table,
+            }
+        }),
+        map(literal_u64, |table_id| DynamicTableRef::Id { table_id }),
+    ));
+    map(
+        rule! {
+            ALTER ~ DYNAMIC ~ TABLE ~ #table_ref ~ REFRESH
+        },
+        |(_, _, _, table, _)| {
+            Statement::AlterDynamicTable(AlterDynamicTableStmt {
+                table,
+                action: AlterDynamicTableAction::Refresh,
+            })
+        },
+    )(i)
+}
+
+fn show_dynamic_tables(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            SHOW ~ DYNAMIC ~ TABLES ~ ( ( FROM | IN ) ~ #dot_separated_idents_1_to_2 )? ~ #show_limit?
+        },
+        |(_, _, _, ctl_db, limit)| {
+            let (catalog, database) = match ctl_db {
+                Some((_, (Some(c), d))) => (Some(c), Some(d)),
+                Some((_, (None, d))) => (None, Some(d)),
+                _ => (None, None),
+            };
+            Statement::ShowDynamicTables(ShowDynamicTablesStmt {
+                catalog,
+                database,
+                limit,
+            })
+        },
+    )(i)
+}
+
 fn dynamic_table_options(
     i: Input,
 ) -> IResult<(
@@ -1206,15 +1206,29 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             date,
         },
     );
-    let interval = map(
-        rule! {
-            INTERVAL ~ #subexpr(0) ~ #interval_kind
-        },
-        |(_, operand, unit)| ExprElement::Interval {
-            expr: operand,
-            unit,
-        },
-    );
+    let interval = alt((
+        map(
+            rule! {
+                INTERVAL ~ #subexpr(0) ~ #interval_kind
+            },
+            |(_, operand, unit)| ExprElement::Interval {
+                expr: operand,
+                unit,
+            },
+        ),
+        map(
+            rule! {
+                INTERVAL ~ #consumed(literal_string)
+            },
+            |(_, (span, interval))| ExprElement::Cast {
+                expr: Box::new(Expr::Literal {
+                    span: transform_span(span.tokens),
+                    value: Literal::String(interval),
+                }),
+                target_type: TypeName::Interval,
+            },
+        ),
+    ));
     let date_trunc = map(
         rule! {
             DATE_TRUNC ~ "(" ~ #interval_kind ~ "," ~ #subexpr(0) ~ ")"
@@ -1287,7 +1301,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 | #date_trunc: "`DATE_TRUNC((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND), ...)`"
                 | #date_expr: "`DATE <str_literal>`"
                 | #timestamp_expr: "`TIMESTAMP <str_literal>`"
-                | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+                | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)` or `INTERVAL <str_literal>`"
                 | #pg_cast : "`::<type_name>`"
                 | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
                 | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
@@ -1643,6 +1657,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     let ty_geography = value(TypeName::Geography, rule! { GEOGRAPHY });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
     map_res(
         alt((
             rule! {
@@ -1673,6 +1688,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_variant
             | #ty_geometry
             | #ty_geography
+            | #ty_interval
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
@@ -539,9 +539,13 @@ pub fn at_snapshot_or_ts(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ OFFSET ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Offset(Box::new(e)),
     );
+    let at_version = map(
+        rule! { "(" ~ VERSION ~ "=>" ~ #literal_u64 ~ ")" },
+        |(_, _, _, v, _)| TimeTravelPoint::Version(v),
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp | #at_offset
+        #at_snapshot | #at_timestamp | #at_offset | #at_version
     )(i)
 }
 
@@ -643,6 +647,8 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
         value(JoinOperator::RightOuter, rule! { RIGHT ~ OUTER? }),
         value(JoinOperator::FullOuter, rule! { FULL ~ OUTER? }),
         value(JoinOperator::CrossJoin, rule! { CROSS }),
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT ~ OUTER? }),
+        value(JoinOperator::Asof, rule! { ASOF }),
     ))(i)
 }
 
@@ -729,12 +735,24 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION (expr)
+    MatchCondition(Expr),
     Group(TableReference),
     Stage {
         location: FileLocation,
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
     },
+    // MATCH_RECOGNIZE (...) [ AS alias ]
+    MatchRecognize {
+        match_recognize: Box<MatchRecognize>,
+        alias: Option<TableAlias>,
+    },
+    // GAP_FILL (...) [ AS alias ]
+    GapFill {
+        gap_fill: Box<GapFill>,
+        alias: Option<TableAlias>,
+    },
 }
 
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
@@ -782,6 +800,30 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, expr)| TableReferenceElement::JoinCondition(JoinCondition::On(Box::new(expr))),
     );
+    let match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ "(" ~ #expr ~ ")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::MatchCondition(expr),
+    );
+    let aliased_match_recognize = map(
+        rule! {
+            #match_recognize ~ #table_alias?
+        },
+        |(match_recognize, alias)| TableReferenceElement::MatchRecognize {
+            match_recognize: Box::new(match_recognize),
+            alias,
+        },
+    );
+    let aliased_gap_fill = map(
+        rule! {
+            #gap_fill ~ #table_alias?
+        },
+        |(gap_fill, alias)| TableReferenceElement::GapFill {
+            gap_fill: Box::new(gap_fill),
+            alias,
+        },
+    );
     let join_condition_using = map(
         rule! {
             USING ~ "(" ~ #comma_separated_list1(ident) ~ ")"
@@ -847,6 +889,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #match_condition
+        | #aliased_match_recognize
+        | #aliased_gap_fill
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
@@ -865,6 +910,183 @@ fn pivot(i: Input) -> IResult<Pivot> {
     )(i)
 }
 
+// MATCH_RECOGNIZE (
+//     [ PARTITION BY expr, ... ]
+//     [ ORDER BY order_by_expr, ... ]
+//     [ MEASURES expr [AS] ident, ... ]
+//     [ ONE ROW PER MATCH | ALL ROWS PER MATCH ]
+//     [ AFTER MATCH SKIP { PAST LAST ROW | TO NEXT ROW } ]
+//     PATTERN ( pattern )
+//     [ DEFINE ident AS expr, ... ]
+// )
+fn match_recognize(i: Input) -> IResult<MatchRecognize> {
+    let measure = map(rule! { #expr ~ AS? ~ #ident }, |(expr, _, alias)| {
+        MatchMeasure { expr, alias }
+    });
+    let rows_per_match = alt((
+        value(RowsPerMatch::OneRow, rule! { ONE ~ ^ROW ~ ^PER ~ ^MATCH }),
+        value(RowsPerMatch::AllRows, rule! { ALL ~ ^ROWS ~ ^PER ~ ^MATCH }),
+    ));
+    let after_match_skip = alt((
+        value(AfterMatchSkip::PastLastRow, rule! { PAST ~ ^LAST ~ ^ROW }),
+        value(AfterMatchSkip::ToNextRow, rule! { TO ~ ^NEXT ~ ^ROW }),
+    ));
+    let definition = map(rule! { #ident ~ ^AS ~ ^#expr }, |(symbol, _, expr)| {
+        SymbolDefinition { symbol, expr }
+    });
+
+    map(
+        rule! {
+            MATCH_RECOGNIZE ~ ^"("
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(expr) )?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
+            ~ ( MEASURES ~ ^#comma_separated_list1(measure) )?
+            ~ #rows_per_match?
+            ~ ( AFTER ~ ^MATCH ~ ^SKIP ~ ^#after_match_skip )?
+            ~ ^PATTERN ~ ^"(" ~ ^#match_pattern ~ ^")"
+            ~ ( DEFINE ~ ^#comma_separated_list1(definition) )?
+            ~ ^")"
+        },
+        |(
+            _,
+            _,
+            opt_partition_by,
+            opt_order_by,
+            opt_measures,
+            opt_rows_per_match,
+            opt_after_match_skip,
+            _,
+            _,
+            pattern,
+            _,
+            opt_definitions,
+            _,
+        )| MatchRecognize {
+            partition_by: opt_partition_by
+                .map(|(_, _, exprs)| exprs)
+                .unwrap_or_default(),
+            order_by: opt_order_by.map(|(_, _, exprs)| exprs).unwrap_or_default(),
+            measures: opt_measures
+                .map(|(_, measures)| measures)
+                .unwrap_or_default(),
+            rows_per_match: opt_rows_per_match.unwrap_or_default(),
+            after_match_skip: opt_after_match_skip
+                .map(|(_, _, _, skip)| skip)
+                .unwrap_or_default(),
+            pattern,
+            definitions: opt_definitions
+                .map(|(_, definitions)| definitions)
+                .unwrap_or_default(),
+        },
+    )(i)
+}
+
+// GAP_FILL (
+//     TIME_BUCKET_GAPFILL ( width, time_column [, start, end ] )
+//     [ PARTITION BY column, ... ]
+//     [ FILL ( column { LOCF | INTERPOLATE }, ... ) ]
+// )
+fn gap_fill(i: Input) -> IResult<GapFill> {
+    let method = alt((
+        value(GapFillMethod::Locf, rule! { LOCF }),
+        value(GapFillMethod::Interpolate, rule! { INTERPOLATE }),
+    ));
+    let fill_column = map(rule! { #ident ~ ^#method }, |(column, method)| {
+        GapFillColumn { column, method }
+    });
+
+    map(
+        rule! {
+            GAP_FILL ~ ^"("
+            ~ ^TIME_BUCKET_GAPFILL ~ ^"(" ~ ^#expr ~ ^"," ~ ^#ident
+            ~ ( "," ~ ^#expr ~ ^"," ~ ^#expr )? ~ ^")"
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(ident) )?
+            ~ ( FILL ~ ^"(" ~ ^#comma_separated_list1(fill_column) ~ ^")" )?
+            ~ ^")"
+        },
+        |(_, _, _, _, width, _, time_column, opt_range, _, opt_partition_by, opt_fill, _)| {
+            let (start, end) = match opt_range {
+                Some((_, start, _, end)) => (Some(start), Some(end)),
+                None => (None, None),
+            };
+            GapFill {
+                width,
+                time_column,
+                start,
+                end,
+                partition_by: opt_partition_by
+                    .map(|(_, _, columns)| columns)
+                    .unwrap_or_default(),
+                fill: opt_fill
+                    .map(|(_, _, columns, _)| columns)
+                    .unwrap_or_default(),
+            }
+        },
+    )(i)
+}
+
+// pattern ::= term { "|" term }
+// term    ::= factor { factor }
+// factor  ::= primary [ quantifier ]
+// primary ::= ident | "(" pattern ")"
+fn match_pattern(i: Input) -> IResult<MatchPattern> {
+    map(
+        rule! { #match_pattern_term ~ ( "|" ~ ^#match_pattern_term )* },
+        |(first, rest)| {
+            if rest.is_empty() {
+                first
+            } else {
+                let mut patterns = vec![first];
+                patterns.extend(rest.into_iter().map(|(_, pattern)| pattern));
+                MatchPattern::Alternation(patterns)
+            }
+        },
+    )(i)
+}
+
+fn match_pattern_term(i: Input) -> IResult<MatchPattern> {
+    map(rule! { #match_pattern_factor+ }, |mut patterns| {
+        if patterns.len() == 1 {
+            patterns.pop().unwrap()
+        } else {
+            MatchPattern::Concat(patterns)
+        }
+    })(i)
+}
+
+fn match_pattern_factor(i: Input) -> IResult<MatchPattern> {
+    let symbol = map(rule! { #ident }, MatchPattern::Symbol);
+    let group = map(rule! { "(" ~ ^#match_pattern ~ ^")" }, |(_, pattern, _)| {
+        pattern
+    });
+    let range = map(
+        rule! { "{" ~ #literal_u64? ~ ( "," ~ #literal_u64? )? ~ ^"}" },
+        |(_, min, opt_max, _)| match opt_max {
+            Some((_, max)) => (min.unwrap_or(0), max),
+            None => (min.unwrap_or(0), min),
+        },
+    );
+    let quantifier = alt((
+        value((0, None), rule! { "*" }),
+        value((1, None), rule! { "+" }),
+        value((0, Some(1)), rule! { "?" }),
+        range,
+    ));
+
+    map(
+        rule! { ( #symbol | #group ) ~ ( #quantifier ~ "?"? )? },
+        |(pattern, opt_quantifier)| match opt_quantifier {
+            Some(((min, max), reluctant)) => MatchPattern::Quantified {
+                pattern: Box::new(pattern),
+                min,
+                max,
+                reluctant: reluctant.is_some(),
+            },
+            None => pattern,
+        },
+    )(i)
+}
+
 // UNPIVOT(ident for ident IN (ident, ...))
 fn unpivot(i: Input) -> IResult<Unpivot> {
     map(
@@ -918,6 +1140,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
             TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchRecognize { .. } => Affix::Postfix(Precedence(20)),
+            TableReferenceElement::GapFill { .. } => Affix::Postfix(Precedence(20)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -1029,6 +1254,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     join: Join {
                         op,
                         condition,
+                        match_condition: None,
                         left: Box::new(lhs),
                         right: Box::new(rhs),
                     },
@@ -1067,6 +1293,37 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::MatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::Asof | JoinOperator::LeftAsof,
+                            condition: JoinCondition::None,
+                            match_condition,
+                            ..
+                        },
+                    ..
+                } if match_condition.is_none() => {
+                    *match_condition = Some(Box::new(expr));
+                    Ok(lhs)
+                }
+                _ => Err("MATCH_CONDITION must follow the right table of an ASOF JOIN"),
+            },
+            TableReferenceElement::MatchRecognize {
+                match_recognize,
+                alias,
+            } => Ok(TableReference::MatchRecognize {
+                span: transform_span(op.span.tokens),
+                table: Box::new(lhs),
+                match_recognize,
+                alias,
+            }),
+            TableReferenceElement::GapFill { gap_fill, alias } => Ok(TableReference::GapFill {
+                span: transform_span(op.span.tokens),
+                table: Box::new(lhs),
+                gap_fill,
+                alias,
+            }),
             _ => unreachable!(),
         }
     }
@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use nom::combinator::map;
 use nom_rule::rule;
 
 use super::common::ident;
 use super::expr::literal_string;
+use super::expr::literal_u64;
 use super::statement::parse_create_option;
+use super::statement::show_options;
+use crate::ast::AlterSequenceStmt;
 use crate::ast::CreateSequenceStmt;
+use crate::ast::DescSequenceStmt;
 use crate::ast::DropSequenceStmt;
 use crate::ast::Statement;
 use crate::parser::common::map_res;
@@ -25,11 +30,15 @@ use crate::parser::common::IResult;
 use crate::parser::common::*;
 use crate::parser::input::Input;
 use crate::parser::token::*;
+use crate::parser::ErrorKind;
 
 pub fn sequence(i: Input) -> IResult<Statement> {
     rule!(
-         #create_sequence: "`CREATE [OR REPLACE] SEQUENCE [IF NOT EXISTS] <sequence> [COMMENT = '<string_literal>']`"
+         #create_sequence: "`CREATE [OR REPLACE] SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH] <u64>] [INCREMENT [BY] <u64>] [CACHE <u64>] [COMMENT = '<string_literal>']`"
+         | #alter_sequence: "`ALTER SEQUENCE [IF EXISTS] <sequence> [RESTART [WITH <u64>]] [INCREMENT [BY] <u64>] [CACHE <u64>] [COMMENT = '<string_literal>']`"
          | #drop_sequence: "`DROP [IF EXISTS] <sequence>`"
+         | #show_sequences: "`SHOW SEQUENCES [<show_limit>]`"
+         | #desc_sequence: "`DESC | DESCRIBE SEQUENCE <sequence>`"
     )(i)
 }
 
@@ -38,20 +47,97 @@ fn create_sequence(i: Input) -> IResult<Statement> {
         rule! {
             CREATE ~ ( OR ~ ^REPLACE )? ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
+            ~ ( START ~ WITH? ~ ^#literal_u64 )?
+            ~ #sequence_increment?
+            ~ #sequence_cache?
             ~ ( COMMENT ~ "=" ~ #literal_string )?
         },
-        |(_, opt_or_replace, _, opt_if_not_exists, sequence, opt_comment)| {
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            sequence,
+            opt_start,
+            increment,
+            cache,
+            opt_comment,
+        )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
             Ok(Statement::CreateSequence(CreateSequenceStmt {
                 create_option,
                 sequence,
+                start: opt_start.map(|(_, _, start)| start),
+                increment,
+                cache,
                 comment: opt_comment.map(|(_, _, comment)| comment),
             }))
         },
     )(i)
 }
 
+fn alter_sequence(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            ALTER ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+            ~ ( RESTART ~ ( WITH ~ ^#literal_u64 )? )?
+            ~ #sequence_increment?
+            ~ #sequence_cache?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, opt_if_exists, sequence, opt_restart, increment, cache, opt_comment)| {
+            Statement::AlterSequence(AlterSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                sequence,
+                restart: opt_restart.map(|(_, restart)| restart.map(|(_, restart)| restart)),
+                increment,
+                cache,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    )(i)
+}
+
+fn show_sequences(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            SHOW ~ SEQUENCES ~ #show_options?
+        },
+        |(_, _, show_options)| Statement::ShowSequences { show_options },
+    )(i)
+}
+
+fn desc_sequence(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ SEQUENCE ~ ^#ident
+        },
+        |(_, _, sequence)| Statement::DescSequence(DescSequenceStmt { sequence }),
+    )(i)
+}
+
+fn sequence_increment(i: Input) -> IResult<i64> {
+    map_res(
+        rule! {
+            INCREMENT ~ BY? ~ ^#literal_u64
+        },
+        |(_, _, increment)| {
+            i64::try_from(increment)
+                .map_err(|_| nom::Err::Failure(ErrorKind::Other("increment is too large")))
+        },
+    )(i)
+}
+
+fn sequence_cache(i: Input) -> IResult<u64> {
+    map(
+        rule! {
+            CACHE ~ ^#literal_u64
+        },
+        |(_, cache)| cache,
+    )(i)
+}
+
 fn drop_sequence(i: Input) -> IResult<Statement> {
     map_res(
         rule! {
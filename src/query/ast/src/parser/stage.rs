@@ -101,7 +101,7 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
     );
 
     let ident_options = map(
-        rule! { (BINARY_FORMAT | MISSING_FIELD_AS | EMPTY_FIELD_AS | NULL_FIELD_AS)  ~ "=" ~ (NULL | STRING | Ident)},
+        rule! { (BINARY_FORMAT | MISSING_FIELD_AS | EMPTY_FIELD_AS | NULL_FIELD_AS | FIELD_SOURCE)  ~ "=" ~ (NULL | STRING | Ident)},
         |(k, _, v)| {
             (
                 k.text().to_string(),
@@ -123,7 +123,8 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
                 | ESCAPE
                 | NULL_FIELD_AS
                 | MISSING_FIELD_AS
-                | ROW_TAG) ~ ^"=" ~ ^#literal_string
+                | ROW_TAG
+                | FIELD_SOURCE) ~ ^"=" ~ ^#literal_string
         },
         |(k, _, v)| (k.text().to_string(), FileFormatValue::String(v)),
     );
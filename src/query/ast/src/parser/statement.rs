@@ -738,6 +738,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( #engine )?
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ( #cluster_type )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( TTL ~ ^#expr )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -752,6 +753,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_ttl,
             opt_table_options,
             opt_as_query,
         )| {
@@ -775,6 +777,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     cluster_type: typ.unwrap_or(ClusterType::Linear),
                     cluster_exprs: exprs,
                 }),
+                ttl: opt_ttl.map(|(_, ttl)| ttl),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 table_type,
@@ -1161,12 +1164,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let create_inverted_index = map_res(
+    let create_table_index = map_res(
         rule! {
             CREATE
             ~ ( OR ~ ^REPLACE )?
             ~ ASYNC?
-            ~ INVERTED ~ INDEX
+            ~ #table_index_type ~ INDEX
             ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
@@ -1177,7 +1180,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             _,
             opt_or_replace,
             opt_async,
-            _,
+            index_type,
             _,
             opt_if_not_exists,
             index_name,
@@ -1190,8 +1193,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
-            Ok(Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+            Ok(Statement::CreateTableIndex(CreateTableIndexStmt {
                 create_option,
+                index_type,
                 index_name,
                 catalog,
                 database,
@@ -1203,13 +1207,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let drop_inverted_index = map(
+    let drop_table_index = map(
         rule! {
-            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            DROP ~ #table_index_type ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
         },
-        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
-            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+        |(_, index_type, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropTableIndex(DropTableIndexStmt {
+                index_type,
                 if_exists: opt_if_exists.is_some(),
                 index_name,
                 catalog,
@@ -1219,12 +1224,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let refresh_inverted_index = map(
+    let refresh_table_index = map(
         rule! {
-            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
+            REFRESH ~ #table_index_type ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
-            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+        |(_, index_type, _, index_name, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshTableIndex(RefreshTableIndexStmt {
+                index_type,
                 index_name,
                 catalog,
                 database,
@@ -1416,6 +1422,18 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             role_name,
         },
     );
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ ( IF ~ ^EXISTS )? ~ #role_name ~ #alter_role_action
+        },
+        |(_, _, opt_if_exists, role_name, action)| {
+            Statement::AlterRole(AlterRoleStmt {
+                if_exists: opt_if_exists.is_some(),
+                role_name,
+                action,
+            })
+        },
+    );
     let grant = map(
         rule! {
             GRANT ~ #grant_source ~ TO ~ #grant_option
@@ -1652,6 +1670,44 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _)| Statement::ShowConnections(ShowConnectionsStmt {}),
     );
 
+    // secrets
+    let create_secret = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ SECRET ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ SECRET_STRING ~ ^"=" ~ ^#literal_string
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, opt_or_replace, _, opt_if_not_exists, name, _, _, value, opt_comment)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateSecret(CreateSecretStmt {
+                create_option,
+                name,
+                value,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            }))
+        },
+    );
+
+    let drop_secret = map(
+        rule! {
+            DROP ~ SECRET ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSecret(DropSecretStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    let show_secrets = map(
+        rule! {
+            SHOW ~ SECRETS
+        },
+        |(_, _)| Statement::ShowSecrets(ShowSecretsStmt {}),
+    );
+
     let call = map(
         rule! {
             CALL ~ #ident ~ "(" ~ #comma_separated_list0(parameter_to_string) ~ ")"
@@ -1914,6 +1970,55 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_workload_group = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ #workload_group_set_options
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, name, set_options)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateWorkloadGroupStmt {
+                create_option,
+                name: name.to_string(),
+                set_options,
+            };
+            Ok(Statement::CreateWorkloadGroup(stmt))
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+             ~ #alter_workload_group_action
+        },
+        |(_, _, _, opt_if_exists, name, action)| {
+            let stmt = AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action,
+            };
+            Statement::AlterWorkloadGroup(stmt)
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropWorkloadGroup(stmt)
+        },
+    );
+    let show_workload_groups = map(
+        rule! {
+            SHOW ~ WORKLOAD ~ ^GROUPS ~ ^#show_options?
+        },
+        |(_, _, _, show_options)| Statement::ShowWorkloadGroups { show_options },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2054,6 +2159,24 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
     let commit = value(Statement::Commit, rule! { COMMIT });
     let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
+    let savepoint = map(
+        rule! {
+            SAVEPOINT ~ ^#ident
+        },
+        |(_, name)| Statement::Savepoint { name },
+    );
+    let rollback_to_savepoint = map(
+        rule! {
+            ROLLBACK ~ TO ~ SAVEPOINT? ~ ^#ident
+        },
+        |(_, _, _, name)| Statement::RollbackToSavepoint { name },
+    );
+    let release_savepoint = map(
+        rule! {
+            RELEASE ~ SAVEPOINT? ~ ^#ident
+        },
+        |(_, _, name)| Statement::ReleaseSavepoint { name },
+    );
 
     let execute_immediate = map(
         rule! {
@@ -2266,7 +2389,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
-        // network policy / password policy
+        // network policy / password policy / workload group
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -2278,6 +2401,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
+            | #create_workload_group: "`CREATE [OR REPLACE] WORKLOAD GROUP [IF NOT EXISTS] name [MAX_CONCURRENCY = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name (SET [MAX_CONCURRENCY = <u64_literal>] ... | UNSET [MAX_CONCURRENCY] ...)`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS [<show_options>]`"
         ),
         rule!(
             #conditional_multi_table_insert() : "`INSERT [OVERWRITE] {FIRST|ALL} { WHEN <condition> THEN intoClause [ ... ] } [ ... ] [ ELSE intoClause ] <subquery>`"
@@ -2289,7 +2416,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
             | #begin
             | #commit
+            | #rollback_to_savepoint : "`ROLLBACK TO [SAVEPOINT] <name>`"
             | #abort
+            | #savepoint : "`SAVEPOINT <name>`"
+            | #release_savepoint : "`RELEASE [SAVEPOINT] <name>`"
         ),
         rule!(
             #show_users : "`SHOW USERS`"
@@ -2300,6 +2430,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
+            | #alter_role : "`ALTER ROLE [IF EXISTS] <role_name> (SET WORKLOAD GROUP = '<name>' | UNSET WORKLOAD GROUP)`"
             | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
@@ -2346,9 +2477,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH <index_type> INDEX <index> [LIMIT <limit>]`"
-            | #create_inverted_index: "`CREATE [OR REPLACE] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
-            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
-            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
+            | #create_table_index: "`CREATE [OR REPLACE] {INVERTED | VECTOR} INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
+            | #drop_table_index: "`DROP {INVERTED | VECTOR} INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_table_index: "`REFRESH {INVERTED | VECTOR} INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -2441,6 +2572,9 @@ AS
             | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
             | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
             | #show_connections: "`SHOW CONNECTIONS`"
+            | #create_secret: "`CREATE [OR REPLACE] SECRET [IF NOT EXISTS] <secret_name> SECRET_STRING = '<string_literal>' [ COMMENT = '<string_literal>' ]`"
+            | #drop_secret: "`DROP SECRET [IF EXISTS] <secret_name>`"
+            | #show_secrets: "`SHOW SECRETS`"
             | #execute_immediate : "`EXECUTE IMMEDIATE $$ <script> $$`"
             | #create_procedure : "`CREATE [ OR REPLACE ] PROCEDURE <procedure_name>() RETURNS { <result_data_type> [ NOT NULL ] | TABLE(<var_name> <data_type>, ...)} LANGUAGE SQL [ COMMENT = '<string_literal>' ] AS <procedure_definition>`"
             | #drop_procedure : "`DROP PROCEDURE <procedure_name>()`"
@@ -2861,6 +2995,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        AutoIncrement(u64, u64),
     }
 
     let nullable = alt((
@@ -2886,6 +3021,22 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
+        map(
+            rule! {
+                (AUTOINCREMENT | IDENTITY)
+                ~ (
+                    "(" ~ ^#literal_u64 ~ ^"," ~ ^#literal_u64 ~ ^")"
+                    | START ~ ^#literal_u64 ~ ^INCREMENT ~ ^#literal_u64
+                )?
+            },
+            |(_, args)| {
+                let (start, increment) = match args {
+                    Some((_, start, _, increment, _)) => (start, increment),
+                    None => (1, 1),
+                };
+                ColumnConstraint::AutoIncrement(start, increment)
+            },
+        ),
     ));
 
     let comment = map(
@@ -2901,7 +3052,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ~ #type_name
             ~ ( #nullable | #expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [AUTOINCREMENT [START <n> INCREMENT <n>]] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
@@ -2940,12 +3091,22 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::AutoIncrement(start, increment) => {
+                def.expr = Some(ColumnExpr::AutoIncrement { start, increment })
+            }
         }
     }
 
     Ok((i, def))
 }
 
+pub fn table_index_type(i: Input) -> IResult<TableIndexType> {
+    alt((
+        value(TableIndexType::Inverted, rule! { INVERTED }),
+        value(TableIndexType::Vector, rule! { VECTOR }),
+    ))(i)
+}
+
 pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
     map_res(
         rule! {
@@ -3037,6 +3198,26 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
     );
 
+    let secret_privs = map(
+        rule! {
+            USAGE ~ ON ~ SECRET ~ #ident
+        },
+        |(_, _, _, secret)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level: AccountMgrLevel::Secret(secret.to_string()),
+        },
+    );
+
+    let secret_all_privs = map(
+        rule! {
+            ALL ~ PRIVILEGES? ~ ON ~ SECRET ~ #ident
+        },
+        |(_, _, _, _, secret)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level: AccountMgrLevel::Secret(secret.to_string()),
+        },
+    );
+
     let stage_privs = map(
         rule! {
             #comma_separated_list1(stage_priv_type) ~ ON ~ STAGE ~ #ident
@@ -3050,9 +3231,11 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     rule!(
         #role : "ROLE <role_name>"
         | #udf_privs: "USAGE ON UDF <udf_name>"
+        | #secret_privs: "USAGE ON SECRET <secret_name>"
         | #privs : "<privileges> ON <privileges_level>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
+        | #secret_all_privs: "ALL [ PRIVILEGES ] ON SECRET <secret_name>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
 }
@@ -3224,10 +3407,12 @@ pub fn grant_ownership_level(i: Input) -> IResult<AccountMgrLevel> {
     enum Object {
         Stage,
         Udf,
+        Secret,
     }
     let object = alt((
         value(Object::Udf, rule! { UDF }),
         value(Object::Stage, rule! { STAGE }),
+        value(Object::Secret, rule! { SECRET }),
     ));
 
     // Object object_name
@@ -3236,13 +3421,14 @@ pub fn grant_ownership_level(i: Input) -> IResult<AccountMgrLevel> {
         |(object, object_name)| match object {
             Object::Stage => AccountMgrLevel::Stage(object_name.to_string()),
             Object::Udf => AccountMgrLevel::UDF(object_name.to_string()),
+            Object::Secret => AccountMgrLevel::Secret(object_name.to_string()),
         },
     );
 
     rule!(
         #db : "<database>.*"
         | #table : "<database>.<table>"
-        | #object : "STAGE | UDF <object_name>"
+        | #object : "STAGE | UDF | SECRET <object_name>"
     )(i)
 }
 
@@ -3519,6 +3705,20 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _)| AlterTableAction::DropTableClusterKey,
     );
 
+    let modify_ttl = map(
+        rule! {
+            MODIFY ~ TTL ~ ^#expr
+        },
+        |(_, _, ttl)| AlterTableAction::ModifyTtl { ttl },
+    );
+
+    let drop_ttl = map(
+        rule! {
+            DROP ~ TTL
+        },
+        |(_, _)| AlterTableAction::DropTtl,
+    );
+
     let recluster_table = map(
         rule! {
             RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ #literal_u64 )?
@@ -3554,6 +3754,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #modify_ttl
+        | #drop_ttl
         | #rename_table
         | #rename_column
         | #modify_table_comment
@@ -4132,6 +4334,18 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, val)| UserOptionItem::MustChangePassword(val),
     );
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| UserOptionItem::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| UserOptionItem::UnsetWorkloadGroup,
+    );
 
     rule!(
         #tenant_setting
@@ -4143,6 +4357,8 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_password_policy
         | #set_disabled_option
         | #must_change_password
+        | #set_workload_group
+        | #unset_workload_group
     )(i)
 }
 
@@ -4439,6 +4655,100 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
     )(i)
 }
 
+pub fn workload_group_set_options(i: Input) -> IResult<WorkloadGroupSetOptions> {
+    map(
+        rule! {
+             ( MAX_CONCURRENCY ~ Eq ~ ^#literal_u64 )?
+             ~ ( QUERY_MEMORY_LIMIT ~ Eq ~ ^#literal_u64 )?
+             ~ ( MEMORY_LIMIT ~ Eq ~ ^#literal_u64 )?
+             ~ ( CPU_WEIGHT ~ Eq ~ ^#literal_u64 )?
+             ~ ( COMMENT ~ Eq ~ ^#literal_string)?
+        },
+        |(
+            opt_max_concurrency,
+            opt_query_memory_limit,
+            opt_memory_limit,
+            opt_cpu_weight,
+            opt_comment,
+        )| {
+            WorkloadGroupSetOptions {
+                max_concurrency: opt_max_concurrency.map(|opt| opt.2),
+                query_memory_limit: opt_query_memory_limit.map(|opt| opt.2),
+                memory_limit: opt_memory_limit.map(|opt| opt.2),
+                cpu_weight: opt_cpu_weight.map(|opt| opt.2),
+                comment: opt_comment.map(|opt| opt.2),
+            }
+        },
+    )(i)
+}
+
+pub fn workload_group_unset_options(i: Input) -> IResult<WorkloadGroupUnSetOptions> {
+    map(
+        rule! {
+             MAX_CONCURRENCY?
+             ~ QUERY_MEMORY_LIMIT?
+             ~ MEMORY_LIMIT?
+             ~ CPU_WEIGHT?
+             ~ COMMENT?
+        },
+        |(
+            opt_max_concurrency,
+            opt_query_memory_limit,
+            opt_memory_limit,
+            opt_cpu_weight,
+            opt_comment,
+        )| {
+            WorkloadGroupUnSetOptions {
+                max_concurrency: opt_max_concurrency.is_some(),
+                query_memory_limit: opt_query_memory_limit.is_some(),
+                memory_limit: opt_memory_limit.is_some(),
+                cpu_weight: opt_cpu_weight.is_some(),
+                comment: opt_comment.is_some(),
+            }
+        },
+    )(i)
+}
+
+pub fn alter_workload_group_action(i: Input) -> IResult<AlterWorkloadGroupAction> {
+    let set_options = map(
+        rule! {
+           SET ~ #workload_group_set_options
+        },
+        |(_, set_options)| AlterWorkloadGroupAction::SetOptions(set_options),
+    );
+    let unset_options = map(
+        rule! {
+           UNSET ~ #workload_group_unset_options
+        },
+        |(_, unset_options)| AlterWorkloadGroupAction::UnSetOptions(unset_options),
+    );
+
+    rule!(
+        #set_options
+        | #unset_options
+    )(i)
+}
+
+pub fn alter_role_action(i: Input) -> IResult<AlterRoleAction> {
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| AlterRoleAction::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| AlterRoleAction::UnsetWorkloadGroup,
+    );
+
+    rule!(
+        #set_workload_group
+        | #unset_workload_group
+    )(i)
+}
+
 pub fn explain_option(i: Input) -> IResult<ExplainOption> {
     map(
         rule! {
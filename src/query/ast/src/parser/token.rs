@@ -355,6 +355,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -375,6 +377,8 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("ASYNC", ignore(ascii_case))]
@@ -421,6 +425,8 @@ pub enum TokenKind {
     BLOCK,
     #[token("CALL", ignore(ascii_case))]
     CALL,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CASE", ignore(ascii_case))]
     CASE,
     #[token("CAST", ignore(ascii_case))]
@@ -469,6 +475,8 @@ pub enum TokenKind {
     COPY,
     #[token("COUNT", ignore(ascii_case))]
     COUNT,
+    #[token("CPU_WEIGHT", ignore(ascii_case))]
+    CPU_WEIGHT,
     #[token("CREDENTIAL", ignore(ascii_case))]
     CREDENTIAL,
     #[token("CREATE", ignore(ascii_case))]
@@ -511,6 +519,8 @@ pub enum TokenKind {
     DECLARE,
     #[token("DEFAULT", ignore(ascii_case))]
     DEFAULT,
+    #[token("DEFINE", ignore(ascii_case))]
+    DEFINE,
     #[token("DEFLATE", ignore(ascii_case))]
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
@@ -601,6 +611,8 @@ pub enum TokenKind {
     FIELDS,
     #[token("FIELD_DELIMITER", ignore(ascii_case))]
     FIELD_DELIMITER,
+    #[token("FIELD_SOURCE", ignore(ascii_case))]
+    FIELD_SOURCE,
     #[token("NAN_DISPLAY", ignore(ascii_case))]
     NAN_DISPLAY,
     #[token("NULL_DISPLAY", ignore(ascii_case))]
@@ -613,6 +625,8 @@ pub enum TokenKind {
     FILE,
     #[token("FILES", ignore(ascii_case))]
     FILES,
+    #[token("FILL", ignore(ascii_case))]
+    FILL,
     #[token("FINAL", ignore(ascii_case))]
     FINAL,
     #[token("FLASHBACK", ignore(ascii_case))]
@@ -651,6 +665,8 @@ pub enum TokenKind {
     SET_VAR,
     #[token("FUSE", ignore(ascii_case))]
     FUSE,
+    #[token("GAP_FILL", ignore(ascii_case))]
+    GAP_FILL,
     #[token("GET", ignore(ascii_case))]
     GET,
     #[token("GENERATED", ignore(ascii_case))]
@@ -665,6 +681,8 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -689,12 +707,16 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IDENTIFIER", ignore(ascii_case))]
     IDENTIFIER,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INCLUDE_QUERY_ID", ignore(ascii_case))]
     INCLUDE_QUERY_ID,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INCREMENTAL", ignore(ascii_case))]
     INCREMENTAL,
     #[token("INDEX", ignore(ascii_case))]
@@ -719,6 +741,8 @@ pub enum TokenKind {
     INT8,
     #[token("INTEGER", ignore(ascii_case))]
     INTEGER,
+    #[token("INTERPOLATE", ignore(ascii_case))]
+    INTERPOLATE,
     #[token("INTERVAL", ignore(ascii_case))]
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
@@ -753,6 +777,8 @@ pub enum TokenKind {
     LATERAL,
     #[token("LINEAR", ignore(ascii_case))]
     LINEAR,
+    #[token("LOCF", ignore(ascii_case))]
+    LOCF,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
     #[token("LOCKS", ignore(ascii_case))]
@@ -790,14 +816,20 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEDIUM", ignore(ascii_case))]
     MEDIUM,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
+    #[token("MEASURES", ignore(ascii_case))]
+    MEASURES,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MEMORY_LIMIT", ignore(ascii_case))]
+    MEMORY_LIMIT,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -822,6 +854,8 @@ pub enum TokenKind {
     NATURAL,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
+    #[token("NEXT", ignore(ascii_case))]
+    NEXT,
     #[token("DISABLED", ignore(ascii_case))]
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
@@ -848,6 +882,8 @@ pub enum TokenKind {
     OFFSET,
     #[token("ON", ignore(ascii_case))]
     ON,
+    #[token("ONE", ignore(ascii_case))]
+    ONE,
     #[token("ON_CREATE", ignore(ascii_case))]
     ON_CREATE,
     #[token("ON_SCHEDULE", ignore(ascii_case))]
@@ -878,6 +914,8 @@ pub enum TokenKind {
     PARQUET,
     #[token("PASSWORD", ignore(ascii_case))]
     PASSWORD,
+    #[token("PAST", ignore(ascii_case))]
+    PAST,
     #[token("PASSWORD_MIN_LENGTH", ignore(ascii_case))]
     PASSWORD_MIN_LENGTH,
     #[token("PASSWORD_MAX_LENGTH", ignore(ascii_case))]
@@ -902,6 +940,8 @@ pub enum TokenKind {
     PASSWORD_HISTORY,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PER", ignore(ascii_case))]
+    PER,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
@@ -926,6 +966,8 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUERY_MEMORY_LIMIT", ignore(ascii_case))]
+    QUERY_MEMORY_LIMIT,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
     #[token("RANGE", ignore(ascii_case))]
@@ -946,6 +988,8 @@ pub enum TokenKind {
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
+    #[token("RESTART", ignore(ascii_case))]
+    RESTART,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
     #[token("RETURN_FAILED_ONLY", ignore(ascii_case))]
@@ -958,6 +1002,12 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH", ignore(ascii_case))]
+    MATCH,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
+    #[token("MATCH_RECOGNIZE", ignore(ascii_case))]
+    MATCH_RECOGNIZE,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -1022,6 +1072,12 @@ pub enum TokenKind {
     SCHEMAS,
     #[token("SECOND", ignore(ascii_case))]
     SECOND,
+    #[token("SECRET", ignore(ascii_case))]
+    SECRET,
+    #[token("SECRETS", ignore(ascii_case))]
+    SECRETS,
+    #[token("SECRET_STRING", ignore(ascii_case))]
+    SECRET_STRING,
     #[token("MILLISECOND", ignore(ascii_case))]
     MILLISECOND,
     #[token("SELECT", ignore(ascii_case))]
@@ -1060,6 +1116,8 @@ pub enum TokenKind {
     SINGLE,
     #[token("SIZE_LIMIT", ignore(ascii_case))]
     SIZE_LIMIT,
+    #[token("SKIP", ignore(ascii_case))]
+    SKIP,
     #[token("MAX_FILES", ignore(ascii_case))]
     MAX_FILES,
     #[token("SKIP_HEADER", ignore(ascii_case))]
@@ -1074,6 +1132,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -1086,6 +1146,8 @@ pub enum TokenKind {
     UPLOAD,
     #[token("SEQUENCE", ignore(ascii_case))]
     SEQUENCE,
+    #[token("SEQUENCES", ignore(ascii_case))]
+    SEQUENCES,
     #[token("SHARE", ignore(ascii_case))]
     SHARE,
     #[token("SHARES", ignore(ascii_case))]
@@ -1138,6 +1200,8 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIME_BUCKET_GAPFILL", ignore(ascii_case))]
+    TIME_BUCKET_GAPFILL,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -1166,6 +1230,8 @@ pub enum TokenKind {
     TRY_CAST,
     #[token("TSV", ignore(ascii_case))]
     TSV,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TUPLE", ignore(ascii_case))]
     TUPLE,
     #[token("TYPE", ignore(ascii_case))]
@@ -1216,6 +1282,10 @@ pub enum TokenKind {
     VARIABLE,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
+    #[token("VERSION", ignore(ascii_case))]
+    VERSION,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("GRAPHICAL", ignore(ascii_case))]
     GRAPHICAL,
     #[token("VIEW", ignore(ascii_case))]
@@ -1276,6 +1346,8 @@ pub enum TokenKind {
     OWNERSHIP,
     #[token("READ", ignore(ascii_case))]
     READ,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
     #[token("WRITE", ignore(ascii_case))]
     WRITE,
     #[token("UDF", ignore(ascii_case))]
@@ -1336,6 +1408,10 @@ pub enum TokenKind {
     ABORT,
     #[token("ROLLBACK", ignore(ascii_case))]
     ROLLBACK,
+    #[token("SAVEPOINT", ignore(ascii_case))]
+    SAVEPOINT,
+    #[token("RELEASE", ignore(ascii_case))]
+    RELEASE,
     #[token("TEMPORARY", ignore(ascii_case))]
     TEMPORARY,
     #[token("TEMP", ignore(ascii_case))]
@@ -1726,6 +1802,10 @@ impl TokenKind {
             | TokenKind::PIPE
             | TokenKind::STREAM
             | TokenKind::NOTIFICATION
+            | TokenKind::ASOF
+            | TokenKind::MATCH_CONDITION
+            | TokenKind::MATCH_RECOGNIZE
+            | TokenKind::GAP_FILL
             if !after_as => true,
             _ => false
         }
@@ -21,6 +21,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -38,6 +40,7 @@ use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReq;
+use databend_common_meta_app::schema::DBIdTableName;
 use databend_common_meta_app::schema::DeleteLockRevReq;
 use databend_common_meta_app::schema::DictionaryIdentity;
 use databend_common_meta_app::schema::DictionaryMeta;
@@ -69,6 +72,8 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListSequencesReply;
+use databend_common_meta_app::schema::ListSequencesReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -240,6 +245,13 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
     /// Get the table name by meta id.
     async fn get_table_name_by_id(&self, table_id: u64) -> Result<Option<String>>;
 
+    /// Get the id of the database that the table belongs to and the table name by meta id.
+    async fn get_db_id_table_name_by_id(&self, _table_id: u64) -> Result<Option<DBIdTableName>> {
+        Err(ErrorCode::Unimplemented(
+            "'get_db_id_table_name_by_id' not implemented",
+        ))
+    }
+
     // Get one table by db and table name.
     async fn get_table(
         &self,
@@ -504,6 +516,10 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         req: GetSequenceNextValueReq,
     ) -> Result<GetSequenceNextValueReply>;
 
+    async fn alter_sequence(&self, req: AlterSequenceReq) -> Result<AlterSequenceReply>;
+
+    async fn list_sequences(&self, req: ListSequencesReq) -> Result<ListSequencesReply>;
+
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply>;
 
     fn set_session_state(&self, _state: SessionState) -> Arc<dyn Catalog> {
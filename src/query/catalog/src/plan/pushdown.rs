@@ -136,6 +136,24 @@ pub struct InvertedIndexInfo {
     pub inverted_index_option: Option<InvertedIndexOption>,
 }
 
+/// Information about vector index, used to search the nearest vectors
+/// of `ORDER BY <distance>(<column>, <query>) LIMIT <n>`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    /// The index name.
+    pub index_name: String,
+    /// The index version.
+    pub index_version: String,
+    /// The name of the indexed column.
+    pub column_name: String,
+    /// The distance function of the index, `cosine` or `l2`.
+    pub distance: String,
+    /// The query vector.
+    pub query_values: Vec<F32>,
+    /// The number of nearest vectors to search.
+    pub limit: usize,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -166,6 +184,8 @@ pub struct PushDownInfo {
     pub change_type: Option<ChangeType>,
     /// Optional inverted index
     pub inverted_index: Option<InvertedIndexInfo>,
+    /// Optional vector index
+    pub vector_index: Option<VectorIndexInfo>,
     /// Used by table sample
     pub sample: Option<SampleConfig>,
 }
@@ -506,6 +506,7 @@ pub enum TimeNavigation {
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    Version(u64),
     StreamInfo(TableInfo),
 }
 
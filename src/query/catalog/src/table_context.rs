@@ -119,6 +119,14 @@ impl Display for ProcessInfoState {
     }
 }
 
+/// The runtime state of a workload group on the current node.
+#[derive(Debug, Clone, Default)]
+pub struct WorkloadGroupStatus {
+    pub running_queries: u64,
+    pub queued_queries: u64,
+    pub memory_usage: i64,
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -181,6 +189,14 @@ pub trait TableContext: Send + Sync {
     fn get_compaction_num_block_hint(&self, _table_name: &str) -> u64 {
         unimplemented!()
     }
+    /// Sets the options that are committed along with the data of the table,
+    /// in the same table meta update.
+    fn set_table_commit_options(&self, _table_id: u64, _options: BTreeMap<String, String>) {
+        unimplemented!()
+    }
+    fn get_table_commit_options(&self, _table_id: u64) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
     fn set_table_snapshot(&self, snapshot: Arc<TableSnapshot>);
     fn get_table_snapshot(&self) -> Option<Arc<TableSnapshot>>;
     fn set_lazy_mutation_delete(&self, lazy: bool);
@@ -241,6 +257,9 @@ pub trait TableContext: Send + Sync {
     fn get_cluster(&self) -> Arc<Cluster>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_queued_queries(&self) -> Vec<ProcessInfo>;
+    fn get_workload_groups_status(&self) -> HashMap<String, WorkloadGroupStatus> {
+        unimplemented!()
+    }
     fn get_queries_profile(&self) -> HashMap<String, Vec<PlanProfile>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
@@ -1402,6 +1402,24 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub mysql_tls_server_key: String,
 
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub postgres_handler_enabled: bool,
+
+    #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "15432")]
+    pub postgres_handler_port: u16,
+
+    #[clap(long, value_name = "VALUE", default_value = "120")]
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub postgres_tls_server_cert: String,
+
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub postgres_tls_server_key: String,
+
     #[clap(long, value_name = "VALUE", default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -1713,6 +1731,13 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
+            postgres_handler_enabled: self.postgres_handler_enabled,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: self
+                .postgres_handler_tcp_keepalive_timeout_secs,
+            postgres_tls_server_cert: self.postgres_tls_server_cert,
+            postgres_tls_server_key: self.postgres_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
             max_server_memory_usage: self.max_server_memory_usage,
@@ -1798,6 +1823,13 @@ impl From<InnerQueryConfig> for QueryConfig {
                 .mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
+            postgres_handler_enabled: inner.postgres_handler_enabled,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: inner
+                .postgres_handler_tcp_keepalive_timeout_secs,
+            postgres_tls_server_cert: inner.postgres_tls_server_cert,
+            postgres_tls_server_key: inner.postgres_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
             max_server_memory_usage: inner.max_server_memory_usage,
@@ -170,6 +170,12 @@ pub struct QueryConfig {
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
+    pub postgres_handler_enabled: bool,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+    pub postgres_tls_server_cert: String,
+    pub postgres_tls_server_key: String,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
     pub max_server_memory_usage: u64,
@@ -262,6 +268,12 @@ impl Default for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: 120,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
+            postgres_handler_enabled: false,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 15432,
+            postgres_handler_tcp_keepalive_timeout_secs: 120,
+            postgres_tls_server_cert: "".to_string(),
+            postgres_tls_server_key: "".to_string(),
             max_active_sessions: 256,
             max_running_queries: 8,
             max_server_memory_usage: 0,
@@ -35,6 +35,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::plans::RefreshTableIndexPlan;
 use databend_common_sql::BloomIndexColumns;
@@ -122,6 +123,7 @@ async fn test_block_pruner() -> Result<()> {
         as_select: None,
         cluster_key: None,
         inverted_indexes: None,
+        sequences: vec![],
     };
 
     let interpreter = CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
@@ -513,6 +515,7 @@ async fn test_block_pruner() -> Result<()> {
         create_option: CreateOption::Create,
         table_id,
         tenant,
+        index_type: TableIndexType::Inverted,
         name: index_name.clone(),
         column_ids: vec![1, 2, 3],
         sync_creation: false,
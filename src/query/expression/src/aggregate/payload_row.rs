@@ -29,6 +29,7 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::TimestampType;
@@ -51,6 +52,7 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         },
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Interval => 16,
         // use address instead
         DataType::Binary
         | DataType::String
@@ -138,6 +140,11 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Interval(buffer) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -302,6 +309,18 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Interval(_) => row_match_column_type::<IntervalType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
         Column::Bitmap(v) | Column::Binary(v) | Column::Variant(v) | Column::Geometry(v) => {
             row_match_binary_column(
                 v,
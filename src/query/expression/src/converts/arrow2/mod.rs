@@ -21,5 +21,6 @@ pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
 pub const ARROW_EXT_TYPE_GEOGRAPHY: &str = "Geography";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
 
 pub use to::set_validities;
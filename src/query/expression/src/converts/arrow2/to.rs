@@ -25,9 +25,11 @@ use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOGRAPHY;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::decimal::DecimalColumn;
 use crate::types::geography::GeographyColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
@@ -171,6 +173,11 @@ fn table_type_to_arrow_type(ty: &TableDataType) -> ArrowDataType {
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        TableDataType::Interval => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INTERVAL.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
     }
 }
 
@@ -357,6 +364,17 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values = unsafe {
+                    std::mem::transmute::<Buffer<months_days_micros>, Buffer<i128>>(col.clone())
+                };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
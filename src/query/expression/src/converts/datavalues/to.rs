@@ -56,7 +56,8 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         | Scalar::Binary(_)
         | Scalar::Map(_)
         | Scalar::Bitmap(_)
-        | Scalar::Geography(_) => {
+        | Scalar::Geography(_)
+        | Scalar::Interval(_) => {
             unimplemented!()
         }
     }
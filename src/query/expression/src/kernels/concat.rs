@@ -45,6 +45,7 @@ use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::DecimalType;
 use crate::types::GeographyType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -186,6 +187,13 @@ impl Column {
                 );
                 Column::Date(buffer)
             }
+            Column::Interval(_) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| IntervalType::try_downcast_column(&col).unwrap()),
+                    capacity,
+                );
+                Column::Interval(buffer)
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
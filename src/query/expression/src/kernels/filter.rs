@@ -254,6 +254,13 @@ impl<'a> ValueVisitor for FilterVisitor<'a> {
         Ok(())
     }
 
+    fn visit_interval(&mut self, buffer: Buffer<interval::months_days_micros>) -> Result<()> {
+        self.result = Some(Value::Column(IntervalType::upcast_column(
+            self.filter_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
@@ -90,6 +90,7 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
         }
         Column::Timestamp(v) => row_space.store_value_uncheckd(&v[row]),
         Column::Date(v) => row_space.store_value_uncheckd(&v[row]),
+        Column::Interval(v) => row_space.store_value_uncheckd(&v[row].0),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             row_space.store_value_uncheckd(&(data.len() as u64));
@@ -150,6 +150,13 @@ where I: databend_common_arrow::arrow::types::Index
         Ok(())
     }
 
+    fn visit_interval(&mut self, buffer: Buffer<interval::months_days_micros>) -> Result<()> {
+        self.result = Some(Value::Column(IntervalType::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
@@ -17,6 +17,7 @@ use ethnum::i256;
 
 use super::row_converter::null_sentinel;
 use crate::types::binary::BinaryColumnBuilder;
+use crate::types::interval::months_days_micros;
 use crate::types::F32;
 use crate::types::F64;
 
@@ -98,6 +99,18 @@ impl FixedLengthEncoding for F64 {
     }
 }
 
+impl FixedLengthEncoding for months_days_micros {
+    type Encoded = [u8; 32];
+
+    fn encode(self) -> [u8; 32] {
+        // Sort by the length of the interval first, then by the fields.
+        let mut b = [0; 32];
+        b[..16].copy_from_slice(&self.total_micros().encode());
+        b[16..].copy_from_slice(&self.0.encode());
+        b
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut BinaryColumnBuilder,
     iter: I,
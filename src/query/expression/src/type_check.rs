@@ -746,6 +746,7 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_decimal",
     "to_bitmap",
     "to_geometry",
+    "to_interval",
     "parse_json",
 ];
 
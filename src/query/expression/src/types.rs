@@ -24,6 +24,7 @@ pub mod empty_map;
 pub mod generic;
 pub mod geography;
 pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -57,6 +58,7 @@ pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
 pub use self::geography::GeographyColumn;
 pub use self::geography::GeographyType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableColumn;
@@ -95,6 +97,7 @@ pub enum DataType {
     Variant,
     Geometry,
     Geography,
+    Interval,
 
     // Used internally for generic types
     Generic(usize),
@@ -146,6 +149,7 @@ impl DataType {
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
@@ -170,6 +174,7 @@ impl DataType {
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
@@ -0,0 +1,639 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_PER_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::utils::date_helper::MICROSECS_PER_DAY;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SEC;
+pub const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+/// Number of days in a month when an interval is compared or normalized.
+pub const DAYS_PER_MONTH: i64 = 30;
+
+/// An interval of time, made of months, days and microseconds.
+///
+/// The three fields are kept separately because their lengths are not fixed:
+/// a month has 28 to 31 days, and a day may have 23 or 25 hours across a DST change.
+/// They are packed into an `i128`, the months take the highest 32 bits, the days
+/// the following 32 bits, and the microseconds the lowest 64 bits.
+#[allow(non_camel_case_types)]
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[repr(transparent)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        let months = (months as i128) << 96;
+        let days = ((days as u32) as i128) << 64;
+        let microseconds = (microseconds as u64) as i128;
+        months_days_micros(months | days | microseconds)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    #[inline]
+    pub fn microseconds(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// Splits an elapsed time into whole days and the remaining microseconds,
+    /// used for the difference of two timestamps.
+    pub fn from_micros(micros: i64) -> Self {
+        Self::new(
+            0,
+            (micros / MICROSECS_PER_DAY) as i32,
+            micros % MICROSECS_PER_DAY,
+        )
+    }
+
+    /// The length of the interval in microseconds, a month counts as 30 days.
+    pub fn total_micros(&self) -> i128 {
+        (self.months() as i128 * DAYS_PER_MONTH as i128 + self.days() as i128)
+            * MICROSECS_PER_DAY as i128
+            + self.microseconds() as i128
+    }
+
+    fn try_new(months: i128, days: i128, microseconds: i128) -> Option<Self> {
+        Some(Self::new(
+            i32::try_from(months).ok()?,
+            i32::try_from(days).ok()?,
+            i64::try_from(microseconds).ok()?,
+        ))
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Self::try_new(
+            self.months() as i128 + other.months() as i128,
+            self.days() as i128 + other.days() as i128,
+            self.microseconds() as i128 + other.microseconds() as i128,
+        )
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Self::try_new(
+            self.months() as i128 - other.months() as i128,
+            self.days() as i128 - other.days() as i128,
+            self.microseconds() as i128 - other.microseconds() as i128,
+        )
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Self::try_new(
+            -(self.months() as i128),
+            -(self.days() as i128),
+            -(self.microseconds() as i128),
+        )
+    }
+
+    /// Divides the interval by `n`, the remainder of the months is carried
+    /// into the days, and the remainder of the days into the microseconds.
+    pub fn checked_div(&self, n: i64) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        let n = n as i128;
+        let months = self.months() as i128;
+        let days = (months % n) * DAYS_PER_MONTH as i128 + self.days() as i128;
+        let micros = (days % n) * MICROSECS_PER_DAY as i128 + self.microseconds() as i128;
+        Self::try_new(months / n, days / n, micros / n)
+    }
+
+    /// Truncates the interval to the given unit, the fields smaller than the unit are set to zero.
+    pub fn trunc(&self, unit: IntervalUnit) -> Self {
+        let months = self.months();
+        let days = self.days();
+        let micros = self.microseconds();
+        match unit {
+            IntervalUnit::Year => Self::new(months / 12 * 12, 0, 0),
+            IntervalUnit::Quarter => Self::new(months / 3 * 3, 0, 0),
+            IntervalUnit::Month => Self::new(months, 0, 0),
+            IntervalUnit::Day => Self::new(months, days, 0),
+            IntervalUnit::Hour => Self::new(months, days, micros - micros % MICROS_PER_HOUR),
+            IntervalUnit::Minute => Self::new(months, days, micros - micros % MICROS_PER_MINUTE),
+            IntervalUnit::Second => Self::new(months, days, micros - micros % MICROS_PER_SEC),
+        }
+    }
+}
+
+/// Intervals are ordered by their length with 30 days per month, intervals of the
+/// same length like `1 month` and `30 days` are ordered by their fields.
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Debug for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Formats the interval in the PostgreSQL style, such as `1 year 2 mons 3 days 04:05:06.789`.
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn plural(n: i64) -> &'static str {
+            if n == 1 { "" } else { "s" }
+        }
+
+        let mut parts = vec![];
+        let years = self.months() / 12;
+        let months = self.months() % 12;
+        if years != 0 {
+            parts.push(format!("{} year{}", years, plural(years as i64)));
+        }
+        if months != 0 {
+            parts.push(format!("{} mon{}", months, plural(months as i64)));
+        }
+        if self.days() != 0 {
+            parts.push(format!("{} day{}", self.days(), plural(self.days() as i64)));
+        }
+
+        let micros = self.microseconds();
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let micros = micros.unsigned_abs();
+            let hours = micros / MICROS_PER_HOUR as u64;
+            let minutes = micros % MICROS_PER_HOUR as u64 / MICROS_PER_MINUTE as u64;
+            let seconds = micros % MICROS_PER_MINUTE as u64 / MICROS_PER_SEC as u64;
+            let fraction = micros % MICROS_PER_SEC as u64;
+            let mut time = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+            if fraction != 0 {
+                let fraction = format!("{fraction:06}");
+                time.push('.');
+                time.push_str(fraction.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// The units an interval can be truncated to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalUnit {
+    Year,
+    Quarter,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = SimpleDomain<months_days_micros>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<months_days_micros>> {
+        domain.as_interval().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<months_days_micros>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.resize(builder.len() + n, item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> Ordering {
+        left.cmp(&right)
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: months_days_micros::new(i32::MIN, i32::MIN, i64::MIN),
+            max: months_days_micros::new(i32::MAX, i32::MAX, i64::MAX),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parses an interval from an ISO-8601 duration such as `P1Y2M3DT4H5M6.5S`,
+/// or a PostgreSQL style string such as `1 year 2 months 3 days 04:05:06.5 ago`.
+pub fn string_to_interval(s: &str) -> Result<months_days_micros> {
+    let s = s.trim();
+    let s = s.strip_prefix('@').unwrap_or(s).trim_start();
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let interval = if rest.starts_with(['P', 'p']) {
+        parse_iso8601_interval(&rest[1..]).map(
+            |interval| {
+                if negative { interval.neg() } else { interval }
+            },
+        )
+    } else {
+        parse_postgres_interval(s)
+    };
+    interval
+        .and_then(|interval| interval.finish())
+        .ok_or_else(|| ErrorCode::BadArguments(format!("invalid interval value: '{}'", s)))
+}
+
+/// The fields of an interval while it is parsed, wide enough not to overflow.
+#[derive(Default)]
+struct IntervalAccumulator {
+    months: i128,
+    days: i128,
+    micros: i128,
+}
+
+impl IntervalAccumulator {
+    /// Adds `value` of the unit, the fraction of a month or a day is spread into
+    /// the smaller fields like PostgreSQL does.
+    fn add(&mut self, value: f64, unit: &str) -> Option<()> {
+        let unit = unit.to_ascii_lowercase();
+        let (months, days, micros) = match unit.as_str() {
+            "millennium" | "millennia" | "millenniums" | "mil" | "mils" => {
+                (value * 12000.0, 0.0, 0.0)
+            }
+            "century" | "centuries" | "c" => (value * 1200.0, 0.0, 0.0),
+            "decade" | "decades" | "dec" | "decs" => (value * 120.0, 0.0, 0.0),
+            "year" | "years" | "yr" | "yrs" | "y" => (value * 12.0, 0.0, 0.0),
+            "quarter" | "quarters" | "qtr" | "q" => (value * 3.0, 0.0, 0.0),
+            "month" | "months" | "mon" | "mons" => (value, 0.0, 0.0),
+            "week" | "weeks" | "w" => (0.0, value * 7.0, 0.0),
+            "day" | "days" | "d" => (0.0, value, 0.0),
+            "hour" | "hours" | "hr" | "hrs" | "h" => (0.0, 0.0, value * MICROS_PER_HOUR as f64),
+            "minute" | "minutes" | "min" | "mins" | "m" => {
+                (0.0, 0.0, value * MICROS_PER_MINUTE as f64)
+            }
+            "second" | "seconds" | "sec" | "secs" | "s" => {
+                (0.0, 0.0, value * MICROS_PER_SEC as f64)
+            }
+            "millisecond" | "milliseconds" | "msec" | "msecs" | "ms" => (0.0, 0.0, value * 1000.0),
+            "microsecond" | "microseconds" | "usec" | "usecs" | "us" => (0.0, 0.0, value),
+            _ => return None,
+        };
+        self.add_fields(months, days, micros)
+    }
+
+    fn add_fields(&mut self, months: f64, days: f64, micros: f64) -> Option<()> {
+        if !(months.is_finite() && days.is_finite() && micros.is_finite()) {
+            return None;
+        }
+        let whole_months = months.trunc();
+        let days = days + (months - whole_months) * DAYS_PER_MONTH as f64;
+        let whole_days = days.trunc();
+        let micros = micros + (days - whole_days) * MICROSECS_PER_DAY as f64;
+        if whole_months.abs() > i64::MAX as f64
+            || whole_days.abs() > i64::MAX as f64
+            || micros.abs() > i64::MAX as f64
+        {
+            return None;
+        }
+        self.months += whole_months as i128;
+        self.days += whole_days as i128;
+        self.micros += micros.round() as i128;
+        Some(())
+    }
+
+    fn neg(self) -> Self {
+        IntervalAccumulator {
+            months: -self.months,
+            days: -self.days,
+            micros: -self.micros,
+        }
+    }
+
+    fn finish(self) -> Option<months_days_micros> {
+        months_days_micros::try_new(self.months, self.days, self.micros)
+    }
+}
+
+fn parse_iso8601_interval(s: &str) -> Option<IntervalAccumulator> {
+    let mut acc = IntervalAccumulator::default();
+    let mut in_time = false;
+    let mut has_field = false;
+    let mut rest = s;
+    while !rest.is_empty() {
+        if let Some(time) = rest.strip_prefix(['T', 't']) {
+            if in_time {
+                return None;
+            }
+            in_time = true;
+            rest = time;
+            continue;
+        }
+        let end =
+            rest.find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+')))?;
+        let value: f64 = rest[..end].replace(',', ".").parse().ok()?;
+        let designator = rest[end..].chars().next()?.to_ascii_uppercase();
+        let unit = match (designator, in_time) {
+            ('Y', false) => "year",
+            ('M', false) => "month",
+            ('W', false) => "week",
+            ('D', false) => "day",
+            ('H', true) => "hour",
+            ('M', true) => "minute",
+            ('S', true) => "second",
+            _ => return None,
+        };
+        acc.add(value, unit)?;
+        has_field = true;
+        rest = &rest[end + 1..];
+    }
+    has_field.then_some(acc)
+}
+
+fn parse_postgres_interval(s: &str) -> Option<IntervalAccumulator> {
+    let mut acc = IntervalAccumulator::default();
+    let mut tokens = s.split_whitespace().peekable();
+    let mut has_field = false;
+    let mut ago = false;
+    while let Some(token) = tokens.next() {
+        if ago {
+            return None;
+        }
+        if token.eq_ignore_ascii_case("ago") {
+            ago = true;
+            continue;
+        }
+        if token.contains(':') {
+            acc.add_fields(0.0, 0.0, parse_time_of_interval(token)?)?;
+            has_field = true;
+            continue;
+        }
+        // The unit can follow the number directly, such as `3days` or `1h`.
+        let end = token
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+            .unwrap_or(token.len());
+        let value: f64 = token[..end].parse().ok()?;
+        let unit = if end < token.len() {
+            &token[end..]
+        } else {
+            match tokens.peek() {
+                Some(next) if !next.eq_ignore_ascii_case("ago") && !next.contains(':') => {
+                    tokens.next().unwrap()
+                }
+                // A number without unit is a number of seconds.
+                _ => "second",
+            }
+        };
+        acc.add(value, unit)?;
+        has_field = true;
+    }
+    if !has_field {
+        return None;
+    }
+    Some(if ago { acc.neg() } else { acc })
+}
+
+/// Parses `[-]HH:MM[:SS[.ffffff]]` into microseconds.
+fn parse_time_of_interval(s: &str) -> Option<f64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut parts = s.split(':');
+    let hours: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = match parts.next() {
+        Some(seconds) => seconds.parse().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || minutes >= 60 || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours
+        .checked_mul(MICROS_PER_HOUR as u64)?
+        .checked_add(minutes * MICROS_PER_MINUTE as u64)?;
+    let micros = micros as f64 + seconds * MICROS_PER_SEC as f64;
+    Some(if negative { -micros } else { micros })
+}
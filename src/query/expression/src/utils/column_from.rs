@@ -67,6 +67,7 @@ impl_from_data! { BinaryType }
 impl_from_data! { StringType }
 impl_from_data! { DateType }
 impl_from_data! { TimestampType }
+impl_from_data! { IntervalType }
 impl_from_data! { VariantType }
 impl_from_data! { BitmapType }
 impl_from_data! { GeometryType }
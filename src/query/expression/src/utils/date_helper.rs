@@ -33,8 +33,11 @@ use databend_common_io::cursor_ext::unwrap_local_time;
 use num_traits::AsPrimitive;
 
 use crate::types::date::clamp_date;
+use crate::types::interval::months_days_micros;
 use crate::types::timestamp::clamp_timestamp;
 use crate::types::timestamp::MICROS_PER_SEC;
+use crate::types::timestamp::TIMESTAMP_MAX;
+use crate::types::timestamp::TIMESTAMP_MIN;
 
 #[derive(Debug, Clone, Copy)]
 pub struct TzLUT {
@@ -530,6 +533,95 @@ impl EvalTimesImpl {
     }
 }
 
+/// The buckets of `time_bucket` and `date_bin`, which are `width` wide and aligned to an origin.
+/// A width of months is counted in the calendar of the time zone, the other widths are fixed,
+/// a day is always 24 hours.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeBucket {
+    months: i32,
+    micros: i64,
+    origin: i64,
+    tz: TzLUT,
+}
+
+impl TimeBucket {
+    pub fn try_create(
+        width: months_days_micros,
+        origin: Option<i64>,
+        tz: TzLUT,
+    ) -> std::result::Result<Self, String> {
+        let months = width.months();
+        let micros = (width.days() as i64)
+            .checked_mul(MICROSECS_PER_DAY)
+            .and_then(|days| days.checked_add(width.microseconds()))
+            .ok_or_else(|| format!("Bucket width {width} is out of range"))?;
+        if months != 0 && micros != 0 {
+            return Err(format!(
+                "Bucket width {width} can not mix months with days or time"
+            ));
+        }
+        if months < 0 || micros < 0 || (months == 0 && micros == 0) {
+            return Err(format!("Bucket width {width} must be positive"));
+        }
+        // Like TimescaleDB, the buckets are aligned to 2000-01-03 by default, which is a Monday,
+        // so the weekly buckets start on Mondays. The buckets of months start on 2000-01-01.
+        let origin = origin.unwrap_or_else(|| {
+            let day = if months != 0 { 1 } else { 3 };
+            let origin = NaiveDate::from_ymd_opt(2000, 1, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
+            tz.tz.from_local_datetime(&origin).earliest().map_or_else(
+                || origin.and_utc().timestamp_micros(),
+                |t| t.timestamp_micros(),
+            )
+        });
+        Ok(Self {
+            months,
+            micros,
+            origin,
+            tz,
+        })
+    }
+
+    /// The index of the bucket which contains `ts`, the bucket starting at the origin is 0.
+    pub fn bucket_index(&self, ts: i64) -> i64 {
+        if self.months == 0 {
+            return (ts as i128 - self.origin as i128).div_euclid(self.micros as i128) as i64;
+        }
+        let origin = self.origin.to_timestamp(self.tz.tz);
+        let datetime = ts.to_timestamp(self.tz.tz);
+        let months = (datetime.year() as i64 - origin.year() as i64) * 12 + datetime.month() as i64
+            - origin.month() as i64;
+        let index = months.div_euclid(self.months as i64);
+        // The bucket starts later in the month than `ts`, e.g. on the 20th for `ts` on the 15th.
+        match self.bucket_start(index) {
+            Ok(start) if start > ts => index - 1,
+            _ => index,
+        }
+    }
+
+    pub fn bucket_start(&self, index: i64) -> std::result::Result<i64, String> {
+        if self.months == 0 {
+            return index
+                .checked_mul(self.micros)
+                .and_then(|delta| delta.checked_add(self.origin))
+                .filter(|start| (TIMESTAMP_MIN..=TIMESTAMP_MAX).contains(start))
+                .ok_or_else(|| "Bucket is out of the range of timestamp".to_string());
+        }
+        let months = index
+            .checked_mul(self.months as i64)
+            .filter(|months| months.abs() <= 12 * 10000)
+            .ok_or_else(|| "Bucket is out of the range of timestamp".to_string())?;
+        EvalMonthsImpl::eval_timestamp(self.origin, self.tz, months)
+    }
+
+    /// The start of the bucket which contains `ts`.
+    pub fn bucket(&self, ts: i64) -> std::result::Result<i64, String> {
+        self.bucket_start(self.bucket_index(ts))
+    }
+}
+
 #[inline]
 pub fn today_date(now: DateTime<Utc>, tz: TzLUT) -> i32 {
     let now = now.with_timezone(&tz.tz);
@@ -539,6 +631,24 @@ pub fn today_date(now: DateTime<Utc>, tz: TzLUT) -> i32 {
         .num_days() as i32
 }
 
+/// The timestamp of the local midnight of `date` in the time zone. The offset of each
+/// date is used, so the daylight saving time is taken into account. If the midnight
+/// is skipped by the clocks, the offset before the change is used.
+pub fn date_to_timestamp(date: i32, tz: Tz) -> i64 {
+    let midnight = NaiveDate::from_ymd_opt(1970, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        + Duration::days(date as i64);
+    match tz.from_local_datetime(&midnight).earliest() {
+        Some(datetime) => datetime.timestamp_micros(),
+        None => {
+            let offset = tz.offset_from_utc_datetime(&midnight).fix();
+            midnight.and_utc().timestamp_micros() - offset.local_minus_utc() as i64 * MICROS_PER_SEC
+        }
+    }
+}
+
 pub trait ToNumber<N> {
     fn to_number(dt: &DateTime<Tz>) -> N;
 }
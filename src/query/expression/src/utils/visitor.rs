@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 use decimal::DecimalType;
 use geometry::GeometryType;
 
+use crate::types::interval::months_days_micros;
 use crate::types::*;
 use crate::*;
 
@@ -67,6 +68,10 @@ pub trait ValueVisitor {
         self.visit_typed_column::<DateType>(buffer)
     }
 
+    fn visit_interval(&mut self, buffer: Buffer<months_days_micros>) -> Result<()> {
+        self.visit_typed_column::<IntervalType>(buffer)
+    }
+
     fn visit_array(&mut self, column: Box<ArrayColumn<AnyType>>) -> Result<()> {
         self.visit_typed_column::<AnyType>(Column::Array(column))
     }
@@ -128,6 +133,7 @@ pub trait ValueVisitor {
             Column::String(column) => self.visit_string(column),
             Column::Timestamp(buffer) => self.visit_timestamp(buffer),
             Column::Date(buffer) => self.visit_date(buffer),
+            Column::Interval(buffer) => self.visit_interval(buffer),
             Column::Array(column) => self.visit_array(column),
             Column::Map(column) => self.visit_map(column),
             Column::Tuple(columns) => self.visit_tuple(columns),
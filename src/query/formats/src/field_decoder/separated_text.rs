@@ -27,6 +27,8 @@ use databend_common_expression::types::date::clamp_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::timestamp::clamp_timestamp;
 use databend_common_expression::types::AnyType;
@@ -50,6 +52,7 @@ use databend_common_io::parse_bitmap;
 use databend_common_io::parse_bytes_to_ewkb;
 use databend_common_meta_app::principal::CsvFileFormatParams;
 use databend_common_meta_app::principal::TsvFileFormatParams;
+use databend_common_meta_app::principal::XmlFileFormatParams;
 use jsonb::parse_value;
 use lexical_core::FromLexical;
 use num_traits::NumCast;
@@ -107,6 +110,22 @@ impl SeparatedTextDecoder {
         }
     }
 
+    pub fn create_xml(_params: &XmlFileFormatParams, options_ext: &FileFormatOptionsExt) -> Self {
+        SeparatedTextDecoder {
+            common_settings: InputCommonSettings {
+                null_if: vec![NULL_BYTES_ESCAPE.as_bytes().to_vec()],
+                true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
+                timezone: options_ext.timezone,
+                disable_variant_check: options_ext.disable_variant_check,
+                binary_format: Default::default(),
+                is_rounding_mode: options_ext.is_rounding_mode,
+                enable_dst_hour_fix: options_ext.enable_dst_hour_fix,
+            },
+            nested_decoder: NestedValues::create(options_ext),
+        }
+    }
+
     fn common_settings(&self) -> &InputCommonSettings {
         &self.common_settings
     }
@@ -144,6 +163,7 @@ impl SeparatedTextDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
+            ColumnBuilder::Interval(c) => self.read_interval(c, data),
             ColumnBuilder::Array(c) => self.read_array(c, data),
             ColumnBuilder::Map(c) => self.read_map(c, data),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
@@ -330,6 +350,12 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, data: &[u8]) -> Result<()> {
+        let s = std::str::from_utf8(data).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+        column.push(string_to_interval(s)?);
+        Ok(())
+    }
+
     fn read_array(&self, column: &mut ArrayColumnBuilder<AnyType>, data: &[u8]) -> Result<()> {
         let mut cursor = Cursor::new(data);
         self.nested_decoder.read_array(column, &mut cursor)
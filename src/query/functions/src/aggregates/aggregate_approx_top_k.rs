@@ -0,0 +1,379 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+
+const DEFAULT_K: u64 = 10;
+/// The number of counters is `k * TOP_K_LOAD_FACTOR` if it's not specified.
+const TOP_K_LOAD_FACTOR: u64 = 3;
+const TOP_K_MAX_SIZE: u64 = 0xFFFFFF;
+
+struct ApproxTopKData {
+    k: u64,
+    counters: u64,
+    data_type: DataType,
+}
+
+impl FunctionData for ApproxTopKData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Clone, BorshSerialize, BorshDeserialize)]
+struct Counter<K> {
+    key: K,
+    count: u64,
+    error: u64,
+}
+
+/// The Space-Saving sketch, see "Efficient Computation of Frequent and Top-k Elements
+/// in Data Streams" by Metwally et al.
+///
+/// At most `capacity` counters are kept, a new key evicts the counter with the minimum
+/// count and inherits the count as its error. The counters form a binary min-heap by
+/// count, so that the minimum counter can be found and updated in `O(log capacity)`.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct SpaceSavingState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + Hash + BorshSerialize + BorshDeserialize,
+{
+    capacity: u64,
+    counters: Vec<Counter<T::Scalar>>,
+    // The position of the counter of each key in `counters`,
+    // rebuilt lazily after the state is deserialized.
+    #[borsh(skip)]
+    index: HashMap<T::Scalar, usize>,
+}
+
+impl<T> Default for SpaceSavingState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + Hash + BorshSerialize + BorshDeserialize,
+{
+    fn default() -> Self {
+        SpaceSavingState::<T> {
+            capacity: 0,
+            counters: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SpaceSavingState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + Hash + BorshSerialize + BorshDeserialize,
+{
+    fn is_full(&self) -> bool {
+        self.counters.len() as u64 >= self.capacity
+    }
+
+    /// The count that a key not in the sketch may have at most.
+    fn min_count(&self) -> u64 {
+        if self.is_full() {
+            self.counters.first().map_or(0, |c| c.count)
+        } else {
+            0
+        }
+    }
+
+    fn ensure_index(&mut self) {
+        if self.index.len() != self.counters.len() {
+            self.index = self
+                .counters
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (c.key.clone(), i))
+                .collect();
+        }
+    }
+
+    fn insert(&mut self, key: T::Scalar) {
+        self.ensure_index();
+        if let Some(&pos) = self.index.get(&key) {
+            self.counters[pos].count += 1;
+            self.sift_down(pos);
+        } else if !self.is_full() {
+            self.index.insert(key.clone(), self.counters.len());
+            self.counters.push(Counter {
+                key,
+                count: 1,
+                error: 0,
+            });
+            self.sift_up(self.counters.len() - 1);
+        } else {
+            let min = self.counters[0].count;
+            self.index.remove(&self.counters[0].key);
+            self.index.insert(key.clone(), 0);
+            self.counters[0] = Counter {
+                key,
+                count: min + 1,
+                error: min,
+            };
+            self.sift_down(0);
+        }
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.counters.swap(i, j);
+        *self.index.get_mut(&self.counters[i].key).unwrap() = i;
+        *self.index.get_mut(&self.counters[j].key).unwrap() = j;
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.counters[parent].count <= self.counters[pos].count {
+                break;
+            }
+            self.swap(parent, pos);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        let len = self.counters.len();
+        loop {
+            let mut smallest = pos;
+            for child in [2 * pos + 1, 2 * pos + 2] {
+                if child < len && self.counters[child].count < self.counters[smallest].count {
+                    smallest = child;
+                }
+            }
+            if smallest == pos {
+                break;
+            }
+            self.swap(pos, smallest);
+            pos = smallest;
+        }
+    }
+
+    fn rebuild(&mut self, counters: Vec<Counter<T::Scalar>>) {
+        self.counters = counters;
+        self.index.clear();
+        self.ensure_index();
+        for pos in (0..self.counters.len() / 2).rev() {
+            self.sift_down(pos);
+        }
+    }
+
+    /// Returns the counters ordered by the count descending.
+    fn top(&self) -> Vec<&Counter<T::Scalar>> {
+        let mut counters = self.counters.iter().collect::<Vec<_>>();
+        counters.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+        counters
+    }
+}
+
+impl<T> UnaryState<T, AnyType> for SpaceSavingState<T>
+where
+    T: ValueType + Sync + Send,
+    T::Scalar: Ord + Hash + Sync + Send + BorshSerialize + BorshDeserialize,
+{
+    fn add(
+        &mut self,
+        other: T::ScalarRef<'_>,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if self.capacity == 0 {
+            let data = unsafe {
+                function_data
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref_unchecked::<ApproxTopKData>()
+            };
+            self.capacity = data.counters;
+        }
+        self.insert(T::to_owned_scalar(other));
+        Ok(())
+    }
+
+    /// Merges two sketches as described in "Mergeable Summaries" by Agarwal et al.,
+    /// a key missing in one sketch is counted with the minimum count of that sketch,
+    /// then the largest counters are kept.
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        if rhs.counters.is_empty() {
+            return Ok(());
+        }
+
+        let capacity = self.capacity.max(rhs.capacity);
+        let lhs_min = self.min_count();
+        let rhs_min = rhs.min_count();
+        let rhs_counters = rhs
+            .counters
+            .iter()
+            .map(|c| (&c.key, c))
+            .collect::<HashMap<_, _>>();
+
+        let mut merged = Vec::with_capacity(self.counters.len() + rhs.counters.len());
+        for counter in self.counters.iter() {
+            let (count, error) = match rhs_counters.get(&counter.key) {
+                Some(r) => (counter.count + r.count, counter.error + r.error),
+                None => (counter.count + rhs_min, counter.error + rhs_min),
+            };
+            merged.push(Counter {
+                key: counter.key.clone(),
+                count,
+                error,
+            });
+        }
+        self.ensure_index();
+        for counter in rhs.counters.iter() {
+            if !self.index.contains_key(&counter.key) {
+                merged.push(Counter {
+                    key: counter.key.clone(),
+                    count: counter.count + lhs_min,
+                    error: counter.error + lhs_min,
+                });
+            }
+        }
+
+        merged.sort_by(|a, b| b.count.cmp(&a.count));
+        merged.truncate(capacity as usize);
+        self.capacity = capacity;
+        self.rebuild(merged);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut ColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let data = unsafe {
+            function_data
+                .unwrap()
+                .as_any()
+                .downcast_ref_unchecked::<ApproxTopKData>()
+        };
+
+        let item_type = DataType::Tuple(vec![
+            data.data_type.clone(),
+            DataType::Number(NumberDataType::UInt64),
+        ]);
+        let top = self.top();
+        let k = top.len().min(data.k as usize);
+        let mut items = ColumnBuilder::with_capacity(&item_type, k);
+        for counter in top.into_iter().take(k) {
+            let item = Scalar::Tuple(vec![
+                T::upcast_scalar(counter.key.clone()),
+                Scalar::Number(NumberScalar::UInt64(counter.count)),
+            ]);
+            items.push(item.as_ref());
+        }
+        builder.push(ScalarRef::Array(items.build()));
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_approx_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].clone();
+    let (k, counters) = get_k_and_counters(&params, display_name)?;
+    let return_type = DataType::Array(Box::new(DataType::Tuple(vec![
+        data_type.clone(),
+        DataType::Number(NumberDataType::UInt64),
+    ])));
+    let function_data = ApproxTopKData {
+        k,
+        counters,
+        data_type: data_type.clone(),
+    };
+
+    with_number_mapped_type!(|NUM| match &data_type {
+        DataType::Number(NumberDataType::NUM) => {
+            let func = AggregateUnaryFunction::<
+                SpaceSavingState<NumberType<NUM>>,
+                NumberType<NUM>,
+                AnyType,
+            >::try_create(display_name, return_type, params, data_type)
+            .with_function_data(Box::new(function_data))
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        _ => {
+            let func =
+                AggregateUnaryFunction::<SpaceSavingState<AnyType>, AnyType, AnyType>::try_create(
+                    display_name,
+                    return_type,
+                    params,
+                    data_type,
+                )
+                .with_function_data(Box::new(function_data))
+                .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+    })
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_top_k_function))
+}
+
+fn get_k_and_counters(params: &[Scalar], display_name: &str) -> Result<(u64, u64)> {
+    let get_param = |param: &Scalar, name: &str| match param {
+        Scalar::Number(number) => match number.integer_to_i128() {
+            Some(n) if n > 0 && n as u64 <= TOP_K_MAX_SIZE => Ok(n as u64),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The {name} of aggregate function {display_name} must be a positive int not greater than {TOP_K_MAX_SIZE}"
+            ))),
+        },
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The {name} of aggregate function {display_name} must be a positive int"
+        ))),
+    };
+
+    let k = match params.first() {
+        Some(param) => get_param(param, "k")?,
+        None => DEFAULT_K,
+    };
+    let counters = match params.get(1) {
+        Some(param) => get_param(param, "counters")?,
+        None => (k * TOP_K_LOAD_FACTOR).min(TOP_K_MAX_SIZE),
+    };
+    if counters < k {
+        return Err(ErrorCode::BadArguments(format!(
+            "The counters of aggregate function {display_name} must not be less than k"
+        )));
+    }
+    Ok((k, counters))
+}
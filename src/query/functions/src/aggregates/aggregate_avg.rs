@@ -21,6 +21,7 @@ use borsh::BorshSerialize;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::*;
 use databend_common_expression::utils::arithmetics_type::ResultTypeOfUnary;
 use databend_common_expression::with_number_mapped_type;
@@ -201,6 +202,53 @@ where
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+struct IntervalAvgState {
+    pub value: months_days_micros,
+    pub count: u64,
+}
+
+impl IntervalAvgState {
+    fn add_internal(&mut self, count: u64, value: months_days_micros) -> Result<()> {
+        self.count += count;
+        self.value = self.value.checked_add(&value).ok_or_else(|| {
+            ErrorCode::Overflow(format!("Interval overflow: {} + {}", self.value, value))
+        })?;
+        Ok(())
+    }
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalAvgState {
+    fn add(
+        &mut self,
+        other: months_days_micros,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.add_internal(1, other)
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add_internal(rhs.count, rhs.value)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<months_days_micros>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        match self.value.checked_div(self.count as i64) {
+            Some(value) => {
+                builder.push(value);
+                Ok(())
+            }
+            None => Err(ErrorCode::Overflow(format!(
+                "Interval overflow: {} div {}",
+                self.value, self.count
+            ))),
+        }
+    }
+}
+
 pub fn try_create_aggregate_avg_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -291,6 +339,14 @@ pub fn try_create_aggregate_avg_function(
                 Ok(Arc::new(func))
             }
         }
+        DataType::Interval => {
+            AggregateUnaryFunction::<IntervalAvgState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                IntervalType::data_type(),
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
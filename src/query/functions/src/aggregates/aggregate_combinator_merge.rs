@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::parser::expr::type_name;
+use databend_common_ast::parser::run_parser;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_ast::parser::ParseMode;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BinaryColumn;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+
+use super::AggregateFunctionFactory;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionCreator;
+use crate::aggregates::aggregate_function_factory::CombinatorDescription;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Merges the binary states produced by the `_state` combinator and returns the
+/// final result of the nested function.
+///
+/// The serialized state does not carry the argument types of the nested function,
+/// so they are given as the string parameters, the other parameters are passed to
+/// the nested function, e.g. `sum_merge('INT NULL')(state)` merges the states
+/// of `sum_state(c)` where `c` is a nullable INT column.
+#[derive(Clone)]
+pub struct AggregateMergeCombinator {
+    name: String,
+    nested_name: String,
+    nested: AggregateFunctionRef,
+}
+
+impl AggregateMergeCombinator {
+    pub fn try_create(
+        nested_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+        _nested_creator: &AggregateFunctionCreator,
+    ) -> Result<AggregateFunctionRef> {
+        let name = format!("MergeCombinator({nested_name})");
+        if arguments.len() != 1 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{name} expect to have one state argument, but got {}",
+                arguments.len()
+            )));
+        }
+        if !matches!(arguments[0].remove_nullable(), DataType::Binary) {
+            return Err(ErrorCode::BadArguments(format!(
+                "The type of the argument for {name} must be binary type, but got {:?}",
+                &arguments[0]
+            )));
+        }
+
+        let mut nested_params = Vec::with_capacity(params.len());
+        let mut nested_arguments = Vec::with_capacity(params.len());
+        for param in params {
+            match param {
+                Scalar::String(ty) => nested_arguments.push(parse_argument_type(&ty)?),
+                param => nested_params.push(param),
+            }
+        }
+
+        // Build the nested function the same way as the `_state` combinator,
+        // so that the states are deserialized by the same function.
+        let nested = AggregateFunctionFactory::instance()
+            .get(nested_name, nested_params, nested_arguments)
+            .map_err(|e| {
+                e.add_message_back(format!(
+                    " ({nested_name}_merge needs the argument types of {nested_name}_state as parameters, e.g. {nested_name}_merge('INT')(state))"
+                ))
+            })?;
+
+        Ok(Arc::new(AggregateMergeCombinator {
+            name,
+            nested_name: nested_name.to_owned(),
+            nested,
+        }))
+    }
+
+    pub fn combinator_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn states(columns: &InputColumns) -> (BinaryColumn, Option<Bitmap>) {
+        match &columns[0] {
+            Column::Nullable(c) => (
+                c.column.as_binary().unwrap().clone(),
+                Some(c.validity.clone()),
+            ),
+            c => (c.as_binary().unwrap().clone(), None),
+        }
+    }
+}
+
+fn parse_argument_type(ty: &str) -> Result<DataType> {
+    let tokens = tokenize_sql(ty)?;
+    let type_name = run_parser(
+        &tokens,
+        Dialect::default(),
+        ParseMode::Default,
+        false,
+        type_name,
+    )?;
+    resolve_argument_type(type_name)
+}
+
+fn resolve_argument_type(type_name: TypeName) -> Result<DataType> {
+    let data_type = match type_name {
+        TypeName::Boolean => DataType::Boolean,
+        TypeName::UInt8 => DataType::Number(NumberDataType::UInt8),
+        TypeName::UInt16 => DataType::Number(NumberDataType::UInt16),
+        TypeName::UInt32 => DataType::Number(NumberDataType::UInt32),
+        TypeName::UInt64 => DataType::Number(NumberDataType::UInt64),
+        TypeName::Int8 => DataType::Number(NumberDataType::Int8),
+        TypeName::Int16 => DataType::Number(NumberDataType::Int16),
+        TypeName::Int32 => DataType::Number(NumberDataType::Int32),
+        TypeName::Int64 => DataType::Number(NumberDataType::Int64),
+        TypeName::Float32 => DataType::Number(NumberDataType::Float32),
+        TypeName::Float64 => DataType::Number(NumberDataType::Float64),
+        TypeName::Decimal { precision, scale } => {
+            DataType::Decimal(DecimalDataType::from_size(DecimalSize {
+                precision,
+                scale,
+            })?)
+        }
+        TypeName::Date => DataType::Date,
+        TypeName::Timestamp => DataType::Timestamp,
+        TypeName::Binary => DataType::Binary,
+        TypeName::String => DataType::String,
+        TypeName::Array(item_type) => DataType::Array(Box::new(resolve_argument_type(*item_type)?)),
+        TypeName::Map { key_type, val_type } => DataType::Map(Box::new(DataType::Tuple(vec![
+            resolve_argument_type(*key_type)?,
+            resolve_argument_type(*val_type)?,
+        ]))),
+        TypeName::Bitmap => DataType::Bitmap,
+        TypeName::Tuple { fields_type, .. } => DataType::Tuple(
+            fields_type
+                .into_iter()
+                .map(resolve_argument_type)
+                .collect::<Result<_>>()?,
+        ),
+        TypeName::Variant => DataType::Variant,
+        TypeName::Geometry => DataType::Geometry,
+        TypeName::Geography => DataType::Geography,
+        TypeName::Interval => DataType::Interval,
+        TypeName::Nullable(inner_type) => {
+            DataType::Nullable(Box::new(resolve_argument_type(*inner_type)?))
+        }
+        TypeName::NotNull(inner_type) => resolve_argument_type(*inner_type)?,
+    };
+    Ok(data_type)
+}
+
+impl AggregateFunction for AggregateMergeCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.nested.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        self.nested.init_state(place);
+    }
+
+    fn state_layout(&self) -> Layout {
+        self.nested.state_layout()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: InputColumns,
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let (states, states_validity) = Self::states(&columns);
+        let validity = match (validity, &states_validity) {
+            (Some(v1), Some(v2)) => Some(v1 & v2),
+            (Some(v), None) | (None, Some(v)) => Some(v.clone()),
+            (None, None) => None,
+        };
+        for (row, mut data) in states.iter().enumerate() {
+            if validity.as_ref().map_or(true, |v| v.get_bit(row)) {
+                self.nested.merge(place, &mut data)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: InputColumns,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let (states, validity) = Self::states(&columns);
+        for (row, (place, mut data)) in places.iter().zip(states.iter()).enumerate() {
+            if validity.as_ref().map_or(true, |v| v.get_bit(row)) {
+                self.nested.merge(place.next(offset), &mut data)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: InputColumns, row: usize) -> Result<()> {
+        let (states, validity) = Self::states(&columns);
+        if validity.as_ref().map_or(true, |v| v.get_bit(row)) {
+            let mut data = unsafe { states.index_unchecked(row) };
+            self.nested.merge(place, &mut data)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.nested.serialize(place, writer)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        self.nested.merge(place, reader)
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.nested.merge_states(place, rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.nested.merge_result(place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        self.nested.need_manual_drop_state()
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        self.nested.drop_state(place);
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<Scalar>,
+        _arguments: Vec<DataType>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // The NULL states are skipped while merging.
+        Ok(Some(Arc::new(self.clone())))
+    }
+}
+
+impl fmt::Display for AggregateMergeCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}_merge", self.nested_name)
+    }
+}
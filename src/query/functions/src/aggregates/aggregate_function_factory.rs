@@ -27,6 +27,7 @@ use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
 const STATE_SUFFIX: &str = "_state";
+const MERGE_SUFFIX: &str = "_merge";
 
 pub type AggregateFunctionCreator =
     Box<dyn Fn(&str, Vec<Scalar>, Vec<DataType>) -> Result<AggregateFunctionRef> + Sync + Send>;
@@ -186,14 +187,18 @@ impl AggregateFunctionFactory {
         }
 
         if !arguments.is_empty() && arguments.iter().any(|f| f.is_nullable_or_null()) {
-            let (new_params, new_arguments) = match name.to_lowercase().strip_suffix(STATE_SUFFIX) {
-                Some(_) => (params.clone(), arguments.clone()),
-                None => {
-                    let new_params = AggregateFunctionCombinatorNull::transform_params(&params)?;
-                    let new_arguments =
-                        AggregateFunctionCombinatorNull::transform_arguments(&arguments)?;
-                    (new_params, new_arguments)
-                }
+            // The `_state` and `_merge` combinators build the nested function with the
+            // original arguments, so that the states are serialized in the same way.
+            let lowercase_name = name.to_lowercase();
+            let (new_params, new_arguments) = if lowercase_name.ends_with(STATE_SUFFIX)
+                || lowercase_name.ends_with(MERGE_SUFFIX)
+            {
+                (params.clone(), arguments.clone())
+            } else {
+                let new_params = AggregateFunctionCombinatorNull::transform_params(&params)?;
+                let new_arguments =
+                    AggregateFunctionCombinatorNull::transform_arguments(&arguments)?;
+                (new_params, new_arguments)
             };
 
             let nested = self.get_impl(name, new_params, new_arguments, &mut features)?;
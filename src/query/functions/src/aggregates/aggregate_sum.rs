@@ -19,6 +19,7 @@ use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::number::*;
 use databend_common_expression::types::*;
 use databend_common_expression::utils::arithmetics_type::ResultTypeOfUnary;
@@ -211,6 +212,37 @@ where
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct IntervalSumState {
+    pub value: months_days_micros,
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalSumState {
+    fn add(
+        &mut self,
+        other: months_days_micros,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.value = self.value.checked_add(&other).ok_or_else(|| {
+            ErrorCode::Overflow(format!("Interval overflow: {} + {}", self.value, other))
+        })?;
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add(rhs.value, None)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<months_days_micros>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value);
+        Ok(())
+    }
+}
+
 pub fn try_create_aggregate_sum_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -291,6 +323,14 @@ pub fn try_create_aggregate_sum_function(
                 )
             }
         }
+        DataType::Interval => {
+            AggregateUnaryFunction::<IntervalSumState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                IntervalType::data_type(),
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
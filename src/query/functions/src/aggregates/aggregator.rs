@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_function_desc;
+use super::aggregate_approx_top_k::aggregate_approx_top_k_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
@@ -25,6 +26,7 @@ use super::aggregate_bitmap::aggregate_bitmap_union_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_xor_count_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
+use super::aggregate_combinator_merge::AggregateMergeCombinator;
 use super::aggregate_combinator_state::AggregateStateCombinator;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
@@ -101,6 +103,7 @@ impl Aggregators {
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("approx_top_k", aggregate_approx_top_k_function_desc());
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
@@ -150,5 +153,6 @@ impl Aggregators {
         factory.register_combinator("_if", AggregateIfCombinator::combinator_desc());
         factory.register_combinator("_distinct", aggregate_combinator_distinct_desc());
         factory.register_combinator("_state", AggregateStateCombinator::combinator_desc());
+        factory.register_combinator("_merge", AggregateMergeCombinator::combinator_desc());
     }
 }
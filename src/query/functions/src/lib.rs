@@ -53,7 +53,10 @@ pub fn is_cacheable_function(name: &str) -> bool {
 #[ctor]
 pub static BUILTIN_FUNCTIONS: FunctionRegistry = builtin_functions();
 
-pub const ASYNC_FUNCTIONS: [&str; 2] = ["nextval", "dict_get"];
+pub const ASYNC_FUNCTIONS: [&str; 3] = ["nextval", "dict_get", "secret"];
+
+// The passphrase of these functions can be a secret stored in meta, like `encrypt(v, secret('k'))`.
+pub const ENCRYPTION_FUNCTIONS: [&str; 3] = ["encrypt", "decrypt", "try_decrypt"];
 
 pub const GENERAL_WINDOW_FUNCTIONS: [&str; 13] = [
     "row_number",
@@ -31,6 +31,7 @@ use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::date::string_to_date;
 use databend_common_expression::types::date::DATE_MAX;
 use databend_common_expression::types::date::DATE_MIN;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::Int64Type;
@@ -48,6 +49,7 @@ use databend_common_expression::types::timestamp::MICROS_PER_SEC;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
@@ -58,6 +60,7 @@ use databend_common_expression::vectorize_1_arg;
 use databend_common_expression::vectorize_2_arg;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::EvalContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionProperty;
@@ -96,7 +99,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_sub_functions(registry);
 
     // date_diff([year | quarter | month | week | day | hour | minute | second], [date | timestamp], [date | timestamp])
-    // [date | timestamp] +/- [date | timestamp]
+    // date - date -> number
+    // timestamp - timestamp -> interval
     register_diff_functions(registry);
 
     // now, today, yesterday, tomorrow
@@ -108,6 +112,10 @@ pub fn register(registry: &mut FunctionRegistry) {
     // to_*([date | timestamp]) -> [date | timestamp]
     register_rounder_functions(registry);
 
+    // time_bucket(interval, timestamp [, origin]) -> timestamp
+    // date_bin(interval, timestamp, origin) -> timestamp
+    register_time_bucket_functions(registry);
+
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
 
@@ -490,8 +498,8 @@ fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
         |ctx, domain| {
             let tz = ctx.tz.tz;
             FunctionDomain::Domain(SimpleDomain {
-                min: calc_date_to_timestamp(domain.min, tz),
-                max: calc_date_to_timestamp(domain.max, tz),
+                min: date_to_timestamp(domain.min, tz),
+                max: date_to_timestamp(domain.max, tz),
             })
         },
         eval_date_to_timestamp,
@@ -503,8 +511,8 @@ fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
             FunctionDomain::Domain(NullableDomain {
                 has_null: false,
                 value: Some(Box::new(SimpleDomain {
-                    min: calc_date_to_timestamp(domain.min, tz),
-                    max: calc_date_to_timestamp(domain.max, tz),
+                    min: date_to_timestamp(domain.min, tz),
+                    max: date_to_timestamp(domain.max, tz),
                 })),
             })
         },
@@ -517,25 +525,9 @@ fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
     ) -> Value<TimestampType> {
         vectorize_with_builder_1_arg::<DateType, TimestampType>(|val, output, _| {
             let tz = ctx.func_ctx.tz.tz;
-            output.push(calc_date_to_timestamp(val, tz));
+            output.push(date_to_timestamp(val, tz));
         })(val, ctx)
     }
-
-    fn calc_date_to_timestamp(val: i32, tz: Tz) -> i64 {
-        let ts = (val as i64) * 24 * 3600 * MICROS_PER_SEC;
-        let epoch_time_with_ltz = tz
-            .from_utc_datetime(
-                &NaiveDate::from_ymd_opt(1970, 1, 1)
-                    .unwrap()
-                    .and_hms_micro_opt(0, 0, 0, 0)
-                    .unwrap(),
-            )
-            .naive_local()
-            .and_utc()
-            .timestamp_micros();
-
-        ts - epoch_time_with_ltz
-    }
 }
 
 fn register_number_to_timestamp(registry: &mut FunctionRegistry) {
@@ -1241,7 +1233,7 @@ fn register_diff_functions(registry: &mut FunctionRegistry) {
         |a, b, _| a - b,
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
         |_, lhs, rhs| {
             (|| {
@@ -1250,14 +1242,14 @@ fn register_diff_functions(registry: &mut FunctionRegistry) {
                 let rm = rhs.max;
                 let rn = rhs.min;
 
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
+                Some(FunctionDomain::Domain(SimpleDomain::<months_days_micros> {
+                    min: months_days_micros::from_micros(ln.checked_sub(rm)?),
+                    max: months_days_micros::from_micros(lm.checked_sub(rn)?),
                 }))
             })()
             .unwrap_or(FunctionDomain::Full)
         },
-        |a, b, _| a - b,
+        |a, b, _| months_days_micros::from_micros(a - b),
     );
 
     registry.register_passthrough_nullable_2_arg::<DateType, DateType, Float64Type, _, _>(
@@ -1981,3 +1973,53 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+fn register_time_bucket_functions(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "time_bucket",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |width, ts, output, ctx| {
+                    eval_time_bucket(width, ts, None, output, ctx);
+                },
+            ),
+        );
+
+    for name in ["time_bucket", "date_bin"] {
+        registry.register_passthrough_nullable_3_arg::<
+            IntervalType,
+            TimestampType,
+            TimestampType,
+            TimestampType,
+            _,
+            _,
+        >(
+            name,
+            |_, _, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_3_arg::<IntervalType, TimestampType, TimestampType, TimestampType>(
+                |width, ts, origin, output, ctx| {
+                    eval_time_bucket(width, ts, Some(origin), output, ctx);
+                },
+            ),
+        );
+    }
+
+    fn eval_time_bucket(
+        width: months_days_micros,
+        ts: i64,
+        origin: Option<i64>,
+        output: &mut Vec<i64>,
+        ctx: &mut EvalContext,
+    ) {
+        match TimeBucket::try_create(width, origin, ctx.func_ctx.tz)
+            .and_then(|bucket| bucket.bucket(ts))
+        {
+            Ok(start) => output.push(start),
+            Err(e) => {
+                ctx.set_error(output.len(), e);
+                output.push(0);
+            }
+        }
+    }
+}
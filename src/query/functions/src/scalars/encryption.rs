@@ -0,0 +1,334 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use aes::Aes128;
+use aes::Aes192;
+use aes::Aes256;
+use aes_gcm::aead::consts::U12;
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::Payload;
+use aes_gcm::AesGcm;
+use aes_gcm::KeyInit;
+use aes_gcm::Nonce;
+use cbc::cipher::block_padding::Pkcs7;
+use cbc::cipher::BlockDecryptMut;
+use cbc::cipher::BlockEncryptMut;
+use cbc::cipher::KeyIvInit;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionProperty;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use sha2::Sha256;
+
+const GCM_NONCE_LEN: usize = 12;
+const GCM_TAG_LEN: usize = 16;
+const CBC_IV_LEN: usize = 16;
+const PASSPHRASE_SALT_LEN: usize = 16;
+// The key is derived for each value, so the rounds are a trade-off with the speed of a scan.
+const PASSPHRASE_KEY_ROUNDS: u32 = 10_000;
+
+type CryptoFn = fn(&[&[u8]]) -> Result<Vec<u8>, String>;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // encrypt(value, passphrase [, aad [, mode]])
+    // decrypt(value, passphrase [, aad [, mode]])
+    //
+    // The key is derived from the passphrase with PBKDF2 and a random salt, the salt
+    // and a random IV are prepended to the ciphertext, so the same value is encrypted
+    // differently.
+    let passphrase_args = [
+        DataType::Binary,
+        DataType::String,
+        DataType::Binary,
+        DataType::String,
+    ];
+    register_crypto_function(registry, "encrypt", &passphrase_args, 2, false, encrypt);
+    register_crypto_function(registry, "decrypt", &passphrase_args, 2, false, decrypt);
+    register_crypto_function(registry, "try_decrypt", &passphrase_args, 2, true, decrypt);
+
+    // encrypt_raw(value, key, iv [, aad [, mode]])
+    // decrypt_raw(value, key, iv [, aad [, mode]])
+    //
+    // The key must be 16, 24 or 32 bytes for AES-128, AES-192 or AES-256.
+    let raw_args = [
+        DataType::Binary,
+        DataType::Binary,
+        DataType::Binary,
+        DataType::Binary,
+        DataType::String,
+    ];
+    register_crypto_function(registry, "encrypt_raw", &raw_args, 3, false, encrypt_raw);
+    register_crypto_function(registry, "decrypt_raw", &raw_args, 3, false, decrypt_raw);
+    register_crypto_function(registry, "try_decrypt_raw", &raw_args, 3, true, decrypt_raw);
+
+    registry.properties.insert(
+        "encrypt".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+}
+
+fn register_crypto_function(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    all_args_type: &[DataType],
+    min_args: usize,
+    error_to_null: bool,
+    op: CryptoFn,
+) {
+    let all_args_type = all_args_type.to_vec();
+    registry.register_function_factory(name, move |_, args_type| {
+        if args_type.len() < min_args || args_type.len() > all_args_type.len() {
+            return None;
+        }
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let f = Function {
+            signature: FunctionSignature {
+                name: name.to_string(),
+                args_type: all_args_type[..args_type.len()].to_vec(),
+                return_type: DataType::Binary,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| eval_crypto(args, ctx, op)),
+            },
+        };
+        let f = if error_to_null { f.error_to_null() } else { f };
+
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+}
+
+fn eval_crypto(args: &[ValueRef<AnyType>], ctx: &mut EvalContext, op: CryptoFn) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let input_rows = len.unwrap_or(1);
+
+    let mut builder = BinaryColumnBuilder::with_capacity(input_rows, 0);
+    let mut row_args = Vec::with_capacity(args.len());
+    for row in 0..input_rows {
+        row_args.clear();
+        row_args.extend(args.iter().map(|arg| arg_bytes(arg, row)));
+        match op(&row_args) {
+            Ok(output) => builder.put_slice(&output),
+            Err(err) => ctx.set_error(builder.len(), err),
+        }
+        builder.commit_row();
+    }
+
+    match len {
+        Some(_) => Value::Column(Column::Binary(builder.build())),
+        _ => Value::Scalar(Scalar::Binary(builder.build_scalar())),
+    }
+}
+
+fn arg_bytes<'a>(arg: &'a ValueRef<AnyType>, row: usize) -> &'a [u8] {
+    match arg.index(row).unwrap() {
+        ScalarRef::Binary(val) => val,
+        ScalarRef::String(val) => val.as_bytes(),
+        _ => unreachable!(),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CipherMode {
+    Gcm,
+    Cbc,
+}
+
+impl CipherMode {
+    fn iv_len(self) -> usize {
+        match self {
+            CipherMode::Gcm => GCM_NONCE_LEN,
+            CipherMode::Cbc => CBC_IV_LEN,
+        }
+    }
+}
+
+fn parse_mode(args: &[&[u8]], mode_idx: usize) -> Result<CipherMode, String> {
+    let Some(mode) = args.get(mode_idx) else {
+        return Ok(CipherMode::Gcm);
+    };
+    let mode = String::from_utf8_lossy(mode).to_ascii_uppercase();
+    match mode.as_str() {
+        "AES-GCM" | "GCM" => Ok(CipherMode::Gcm),
+        "AES-CBC" | "CBC" | "AES-CBC/PKCS" => Ok(CipherMode::Cbc),
+        _ => Err(format!(
+            "unsupported encryption mode '{mode}', expected 'AES-GCM' or 'AES-CBC'"
+        )),
+    }
+}
+
+fn parse_aad<'a>(args: &[&'a [u8]], aad_idx: usize, mode: CipherMode) -> Result<&'a [u8], String> {
+    let aad = args.get(aad_idx).copied().unwrap_or_default();
+    if mode == CipherMode::Cbc && !aad.is_empty() {
+        return Err("additional authenticated data is only supported by AES-GCM".to_string());
+    }
+    Ok(aad)
+}
+
+fn passphrase_key(passphrase: &[u8], salt: &[u8]) -> Result<[u8; 32], String> {
+    if passphrase.is_empty() {
+        return Err("passphrase must not be empty".to_string());
+    }
+    let mut key = [0u8; 32];
+    pbkdf2_hmac::<Sha256>(passphrase, salt, PASSPHRASE_KEY_ROUNDS, &mut key);
+    Ok(key)
+}
+
+// The output of encrypt is the salt and the IV followed by the ciphertext (and the tag for AES-GCM).
+fn encrypt(args: &[&[u8]]) -> Result<Vec<u8>, String> {
+    let mode = parse_mode(args, 3)?;
+    let aad = parse_aad(args, 2, mode)?;
+
+    let mut output = vec![0u8; PASSPHRASE_SALT_LEN + mode.iv_len()];
+    rand::thread_rng().fill_bytes(&mut output);
+    let (salt, iv) = output.split_at(PASSPHRASE_SALT_LEN);
+    let key = passphrase_key(args[1], salt)?;
+
+    let ciphertext = aes_encrypt(mode, &key, iv, args[0], aad)?;
+    output.extend_from_slice(&ciphertext);
+    Ok(output)
+}
+
+fn decrypt(args: &[&[u8]]) -> Result<Vec<u8>, String> {
+    let mode = parse_mode(args, 3)?;
+    let aad = parse_aad(args, 2, mode)?;
+
+    // AES-GCM has a tag and AES-CBC has at least one padded block.
+    let iv_len = mode.iv_len();
+    let min_len = match mode {
+        CipherMode::Gcm => PASSPHRASE_SALT_LEN + iv_len + GCM_TAG_LEN,
+        CipherMode::Cbc => PASSPHRASE_SALT_LEN + iv_len * 2,
+    };
+    let value = args[0];
+    if value.len() < min_len {
+        return Err("decryption failed, the encrypted value is too short".to_string());
+    }
+    let (salt, value) = value.split_at(PASSPHRASE_SALT_LEN);
+    let key = passphrase_key(args[1], salt)?;
+    aes_decrypt(mode, &key, &value[..iv_len], &value[iv_len..], aad)
+}
+
+fn encrypt_raw(args: &[&[u8]]) -> Result<Vec<u8>, String> {
+    let mode = parse_mode(args, 4)?;
+    let aad = parse_aad(args, 3, mode)?;
+    aes_encrypt(mode, args[1], args[2], args[0], aad)
+}
+
+fn decrypt_raw(args: &[&[u8]]) -> Result<Vec<u8>, String> {
+    let mode = parse_mode(args, 4)?;
+    let aad = parse_aad(args, 3, mode)?;
+    aes_decrypt(mode, args[1], args[2], args[0], aad)
+}
+
+// Dispatch to the AES cipher of the key length.
+macro_rules! with_aes_cipher {
+    ($key:expr, | $cipher:ident | $body:expr) => {
+        match $key.len() {
+            16 => {
+                type $cipher = Aes128;
+                $body
+            }
+            24 => {
+                type $cipher = Aes192;
+                $body
+            }
+            32 => {
+                type $cipher = Aes256;
+                $body
+            }
+            len => Err(format!(
+                "invalid key length {len}, the key must be 16, 24 or 32 bytes"
+            )),
+        }
+    };
+}
+
+fn check_iv(mode: CipherMode, iv: &[u8]) -> Result<(), String> {
+    let iv_len = mode.iv_len();
+    if iv.len() != iv_len {
+        return Err(format!(
+            "invalid iv length {}, the iv must be {iv_len} bytes",
+            iv.len()
+        ));
+    }
+    Ok(())
+}
+
+fn aes_encrypt(
+    mode: CipherMode,
+    key: &[u8],
+    iv: &[u8],
+    plaintext: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, String> {
+    check_iv(mode, iv)?;
+    with_aes_cipher!(key, |C| match mode {
+        CipherMode::Gcm => AesGcm::<C, U12>::new_from_slice(key)
+            .map_err(|e| e.to_string())?
+            .encrypt(Nonce::<U12>::from_slice(iv), Payload {
+                msg: plaintext,
+                aad,
+            })
+            .map_err(|_| "encryption failed".to_string()),
+        CipherMode::Cbc => Ok(cbc::Encryptor::<C>::new_from_slices(key, iv)
+            .map_err(|e| e.to_string())?
+            .encrypt_padded_vec_mut::<Pkcs7>(plaintext)),
+    })
+}
+
+// The error does not tell whether the key, the aad or the data is wrong.
+fn aes_decrypt(
+    mode: CipherMode,
+    key: &[u8],
+    iv: &[u8],
+    ciphertext: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, String> {
+    check_iv(mode, iv)?;
+    with_aes_cipher!(key, |C| match mode {
+        CipherMode::Gcm => AesGcm::<C, U12>::new_from_slice(key)
+            .map_err(|e| e.to_string())?
+            .decrypt(Nonce::<U12>::from_slice(iv), Payload {
+                msg: ciphertext,
+                aad,
+            })
+            .map_err(|_| "decryption failed".to_string()),
+        CipherMode::Cbc => cbc::Decryptor::<C>::new_from_slices(key, iv)
+            .map_err(|e| e.to_string())?
+            .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+            .map_err(|_| "decryption failed".to_string()),
+    })
+}
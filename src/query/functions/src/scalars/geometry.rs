@@ -29,7 +29,9 @@ use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::vectorize_with_builder_4_arg;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
+use databend_common_io::ewkb_to_geos;
 use databend_common_io::geometry_format;
+use databend_common_io::geos_to_ewkb;
 use databend_common_io::parse_to_ewkb;
 use databend_common_io::parse_to_subtype;
 use databend_common_io::Axis;
@@ -52,7 +54,9 @@ use geohash::encode;
 use geos::geo_types;
 use geos::geo_types::Coord;
 use geos::geo_types::LineString;
+use geos::Geom;
 use geos::Geometry;
+use geos::GeometryTypes;
 use geozero::geojson::GeoJson;
 use geozero::wkb::Ewkb;
 use geozero::wkb::Wkb;
@@ -1573,6 +1577,200 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    register_geometry_predicate(registry, "st_intersects", |l, r| l.intersects(r));
+    register_geometry_predicate(registry, "st_within", |l, r| l.within(r));
+    register_geometry_predicate(registry, "st_touches", |l, r| l.touches(r));
+    register_geometry_predicate(registry, "st_disjoint", |l, r| l.disjoint(r));
+    register_geometry_predicate(registry, "st_covers", |l, r| l.covers(r));
+
+    register_geometry_measure(registry, "st_area", |g| g.area());
+    register_geometry_measure(registry, "st_perimeter", |g| match g.geometry_type() {
+        GeometryTypes::Polygon | GeometryTypes::MultiPolygon => g.length(),
+        _ => Ok(0.0),
+    });
+
+    register_geometry_unary_op(registry, "st_centroid", |g| g.get_centroid());
+    register_geometry_unary_op(registry, "st_envelope", |g| g.envelope());
+
+    register_geometry_binary_op(registry, "st_union", |l, r| l.union(r));
+    register_geometry_binary_op(registry, "st_intersection", |l, r| l.intersection(r));
+    register_geometry_binary_op(registry, "st_difference", |l, r| l.difference(r));
+
+    register_geometry_op_with_f64(registry, "st_buffer", |g, distance| {
+        g.buffer(distance, BUFFER_QUADRANT_SEGMENTS)
+    });
+    register_geometry_op_with_f64(registry, "st_simplify", |g, tolerance| {
+        g.simplify(tolerance)
+    });
+}
+
+/// Number of segments used to approximate a quarter circle in `st_buffer`.
+const BUFFER_QUADRANT_SEGMENTS: i32 = 8;
+
+/// Decode two geometries that must share the same SRID.
+fn geos_pair(
+    l_ewkb: &[u8],
+    r_ewkb: &[u8],
+) -> databend_common_exception::Result<(Geometry, Geometry, Option<i32>)> {
+    let l_geos = ewkb_to_geos(l_ewkb)?;
+    let r_geos = ewkb_to_geos(r_ewkb)?;
+    let l_srid = l_geos.srid();
+    if l_srid != r_geos.srid() {
+        return Err(ErrorCode::GeometryError("Srid does not match!"));
+    }
+    Ok((l_geos, r_geos, l_srid))
+}
+
+fn register_geometry_predicate(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    predicate: fn(&Geometry, &Geometry) -> geos::GResult<bool>,
+) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        name,
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            move |l_ewkb, r_ewkb, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                let result = geos_pair(l_ewkb, r_ewkb).and_then(|(l_geos, r_geos, _)| {
+                    predicate(&l_geos, &r_geos).map_err(|e| ErrorCode::GeometryError(e.to_string()))
+                });
+                match result {
+                    Ok(v) => builder.push(v),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+fn register_geometry_measure(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    measure: fn(&Geometry) -> geos::GResult<f64>,
+) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(move |ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0_f64));
+                    return;
+                }
+            }
+            let result = ewkb_to_geos(ewkb).and_then(|geos| {
+                measure(&geos).map_err(|e| ErrorCode::GeometryError(e.to_string()))
+            });
+            match result {
+                Ok(v) => builder.push(format!("{:.9}", v).parse().unwrap()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0_f64));
+                }
+            }
+        }),
+    );
+}
+
+fn register_geometry_unary_op(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(&Geometry) -> geos::GResult<Geometry>,
+) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(move |ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            let result = ewkb_to_geos(ewkb).and_then(|geos| {
+                let output = op(&geos).map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
+                geos_to_ewkb(&output, geos.srid())
+            });
+            match result {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+}
+
+fn register_geometry_binary_op(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(&Geometry, &Geometry) -> geos::GResult<Geometry>,
+) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, GeometryType, _, _>(
+        name,
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, GeometryType>(
+            move |l_ewkb, r_ewkb, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                let result = geos_pair(l_ewkb, r_ewkb).and_then(|(l_geos, r_geos, srid)| {
+                    let output = op(&l_geos, &r_geos)
+                        .map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
+                    geos_to_ewkb(&output, srid)
+                });
+                match result {
+                    Ok(data) => builder.put_slice(data.as_slice()),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+}
+
+fn register_geometry_op_with_f64(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    op: fn(&Geometry, f64) -> geos::GResult<Geometry>,
+) {
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            name,
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                move |ewkb, arg, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+                    let result = ewkb_to_geos(ewkb).and_then(|geos| {
+                        let output = op(&geos, arg.into())
+                            .map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
+                        geos_to_ewkb(&output, geos.srid())
+                    });
+                    match result {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
 }
 
 fn st_transform_impl(
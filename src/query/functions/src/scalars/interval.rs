@@ -0,0 +1,264 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::interval::IntervalUnit;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::IntervalType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::date_helper::EvalDaysImpl;
+use databend_common_expression::utils::date_helper::EvalMonthsImpl;
+use databend_common_expression::utils::date_helper::TzLUT;
+use databend_common_expression::vectorize_1_arg;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast(interval AS string)
+    // to_string(interval)
+    register_interval_to_string(registry);
+
+    // interval [+ | -] interval
+    // -interval
+    register_interval_arithmetic(registry);
+
+    // timestamp [+ | -] interval
+    // interval + timestamp
+    register_timestamp_add_sub_interval(registry);
+
+    // to_start_of_[year | quarter | month | day | hour | minute | second](interval) -> interval
+    register_interval_rounder_functions(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`. {}", e.message()),
+                    );
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", val).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", val).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_interval_arithmetic(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_add(&b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_sub(&b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|a, output, ctx| {
+            match a.checked_neg() {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+}
+
+/// Adds the interval to the timestamp field by field: the months are added in the
+/// calendar of the time zone, then the days, and the microseconds at last.
+fn add_interval_to_timestamp(
+    ts: i64,
+    interval: months_days_micros,
+    tz: TzLUT,
+) -> std::result::Result<i64, String> {
+    let mut ts = if interval.months() != 0 {
+        EvalMonthsImpl::eval_timestamp(ts, tz, interval.months())?
+    } else {
+        ts
+    };
+    if interval.days() != 0 {
+        ts = EvalDaysImpl::eval_timestamp(ts, interval.days());
+    }
+    let mut ts = ts.saturating_add(interval.microseconds());
+    clamp_timestamp(&mut ts);
+    Ok(ts)
+}
+
+fn register_timestamp_add_sub_interval(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match add_interval_to_timestamp(
+                    ts,
+                    interval,
+                    ctx.func_ctx.tz,
+                ) {
+                    Ok(t) => output.push(t),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| match add_interval_to_timestamp(
+                    ts,
+                    interval,
+                    ctx.func_ctx.tz,
+                ) {
+                    Ok(t) => output.push(t),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let res = interval
+                        .checked_neg()
+                        .ok_or_else(|| "interval out of range".to_string())
+                        .and_then(|interval| {
+                            add_interval_to_timestamp(ts, interval, ctx.func_ctx.tz)
+                        });
+                    match res {
+                        Ok(t) => output.push(t),
+                        Err(e) => {
+                            ctx.set_error(output.len(), e);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+}
+
+fn register_interval_rounder_functions(registry: &mut FunctionRegistry) {
+    for (name, unit) in [
+        ("to_start_of_year", IntervalUnit::Year),
+        ("to_start_of_quarter", IntervalUnit::Quarter),
+        ("to_start_of_month", IntervalUnit::Month),
+        ("to_start_of_day", IntervalUnit::Day),
+        ("to_start_of_hour", IntervalUnit::Hour),
+        ("to_start_of_minute", IntervalUnit::Minute),
+        ("to_start_of_second", IntervalUnit::Second),
+    ] {
+        registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+            name,
+            |_, _| FunctionDomain::Full,
+            vectorize_1_arg::<IntervalType, IntervalType>(move |val, _| val.trunc(unit)),
+        );
+    }
+}
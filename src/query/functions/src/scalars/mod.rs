@@ -24,12 +24,14 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod encryption;
 mod geo;
 mod geo_h3;
 mod geography;
 mod geometry;
 mod hash;
 mod hilbert;
+mod interval;
 mod map;
 mod math;
 mod other;
@@ -66,4 +68,6 @@ pub fn register(registry: &mut FunctionRegistry) {
     geometry::register(registry);
     geography::register(registry);
     hilbert::register(registry);
+    interval::register(registry);
+    encryption::register(registry);
 }
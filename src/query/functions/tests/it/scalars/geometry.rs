@@ -60,6 +60,20 @@ fn test_geometry() {
     test_st_ymax(file);
     test_st_ymin(file);
     test_st_transform(file);
+    test_st_intersects(file);
+    test_st_within(file);
+    test_st_touches(file);
+    test_st_disjoint(file);
+    test_st_covers(file);
+    test_st_area(file);
+    test_st_perimeter(file);
+    test_st_centroid(file);
+    test_st_envelope(file);
+    test_st_union(file);
+    test_st_intersection(file);
+    test_st_difference(file);
+    test_st_buffer(file);
+    test_st_simplify(file);
 }
 
 fn test_haversine(file: &mut impl Write) {
@@ -679,3 +693,216 @@ fn test_st_transform(file: &mut impl Write) {
         ("c", Int32Type::from_data(vec![28992])),
     ]);
 }
+
+fn test_st_intersects(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_intersects(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_intersects(to_geometry('LINESTRING EMPTY'), to_geometry('POINT(0 0)'))",
+        &[],
+    );
+    run_ast(file, "st_intersects(NULL, to_geometry('POINT(0 0)'))", &[]);
+}
+
+fn test_st_within(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_within(to_geometry('POINT(1 1)'), to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_within(to_geometry('POINT(5 5)'), to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_within(to_geometry('LINESTRING EMPTY'), to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+    run_ast(file, "st_within(to_geometry('POINT(0 0)'), NULL)", &[]);
+}
+
+fn test_st_touches(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_touches(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_touches(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POINT(1 1)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_touches(to_geometry('LINESTRING EMPTY'), to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_touches(NULL, to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+}
+
+fn test_st_disjoint(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_disjoint(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POINT(5 5)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_disjoint(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_disjoint(to_geometry('LINESTRING EMPTY'), to_geometry('POINT(0 0)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_disjoint(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), NULL)",
+        &[],
+    );
+}
+
+fn test_st_covers(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_covers(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('LINESTRING(0 0, 2 0)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_covers(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POINT(5 5)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_covers(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('LINESTRING EMPTY'))",
+        &[],
+    );
+    run_ast(file, "st_covers(NULL, to_geometry('POINT(0 0)'))", &[]);
+}
+
+fn test_st_area(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_area(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+    run_ast(file, "st_area(to_geometry('LINESTRING(0 0, 2 0)'))", &[]);
+    run_ast(file, "st_area(to_geometry('LINESTRING EMPTY'))", &[]);
+    run_ast(file, "st_area(NULL)", &[]);
+}
+
+fn test_st_perimeter(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_perimeter(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_perimeter(to_geometry('LINESTRING(0 0, 2 0)'))",
+        &[],
+    );
+    run_ast(file, "st_perimeter(to_geometry('LINESTRING EMPTY'))", &[]);
+    run_ast(file, "st_perimeter(NULL)", &[]);
+}
+
+fn test_st_centroid(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_centroid(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_centroid(to_geometry('SRID=4326;LINESTRING(0 0, 2 0)'))",
+        &[],
+    );
+    run_ast(file, "st_centroid(NULL)", &[]);
+}
+
+fn test_st_envelope(file: &mut impl Write) {
+    run_ast(file, "st_envelope(to_geometry('LINESTRING(0 0, 2 3)'))", &[
+    ]);
+    run_ast(file, "st_envelope(NULL)", &[]);
+}
+
+fn test_st_union(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_union(to_geometry('SRID=4326;POINT(0 0)'), to_geometry('SRID=4326;POINT(1 1)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_area(st_union(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POLYGON((2 0, 4 0, 4 2, 2 2, 2 0))')))",
+        &[],
+    );
+    run_ast(file, "st_union(NULL, to_geometry('POINT(0 0)'))", &[]);
+}
+
+fn test_st_intersection(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_area(st_intersection(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))')))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_intersection(to_geometry('LINESTRING(0 0, 2 2)'), to_geometry('LINESTRING(0 2, 2 0)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_intersection(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), NULL)",
+        &[],
+    );
+}
+
+fn test_st_difference(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_area(st_difference(to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), to_geometry('POLYGON((1 1, 3 1, 3 3, 1 3, 1 1))')))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_difference(to_geometry('LINESTRING EMPTY'), to_geometry('POINT(0 0)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_difference(NULL, to_geometry('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'))",
+        &[],
+    );
+}
+
+fn test_st_buffer(file: &mut impl Write) {
+    run_ast(file, "st_area(st_buffer(to_geometry('POINT(0 0)'), 1))", &[
+    ]);
+    run_ast(file, "st_buffer(to_geometry('POINT(0 0)'), NULL)", &[]);
+}
+
+fn test_st_simplify(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_simplify(to_geometry('LINESTRING(0 0, 1 0.01, 2 0)'), 0.1)",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_simplify(to_geometry('LINESTRING(0 0, 2 0)'), NULL)",
+        &[],
+    );
+}
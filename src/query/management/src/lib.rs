@@ -19,13 +19,17 @@ mod connection;
 mod file_format;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod quota;
 mod role;
+mod secret;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 pub mod udf;
 mod user;
+mod workload_group;
 
 mod client_session;
 pub mod errors;
@@ -38,11 +42,13 @@ pub use connection::ConnectionMgr;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyMgr;
+pub use pipe::PipeMgr;
 pub use procedure::ProcedureMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use secret::SecretMgr;
 pub use serde::check_and_upgrade_to_pb;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
@@ -50,5 +56,8 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskMgr;
+pub use task::TaskRunMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
+pub use workload_group::WorkloadGroupMgr;
@@ -16,6 +16,7 @@ mod catalog_context;
 mod database_catalog;
 mod immutable_catalog;
 mod mutable_catalog;
+mod sequence_cache;
 mod session_catalog;
 pub mod table_memory_meta;
 
@@ -23,4 +24,5 @@ pub use catalog_context::CatalogContext;
 pub use database_catalog::DatabaseCatalog;
 pub use immutable_catalog::ImmutableCatalog;
 pub use mutable_catalog::MutableCatalog;
+pub use sequence_cache::SequenceCache;
 pub use session_catalog::SessionCatalog;
@@ -30,6 +30,8 @@ use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
 use databend_common_meta_app::schema::index_id_ident::IndexId;
 use databend_common_meta_app::schema::index_id_ident::IndexIdIdent;
+use databend_common_meta_app::schema::AlterSequenceReply;
+use databend_common_meta_app::schema::AlterSequenceReq;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CommitTableMetaReply;
 use databend_common_meta_app::schema::CommitTableMetaReq;
@@ -48,6 +50,7 @@ use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReq;
+use databend_common_meta_app::schema::DBIdTableName;
 use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::schema::DatabaseType;
@@ -83,6 +86,8 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListSequencesReply;
+use databend_common_meta_app::schema::ListSequencesReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -122,6 +127,7 @@ use log::info;
 use log::warn;
 
 use crate::catalogs::default::catalog_context::CatalogContext;
+use crate::catalogs::default::SequenceCache;
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
 use crate::databases::DatabaseFactory;
@@ -139,6 +145,7 @@ pub struct MutableCatalog {
     ctx: CatalogContext,
     tenant: Tenant,
     disable_table_info_refresh: bool,
+    sequence_cache: Arc<SequenceCache>,
 }
 
 impl Debug for MutableCatalog {
@@ -197,6 +204,7 @@ impl MutableCatalog {
             ctx,
             tenant,
             disable_table_info_refresh: false,
+            sequence_cache: Arc::new(SequenceCache::default()),
         })
     }
 
@@ -473,6 +481,12 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    #[async_backtrace::framed]
+    async fn get_db_id_table_name_by_id(&self, table_id: MetaId) -> Result<Option<DBIdTableName>> {
+        let res = self.ctx.meta.get_db_id_table_name_by_id(table_id).await?;
+        Ok(res)
+    }
+
     #[async_backtrace::framed]
     async fn get_table(
         &self,
@@ -698,6 +712,7 @@ impl Catalog for MutableCatalog {
     }
 
     async fn create_sequence(&self, req: CreateSequenceReq) -> Result<CreateSequenceReply> {
+        self.sequence_cache.invalidate(&req.ident);
         Ok(self.ctx.meta.create_sequence(req).await?)
     }
 
@@ -719,10 +734,29 @@ impl Catalog for MutableCatalog {
         &self,
         req: GetSequenceNextValueReq,
     ) -> Result<GetSequenceNextValueReply> {
-        Ok(self.ctx.meta.get_sequence_next_value(req).await?)
+        if let Some(reply) = self.sequence_cache.take(&req.ident, req.count) {
+            return Ok(reply);
+        }
+
+        let batch_req = GetSequenceNextValueReq {
+            ident: req.ident.clone(),
+            count: self.sequence_cache.batch_size(&req.ident, req.count),
+        };
+        let batch = self.ctx.meta.get_sequence_next_value(batch_req).await?;
+        Ok(self.sequence_cache.fill(req.ident, req.count, batch))
+    }
+
+    async fn alter_sequence(&self, req: AlterSequenceReq) -> Result<AlterSequenceReply> {
+        self.sequence_cache.invalidate(&req.ident);
+        Ok(self.ctx.meta.alter_sequence(req).await?)
+    }
+
+    async fn list_sequences(&self, req: ListSequencesReq) -> Result<ListSequencesReply> {
+        Ok(self.ctx.meta.list_sequences(req).await?)
     }
 
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
+        self.sequence_cache.invalidate(&req.ident);
         Ok(self.ctx.meta.drop_sequence(req).await?)
     }
 
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_meta_app::schema::GetSequenceNextValueReply;
+use databend_common_meta_app::schema::SequenceIdent;
+use parking_lot::Mutex;
+
+/// Sequence values allocated from the meta-service but not handed out yet.
+///
+/// A sequence with `CACHE n` allocates at least `n` values per meta-service round trip,
+/// the values left over are kept here and served to later requests on this node.
+/// Values cached on other nodes are skipped, so a cached sequence may have gaps.
+#[derive(Default)]
+pub struct SequenceCache {
+    ranges: Mutex<HashMap<SequenceIdent, CachedRange>>,
+}
+
+struct CachedRange {
+    next: u64,
+    step: i64,
+    remaining: u64,
+    cache: u64,
+}
+
+impl SequenceCache {
+    /// Takes `count` values from the cached range if it holds enough of them.
+    pub fn take(&self, ident: &SequenceIdent, count: u64) -> Option<GetSequenceNextValueReply> {
+        let mut ranges = self.ranges.lock();
+        let range = ranges.get_mut(ident)?;
+        if range.remaining < count {
+            return None;
+        }
+
+        let start = range.next;
+        let step = range.step as u64;
+        range.next += count * step;
+        range.remaining -= count;
+        Some(GetSequenceNextValueReply {
+            start,
+            step: range.step,
+            end: start + (count - 1) * step,
+            cache: range.cache,
+        })
+    }
+
+    /// The number of values to allocate from the meta-service to serve `count` values.
+    pub fn batch_size(&self, ident: &SequenceIdent, count: u64) -> u64 {
+        let ranges = self.ranges.lock();
+        match ranges.get(ident) {
+            Some(range) => count.max(range.cache),
+            None => count,
+        }
+    }
+
+    /// Serves `count` values from a freshly allocated batch and caches the rest.
+    pub fn fill(
+        &self,
+        ident: SequenceIdent,
+        count: u64,
+        batch: GetSequenceNextValueReply,
+    ) -> GetSequenceNextValueReply {
+        let step = batch.step as u64;
+        let allocated = (batch.end - batch.start) / step + 1;
+        let reply = GetSequenceNextValueReply {
+            start: batch.start,
+            step: batch.step,
+            end: batch.start + (count - 1) * step,
+            cache: batch.cache,
+        };
+
+        let range = CachedRange {
+            next: reply.end + step,
+            step: batch.step,
+            remaining: allocated - count,
+            cache: batch.cache,
+        };
+        self.ranges.lock().insert(ident, range);
+        reply
+    }
+
+    /// Drops the cached values, e.g. after the sequence is altered or dropped.
+    pub fn invalidate(&self, ident: &SequenceIdent) {
+        self.ranges.lock().remove(ident);
+    }
+}
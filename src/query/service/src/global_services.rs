@@ -50,6 +50,7 @@ use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadGroupManager;
 
 pub struct GlobalServices;
 
@@ -107,6 +108,7 @@ impl GlobalServices {
         }
 
         QueriesQueueManager::init(config.query.max_running_queries as usize)?;
+        WorkloadGroupManager::init()?;
         HttpQueryManager::init(config).await?;
         ClientSessionManager::init(config).await?;
         DataExchangeManager::init()?;
@@ -61,7 +61,7 @@ enum ObjectId {
 // some statements like `SELECT 1`, `SHOW USERS`, `SHOW ROLES`, `SHOW TABLES` will be
 // rewritten to the queries on the system tables, we need to skip the privilege check on
 // these tables.
-const SYSTEM_TABLES_ALLOW_LIST: [&str; 20] = [
+const SYSTEM_TABLES_ALLOW_LIST: [&str; 21] = [
     "catalogs",
     "columns",
     "databases",
@@ -73,6 +73,7 @@ const SYSTEM_TABLES_ALLOW_LIST: [&str; 20] = [
     "password_policies",
     "streams",
     "streams_terse",
+    "dynamic_tables",
     "virtual_columns",
     "users",
     "roles",
@@ -176,6 +177,9 @@ impl PrivilegeAccess {
             GrantObject::UDF(name) => OwnershipObject::UDF {
                 name: name.to_string(),
             },
+            GrantObject::Secret(name) => OwnershipObject::Secret {
+                name: name.to_string(),
+            },
             GrantObject::Global => return Ok(None),
         };
 
@@ -465,6 +469,7 @@ impl PrivilegeAccess {
             | GrantObject::DatabaseById(_, _)
             | GrantObject::UDF(_)
             | GrantObject::Stage(_)
+            | GrantObject::Secret(_)
             | GrantObject::TableById(_, _, _) => true,
             GrantObject::Global => false,
         };
@@ -516,6 +521,7 @@ impl PrivilegeAccess {
                     GrantObject::Global
                     | GrantObject::UDF(_)
                     | GrantObject::Stage(_)
+                    | GrantObject::Secret(_)
                     | GrantObject::Database(_, _)
                     | GrantObject::Table(_, _, _) => Err(ErrorCode::PermissionDenied(format!(
                         "Permission denied: privilege [{:?}] is required on {} for user {} with roles [{}]. \
@@ -737,7 +743,8 @@ impl AccessChecker for PrivilegeAccess {
                         let roles_name: Vec<String> = roles.iter().map(|role| role.name.to_string()).collect();
                         check_ownership_access(&identity, catalog, database, show_db_id, &ownerships, &roles_name)?;
                     }
-                    Some(RewriteKind::ShowStreams(database)) => {
+                    Some(RewriteKind::ShowStreams(database))
+                    | Some(RewriteKind::ShowDynamicTables(database)) => {
                         let ctl = self.ctx.get_catalog(&ctl_name).await?;
                         let (show_db_id, table_id) = match self.convert_to_id(&tenant, &ctl, database, None, false).await? {
                             ObjectId::Table(db_id, table_id) => { (db_id, Some(table_id)) }
@@ -866,6 +873,28 @@ impl AccessChecker for PrivilegeAccess {
                                         .await?;
                 }
             }
+            Plan::CreateSecret(_) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, true, false)
+                    .await?;
+            }
+            Plan::DropSecret(plan) => {
+                // Only the owner of the secret can drop it.
+                match UserApiProvider::instance().get_secret(&tenant, &plan.name).await {
+                    Ok(_) => {
+                        self.validate_access(
+                            &GrantObject::Secret(plan.name.clone()),
+                            UserPrivilegeType::Drop,
+                            false,
+                            false,
+                        )
+                        .await?;
+                    }
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_SECRET && plan.if_exists => {
+                        return Ok(());
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
             Plan::DropStage(plan) => {
                 match UserApiProvider::instance().get_stage(&tenant, &plan.name).await {
                     Ok(stage) => {
@@ -988,6 +1017,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Drop, false, false).await?
             }
+            Plan::ModifyTableTtl(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::ReclusterTable{s_expr, ..} => {
                 let plan: Recluster = s_expr.plan().clone().try_into()?;
                 // UDF has been disabled in recluster, no need to check udf privilege access.
@@ -1132,6 +1164,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::CreateDynamicTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
             }
+            Plan::RefreshDynamicTable(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::CreateUser(_) => {
                 self.validate_access(
                     &GrantObject::Global,
@@ -1183,7 +1218,8 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterUDF(_)
             | Plan::RefreshIndex(_)
             | Plan::RefreshTableIndex(_)
-            | Plan::AlterUser(_) => {
+            | Plan::AlterUser(_)
+            | Plan::AlterRole(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Alter, false, false)
                     .await?;
             }
@@ -1217,10 +1253,18 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
             | Plan::DropConnection(_)
+            | Plan::ShowSecrets(_)
+            | Plan::CreatePipe(_)
+            | Plan::AlterPipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DescPipe(_)
             | Plan::CreateIndex(_)
             | Plan::CreateTableIndex(_)
             | Plan::CreateNotification(_)
@@ -1235,6 +1279,7 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DropTask(_)     // TODO: need to build ownership info for task
             | Plan::AlterTask(_)
             | Plan::CreateSequence(_)
+            | Plan::AlterSequence(_)
             | Plan::DropSequence(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
                     .await?;
@@ -1276,6 +1321,9 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::Commit => {}
             Plan::Abort => {}
+            Plan::Savepoint { .. }
+            | Plan::RollbackToSavepoint { .. }
+            | Plan::ReleaseSavepoint { .. } => {}
         }
 
         Ok(())
@@ -1316,7 +1364,9 @@ fn check_ownership_access(
                         return Ok(());
                     }
                 }
-                OwnershipObject::UDF { .. } | OwnershipObject::Stage { .. } => {}
+                OwnershipObject::UDF { .. }
+                | OwnershipObject::Stage { .. }
+                | OwnershipObject::Secret { .. } => {}
             }
         }
     }
@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::ChangesInterval;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_ast::ast::TimeTravelPoint;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::NameResolutionContext;
+use databend_common_storages_fuse::FuseTable;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+
+use crate::sessions::QueryContext;
+
+/// The source table referenced by an incremental dynamic table query.
+pub struct DynamicTableSource {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+/// Returns the source table if the dynamic table query can be refreshed incrementally.
+///
+/// Only the queries that project and filter the rows of a single table are supported,
+/// the result of such a query over the appended rows is exactly the rows to be appended
+/// into the dynamic table.
+pub fn incremental_source(query: &Query) -> Option<DynamicTableSource> {
+    if query.with.is_some()
+        || !query.order_by.is_empty()
+        || !query.limit.is_empty()
+        || query.offset.is_some()
+    {
+        return None;
+    }
+
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+    if select.distinct
+        || select.top_n.is_some()
+        || select.group_by.is_some()
+        || select.having.is_some()
+        || select.window_list.is_some()
+        || select.qualify.is_some()
+        || select.from.len() != 1
+    {
+        return None;
+    }
+
+    let mut visitor = IncrementalExprVisitor { incremental: true };
+    for target in &select.select_list {
+        match target {
+            SelectTarget::AliasedExpr { expr, .. } => expr.drive(&mut visitor),
+            // The changes of the source contain the extra change columns.
+            SelectTarget::StarColumns { .. } => return None,
+        }
+    }
+    if let Some(selection) = &select.selection {
+        selection.drive(&mut visitor);
+    }
+    if !visitor.incremental {
+        return None;
+    }
+
+    match &select.from[0] {
+        TableReference::Table {
+            catalog,
+            database,
+            table,
+            temporal: None,
+            with_options: None,
+            pivot: None,
+            unpivot: None,
+            sample: None,
+            ..
+        } => Some(DynamicTableSource {
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the source table if its appended rows can be read by the `CHANGES` clause.
+pub async fn get_incremental_source_table(
+    ctx: &Arc<QueryContext>,
+    source: &DynamicTableSource,
+) -> Result<Option<Arc<dyn Table>>> {
+    let name_resolution_ctx = NameResolutionContext::try_from(ctx.get_settings().as_ref())?;
+    let catalog = source
+        .catalog
+        .as_ref()
+        .map(|catalog| normalize_identifier(catalog, &name_resolution_ctx).name)
+        .unwrap_or_else(|| ctx.get_current_catalog());
+    let database = source
+        .database
+        .as_ref()
+        .map(|database| normalize_identifier(database, &name_resolution_ctx).name)
+        .unwrap_or_else(|| ctx.get_current_database());
+    let table_name = normalize_identifier(&source.table, &name_resolution_ctx).name;
+
+    let table = ctx.get_table(&catalog, &database, &table_name).await?;
+    if FuseTable::try_from_table(table.as_ref()).is_ok() && table.change_tracking_enabled() {
+        Ok(Some(table))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Rewrites the source of an incremental dynamic table query to read the rows
+/// appended between the two snapshots.
+pub fn rewrite_incremental_query(query: &mut Query, begin_snapshot: String, end_snapshot: String) {
+    if let SetExpr::Select(select) = &mut query.body {
+        if let Some(TableReference::Table { temporal, .. }) = select.from.first_mut() {
+            *temporal = Some(TemporalClause::Changes(ChangesInterval {
+                append_only: true,
+                at_point: TimeTravelPoint::Snapshot(begin_snapshot),
+                end_point: Some(TimeTravelPoint::Snapshot(end_snapshot)),
+            }));
+        }
+    }
+}
+
+/// Rewrites the source of an incremental dynamic table query to read the snapshot,
+/// so that the query reads exactly the snapshot recorded as refreshed to.
+pub fn pin_source_snapshot(query: &mut Query, snapshot: String) {
+    if let SetExpr::Select(select) = &mut query.body {
+        if let Some(TableReference::Table { temporal, .. }) = select.from.first_mut() {
+            *temporal = Some(TemporalClause::TimeTravel(TimeTravelPoint::Snapshot(
+                snapshot,
+            )));
+        }
+    }
+}
+
+/// The name of the task that refreshes the dynamic table periodically, it is keyed
+/// by the table id which is unique and stays the same when the table is renamed.
+pub fn dynamic_table_task_name(table_id: u64) -> String {
+    format!("_dynamic_table_refresh_{}", table_id)
+}
+
+#[derive(Visitor)]
+#[visitor(Expr(enter), FunctionCall(enter))]
+struct IncrementalExprVisitor {
+    incremental: bool,
+}
+
+impl IncrementalExprVisitor {
+    fn enter_expr(&mut self, expr: &Expr) {
+        if matches!(
+            expr,
+            Expr::Subquery { .. } | Expr::Exists { .. } | Expr::InSubquery { .. }
+        ) {
+            self.incremental = false;
+        }
+    }
+
+    fn enter_function_call(&mut self, func: &FunctionCall) {
+        if func.window.is_some() || AggregateFunctionFactory::instance().contains(&func.name.name) {
+            self.incremental = false;
+        }
+    }
+}
@@ -93,6 +93,11 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Secret(secret) => {
+            UserApiProvider::instance()
+                .get_secret(&tenant, secret)
+                .await?;
+        }
         GrantObject::Global => (),
     }
 
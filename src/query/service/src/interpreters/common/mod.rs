@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dynamic_table;
 mod grant;
 mod metrics;
 mod notification;
@@ -23,14 +24,24 @@ mod util;
 
 pub mod table_option_validation;
 
+pub use dynamic_table::dynamic_table_task_name;
+pub use dynamic_table::get_incremental_source_table;
+pub use dynamic_table::incremental_source;
+pub use dynamic_table::pin_source_snapshot;
+pub use dynamic_table::rewrite_incremental_query;
+pub use dynamic_table::DynamicTableSource;
 pub use grant::validate_grant_object_exists;
 pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_ttl;
+pub use table::drop_auto_increment_sequences;
+pub use table::remove_expired_blocks;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
+pub use task::make_task_schedule;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
 
@@ -12,14 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::schema::DropSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_sql::auto_increment_sequences;
 use databend_common_sql::parse_computed_expr;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +63,78 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Checks the TTL of the table is still a DATE or TIMESTAMP expression
+/// after `column` is dropped, renamed or modified in `schema`.
+pub fn check_referenced_ttl(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: DataSchemaRef,
+    column: &str,
+) -> Result<()> {
+    let Some(ttl) = options.get(OPT_KEY_TTL) else {
+        return Ok(());
+    };
+    match parse_computed_expr(ctx, schema, ttl) {
+        Ok(expr) => {
+            let data_type = expr.data_type();
+            if !matches!(
+                data_type.remove_nullable(),
+                DataType::Timestamp | DataType::Date
+            ) {
+                return Err(ErrorCode::IllegalTableTtl(format!(
+                    "expected TTL expression `{}` have type DATE or TIMESTAMP, but `{}` has type {}",
+                    ttl, column, data_type,
+                )));
+            }
+        }
+        Err(_) => {
+            return Err(ErrorCode::IllegalTableTtl(format!(
+                "column `{}` is referenced by TTL `{}`, modify or drop the TTL first",
+                column, ttl,
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Removes the blocks of a fuse table whose rows are all expired by the TTL of the table.
+///
+/// The caller should hold the lock of the table.
+pub async fn remove_expired_blocks(
+    ctx: Arc<dyn TableContext>,
+    catalog: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let table = ctx
+        .get_catalog(catalog)
+        .await?
+        .get_table(&ctx.get_tenant(), database, table)
+        .await?;
+    if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+        fuse_table.remove_expired_blocks(ctx.clone()).await?;
+    }
+    Ok(())
+}
+
+/// Drops the sequences owned by the `AUTOINCREMENT` columns of a purged or replaced table.
+pub async fn drop_auto_increment_sequences(
+    ctx: Arc<dyn TableContext>,
+    schema: &TableSchema,
+) -> Result<()> {
+    let sequences = auto_increment_sequences(schema);
+    if sequences.is_empty() {
+        return Ok(());
+    }
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    for sequence in sequences {
+        let req = DropSequenceReq {
+            if_exists: true,
+            ident: SequenceIdent::new(&tenant, sequence),
+        };
+        catalog.drop_sequence(req).await?;
+    }
+    Ok(())
+}
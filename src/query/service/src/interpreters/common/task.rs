@@ -22,6 +22,7 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::ClientConfig;
 use databend_common_cloud_control::pb::schedule_options::ScheduleType;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskSchedule;
 
 use crate::sessions::QueryContext;
 
@@ -52,6 +53,16 @@ pub fn make_schedule_options(
     }
 }
 
+pub fn make_task_schedule(opt: ScheduleOptions) -> TaskSchedule {
+    match opt {
+        ScheduleOptions::IntervalSecs(secs, ms) => TaskSchedule::Interval { secs, millis: ms },
+        ScheduleOptions::CronExpression(expr, time_zone) => TaskSchedule::Cron {
+            expr,
+            time_zone: time_zone.filter(|tz| !tz.is_empty()),
+        },
+    }
+}
+
 pub fn make_warehouse_options(
     opt: WarehouseOptions,
 ) -> databend_common_cloud_control::pb::WarehouseOptions {
@@ -64,6 +64,7 @@ use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadGroupManager;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
@@ -259,18 +260,38 @@ async fn plan_sql(
         // planning the statement, to avoid potential deadlocks.
         // See PR https://github.com/databendlabs/databend/pull/16632
         let query_entry = QueryEntry::create_entry(&ctx, &extras, true)?;
-        let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+        let guard = acquire_queue(&ctx, query_entry).await?;
         let plan = planner.plan_stmt(&extras.statement).await?;
         Ok((plan, extras, guard))
     } else {
         // No lock is needed, plan the statement first, then acquire the queue guard.
         let plan = planner.plan_stmt(&extras.statement).await?;
         let query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
-        let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+        let guard = acquire_queue(&ctx, query_entry).await?;
         Ok((plan, extras, guard))
     }
 }
 
+/// Wait in the queue of the workload group bound to the current user or role first,
+/// then in the global queue of the node.
+async fn acquire_queue(
+    ctx: &Arc<QueryContext>,
+    query_entry: QueryEntry,
+) -> Result<AcquireQueueGuard> {
+    let workload_group_mgr = WorkloadGroupManager::instance();
+    let group_guard = match workload_group_mgr.resolve(ctx).await? {
+        Some(group) => Some(
+            workload_group_mgr
+                .acquire(group, query_entry.clone())
+                .await?,
+        ),
+        None => None,
+    };
+
+    let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+    Ok(guard.with_workload_group(group_guard))
+}
+
 fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &mut Option<Statement>, sql: &str) {
     let (query_hash, query_parameterized_hash) = if let Some(stmt) = stmt {
         let query_hash = format!("{:x}", Md5::digest(stmt.to_string()));
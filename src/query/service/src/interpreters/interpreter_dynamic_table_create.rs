@@ -0,0 +1,243 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::AlterTaskOptions;
+use databend_common_ast::ast::Engine;
+use databend_common_ast::ast::InitializeMode;
+use databend_common_ast::ast::RefreshMode;
+use databend_common_ast::ast::ScheduleOptions;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TargetLag;
+use databend_common_ast::ast::TaskSql;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_sql::plans::CreateDynamicTablePlan;
+use databend_common_sql::plans::CreateTablePlan;
+use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_sql::plans::DropTaskPlan;
+use databend_common_sql::Planner;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+
+use crate::interpreters::common::dynamic_table_task_name;
+use crate::interpreters::common::get_incremental_source_table;
+use crate::interpreters::common::incremental_source;
+use crate::interpreters::interpreter_task_alter::AlterTaskInterpreter;
+use crate::interpreters::interpreter_task_create::CreateTaskInterpreter;
+use crate::interpreters::interpreter_task_drop::DropTaskInterpreter;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CreateDynamicTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateDynamicTablePlan,
+}
+
+impl CreateDynamicTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateDynamicTablePlan) -> Result<Self> {
+        Ok(CreateDynamicTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateDynamicTableInterpreter {
+    fn name(&self) -> &str {
+        "CreateDynamicTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+        let tokens = tokenize_sql(&self.plan.as_query)?;
+        let (stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        let Statement::Query(query) = stmt else {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "invalid query of dynamic table {}.{}",
+                self.plan.database, self.plan.table
+            )));
+        };
+
+        let source = incremental_source(&query);
+        if self.plan.refresh_mode == RefreshMode::Incremental && source.is_none() {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "dynamic table {}.{} can not be refreshed incrementally, the query must only project and filter the rows of a single table",
+                self.plan.database, self.plan.table
+            )));
+        }
+
+        let mut options = self.plan.options.clone();
+        let mut as_select = None;
+        if self.plan.initialize == InitializeMode::OnCreate {
+            // The source table is cached in the query context, so the initial data
+            // is read from the same snapshot as the one recorded here.
+            if self.plan.refresh_mode != RefreshMode::Full {
+                if let Some(source) = &source {
+                    if let Some(table) = get_incremental_source_table(&self.ctx, source).await? {
+                        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                        if let Some(location) = fuse_table.snapshot_loc().await? {
+                            options.insert(OPT_KEY_SOURCE_SNAPSHOT_LOCATION.to_string(), location);
+                        }
+                    }
+                }
+            }
+
+            let mut planner = Planner::new(self.ctx.clone());
+            let (select_plan, _) = planner.plan_sql(&self.plan.as_query).await?;
+            as_select = Some(Box::new(select_plan));
+        }
+
+        let create_table_plan = CreateTablePlan {
+            create_option: self.plan.create_option,
+            tenant: self.plan.tenant.clone(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+            schema: self.plan.schema.clone(),
+            engine: Engine::Fuse,
+            engine_options: BTreeMap::new(),
+            storage_params: None,
+            options,
+            field_comments: self.plan.field_comments.clone(),
+            cluster_key: self.plan.cluster_key.clone(),
+            as_select,
+            inverted_indexes: None,
+            sequences: vec![],
+        };
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let replaced_table_id = if self.plan.create_option == CreateOption::CreateOrReplace {
+            match catalog
+                .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+                .await
+            {
+                Ok(table) => table
+                    .options()
+                    .contains_key(OPT_KEY_TARGET_LAG)
+                    .then(|| table.get_id()),
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => None,
+                Err(e) => return Err(e),
+            }
+        } else {
+            None
+        };
+
+        let mut build_res =
+            CreateTableInterpreter::try_create(self.ctx.clone(), create_table_plan)?
+                .execute2()
+                .await?;
+
+        // With `AS SELECT`, the table is only committed once the pipeline finishes, and
+        // till then the table name still refers to the replaced table, if any.
+        if build_res.main_pipeline.is_empty() {
+            create_refresh_task(self.ctx.clone(), &self.plan, replaced_table_id).await?;
+        } else {
+            let ctx = self.ctx.clone();
+            let plan = self.plan.clone();
+            build_res
+                .main_pipeline
+                .set_on_finished(move |info: &ExecutionInfo| {
+                    if info.res.is_ok() {
+                        GlobalIORuntime::instance().block_on(async move {
+                            create_refresh_task(ctx, &plan, replaced_table_id).await
+                        })?;
+                    }
+                    Ok(())
+                });
+        }
+
+        Ok(build_res)
+    }
+}
+
+/// Drops the refresh task of the replaced dynamic table, then creates and resumes the
+/// one of the new table if it has a target lag.
+#[async_backtrace::framed]
+async fn create_refresh_task(
+    ctx: Arc<QueryContext>,
+    plan: &CreateDynamicTablePlan,
+    replaced_table_id: Option<u64>,
+) -> Result<()> {
+    // The task is keyed by the table id, drop the one of the replaced dynamic table.
+    if let Some(table_id) = replaced_table_id {
+        let drop_task_plan = DropTaskPlan {
+            if_exists: true,
+            tenant: plan.tenant.clone(),
+            task_name: dynamic_table_task_name(table_id),
+        };
+        DropTaskInterpreter::try_create(ctx.clone(), drop_task_plan)?
+            .execute2()
+            .await?;
+    }
+
+    // The dynamic table is refreshed by a task which is resumed right after it is created.
+    let TargetLag::IntervalSecs(secs) = plan.target_lag else {
+        return Ok(());
+    };
+    // The table is referred by id, so that the task keeps working after it is renamed.
+    let catalog = ctx.get_catalog(&plan.catalog).await?;
+    let table_id = catalog
+        .get_table(&plan.tenant, &plan.database, &plan.table)
+        .await?
+        .get_id();
+    let refresh_sql = format!("ALTER DYNAMIC TABLE {} REFRESH", table_id);
+    let task_name = dynamic_table_task_name(table_id);
+    let create_task_plan = CreateTaskPlan {
+        if_not_exists: true,
+        tenant: plan.tenant.clone(),
+        task_name: task_name.clone(),
+        warehouse_opts: plan.warehouse_opts.clone(),
+        schedule_opts: Some(ScheduleOptions::IntervalSecs(secs, 0)),
+        after: vec![],
+        when_condition: None,
+        suspend_task_after_num_failures: None,
+        error_integration: None,
+        session_parameters: BTreeMap::new(),
+        sql: TaskSql::SingleStatement(refresh_sql),
+        comment: Some(format!(
+            "refresh dynamic table {}.{}",
+            plan.database, plan.table
+        )),
+    };
+    CreateTaskInterpreter::try_create(ctx.clone(), create_task_plan)?
+        .execute2()
+        .await?;
+
+    let alter_task_plan = AlterTaskPlan {
+        if_exists: false,
+        tenant: plan.tenant.clone(),
+        task_name,
+        alter_options: AlterTaskOptions::Resume,
+    };
+    AlterTaskInterpreter::try_create(ctx, alter_task_plan)?
+        .execute2()
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,243 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::RefreshMode;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::RefreshDynamicTablePlan;
+use databend_common_sql::Planner;
+use databend_common_storages_fuse::io::SnapshotsIO;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+use log::info;
+
+use crate::interpreters::common::get_incremental_source_table;
+use crate::interpreters::common::incremental_source;
+use crate::interpreters::common::pin_source_snapshot;
+use crate::interpreters::common::rewrite_incremental_query;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct RefreshDynamicTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshDynamicTablePlan,
+}
+
+impl RefreshDynamicTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshDynamicTablePlan) -> Result<Self> {
+        Ok(RefreshDynamicTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshDynamicTableInterpreter {
+    fn name(&self) -> &str {
+        "RefreshDynamicTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+        // The table is replaced by another one with the same name.
+        if self
+            .plan
+            .table_id
+            .is_some_and(|table_id| table_id != table.get_id())
+        {
+            return Err(ErrorCode::UnknownTableId(format!(
+                "Unknown dynamic table id {}",
+                self.plan.table_id.unwrap()
+            )));
+        }
+        let options = table.options();
+        let Some(as_query) = options
+            .get(OPT_KEY_AS_QUERY)
+            .filter(|_| options.contains_key(OPT_KEY_TARGET_LAG))
+        else {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "{}.{} is not a dynamic table",
+                self.plan.database, self.plan.table
+            )));
+        };
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(as_query)?;
+        let (stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        let Statement::Query(mut query) = stmt else {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "invalid query of dynamic table {}.{}",
+                self.plan.database, self.plan.table
+            )));
+        };
+
+        // Refresh incrementally only if the rows of the source table are appended since
+        // the last refresh, otherwise the dynamic table is overwritten by the whole query.
+        let mut overwrite = true;
+        let mut source_location = None;
+        let full_refresh = options
+            .get(OPT_KEY_REFRESH_MODE)
+            .is_some_and(|mode| mode == &RefreshMode::Full.to_string());
+        if !full_refresh {
+            if let Some(source) = incremental_source(&query) {
+                if let Some(source_table) = get_incremental_source_table(&self.ctx, &source).await?
+                {
+                    let fuse_table = FuseTable::try_from_table(source_table.as_ref())?;
+                    let latest_location = fuse_table.snapshot_loc().await?;
+                    let base_location = options.get(OPT_KEY_SOURCE_SNAPSHOT_LOCATION).cloned();
+                    if base_location.is_some() && base_location == latest_location {
+                        info!(
+                            "dynamic table {}.{} is up to date, skip refresh",
+                            self.plan.database, self.plan.table
+                        );
+                        return Ok(PipelineBuildResult::create());
+                    }
+
+                    // The query reads the snapshot that is recorded as refreshed to,
+                    // the rows appended since planning are left to the next refresh.
+                    if let Some(latest_snapshot) = fuse_table.read_table_snapshot().await? {
+                        let latest_snapshot_id = latest_snapshot.snapshot_id.simple().to_string();
+                        let append_only = match &base_location {
+                            Some(base_location) => {
+                                fuse_table
+                                    .optimize_stream_mode(
+                                        &StreamMode::Standard,
+                                        &Some(base_location.clone()),
+                                    )
+                                    .await?
+                                    == StreamMode::AppendOnly
+                            }
+                            None => false,
+                        };
+                        if append_only {
+                            let (base_snapshot, _) = SnapshotsIO::read_snapshot(
+                                base_location.unwrap(),
+                                fuse_table.get_operator(),
+                            )
+                            .await?;
+                            rewrite_incremental_query(
+                                &mut query,
+                                base_snapshot.snapshot_id.simple().to_string(),
+                                latest_snapshot_id,
+                            );
+                            overwrite = false;
+                        } else {
+                            pin_source_snapshot(&mut query, latest_snapshot_id);
+                        }
+                    }
+                    source_location = latest_location;
+                }
+            }
+        }
+        info!(
+            "refresh dynamic table {}.{}, incremental: {}",
+            self.plan.database, self.plan.table, !overwrite
+        );
+
+        let stmt = Statement::Insert(InsertStmt {
+            hints: None,
+            with: None,
+            catalog: Some(Identifier::from_name_with_quoted(
+                None,
+                self.plan.catalog.clone(),
+                Some('"'),
+            )),
+            database: Some(Identifier::from_name_with_quoted(
+                None,
+                self.plan.database.clone(),
+                Some('"'),
+            )),
+            table: Identifier::from_name_with_quoted(None, self.plan.table.clone(), Some('"')),
+            columns: vec![],
+            source: InsertSource::Select { query },
+            overwrite,
+        });
+        // Record the snapshot of the source table that the dynamic table is refreshed to,
+        // it is committed with the data in the same table meta update.
+        if let Some(location) = &source_location {
+            self.ctx.set_table_commit_options(
+                table.get_id(),
+                BTreeMap::from([(
+                    OPT_KEY_SOURCE_SNAPSHOT_LOCATION.to_string(),
+                    location.clone(),
+                )]),
+            );
+        }
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let plan = planner.plan_stmt(&stmt).await?;
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), &plan).await?;
+        let build_res = interpreter.execute2().await?;
+
+        // Nothing to commit, record the snapshot alone.
+        if let Some(location) = source_location {
+            if build_res.main_pipeline.is_empty() {
+                update_source_snapshot_location(self.ctx.clone(), self.plan.clone(), location)
+                    .await?;
+            }
+        }
+
+        Ok(build_res)
+    }
+}
+
+async fn update_source_snapshot_location(
+    ctx: Arc<QueryContext>,
+    plan: RefreshDynamicTablePlan,
+    location: String,
+) -> Result<()> {
+    let catalog = ctx.get_catalog(&plan.catalog).await?;
+    let table = catalog
+        .get_table(&ctx.get_tenant(), &plan.database, &plan.table)
+        .await?;
+    let req = UpsertTableOptionReq {
+        table_id: table.get_id(),
+        seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+        options: HashMap::from([(OPT_KEY_SOURCE_SNAPSHOT_LOCATION.to_string(), Some(location))]),
+    };
+    catalog
+        .upsert_table_option(&ctx.get_tenant(), &plan.database, req)
+        .await?;
+    Ok(())
+}
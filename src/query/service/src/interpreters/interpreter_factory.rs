@@ -50,11 +50,18 @@ use crate::interpreters::interpreter_notification_alter::AlterNotificationInterp
 use crate::interpreters::interpreter_notification_create::CreateNotificationInterpreter;
 use crate::interpreters::interpreter_notification_desc::DescNotificationInterpreter;
 use crate::interpreters::interpreter_notification_drop::DropNotificationInterpreter;
+use crate::interpreters::interpreter_pipe_alter::AlterPipeInterpreter;
+use crate::interpreters::interpreter_pipe_create::CreatePipeInterpreter;
+use crate::interpreters::interpreter_pipe_desc::DescPipeInterpreter;
+use crate::interpreters::interpreter_pipe_drop::DropPipeInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_procedure_call::CallProcedureInterpreter;
 use crate::interpreters::interpreter_procedure_create::CreateProcedureInterpreter;
 use crate::interpreters::interpreter_procedure_drop::DropProcedureInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
+use crate::interpreters::interpreter_secret_create::CreateSecretInterpreter;
+use crate::interpreters::interpreter_secret_drop::DropSecretInterpreter;
+use crate::interpreters::interpreter_secret_show::ShowSecretsInterpreter;
 use crate::interpreters::interpreter_set_priority::SetPriorityInterpreter;
 use crate::interpreters::interpreter_system_action::SystemActionInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
@@ -69,12 +76,17 @@ use crate::interpreters::interpreter_tasks_show::ShowTasksInterpreter;
 use crate::interpreters::interpreter_txn_abort::AbortInterpreter;
 use crate::interpreters::interpreter_txn_begin::BeginInterpreter;
 use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
+use crate::interpreters::interpreter_txn_release_savepoint::ReleaseSavepointInterpreter;
+use crate::interpreters::interpreter_txn_rollback_to_savepoint::RollbackToSavepointInterpreter;
+use crate::interpreters::interpreter_txn_savepoint::SavepointInterpreter;
 use crate::interpreters::interpreter_view_describe::DescribeViewInterpreter;
 use crate::interpreters::AlterUserInterpreter;
+use crate::interpreters::CreateDynamicTableInterpreter;
 use crate::interpreters::CreateStreamInterpreter;
 use crate::interpreters::DescUserInterpreter;
 use crate::interpreters::DropStreamInterpreter;
 use crate::interpreters::DropUserInterpreter;
+use crate::interpreters::RefreshDynamicTableInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::sessions::QueryContext;
 use crate::sql::plans::Plan;
@@ -247,6 +259,9 @@ impl InterpreterFactory {
             Plan::DropTableClusterKey(drop_table_cluster_key) => Ok(Arc::new(
                 DropTableClusterKeyInterpreter::try_create(ctx, *drop_table_cluster_key.clone())?,
             )),
+            Plan::ModifyTableTtl(modify_table_ttl) => Ok(Arc::new(
+                ModifyTableTtlInterpreter::try_create(ctx, *modify_table_ttl.clone())?,
+            )),
             Plan::ReclusterTable { s_expr, is_final } => {
                 Ok(Arc::new(ReclusterTableInterpreter::try_create(
                     ctx,
@@ -321,7 +336,21 @@ impl InterpreterFactory {
             )?)),
 
             // dynamic tables
-            Plan::CreateDynamicTable(_) => Err(ErrorCode::Unimplemented("todo")),
+            Plan::CreateDynamicTable(create_dynamic_table) => Ok(Arc::new(
+                CreateDynamicTableInterpreter::try_create(ctx, *create_dynamic_table.clone())?,
+            )),
+            Plan::RefreshDynamicTable(refresh_dynamic_table) => Ok(Arc::new(
+                RefreshDynamicTableInterpreter::try_create(ctx, *refresh_dynamic_table.clone())?,
+            )),
+
+            // Pipes
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
@@ -394,6 +423,10 @@ impl InterpreterFactory {
                 ctx,
                 *drop_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
                 ctx,
                 *set_role.clone(),
@@ -524,6 +557,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -554,9 +598,29 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
+
+            Plan::CreateSecret(p) => Ok(Arc::new(CreateSecretInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSecret(p) => Ok(Arc::new(DropSecretInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowSecrets(_) => Ok(Arc::new(ShowSecretsInterpreter::try_create(ctx)?)),
             Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
             Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
             Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+            Plan::Savepoint { name } => Ok(Arc::new(SavepointInterpreter::try_create(
+                ctx,
+                name.clone(),
+            )?)),
+            Plan::RollbackToSavepoint { name } => Ok(Arc::new(
+                RollbackToSavepointInterpreter::try_create(ctx, name.clone())?,
+            )),
+            Plan::ReleaseSavepoint { name } => Ok(Arc::new(
+                ReleaseSavepointInterpreter::try_create(ctx, name.clone())?,
+            )),
             Plan::CreateNotification(p) => Ok(Arc::new(CreateNotificationInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -584,6 +648,10 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::AlterSequence(p) => Ok(Arc::new(AlterSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -26,6 +26,7 @@ use crate::pipelines::PipelineBuildResult;
 use crate::servers::flight::v1::actions::KILL_QUERY;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
+use crate::sessions::WorkloadGroupManager;
 
 pub struct KillInterpreter {
     ctx: Arc<QueryContext>,
@@ -89,7 +90,9 @@ impl KillInterpreter {
             },
             Some(kill_session) if self.plan.kill_connection => {
                 if let Some(query_id) = kill_session.get_current_query_id() {
-                    if QueriesQueueManager::instance().remove(query_id) {
+                    if WorkloadGroupManager::instance().remove(&query_id)
+                        || QueriesQueueManager::instance().remove(query_id)
+                    {
                         return Ok(PipelineBuildResult::create());
                     }
                 }
@@ -99,7 +102,9 @@ impl KillInterpreter {
             }
             Some(kill_session) => {
                 if let Some(query_id) = kill_session.get_current_query_id() {
-                    if QueriesQueueManager::instance().remove(query_id) {
+                    if WorkloadGroupManager::instance().remove(&query_id)
+                        || QueriesQueueManager::instance().remove(query_id)
+                    {
                         return Ok(PipelineBuildResult::create());
                     }
                 }
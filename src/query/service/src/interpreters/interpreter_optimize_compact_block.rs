@@ -25,6 +25,7 @@ use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans::OptimizeCompactBlock;
 use databend_common_sql::MetadataRef;
 
+use crate::interpreters::common::remove_expired_blocks;
 use crate::interpreters::interpreter_optimize_purge::purge;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -81,6 +82,8 @@ impl Interpreter for OptimizeCompactBlockInterpreter {
             .acquire_table_lock(&catalog, &database, &table, &self.lock_opt)
             .await?;
 
+        remove_expired_blocks(self.ctx.clone(), &catalog, &database, &table).await?;
+
         let mut build_res = PipelineBuildResult::create();
         let mut builder = PhysicalPlanBuilder::new(MetadataRef::default(), self.ctx.clone(), false);
         match builder.build(&self.s_expr, HashSet::new()).await {
@@ -19,6 +19,7 @@ use databend_common_catalog::table::TableExt;
 use databend_common_exception::Result;
 use databend_common_sql::plans::OptimizeCompactSegmentPlan;
 
+use crate::interpreters::common::remove_expired_blocks;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -58,6 +59,14 @@ impl Interpreter for OptimizeCompactSegmentInterpreter {
             )
             .await?;
 
+        remove_expired_blocks(
+            self.ctx.clone(),
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+        )
+        .await?;
+
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let table = catalog
             .get_table(
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::UserDefinedSecret;
+use databend_common_sql::plans::CreateSecretPlan;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateSecretInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateSecretPlan,
+}
+
+impl CreateSecretInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateSecretPlan) -> Result<Self> {
+        Ok(Self { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateSecretInterpreter {
+    fn name(&self) -> &str {
+        "CreateSecretInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_secret_execute");
+
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+        let secret = UserDefinedSecret::new(&plan.name, plan.value, plan.comment);
+
+        let tenant = self.ctx.get_tenant();
+        user_mgr
+            .add_secret(&tenant, secret, &plan.create_option)
+            .await?;
+
+        // Grant ownership as the current role
+        let role_api = user_mgr.role_api(&tenant);
+        if let Some(current_role) = self.ctx.get_current_role() {
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Secret {
+                        name: plan.name.clone(),
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
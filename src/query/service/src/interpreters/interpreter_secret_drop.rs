@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_sql::plans::DropSecretPlan;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropSecretInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropSecretPlan,
+}
+
+impl DropSecretInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropSecretPlan) -> Result<Self> {
+        Ok(DropSecretInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropSecretInterpreter {
+    fn name(&self) -> &str {
+        "DropSecretInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_secret_execute");
+
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        // The secret may not exist with `IF EXISTS`, revoking the ownership of it is a no-op.
+        let role_api = user_mgr.role_api(&tenant);
+        role_api
+            .revoke_ownership(&OwnershipObject::Secret {
+                name: self.plan.name.clone(),
+            })
+            .await?;
+        RoleCacheManager::instance().invalidate_cache(&tenant);
+
+        user_mgr
+            .drop_secret(&tenant, &self.plan.name, self.plan.if_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -52,6 +52,9 @@ impl Interpreter for CreateSequenceInterpreter {
             ident: self.plan.ident.clone(),
             comment: self.plan.comment.clone(),
             create_on: Utc::now(),
+            start: self.plan.start,
+            step: self.plan.step,
+            cache: self.plan.cache,
         };
         let catalog = self.ctx.get_default_catalog()?;
         let _reply = catalog.create_sequence(req).await?;
@@ -21,6 +21,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::is_internal_column;
+use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_license::license::Feature;
 use databend_common_license::license::Feature::ComputedColumn;
@@ -30,6 +31,7 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CommitTableMetaReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
@@ -56,6 +58,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::drop_auto_increment_sequences;
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
@@ -176,10 +179,12 @@ impl CreateTableInterpreter {
         req.as_dropped = true;
         req.table_meta.drop_on = Some(Utc::now());
         let table_meta = req.table_meta.clone();
+        let replaced_schema = self.replaced_table_schema().await;
         let reply = catalog.create_table(req.clone()).await?;
         if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
             return Ok(PipelineBuildResult::create());
         }
+        self.create_sequences().await?;
 
         let table_id = reply.table_id;
         let prev_table_id = reply.prev_table_id;
@@ -261,6 +266,7 @@ impl CreateTableInterpreter {
                         "create_table_as_select {} success, commit table meta data by table id {}",
                         qualified_table_name, table_id
                     );
+                    let sequence_ctx = ctx.clone();
                     let fut = async move {
                         let req = CommitTableMetaReq {
                             name_ident: TableNameIdent {
@@ -273,7 +279,13 @@ impl CreateTableInterpreter {
                             prev_table_id,
                             orphan_table_name,
                         };
-                        catalog.commit_table_meta(req).await
+                        catalog.commit_table_meta(req).await?;
+                        match (prev_table_id, replaced_schema) {
+                            (Some(_), Some(schema)) => {
+                                drop_auto_increment_sequences(sequence_ctx, &schema).await
+                            }
+                            _ => Ok(()),
+                        }
                     };
 
                     GlobalIORuntime::instance().block_on(fut).map_err(|e| {
@@ -324,7 +336,14 @@ impl CreateTableInterpreter {
             self.build_request(stat)
         }?;
 
+        let replaced_schema = self.replaced_table_schema().await;
         let reply = catalog.create_table(req.clone()).await?;
+        if reply.new_table || self.plan.create_option == CreateOption::CreateOrReplace {
+            self.create_sequences().await?;
+        }
+        if let (Some(_), Some(schema)) = (reply.prev_table_id, replaced_schema) {
+            drop_auto_increment_sequences(self.ctx.clone(), &schema).await?;
+        }
 
         if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
             // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
@@ -351,6 +370,47 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    /// The schema of the table replaced by `CREATE OR REPLACE`, its `AUTOINCREMENT`
+    /// sequences are dropped once the new table is created.
+    #[async_backtrace::framed]
+    async fn replaced_table_schema(&self) -> Option<TableSchemaRef> {
+        if self.plan.create_option != CreateOption::CreateOrReplace {
+            return None;
+        }
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await.ok()?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await
+            .ok()?;
+        Some(table.schema())
+    }
+
+    /// Create the sequences of the `AUTOINCREMENT` columns.
+    #[async_backtrace::framed]
+    async fn create_sequences(&self) -> Result<()> {
+        if self.plan.sequences.is_empty() {
+            return Ok(());
+        }
+        let catalog = self.ctx.get_default_catalog()?;
+        for sequence in &self.plan.sequences {
+            let req = CreateSequenceReq {
+                create_option: sequence.create_option,
+                ident: sequence.ident.clone(),
+                comment: sequence.comment.clone(),
+                create_on: Utc::now(),
+                start: sequence.start,
+                step: sequence.step,
+                cache: sequence.cache,
+            };
+            catalog.create_sequence(req).await?;
+        }
+        Ok(())
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
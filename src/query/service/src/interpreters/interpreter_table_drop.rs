@@ -21,14 +21,19 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_sql::plans::DropTablePlan;
+use databend_common_sql::plans::DropTaskPlan;
 use databend_common_storages_fuse::operations::TruncateMode;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 
+use crate::interpreters::common::drop_auto_increment_sequences;
+use crate::interpreters::common::dynamic_table_task_name;
+use crate::interpreters::interpreter_task_drop::DropTaskInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -133,6 +138,24 @@ impl Interpreter for DropTableInterpreter {
             })
             .await?;
 
+        // drop the task that refreshes the dynamic table.
+        if tbl.options().contains_key(OPT_KEY_TARGET_LAG) {
+            let drop_task_plan = DropTaskPlan {
+                if_exists: true,
+                tenant: tenant.clone(),
+                task_name: dynamic_table_task_name(table_id),
+            };
+            DropTaskInterpreter::try_create(self.ctx.clone(), drop_task_plan)?
+                .execute2()
+                .await?;
+        }
+
+        // drop the sequences of the `AUTOINCREMENT` columns only if the table can not be undropped,
+        // otherwise they are dropped by `VACUUM DROP TABLE` once the table is purged.
+        if self.plan.all || is_temp {
+            drop_auto_increment_sequences(self.ctx.clone(), &tbl.schema()).await?;
+        }
+
         if !is_temp {
             // we should do `drop ownership` after actually drop table, otherwise when we drop the ownership,
             // but the table still exists, in the interval maybe some unexpected things will happen.
@@ -28,6 +28,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -97,13 +98,25 @@ impl Interpreter for DropTableColumnInterpreter {
                 self.plan.column.as_str(),
             )?;
         }
-        // If the column is inverted index column, the column can't be dropped.
+        // Check if this column is referenced by the TTL of the table.
+        let mut schema: DataSchema = table_info.schema().into();
+        schema.drop_column(self.plan.column.as_str())?;
+        check_referenced_ttl(
+            self.ctx.clone(),
+            table_info.options(),
+            Arc::new(schema),
+            self.plan.column.as_str(),
+        )?;
+        // If the column is index column, the column can't be dropped.
         if !table_info.meta.indexes.is_empty() {
             for (index_name, index) in &table_info.meta.indexes {
                 if index.column_ids.contains(&field.column_id) {
                     return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
-                        "column `{}` is referenced by inverted index, drop inverted index `{}` first",
-                        field.name, index_name,
+                        "column `{}` is referenced by {} index, drop {} index `{}` first",
+                        field.name,
+                        index.index_type.to_string().to_lowercase(),
+                        index.index_type.to_string().to_lowercase(),
+                        index_name,
                     )));
                 }
             }
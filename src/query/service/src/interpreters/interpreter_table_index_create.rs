@@ -14,10 +14,12 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::TableIndexType as AstTableIndexType;
 use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTableIndexPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
@@ -49,9 +51,10 @@ impl Interpreter for CreateTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
-
+        let index_type = match self.plan.index_type {
+            AstTableIndexType::Vector => TableIndexType::Vector,
+            _ => TableIndexType::Inverted,
+        };
         let index_name = self.plan.index_name.clone();
         let column_ids = self.plan.column_ids.clone();
         let sync_creation = self.plan.sync_creation;
@@ -63,16 +66,27 @@ impl Interpreter for CreateTableIndexInterpreter {
             create_option: self.plan.create_option,
             tenant,
             table_id,
+            index_type,
             name: index_name,
             column_ids,
             sync_creation,
             options: self.plan.index_options.clone(),
         };
 
-        let handler = get_inverted_index_handler();
-        let _ = handler
-            .do_create_table_index(catalog, create_index_req)
-            .await?;
+        match index_type {
+            TableIndexType::Inverted => {
+                LicenseManagerSwitch::instance()
+                    .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+                let handler = get_inverted_index_handler();
+                let _ = handler
+                    .do_create_table_index(catalog, create_index_req)
+                    .await?;
+            }
+            TableIndexType::Vector => {
+                catalog.create_table_index(create_index_req).await?;
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
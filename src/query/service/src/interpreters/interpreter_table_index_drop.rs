@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::TableIndexType;
 use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
@@ -49,9 +50,6 @@ impl Interpreter for DropTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
-
         let index_name = self.plan.index_name.clone();
         let table_id = self.plan.table_id;
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
@@ -62,8 +60,18 @@ impl Interpreter for DropTableIndexInterpreter {
             name: index_name,
         };
 
-        let handler = get_inverted_index_handler();
-        let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
+        match self.plan.index_type {
+            TableIndexType::Vector => {
+                catalog.drop_table_index(drop_index_req).await?;
+            }
+            _ => {
+                LicenseManagerSwitch::instance()
+                    .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+                let handler = get_inverted_index_handler();
+                let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -20,6 +20,7 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::RefreshTableIndexPlan;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
@@ -51,9 +52,6 @@ impl Interpreter for RefreshTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
-
         let table = self
             .ctx
             .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
@@ -66,7 +64,7 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let table_meta = &table.get_table_info().meta;
         let Some(index) = table_meta.indexes.get(&index_name) else {
             return Err(ErrorCode::RefreshIndexError(format!(
-                "Inverted index {} does not exist",
+                "Index {} does not exist",
                 index_name
             )));
         };
@@ -81,7 +79,7 @@ impl Interpreter for RefreshTableIndexInterpreter {
         }
         if index_fields.len() != index.column_ids.len() {
             return Err(ErrorCode::RefreshIndexError(format!(
-                "Inverted index {} is invalid",
+                "Index {} is invalid",
                 index_name
             )));
         }
@@ -91,17 +89,37 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let mut build_res = PipelineBuildResult::create();
 
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-        fuse_table
-            .do_refresh_inverted_index(
-                self.ctx.clone(),
-                index_name,
-                index_version,
-                &index.options,
-                index_schema,
-                segment_locs,
-                &mut build_res.main_pipeline,
-            )
-            .await?;
+        match index.index_type {
+            TableIndexType::Inverted => {
+                LicenseManagerSwitch::instance()
+                    .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+                fuse_table
+                    .do_refresh_inverted_index(
+                        self.ctx.clone(),
+                        index_name,
+                        index_version,
+                        &index.options,
+                        index_schema,
+                        segment_locs,
+                        &mut build_res.main_pipeline,
+                    )
+                    .await?;
+            }
+            TableIndexType::Vector => {
+                fuse_table
+                    .do_refresh_vector_index(
+                        self.ctx.clone(),
+                        index_name,
+                        index_version,
+                        &index.options,
+                        index_schema,
+                        segment_locs,
+                        &mut build_res.main_pipeline,
+                    )
+                    .await?;
+            }
+        }
 
         Ok(build_res)
     }
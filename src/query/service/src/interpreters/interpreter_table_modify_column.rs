@@ -51,6 +51,7 @@ use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -190,6 +191,13 @@ impl ModifyTableColumnInterpreter {
                     data_schema.set_field_type(i, data_type.into());
                     check_referenced_computed_columns(
                         self.ctx.clone(),
+                        Arc::new(data_schema.clone()),
+                        column,
+                    )?;
+                    // Check if this column is referenced by the TTL of the table.
+                    check_referenced_ttl(
+                        self.ctx.clone(),
+                        table_info.options(),
                         Arc::new(data_schema),
                         column,
                     )?;
@@ -204,7 +212,7 @@ impl ModifyTableColumnInterpreter {
                             data_type
                         )));
                     }
-                    // If the column is inverted index column, the type can't be changed.
+                    // If the column is index column, the type can't be changed.
                     if !table_info.meta.indexes.is_empty() {
                         for (index_name, index) in &table_info.meta.indexes {
                             if index.column_ids.contains(&old_field.column_id)
@@ -212,8 +220,11 @@ impl ModifyTableColumnInterpreter {
                                     != field.data_type.remove_nullable()
                             {
                                 return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
-                                    "column `{}` is referenced by inverted index, drop inverted index `{}` first",
-                                    column, index_name,
+                                    "column `{}` is referenced by {} index, drop {} index `{}` first",
+                                    column,
+                                    index.index_type.to_string().to_lowercase(),
+                                    index.index_type.to_string().to_lowercase(),
+                                    index_name,
                                 )));
                             }
                         }
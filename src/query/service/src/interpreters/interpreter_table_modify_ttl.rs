@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::ModifyTableTtlPlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct ModifyTableTtlInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ModifyTableTtlPlan,
+}
+
+impl ModifyTableTtlInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ModifyTableTtlPlan) -> Result<Self> {
+        Ok(ModifyTableTtlInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ModifyTableTtlInterpreter {
+    fn name(&self) -> &str {
+        "ModifyTableTtlInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let database = self.plan.database.as_str();
+        let table_name = self.plan.table.as_str();
+        let table = catalog
+            .get_table(&self.ctx.get_tenant(), database, table_name)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+        if FuseTable::try_from_table(table.as_ref()).is_err() {
+            return Err(ErrorCode::IllegalTableTtl(format!(
+                "TTL is only supported for FUSE engine, but got {}",
+                table.engine()
+            )));
+        }
+
+        let table_version = table.get_table_info().ident.seq;
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table_version),
+            options: HashMap::from([(OPT_KEY_TTL.to_string(), self.plan.ttl.clone())]),
+        };
+
+        catalog
+            .upsert_table_option(&self.ctx.get_tenant(), database, req)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
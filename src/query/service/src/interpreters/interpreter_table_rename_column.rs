@@ -28,6 +28,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -104,6 +105,13 @@ impl Interpreter for RenameTableColumnInterpreter {
                     self.plan.old_column.as_str(),
                 )?;
             }
+            // Check if old column is referenced by the TTL of the table.
+            check_referenced_ttl(
+                self.ctx.clone(),
+                table_info.options(),
+                Arc::new(DataSchema::from(&self.plan.schema)),
+                self.plan.old_column.as_str(),
+            )?;
 
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
 
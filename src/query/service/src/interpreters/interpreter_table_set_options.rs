@@ -22,6 +22,7 @@ use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::SetOptionsPlan;
 use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
@@ -103,6 +104,12 @@ impl Interpreter for SetOptionsInterpreter {
         }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
+            if is_reserved_opt_key(&key) {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {key} for alter table statement",
+                )));
+            }
             if !is_valid_create_opt(&key) {
                 error!("{}", &error_str);
                 return Err(ErrorCode::TableOptionInvalid(format!(
@@ -26,6 +26,7 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
@@ -37,6 +38,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -215,6 +217,10 @@ impl ShowCreateTableInterpreter {
             }
 
             for index_field in table_info.meta.indexes.values() {
+                // Only inverted indexes can be defined in the create table statement.
+                if index_field.index_type != TableIndexType::Inverted {
+                    continue;
+                }
                 let sync = if index_field.sync_creation {
                     "SYNC"
                 } else {
@@ -266,6 +272,10 @@ impl ShowCreateTableInterpreter {
                 .push_str(format!(" CLUSTER BY {}{}", cluster_type, cluster_keys_str).as_str());
         }
 
+        if let Some(ttl) = table_info.options().get(OPT_KEY_TTL) {
+            table_create_sql.push_str(format!(" TTL {}", ttl).as_str());
+        }
+
         if !hide_options_in_show_create_table || engine == "ICEBERG" || engine == "DELTA" {
             table_create_sql.push_str({
                 let mut opts = table_info.options().iter().collect::<Vec<_>>();
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_ast::ast::TaskSql;
 use databend_common_catalog::table_context::TableContext;
@@ -26,10 +27,13 @@ use databend_common_cloud_control::pb::WarehouseOptions;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskStatus;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -137,6 +141,112 @@ impl AlterTaskInterpreter {
         }
         req
     }
+
+    // Alters the task in the meta service.
+    async fn alter_local_task(&self) -> Result<()> {
+        let plan = &self.plan;
+        let user_api = UserApiProvider::instance();
+        if let AlterTaskOptions::AddAfter(predecessors) = &plan.alter_options {
+            for predecessor in predecessors.iter() {
+                user_api.get_task(&plan.tenant, predecessor).await?;
+            }
+        }
+
+        let now = Utc::now();
+        user_api
+            .update_task(&plan.tenant, &plan.task_name, plan.if_exists, |task| {
+                match plan.alter_options.clone() {
+                    AlterTaskOptions::Resume => {
+                        task.status = TaskStatus::Started;
+                        task.next_scheduled_at = task
+                            .schedule
+                            .as_ref()
+                            .and_then(|schedule| schedule.next_run_after(now));
+                    }
+                    AlterTaskOptions::Suspend => {
+                        task.status = TaskStatus::Suspended;
+                        task.next_scheduled_at = None;
+                        task.last_suspended_at = Some(now);
+                    }
+                    AlterTaskOptions::Set {
+                        schedule,
+                        comments,
+                        warehouse,
+                        suspend_task_after_num_failures,
+                        error_integration,
+                        session_parameters,
+                    } => {
+                        if let Some(schedule) = schedule {
+                            if !task.after.is_empty() {
+                                return Err(ErrorCode::IllegalTask(format!(
+                                    "task {} runs after other tasks, it can not be scheduled",
+                                    task.task_name
+                                )));
+                            }
+                            let schedule = make_task_schedule(schedule);
+                            if task.status == TaskStatus::Started {
+                                task.next_scheduled_at = schedule.next_run_after(now);
+                            }
+                            task.schedule = Some(schedule);
+                        }
+                        if comments.is_some() {
+                            task.comment = comments;
+                        }
+                        if warehouse.is_some() {
+                            task.warehouse = warehouse;
+                        }
+                        if suspend_task_after_num_failures.is_some() {
+                            task.suspend_task_after_num_failures = suspend_task_after_num_failures;
+                        }
+                        if error_integration.is_some() {
+                            task.error_integration = error_integration;
+                        }
+                        if let Some(session_parameters) = session_parameters {
+                            task.session_params = session_parameters;
+                        }
+                    }
+                    AlterTaskOptions::Unset { warehouse } => {
+                        if warehouse {
+                            task.warehouse = None;
+                        }
+                    }
+                    AlterTaskOptions::ModifyAs(sql) => match sql {
+                        TaskSql::SingleStatement(stmt) => {
+                            task.query_text = stmt;
+                            task.script_sqls = vec![];
+                        }
+                        TaskSql::ScriptBlock(ref sqls) => {
+                            task.query_text = format!("{}", sql);
+                            task.script_sqls = sqls.clone();
+                        }
+                    },
+                    AlterTaskOptions::ModifyWhen(expr) => {
+                        task.when_condition = Some(expr.to_string());
+                    }
+                    AlterTaskOptions::AddAfter(predecessors) => {
+                        if task.schedule.is_some() {
+                            return Err(ErrorCode::IllegalTask(format!(
+                                "task {} is scheduled, it can not run after other tasks",
+                                task.task_name
+                            )));
+                        }
+                        for predecessor in predecessors {
+                            if !task.after.contains(&predecessor) {
+                                task.after.push(predecessor);
+                            }
+                        }
+                    }
+                    AlterTaskOptions::RemoveAfter(predecessors) => {
+                        task.after
+                            .retain(|predecessor| !predecessors.contains(predecessor));
+                    }
+                }
+                task.updated_at = now;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -154,9 +264,8 @@ impl Interpreter for AlterTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.alter_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::TaskSql;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::make_request;
@@ -23,10 +24,15 @@ use databend_common_cloud_control::pb::CreateTaskRequest;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskInfo;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule;
 use crate::interpreters::common::make_warehouse_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -47,12 +53,7 @@ impl CreateTaskInterpreter {
 impl CreateTaskInterpreter {
     fn build_request(&self) -> CreateTaskRequest {
         let plan = self.plan.clone();
-        let owner = self
-            .ctx
-            .get_current_role()
-            .unwrap_or_default()
-            .identity()
-            .to_string();
+        let owner = self.owner();
         let mut req = CreateTaskRequest {
             task_name: plan.task_name,
             tenant_id: plan.tenant.tenant_name().to_string(),
@@ -83,6 +84,63 @@ impl CreateTaskInterpreter {
         }
         req
     }
+
+    fn owner(&self) -> String {
+        self.ctx
+            .get_current_role()
+            .unwrap_or_default()
+            .identity()
+            .to_string()
+    }
+
+    // Creates the task in the meta service, it is scheduled by the task scheduler
+    // of databend-query after it is resumed.
+    async fn create_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        if plan.schedule_opts.is_some() && !plan.after.is_empty() {
+            return Err(ErrorCode::IllegalTask(format!(
+                "task {} runs after other tasks, it can not be scheduled",
+                plan.task_name
+            )));
+        }
+        let user_api = UserApiProvider::instance();
+        for predecessor in plan.after.iter() {
+            user_api.get_task(&plan.tenant, predecessor).await?;
+        }
+
+        let (query_text, script_sqls) = match plan.sql {
+            TaskSql::SingleStatement(stmt) => (stmt, vec![]),
+            TaskSql::ScriptBlock(ref sqls) => (format!("{}", plan.sql), sqls.clone()),
+        };
+        let now = Utc::now();
+        let task = TaskInfo {
+            task_id: 0,
+            task_name: plan.task_name,
+            query_text,
+            script_sqls,
+            when_condition: plan.when_condition,
+            after: plan.after,
+            comment: plan.comment,
+            owner: self.owner(),
+            schedule: plan.schedule_opts.map(make_task_schedule),
+            warehouse: plan.warehouse_opts.warehouse,
+            suspend_task_after_num_failures: plan.suspend_task_after_num_failures,
+            error_integration: plan.error_integration,
+            session_params: plan.session_parameters,
+            status: TaskStatus::Suspended,
+            consecutive_failures: 0,
+            next_scheduled_at: None,
+            last_suspended_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+        let create_option = if plan.if_not_exists {
+            CreateOption::CreateIfNotExists
+        } else {
+            CreateOption::Create
+        };
+        user_api.add_task(&plan.tenant, task, &create_option).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -100,9 +158,8 @@ impl Interpreter for CreateTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.create_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
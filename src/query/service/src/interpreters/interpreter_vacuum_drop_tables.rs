@@ -34,6 +34,7 @@ use databend_common_sql::plans::VacuumDropTablePlan;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
 use log::info;
 
+use crate::interpreters::common::drop_auto_increment_sequences;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -163,6 +164,10 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .into_iter()
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
+        let table_schemas = tables
+            .iter()
+            .map(|tbl| (tbl.get_id(), tbl.schema()))
+            .collect::<Vec<_>>();
 
         let handler = get_vacuum_handler();
         let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
@@ -206,6 +211,14 @@ impl Interpreter for VacuumDropTablesInterpreter {
                 failed_db_ids, failed_tables, success_dropped_ids
             );
             self.gc_drop_tables(catalog, success_dropped_ids).await?;
+
+            // the `AUTOINCREMENT` sequences are kept while the table can be undropped,
+            // drop them once the table is purged.
+            for (table_id, schema) in table_schemas {
+                if !failed_tables.contains(&table_id) {
+                    drop_auto_increment_sequences(self.ctx.clone(), &schema).await?;
+                }
+            }
         }
 
         match files_opt {
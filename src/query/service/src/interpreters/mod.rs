@@ -39,6 +39,8 @@ mod interpreter_database_undrop;
 mod interpreter_dictionary_create;
 mod interpreter_dictionary_drop;
 mod interpreter_dictionary_show_create;
+mod interpreter_dynamic_table_create;
+mod interpreter_dynamic_table_refresh;
 mod interpreter_execute_immediate;
 mod interpreter_explain;
 mod interpreter_factory;
@@ -69,6 +71,10 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_pipe_alter;
+mod interpreter_pipe_create;
+mod interpreter_pipe_desc;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -76,6 +82,7 @@ mod interpreter_procedure_call;
 mod interpreter_procedure_create;
 mod interpreter_procedure_drop;
 mod interpreter_replace;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -83,7 +90,11 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_secret_create;
+mod interpreter_secret_drop;
+mod interpreter_secret_show;
 mod interpreter_select;
+mod interpreter_sequence_alter;
 mod interpreter_sequence_create;
 mod interpreter_sequence_drop;
 mod interpreter_set;
@@ -103,6 +114,7 @@ mod interpreter_table_index_drop;
 mod interpreter_table_index_refresh;
 mod interpreter_table_modify_column;
 mod interpreter_table_modify_comment;
+mod interpreter_table_modify_ttl;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
@@ -122,6 +134,9 @@ mod interpreter_tasks_show;
 mod interpreter_txn_abort;
 mod interpreter_txn_begin;
 mod interpreter_txn_commit;
+mod interpreter_txn_release_savepoint;
+mod interpreter_txn_rollback_to_savepoint;
+mod interpreter_txn_savepoint;
 mod interpreter_unset;
 mod interpreter_use_database;
 mod interpreter_user_alter;
@@ -144,6 +159,9 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
 mod util;
 
 pub use access::ManagementModeAccess;
@@ -163,6 +181,8 @@ pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
+pub use interpreter_dynamic_table_create::CreateDynamicTableInterpreter;
+pub use interpreter_dynamic_table_refresh::RefreshDynamicTableInterpreter;
 pub use interpreter_execute_immediate::ExecuteImmediateInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
@@ -187,6 +207,7 @@ pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
@@ -194,6 +215,7 @@ pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
 pub use interpreter_select::SelectInterpreter;
+pub use interpreter_sequence_alter::AlterSequenceInterpreter;
 pub use interpreter_sequence_create::CreateSequenceInterpreter;
 pub use interpreter_sequence_drop::DropSequenceInterpreter;
 pub use interpreter_set::SetInterpreter;
@@ -213,6 +235,7 @@ pub use interpreter_table_index_drop::DropTableIndexInterpreter;
 pub use interpreter_table_index_refresh::RefreshTableIndexInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_modify_comment::ModifyTableCommentInterpreter;
+pub use interpreter_table_modify_ttl::ModifyTableTtlInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
@@ -242,3 +265,6 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
@@ -50,12 +50,14 @@ pub mod interpreters;
 pub mod local;
 pub mod locks;
 pub mod pipelines;
+pub mod pipes;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod tasks;
 pub mod test_kits;
 
 mod builtin;
@@ -14,6 +14,10 @@
 
 mod lock_holder;
 mod lock_manager;
+mod scheduler_lease;
 mod table_lock;
 
 pub use lock_manager::LockManager;
+pub use scheduler_lease::SchedulerLease;
+pub use scheduler_lease::PIPE_SCHEDULER_LEASE;
+pub use scheduler_lease::TASK_SCHEDULER_LEASE;
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+
+const SCHEDULER_LEASE_KEY_PREFIX: &str = "__fd_scheduler_leases";
+
+/// The lease which elects the node to run the task scheduler of the tenant.
+pub const TASK_SCHEDULER_LEASE: &str = "task_scheduler";
+/// The lease which elects the node to run the pipe scheduler of the tenant.
+pub const PIPE_SCHEDULER_LEASE: &str = "pipe_scheduler";
+
+const SCHEDULER_LEASE_TTL: Duration = Duration::from_secs(30);
+
+/// A lease in the meta service which is held by one node at a time, e.g. the node
+/// which runs a background scheduler of the tenant.
+///
+/// The lease is a key with a TTL, it is renewed in background while the lease is held and
+/// removed when the lease is dropped. If the node goes away, the key expires and another
+/// node takes over the lease.
+pub struct SchedulerLease {
+    shutdown_flag: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+}
+
+impl SchedulerLease {
+    /// Tries to acquire the lease `name` of the tenant, returns `None` if it is held by another holder.
+    #[async_backtrace::framed]
+    pub async fn try_acquire(tenant: &Tenant, name: &str) -> Result<Option<SchedulerLease>> {
+        let key = format!(
+            "{}/{}/{}",
+            SCHEDULER_LEASE_KEY_PREFIX,
+            escape_for_key(tenant.tenant_name())?,
+            escape_for_key(name)?
+        );
+        let holder = GlobalConfig::instance().query.node_id.clone();
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let reply = meta_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(holder.into_bytes()),
+                Some(MetaSpec::new_ttl(SCHEDULER_LEASE_TTL)),
+            ))
+            .await?;
+        if !reply.is_changed() {
+            return Ok(None);
+        }
+        let Some(mut seq) = reply.result.map(|v| v.seq) else {
+            return Ok(None);
+        };
+
+        let lease = SchedulerLease {
+            shutdown_flag: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+        };
+        GlobalIORuntime::instance().spawn({
+            let shutdown_flag = lease.shutdown_flag.clone();
+            let shutdown_notify = lease.shutdown_notify.clone();
+            async move {
+                let mut notified = Box::pin(shutdown_notify.notified());
+                while !shutdown_flag.load(Ordering::SeqCst) {
+                    let renew_gap = Box::pin(sleep(SCHEDULER_LEASE_TTL / 3));
+                    match select(notified, renew_gap).await {
+                        Either::Left((_, _)) => {
+                            // shutdown.
+                            break;
+                        }
+                        Either::Right((_, new_notified)) => {
+                            notified = new_notified;
+                            let reply = meta_api
+                                .upsert_kv(UpsertKVReq::new(
+                                    &key,
+                                    MatchSeq::Exact(seq),
+                                    Operation::AsIs,
+                                    Some(MetaSpec::new_ttl(SCHEDULER_LEASE_TTL)),
+                                ))
+                                .await;
+                            match reply {
+                                Ok(reply) if reply.is_changed() => {
+                                    seq = reply.result.map_or(seq, |v| v.seq);
+                                }
+                                Ok(_) => {
+                                    log::warn!("scheduler lease {} is lost", key);
+                                    return;
+                                }
+                                Err(e) => {
+                                    log::warn!("failed to renew scheduler lease {}: {:?}", key, e);
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Err(e) = meta_api
+                    .upsert_kv(UpsertKVReq::new(
+                        &key,
+                        MatchSeq::Exact(seq),
+                        Operation::Delete,
+                        None,
+                    ))
+                    .await
+                {
+                    log::warn!("failed to release scheduler lease {}: {:?}", key, e);
+                }
+            }
+        });
+
+        Ok(Some(lease))
+    }
+}
+
+impl Drop for SchedulerLease {
+    fn drop(&mut self) {
+        self.shutdown_flag.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_one();
+    }
+}
@@ -17,10 +17,13 @@ use std::sync::Arc;
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
+use crate::pipelines::processors::transforms::missing_sequence_fields;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformFillSequenceColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -40,6 +43,19 @@ impl PipelineBuilder {
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+        // Fill missing sequence columns, the values are allocated from the sequences.
+        let sequence_fields = missing_sequence_fields(&source_schema, &default_schema);
+        let source_schema = if sequence_fields.is_empty() {
+            source_schema
+        } else {
+            pipeline.try_add_async_transformer(|| {
+                TransformFillSequenceColumns::try_new(ctx.clone(), &sequence_fields)
+            })?;
+            let mut fields = source_schema.fields().clone();
+            fields.extend(sequence_fields.into_iter().map(|(field, _)| field));
+            DataSchemaRefExt::create(fields)
+        };
+
         // Fill missing default columns and resort the columns.
         if source_schema != default_schema {
             pipeline.try_add_transformer(|| {
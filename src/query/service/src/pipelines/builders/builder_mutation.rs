@@ -20,12 +20,14 @@ use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_transforms::processors::create_dummy_item;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
 use databend_common_pipeline_transforms::processors::BlockCompactBuilder;
 use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
 use databend_common_pipeline_transforms::processors::TransformCompactBlock;
@@ -38,7 +40,9 @@ use databend_common_storages_fuse::operations::TransformSerializeSegment;
 use databend_common_storages_fuse::operations::UnMatchedExprs;
 use databend_common_storages_fuse::FuseTable;
 
+use crate::pipelines::processors::transforms::missing_sequence_fields;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformFillSequenceColumns;
 use crate::pipelines::processors::TransformResortAddOnWithoutSourceSchema;
 use crate::pipelines::PipelineBuilder;
 
@@ -180,12 +184,48 @@ impl PipelineBuilder {
         tbl: Arc<dyn Table>,
         transform_len: usize,
         need_match: bool,
-        unmatched: UnMatchedExprs,
+        mut unmatched: UnMatchedExprs,
     ) -> Result<()> {
         let table = FuseTable::try_from_table(tbl.as_ref())?;
+        let table_default_schema = &table.schema_with_stream().remove_computed_fields();
+        let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
+
+        // fill sequence columns of the inserted rows
+        let unmatched_sequence_fields = unmatched
+            .iter()
+            .map(|(source_schema, _, _)| missing_sequence_fields(source_schema, &default_schema))
+            .collect::<Vec<_>>();
+        if unmatched_sequence_fields
+            .iter()
+            .any(|fields| !fields.is_empty())
+        {
+            let mut builder = self.main_pipeline.add_transform_with_specified_len(
+                |input, output| {
+                    let transform = TransformFillSequenceColumns::try_new_for_merge_into(
+                        self.ctx.clone(),
+                        &unmatched_sequence_fields,
+                    )?;
+                    Ok(ProcessorPtr::create(AsyncTransformer::create(
+                        input, output, transform,
+                    )))
+                },
+                transform_len,
+            )?;
+            if need_match {
+                builder.add_items_prepend(vec![create_dummy_item()]);
+            }
+            self.main_pipeline.add_pipe(builder.finalize());
+
+            for ((source_schema, _, _), fields) in
+                unmatched.iter_mut().zip(unmatched_sequence_fields)
+            {
+                let mut source_fields = source_schema.fields().clone();
+                source_fields.extend(fields.into_iter().map(|(field, _)| field));
+                *source_schema = DataSchemaRefExt::create(source_fields);
+            }
+        }
 
         // fill default columns
-        let table_default_schema = &table.schema_with_stream().remove_computed_fields();
         let mut builder = self
             .main_pipeline
             .try_create_transform_pipeline_builder_with_len(
@@ -207,7 +247,6 @@ impl PipelineBuilder {
 
         // fill computed columns
         let table_computed_schema = &table.schema_with_stream().remove_virtual_computed_fields();
-        let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
         if default_schema != computed_schema {
             builder = self
@@ -23,6 +23,10 @@ use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::SortColumnDescription;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use databend_common_sql::executor::physical_plans::GapFill;
+use databend_common_sql::executor::physical_plans::MatchRecognize;
 use databend_common_sql::executor::physical_plans::Window;
 use databend_common_sql::executor::physical_plans::WindowPartition;
 use databend_storages_common_cache::TempDirManager;
@@ -30,6 +34,8 @@ use opendal::services::Fs;
 use opendal::Operator;
 
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformGapFill;
+use crate::pipelines::processors::transforms::TransformMatchRecognize;
 use crate::pipelines::processors::transforms::TransformWindow;
 use crate::pipelines::processors::transforms::TransformWindowPartitionCollect;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
@@ -136,6 +142,50 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_match_recognize(&mut self, match_recognize: &MatchRecognize) -> Result<()> {
+        self.build_pipeline(&match_recognize.input)?;
+
+        let input_schema = match_recognize.input.output_schema()?;
+        self.add_sorted_partitions_transform(match_recognize.partition_by.is_empty(), || {
+            TransformMatchRecognize::try_create(match_recognize, &input_schema)
+        })
+    }
+
+    /// Adds a transform whose input is grouped by the partition keys, such as the transform
+    /// of `MATCH_RECOGNIZE`. Like `TransformWindow`, all the rows must be in one pipeline
+    /// without partition keys.
+    fn add_sorted_partitions_transform<T, F>(&mut self, no_partition: bool, create: F) -> Result<()>
+    where
+        T: AccumulatingTransform + 'static,
+        F: Fn() -> Result<T>,
+    {
+        let old_output_len = self.main_pipeline.output_len();
+        if no_partition {
+            self.main_pipeline.try_resize(1)?;
+        }
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                create()?,
+            )))
+        })?;
+        if no_partition {
+            self.main_pipeline.try_resize(old_output_len)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn build_gap_fill(&mut self, gap_fill: &GapFill) -> Result<()> {
+        self.build_pipeline(&gap_fill.input)?;
+
+        let input_schema = gap_fill.input.output_schema()?;
+        let tz = self.func_ctx.tz;
+        self.add_sorted_partitions_transform(gap_fill.partition_by.is_empty(), || {
+            TransformGapFill::try_create(gap_fill, input_schema.clone(), tz)
+        })
+    }
+
     pub(crate) fn build_window_partition(
         &mut self,
         window_partition: &WindowPartition,
@@ -168,7 +168,7 @@ const POINTS_MASK: u64 = 0xFFFFFFFF00000000;
 const EPOCH_MASK: u64 = 0x00000000FFFFFFFF;
 
 // DEFAULT_POINTS is equal to Priority::MEDIUM
-const DEFAULT_POINTS: u64 = 3;
+pub(crate) const DEFAULT_POINTS: u64 = 3;
 
 struct ExecutingGraph {
     finished_nodes: AtomicUsize,
@@ -18,6 +18,8 @@ use std::time::Duration;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 
+use crate::sessions::WorkloadGroupManager;
+
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
@@ -31,7 +33,14 @@ impl ExecutorSettings {
     pub fn try_create(ctx: Arc<dyn TableContext>) -> Result<ExecutorSettings> {
         let query_id = ctx.get_id();
         let settings = ctx.get_settings();
-        let max_threads = settings.get_max_threads()?;
+        let mut max_threads = settings.get_max_threads()?;
+        // The threads of a query in workload group are scaled by the cpu weight of the group.
+        if let Some(query) = WorkloadGroupManager::instance().get_query(&query_id) {
+            max_threads = query
+                .group
+                .scale_by_cpu_weight(max_threads)
+                .min(max_threads);
+        }
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
 
         Ok(ExecutorSettings {
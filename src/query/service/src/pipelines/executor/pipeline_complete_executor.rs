@@ -27,6 +27,7 @@ use fastrace::prelude::*;
 
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::sessions::WorkloadGroupManager;
 
 pub struct PipelineCompleteExecutor {
     executor: Arc<PipelineExecutor>,
@@ -37,10 +38,14 @@ pub struct PipelineCompleteExecutor {
 impl PipelineCompleteExecutor {
     fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.mem_stat = Some(MemStat::create(format!(
-            "QueryExecutionMemStat-{}",
-            query_id
-        )));
+        // The memory of a query in workload group is also tracked by the memory stat of the group.
+        tracking_payload.mem_stat = match WorkloadGroupManager::instance().get_query(query_id) {
+            Some(query) => Some(query.mem_stat.clone()),
+            None => Some(MemStat::create(format!(
+                "QueryExecutionMemStat-{}",
+                query_id
+            ))),
+        };
         tracking_payload
     }
 
@@ -35,10 +35,12 @@ use log::info;
 use parking_lot::Condvar;
 use parking_lot::Mutex;
 
+use crate::pipelines::executor::executor_graph::DEFAULT_POINTS;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::GlobalQueriesExecutor;
 use crate::pipelines::executor::QueryPipelineExecutor;
 use crate::pipelines::executor::RunningGraph;
+use crate::sessions::WorkloadGroupManager;
 
 pub type InitCallback = Box<dyn FnOnce() -> Result<()> + Send + Sync + 'static>;
 
@@ -78,6 +80,7 @@ impl PipelineExecutor {
                 settings.query_id.clone(),
                 Some(finish_condvar.clone()),
             )?;
+            Self::apply_workload_group_weight(&graph, &settings);
 
             Ok(PipelineExecutor::QueriesPipelineExecutor(QueryWrapper {
                 graph,
@@ -133,6 +136,7 @@ impl PipelineExecutor {
                 settings.query_id.clone(),
                 Some(finish_condvar.clone()),
             )?;
+            Self::apply_workload_group_weight(&graph, &settings);
 
             Ok(PipelineExecutor::QueriesPipelineExecutor(QueryWrapper {
                 graph,
@@ -286,6 +290,14 @@ impl PipelineExecutor {
         }
     }
 
+    /// The queries of a workload group with a higher cpu weight get more points
+    /// to be scheduled by the global queries executor.
+    fn apply_workload_group_weight(graph: &Arc<RunningGraph>, settings: &ExecutorSettings) {
+        if let Some(query) = WorkloadGroupManager::instance().get_query(&settings.query_id) {
+            graph.change_priority(query.group.scale_by_cpu_weight(DEFAULT_POINTS));
+        }
+    }
+
     pub fn change_priority(&self, priority: u8) {
         match self {
             PipelineExecutor::QueryPipelineExecutor(_) => {
@@ -43,6 +43,7 @@ use crate::pipelines::executor::PipelineExecutor;
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::ProcessorPtr;
 use crate::pipelines::PipelineBuildResult;
+use crate::sessions::WorkloadGroupManager;
 
 struct State {
     is_finished: AtomicBool,
@@ -104,10 +105,14 @@ pub struct PipelinePullingExecutor {
 impl PipelinePullingExecutor {
     fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.mem_stat = Some(MemStat::create(format!(
-            "QueryExecutionMemStat-{}",
-            query_id
-        )));
+        // The memory of a query in workload group is also tracked by the memory stat of the group.
+        tracking_payload.mem_stat = match WorkloadGroupManager::instance().get_query(query_id) {
+            Some(query) => Some(query.mem_stat.clone()),
+            None => Some(MemStat::create(format!(
+                "QueryExecutionMemStat-{}",
+                query_id
+            ))),
+        };
         tracking_payload
     }
 
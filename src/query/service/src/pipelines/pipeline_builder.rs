@@ -170,6 +170,10 @@ impl PipelineBuilder {
             PhysicalPlan::WindowPartition(window_partition) => {
                 self.build_window_partition(window_partition)
             }
+            PhysicalPlan::MatchRecognize(match_recognize) => {
+                self.build_match_recognize(match_recognize)
+            }
+            PhysicalPlan::GapFill(gap_fill) => self.build_gap_fill(gap_fill),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
             PhysicalPlan::RowFetch(row_fetch) => self.build_row_fetch(row_fetch),
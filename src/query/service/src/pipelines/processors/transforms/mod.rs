@@ -26,6 +26,7 @@ mod transform_cast_schema;
 mod transform_create_sets;
 mod transform_dictionary;
 mod transform_expression_scan;
+mod transform_fill_sequence_columns;
 mod transform_filter;
 mod transform_limit;
 mod transform_materialized_cte;
@@ -53,6 +54,8 @@ pub use transform_cache_scan::TransformCacheScan;
 pub use transform_cast_schema::TransformCastSchema;
 pub use transform_create_sets::TransformCreateSets;
 pub use transform_expression_scan::TransformExpressionScan;
+pub use transform_fill_sequence_columns::missing_sequence_fields;
+pub use transform_fill_sequence_columns::TransformFillSequenceColumns;
 pub use transform_filter::TransformFilter;
 pub use transform_limit::TransformLimit;
 pub use transform_materialized_cte::MaterializedCteSink;
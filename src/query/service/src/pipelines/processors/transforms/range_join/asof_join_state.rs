@@ -0,0 +1,302 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Ordering;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::RangeJoin;
+use databend_common_sql::plans::JoinType;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+pub struct AsofJoinState {
+    is_left_join: bool,
+    right_data_types: Vec<DataType>,
+    // The concatenated right table
+    right_block: RwLock<Option<DataBlock>>,
+    // The keys of the right table sorted by the equi keys, then by the key of match condition.
+    // The rows with null keys are removed because they never match.
+    right_keys: RwLock<SortedKeys>,
+}
+
+#[derive(Default)]
+struct SortedKeys {
+    // The equi keys followed by the key of match condition
+    columns: Vec<Column>,
+    // The row index in the right table of each sorted row
+    rows: Vec<u32>,
+}
+
+impl AsofJoinState {
+    pub(crate) fn new(asof_join: &RangeJoin) -> Result<Self> {
+        let right_data_types = asof_join
+            .right
+            .output_schema()?
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect();
+        Ok(Self {
+            is_left_join: asof_join.join_type == JoinType::LeftAsof,
+            right_data_types,
+            right_block: RwLock::new(None),
+            right_keys: Default::default(),
+        })
+    }
+}
+
+impl RangeJoinState {
+    pub(crate) fn asof_partition(&self) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let right_table = self.right_table.read();
+        if !right_table.is_empty() {
+            let right_block = DataBlock::concat(&right_table)?;
+            let key_columns = self.eval_keys(
+                &right_block,
+                self.conditions
+                    .iter()
+                    .map(|condition| &condition.right_expr),
+            )?;
+            let mut rows = (0..right_block.num_rows() as u32)
+                .filter(|row| !has_null_key(&key_columns, *row as usize))
+                .collect::<Vec<_>>();
+            rows.sort_by(|a, b| compare_keys(&key_columns, *a as usize, &key_columns, *b as usize));
+
+            let key_block = DataBlock::new(
+                key_columns
+                    .into_iter()
+                    .map(|column| BlockEntry::new(column.data_type(), Value::Column(column)))
+                    .collect(),
+                right_block.num_rows(),
+            );
+            let sorted_block = key_block.take(&rows, &mut None)?;
+            let columns = sorted_block
+                .columns()
+                .iter()
+                .map(|entry| {
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, sorted_block.num_rows())
+                })
+                .collect();
+            *asof_join_state.right_keys.write() = SortedKeys { columns, rows };
+            *asof_join_state.right_block.write() = Some(right_block);
+        }
+
+        // Each left block is probed by one task
+        let left_table = self.left_table.read();
+        let mut tasks = self.tasks.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+        }
+        Ok(())
+    }
+
+    pub fn asof_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let (left_idx, _) = self.tasks.read()[task_id];
+        let left_block = self.left_table.read()[left_idx].clone();
+        let key_columns = self.eval_keys(
+            &left_block,
+            self.conditions.iter().map(|condition| &condition.left_expr),
+        )?;
+        let operator = AsofOperator::try_create(self.conditions.last().unwrap().operator.as_str())?;
+
+        let mut left_matched = Vec::with_capacity(left_block.num_rows());
+        let mut right_matched = Vec::with_capacity(left_block.num_rows());
+        let mut left_unmatched = vec![];
+        {
+            let right_keys = asof_join_state.right_keys.read();
+            for row in 0..left_block.num_rows() {
+                let matched = if has_null_key(&key_columns, row) {
+                    None
+                } else {
+                    right_keys.find_closest_row(&key_columns, row, operator)
+                };
+                match matched {
+                    Some(right_row) => {
+                        left_matched.push(row as u32);
+                        right_matched.push(right_row);
+                    }
+                    None => left_unmatched.push(row as u32),
+                }
+            }
+        }
+
+        let mut result_blocks = Vec::with_capacity(2);
+        if !left_matched.is_empty() {
+            let mut result_block = left_block.take(&left_matched, &mut None)?;
+            let right_block = asof_join_state.right_block.read();
+            let right_result_block = right_block
+                .as_ref()
+                .unwrap()
+                .take(&right_matched, &mut None)?;
+            for entry in right_result_block.columns() {
+                if asof_join_state.is_left_join {
+                    result_block.add_column(BlockEntry::new(
+                        entry.data_type.wrap_nullable(),
+                        entry.value.clone().wrap_nullable(None),
+                    ));
+                } else {
+                    result_block.add_column(entry.clone());
+                }
+            }
+            result_blocks.push(result_block);
+        }
+        if asof_join_state.is_left_join && !left_unmatched.is_empty() {
+            let mut result_block = left_block.take(&left_unmatched, &mut None)?;
+            for data_type in asof_join_state.right_data_types.iter() {
+                result_block.add_column(BlockEntry::new(
+                    data_type.wrap_nullable(),
+                    Value::Scalar(Scalar::Null),
+                ));
+            }
+            result_blocks.push(result_block);
+        }
+        Ok(result_blocks)
+    }
+
+    fn eval_keys<'a>(
+        &self,
+        block: &DataBlock,
+        exprs: impl Iterator<Item = &'a RemoteExpr>,
+    ) -> Result<Vec<Column>> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+        exprs
+            .map(|expr| {
+                let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+                Ok(evaluator
+                    .run(&expr)?
+                    .convert_to_full_column(expr.data_type(), block.num_rows()))
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum AsofOperator {
+    Gte,
+    Gt,
+    Lte,
+    Lt,
+}
+
+impl AsofOperator {
+    fn try_create(op: &str) -> Result<Self> {
+        match op {
+            "gte" => Ok(AsofOperator::Gte),
+            "gt" => Ok(AsofOperator::Gt),
+            "lte" => Ok(AsofOperator::Lte),
+            "lt" => Ok(AsofOperator::Lt),
+            _ => Err(ErrorCode::Internal(format!(
+                "Unsupported operator {} in the match condition of asof join",
+                op
+            ))),
+        }
+    }
+}
+
+impl SortedKeys {
+    // Find the closest right row satisfying `left_key <op> right_key` among the rows
+    // with the same equi keys as the left row.
+    fn find_closest_row(&self, left: &[Column], row: usize, op: AsofOperator) -> Option<u32> {
+        if self.rows.is_empty() {
+            return None;
+        }
+        let num_equi_keys = self.columns.len() - 1;
+        let compare_equi_keys = |idx: usize| {
+            compare_keys(
+                &self.columns[..num_equi_keys],
+                idx,
+                &left[..num_equi_keys],
+                row,
+            )
+        };
+        let begin = partition_point(0, self.rows.len(), |idx| {
+            compare_equi_keys(idx) == Ordering::Less
+        });
+        let end = partition_point(begin, self.rows.len(), |idx| {
+            compare_equi_keys(idx) != Ordering::Greater
+        });
+
+        let right_key = &self.columns[num_equi_keys];
+        let left_key = unsafe { left[num_equi_keys].index_unchecked(row) };
+        let compare_key = |idx: usize| unsafe { right_key.index_unchecked(idx) }.cmp(&left_key);
+        let idx = match op {
+            AsofOperator::Gte => {
+                partition_point(begin, end, |idx| compare_key(idx) != Ordering::Greater)
+                    .checked_sub(1)
+                    .filter(|idx| *idx >= begin)
+            }
+            AsofOperator::Gt => {
+                partition_point(begin, end, |idx| compare_key(idx) == Ordering::Less)
+                    .checked_sub(1)
+                    .filter(|idx| *idx >= begin)
+            }
+            AsofOperator::Lte => Some(partition_point(begin, end, |idx| {
+                compare_key(idx) == Ordering::Less
+            }))
+            .filter(|idx| *idx < end),
+            AsofOperator::Lt => Some(partition_point(begin, end, |idx| {
+                compare_key(idx) != Ordering::Greater
+            }))
+            .filter(|idx| *idx < end),
+        };
+        idx.map(|idx| self.rows[idx])
+    }
+}
+
+// Returns the first index in `[begin, end)` for which `pred` is false, `pred` must be true
+// for a prefix of the range.
+fn partition_point(begin: usize, end: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (begin, end);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+fn has_null_key(key_columns: &[Column], row: usize) -> bool {
+    key_columns
+        .iter()
+        .any(|column| unsafe { column.index_unchecked(row) }.is_null())
+}
+
+fn compare_keys(lhs: &[Column], lhs_row: usize, rhs: &[Column], rhs_row: usize) -> Ordering {
+    for (l, r) in lhs.iter().zip(rhs.iter()) {
+        let ordering = unsafe { l.index_unchecked(lhs_row).cmp(&r.index_unchecked(rhs_row)) };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::ewkb_bounding_box;
+use parking_lot::RwLock;
+use rstar::primitives::GeomWithData;
+use rstar::primitives::Rectangle;
+use rstar::RTree;
+use rstar::AABB;
+
+use crate::pipelines::processors::transforms::range_join::filter_block;
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+// The bounding box of a right table geometry, with its row index
+type IndexedBox = GeomWithData<Rectangle<[f64; 2]>, u32>;
+
+#[derive(Default)]
+pub struct SpatialJoinState {
+    // The concatenated right table
+    right_block: RwLock<Option<DataBlock>>,
+    // R-tree on the bounding boxes of the right table geometries
+    rtree: RwLock<RTree<IndexedBox>>,
+}
+
+impl RangeJoinState {
+    pub(crate) fn spatial_partition(&self) -> Result<()> {
+        let spatial_join_state = self.spatial_join_state.as_ref().unwrap();
+        let right_table = self.right_table.read();
+        if !right_table.is_empty() {
+            let right_block = DataBlock::concat(&right_table)?;
+            let column = self.eval_geometry(&right_block, &self.conditions[0].right_expr)?;
+            let mut boxes = Vec::with_capacity(right_block.num_rows());
+            for row in 0..right_block.num_rows() {
+                if let Some(bbox) = bounding_box(&column, row)? {
+                    boxes.push(GeomWithData::new(bbox_rectangle(bbox), row as u32));
+                }
+            }
+            *spatial_join_state.rtree.write() = RTree::bulk_load(boxes);
+            *spatial_join_state.right_block.write() = Some(right_block);
+        }
+
+        // Each left block is probed by one task
+        let left_table = self.left_table.read();
+        let mut tasks = self.tasks.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+        }
+        Ok(())
+    }
+
+    pub fn spatial_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let spatial_join_state = self.spatial_join_state.as_ref().unwrap();
+        let right_block = spatial_join_state.right_block.read();
+        let Some(right_block) = right_block.as_ref() else {
+            return Ok(vec![]);
+        };
+        let (left_idx, _) = self.tasks.read()[task_id];
+        let left_block = self.left_table.read()[left_idx].clone();
+        let column = self.eval_geometry(&left_block, &self.conditions[0].left_expr)?;
+
+        // Find the candidate pairs whose bounding boxes intersect
+        let mut left_matched = vec![];
+        let mut right_matched = vec![];
+        {
+            let rtree = spatial_join_state.rtree.read();
+            for row in 0..left_block.num_rows() {
+                let Some(bbox) = bounding_box(&column, row)? else {
+                    continue;
+                };
+                let envelope = AABB::from_corners([bbox[0], bbox[1]], [bbox[2], bbox[3]]);
+                for candidate in rtree.locate_in_envelope_intersecting(&envelope) {
+                    left_matched.push(row as u32);
+                    right_matched.push(candidate.data);
+                }
+            }
+        }
+        if left_matched.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut result_block = left_block.take(&left_matched, &mut None)?;
+        let right_result_block = right_block.take(&right_matched, &mut None)?;
+        for entry in right_result_block.columns() {
+            result_block.add_column(entry.clone());
+        }
+        // The spatial predicate is one of the other conditions
+        for filter in self.other_conditions.iter() {
+            result_block = filter_block(result_block, filter)?;
+        }
+        Ok(vec![result_block])
+    }
+
+    fn eval_geometry(&self, block: &DataBlock, expr: &RemoteExpr) -> Result<Column> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+        Ok(evaluator
+            .run(&expr)?
+            .convert_to_full_column(expr.data_type(), block.num_rows()))
+    }
+}
+
+// Null and empty geometries have no bounding box, they never match.
+fn bounding_box(column: &Column, row: usize) -> Result<Option<[f64; 4]>> {
+    match unsafe { column.index_unchecked(row) } {
+        ScalarRef::Geometry(ewkb) => ewkb_bounding_box(ewkb),
+        _ => Ok(None),
+    }
+}
+
+fn bbox_rectangle(bbox: [f64; 4]) -> Rectangle<[f64; 2]> {
+    Rectangle::from_corners([bbox[0], bbox[1]], [bbox[2], bbox[3]])
+}
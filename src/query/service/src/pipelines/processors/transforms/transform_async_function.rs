@@ -21,11 +21,13 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
 use databend_common_expression::Value;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_pipeline_transforms::processors::AsyncTransform;
 use databend_common_storages_fuse::TableContext;
+use databend_common_users::UserApiProvider;
 
 use crate::pipelines::processors::transforms::transform_dictionary::DictionaryOperator;
 use crate::sessions::QueryContext;
@@ -70,8 +72,10 @@ impl TransformAsyncFunction {
                 count,
             };
             let resp = catalog.get_sequence_next_value(req).await?;
-            let range = resp.start..resp.start + count;
-            UInt64Type::from_data(range.collect::<Vec<u64>>())
+            let values = (0..count)
+                .map(|i| resp.start + i * resp.step as u64)
+                .collect::<Vec<u64>>();
+            UInt64Type::from_data(values)
         };
         let entry = BlockEntry {
             data_type: data_type.clone(),
@@ -81,6 +85,26 @@ impl TransformAsyncFunction {
 
         Ok(())
     }
+
+    // transform add secret value column.
+    async fn transform_secret(
+        &self,
+        data_block: &mut DataBlock,
+        secret_name: &str,
+        data_type: &DataType,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let secret = UserApiProvider::instance()
+            .get_secret(&tenant, secret_name)
+            .await?;
+        let entry = BlockEntry {
+            data_type: data_type.clone(),
+            value: Value::Scalar(Scalar::String(secret.value)),
+        };
+        data_block.add_column(entry);
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -109,6 +133,10 @@ impl AsyncTransform for TransformAsyncFunction {
                     )
                     .await?;
                 }
+                AsyncFunctionArgument::SecretFunction(secret_name) => {
+                    self.transform_secret(&mut data_block, secret_name, &async_func_desc.data_type)
+                        .await?;
+                }
             }
         }
         Ok(data_block)
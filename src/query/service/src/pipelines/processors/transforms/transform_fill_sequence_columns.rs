@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::SourceSchemaIndex;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_sql::parse_sequence_default;
+use databend_common_storages_fuse::TableContext;
+
+use crate::sessions::QueryContext;
+
+/// Returns the fields of `schema` missing in `source_schema` whose values come from a sequence,
+/// along with the sequence names.
+pub fn missing_sequence_fields(
+    source_schema: &DataSchema,
+    schema: &DataSchema,
+) -> Vec<(DataField, String)> {
+    schema
+        .fields()
+        .iter()
+        .filter(|f| !source_schema.has_field(f.name()))
+        .filter_map(|f| {
+            let sequence = parse_sequence_default(f.default_expr()?)?;
+            Some((f.clone(), sequence))
+        })
+        .collect()
+}
+
+struct SequenceColumn {
+    sequence: String,
+    data_type: DataType,
+    cast_expr: Expr,
+}
+
+/// Appends the columns of `AUTOINCREMENT` fields missing in the source.
+///
+/// The values of each column are allocated from its sequence in one request per block.
+pub struct TransformFillSequenceColumns {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    // The columns to fill for each `SourceSchemaIndex` of merge into, or only one item otherwise.
+    columns: Vec<Vec<SequenceColumn>>,
+    merge_into: bool,
+}
+
+impl TransformFillSequenceColumns {
+    pub fn try_new(ctx: Arc<QueryContext>, fields: &[(DataField, String)]) -> Result<Self> {
+        Self::try_create(ctx, &[fields.to_vec()], false)
+    }
+
+    pub fn try_new_for_merge_into(
+        ctx: Arc<QueryContext>,
+        unmatched_fields: &[Vec<(DataField, String)>],
+    ) -> Result<Self> {
+        Self::try_create(ctx, unmatched_fields, true)
+    }
+
+    fn try_create(
+        ctx: Arc<QueryContext>,
+        fields: &[Vec<(DataField, String)>],
+        merge_into: bool,
+    ) -> Result<Self> {
+        let columns = fields
+            .iter()
+            .map(|fields| {
+                fields
+                    .iter()
+                    .map(|(field, sequence)| {
+                        let column = Expr::ColumnRef {
+                            span: None,
+                            id: 0,
+                            data_type: DataType::Number(NumberDataType::UInt64),
+                            display_name: field.name().clone(),
+                        };
+                        let cast_expr =
+                            check_cast(None, false, column, field.data_type(), &BUILTIN_FUNCTIONS)?;
+                        Ok(SequenceColumn {
+                            sequence: sequence.clone(),
+                            data_type: field.data_type().clone(),
+                            cast_expr,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            columns,
+            merge_into,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformFillSequenceColumns {
+    const NAME: &'static str = "FillSequenceColumns";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let index = if self.merge_into {
+            // The updated rows of merge into have no meta, they already hold all the columns.
+            match data_block
+                .get_meta()
+                .and_then(SourceSchemaIndex::downcast_ref_from)
+            {
+                Some(index) => *index,
+                None => return Ok(data_block),
+            }
+        } else {
+            0
+        };
+
+        let count = data_block.num_rows() as u64;
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_default_catalog()?;
+        for column in &self.columns[index] {
+            let values = if count == 0 {
+                vec![]
+            } else {
+                let req = GetSequenceNextValueReq {
+                    ident: SequenceIdent::new(&tenant, &column.sequence),
+                    count,
+                };
+                let resp = catalog.get_sequence_next_value(req).await?;
+                (0..count)
+                    .map(|i| resp.start + i * resp.step as u64)
+                    .collect::<Vec<u64>>()
+            };
+            let block = DataBlock::new_from_columns(vec![UInt64Type::from_data(values)]);
+            let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let value = evaluator.run(&column.cast_expr)?;
+            data_block.add_column(BlockEntry::new(column.data_type.clone(), value));
+        }
+        Ok(data_block)
+    }
+}
@@ -32,6 +32,7 @@ use databend_common_pipeline_transforms::processors::Transformer;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::parse_exprs;
+use databend_common_sql::parse_sequence_default;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::operations::UnMatchedExprs;
 
@@ -67,6 +68,14 @@ pub fn build_expression_transform(
     for f in output_schema.fields().iter() {
         let expr = if !input_schema.has_field(f.name()) {
             if let Some(default_expr) = f.default_expr() {
+                // The sequence columns are filled by `TransformFillSequenceColumns` before.
+                if parse_sequence_default(default_expr).is_some() {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "can't fill the AUTOINCREMENT column `{}` of table `{}` in this statement",
+                        f.name(),
+                        table.name()
+                    )));
+                }
                 let expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?.remove(0);
                 check_cast(None, false, expr, f.data_type(), &BUILTIN_FUNCTIONS)?
             } else {
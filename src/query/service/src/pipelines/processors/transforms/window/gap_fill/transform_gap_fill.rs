@@ -0,0 +1,326 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::F32;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::utils::date_helper::date_to_timestamp;
+use databend_common_expression::utils::date_helper::TimeBucket;
+use databend_common_expression::utils::date_helper::TzLUT;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_sql::executor::physical_plans::GapFill;
+use databend_common_sql::plans::GapFillKind;
+
+use crate::pipelines::processors::transforms::window::SortedPartitions;
+
+/// The maximum number of buckets generated for one partition, which protects
+/// against a tiny bucket width over a long time range.
+const MAX_GAP_FILL_BUCKETS: i64 = 10_000_000;
+
+struct FillColumn {
+    offset: usize,
+    kind: GapFillKind,
+    return_type: DataType,
+}
+
+/// A row of the output, `row` is the input row, or `None` for a missing bucket.
+struct FillRow {
+    row: Option<usize>,
+    time: Option<i64>,
+}
+
+/// `TransformGapFill` runs `GAP_FILL`. Like `TransformMatchRecognize`, the input is
+/// grouped by the partition keys and sorted by the time column.
+pub struct TransformGapFill {
+    input_schema: DataSchemaRef,
+    partitions: SortedPartitions,
+    time_offset: usize,
+    bucket: TimeBucket,
+    /// The first bucket index to fill, if the start is given.
+    start: Option<i64>,
+    /// The last bucket index to fill, if the end is given.
+    end: Option<i64>,
+    columns: Vec<FillColumn>,
+    tz: TzLUT,
+
+    /// Whether any partition has been processed.
+    processed: bool,
+}
+
+impl TransformGapFill {
+    pub fn try_create(plan: &GapFill, input_schema: DataSchemaRef, tz: TzLUT) -> Result<Self> {
+        let offset_of = |index: usize| input_schema.index_of(&index.to_string());
+        let partition_by = plan
+            .partition_by
+            .iter()
+            .map(|index| offset_of(*index))
+            .collect::<Result<Vec<_>>>()?;
+        let time_offset = offset_of(plan.time_column)?;
+        let columns = plan
+            .columns
+            .iter()
+            .map(|column| {
+                Ok(FillColumn {
+                    offset: offset_of(column.arg)?,
+                    kind: column.kind,
+                    return_type: column.return_type.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let bucket =
+            TimeBucket::try_create(plan.width, None, tz).map_err(ErrorCode::BadArguments)?;
+        let start = plan.start.map(|start| bucket.bucket_index(start));
+        let end = plan
+            .end
+            .map(|end| bucket.bucket_index(end.saturating_sub(1)));
+
+        Ok(Self {
+            input_schema,
+            partitions: SortedPartitions::create(partition_by),
+            time_offset,
+            bucket,
+            start,
+            end,
+            columns,
+            tz,
+            processed: false,
+        })
+    }
+
+    fn time_of(&self, value: ScalarRef) -> Option<i64> {
+        match value {
+            ScalarRef::Timestamp(ts) => Some(ts),
+            ScalarRef::Date(date) => Some(date_to_timestamp(date, self.tz.tz)),
+            _ => None,
+        }
+    }
+
+    fn process_partition(&mut self, blocks: Vec<DataBlock>) -> Result<Vec<DataBlock>> {
+        self.processed = true;
+        let block = if blocks.is_empty() {
+            DataBlock::empty_with_schema(self.input_schema.clone())
+        } else {
+            DataBlock::concat(&blocks)?
+        };
+
+        // The rows with a time are put in their buckets, the rows without a time
+        // are returned as they are.
+        let time = &block.get_by_offset(self.time_offset).value;
+        let mut rows = vec![];
+        let mut null_rows = vec![];
+        for row in 0..block.num_rows() {
+            match self.time_of(time.index(row).unwrap()) {
+                Some(ts) => rows.push((row, self.bucket.bucket_index(ts))),
+                None => null_rows.push(row),
+            }
+        }
+        rows.sort_by_key(|(_, index)| *index);
+
+        let first = self.start.or_else(|| rows.first().map(|(_, index)| *index));
+        let last = self.end.or_else(|| rows.last().map(|(_, index)| *index));
+        let mut output = vec![];
+        if let (Some(first), Some(last)) = (first, last) {
+            if last.saturating_sub(first) >= MAX_GAP_FILL_BUCKETS {
+                return Err(ErrorCode::BadArguments(format!(
+                    "GAP_FILL can not generate more than {MAX_GAP_FILL_BUCKETS} buckets in a partition"
+                )));
+            }
+            // The rows out of the range of `start` and `end` are dropped.
+            let mut rows = rows.iter().peekable();
+            for index in first..=last {
+                let time = self
+                    .bucket
+                    .bucket_start(index)
+                    .map_err(ErrorCode::BadArguments)?;
+                let mut found = false;
+                while let Some((row, row_index)) = rows.peek() {
+                    if *row_index > index {
+                        break;
+                    }
+                    if *row_index == index {
+                        output.push(FillRow {
+                            row: Some(*row),
+                            time: Some(time),
+                        });
+                        found = true;
+                    }
+                    rows.next();
+                }
+                if !found {
+                    output.push(FillRow {
+                        row: None,
+                        time: Some(time),
+                    });
+                }
+            }
+        }
+        output.extend(null_rows.into_iter().map(|row| FillRow {
+            row: Some(row),
+            time: None,
+        }));
+        if output.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let entries = self
+            .columns
+            .iter()
+            .map(|column| self.fill_column(&block, column, &output))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(vec![DataBlock::new(entries, output.len())])
+    }
+
+    fn fill_column(
+        &self,
+        block: &DataBlock,
+        column: &FillColumn,
+        output: &[FillRow],
+    ) -> Result<BlockEntry> {
+        let value = &block.get_by_offset(column.offset).value;
+        let mut builder = ColumnBuilder::with_capacity(&column.return_type, output.len());
+        match column.kind {
+            GapFillKind::Time => {
+                for fill_row in output {
+                    match fill_row.time {
+                        Some(time) => builder.push(ScalarRef::Timestamp(time)),
+                        None => builder.push(ScalarRef::Null),
+                    }
+                }
+            }
+            GapFillKind::Partition => {
+                // All the rows of a partition have the same partition keys.
+                for fill_row in output {
+                    builder.push(value.index(fill_row.row.unwrap_or(0)).unwrap());
+                }
+            }
+            GapFillKind::Null => {
+                for fill_row in output {
+                    match fill_row.row {
+                        Some(row) => builder.push(value.index(row).unwrap()),
+                        None => builder.push(ScalarRef::Null),
+                    }
+                }
+            }
+            GapFillKind::Locf => {
+                let mut last: Option<Scalar> = None;
+                for fill_row in output {
+                    match fill_row.row {
+                        Some(row) => {
+                            let scalar = value.index(row).unwrap();
+                            if scalar != ScalarRef::Null {
+                                last = Some(scalar.to_owned());
+                            }
+                            builder.push(scalar);
+                        }
+                        None => match &last {
+                            Some(last) => builder.push(last.as_ref()),
+                            None => builder.push(ScalarRef::Null),
+                        },
+                    }
+                }
+            }
+            GapFillKind::Interpolate => {
+                let DataType::Number(number_type) = column.return_type.remove_nullable() else {
+                    unreachable!("GAP_FILL only interpolates numbers")
+                };
+                // The known points of the line, which are the rows with a time and a value.
+                let points = output
+                    .iter()
+                    .map(|fill_row| match (fill_row.row, fill_row.time) {
+                        (Some(row), Some(time)) => match value.index(row).unwrap() {
+                            ScalarRef::Number(number) => Some((time, number.to_f64().into_inner())),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let mut next = vec![None; output.len()];
+                for i in (0..output.len().saturating_sub(1)).rev() {
+                    next[i] = points[i + 1].or(next[i + 1]);
+                }
+                let mut prev = None;
+                for (i, fill_row) in output.iter().enumerate() {
+                    match fill_row.row {
+                        Some(row) => builder.push(value.index(row).unwrap()),
+                        None => {
+                            let time = fill_row.time.unwrap();
+                            match (prev, next[i]) {
+                                (Some((t0, v0)), Some((t1, v1))) => {
+                                    let ratio = (time - t0) as f64 / (t1 - t0) as f64;
+                                    let number = f64_to_number(v0 + (v1 - v0) * ratio, number_type);
+                                    builder.push(ScalarRef::Number(number));
+                                }
+                                _ => builder.push(ScalarRef::Null),
+                            }
+                        }
+                    }
+                    if points[i].is_some() {
+                        prev = points[i];
+                    }
+                }
+            }
+        }
+        Ok(BlockEntry::new(
+            column.return_type.clone(),
+            Value::Column(builder.build()),
+        ))
+    }
+}
+
+/// The interpolation lies between two values of the column, so it never overflows.
+fn f64_to_number(value: f64, number_type: NumberDataType) -> NumberScalar {
+    with_integer_mapped_type!(|NUM_TYPE| match number_type {
+        NumberDataType::NUM_TYPE => NumberScalar::from(value.round() as NUM_TYPE),
+        NumberDataType::Float32 => NumberScalar::Float32(F32::from(value as f32)),
+        NumberDataType::Float64 => NumberScalar::Float64(F64::from(value)),
+    })
+}
+
+impl AccumulatingTransform for TransformGapFill {
+    const NAME: &'static str = "TransformGapFill";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let mut output = vec![];
+        for blocks in self.partitions.push(data) {
+            output.extend(self.process_partition(blocks)?);
+        }
+        Ok(output)
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+        // Without partition keys, the buckets between `start` and `end` are
+        // filled even if the input is empty.
+        if self.partitions.is_empty() && (self.processed || self.partitions.has_partition_keys()) {
+            return Ok(vec![]);
+        }
+        let blocks = self.partitions.take();
+        self.process_partition(blocks)
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::RowPattern;
+
+/// Quantifiers are expanded when the pattern is compiled, so the number of
+/// instructions is limited to avoid huge programs like `A{1000000}`.
+const MAX_PATTERN_INSTRUCTIONS: usize = 100_000;
+
+#[derive(Clone, Copy, Debug)]
+enum Inst {
+    /// Consume one row mapped to the pattern variable.
+    Symbol(usize),
+    /// Try the first branch, and backtrack to the second one if it fails.
+    Split(usize, usize),
+    Jmp(usize),
+    Match,
+}
+
+/// `RowPatternMatcher` finds the preferred match of a row pattern by backtracking,
+/// which follows the SQL:2016 semantics: alternatives are tried from left to right,
+/// greedy quantifiers prefer more rows and reluctant quantifiers prefer fewer rows.
+#[derive(Clone, Debug)]
+pub struct RowPatternMatcher {
+    insts: Vec<Inst>,
+}
+
+impl RowPatternMatcher {
+    pub fn try_create(pattern: &RowPattern) -> Result<Self> {
+        let mut insts = vec![];
+        compile(pattern, &mut insts)?;
+        insts.push(Inst::Match);
+        Ok(Self { insts })
+    }
+
+    /// Find the preferred match starting at row `start`, returns the pattern
+    /// variable of each matched row. `is_symbol(symbol, row)` tells whether the
+    /// row satisfies the `DEFINE` condition of the pattern variable.
+    pub fn find(
+        &self,
+        start: usize,
+        num_rows: usize,
+        is_symbol: impl Fn(usize, usize) -> bool,
+    ) -> Option<Vec<usize>> {
+        let mut path = Vec::new();
+        // Backtracking points: (pc, row, length of path).
+        let mut stack = vec![(0, start, 0)];
+        // A state that has been visited can not lead to a match anymore,
+        // this also prevents infinite loops on empty iterations like `(A*)*`.
+        let mut visited = HashSet::new();
+
+        while let Some((mut pc, mut row, path_len)) = stack.pop() {
+            path.truncate(path_len);
+            loop {
+                if !visited.insert((pc, row)) {
+                    break;
+                }
+                match self.insts[pc] {
+                    Inst::Symbol(symbol) => {
+                        if row >= num_rows || !is_symbol(symbol, row) {
+                            break;
+                        }
+                        path.push(symbol);
+                        row += 1;
+                        pc += 1;
+                    }
+                    Inst::Split(first, second) => {
+                        stack.push((second, row, path.len()));
+                        pc = first;
+                    }
+                    Inst::Jmp(target) => pc = target,
+                    Inst::Match => return Some(path),
+                }
+            }
+        }
+        None
+    }
+}
+
+fn compile(pattern: &RowPattern, insts: &mut Vec<Inst>) -> Result<()> {
+    if insts.len() > MAX_PATTERN_INSTRUCTIONS {
+        return Err(ErrorCode::SemanticError(
+            "Row pattern of MATCH_RECOGNIZE is too large",
+        ));
+    }
+    match pattern {
+        RowPattern::Symbol(symbol) => insts.push(Inst::Symbol(*symbol)),
+        RowPattern::Concat(patterns) => {
+            for pattern in patterns {
+                compile(pattern, insts)?;
+            }
+        }
+        RowPattern::Alternation(patterns) => {
+            let mut jumps = Vec::with_capacity(patterns.len());
+            for (i, pattern) in patterns.iter().enumerate() {
+                if i + 1 == patterns.len() {
+                    compile(pattern, insts)?;
+                    break;
+                }
+                let split = insts.len();
+                insts.push(Inst::Split(split + 1, 0));
+                compile(pattern, insts)?;
+                jumps.push(insts.len());
+                insts.push(Inst::Jmp(0));
+                insts[split] = Inst::Split(split + 1, insts.len());
+            }
+            let end = insts.len();
+            for jump in jumps {
+                insts[jump] = Inst::Jmp(end);
+            }
+        }
+        RowPattern::Quantified {
+            pattern,
+            min,
+            max,
+            reluctant,
+        } => {
+            for _ in 0..*min {
+                compile(pattern, insts)?;
+            }
+            match max {
+                None => {
+                    let split = insts.len();
+                    insts.push(Inst::Jmp(0));
+                    compile(pattern, insts)?;
+                    insts.push(Inst::Jmp(split));
+                    insts[split] = split_inst(split + 1, insts.len(), *reluctant);
+                }
+                Some(max) => {
+                    let mut splits = vec![];
+                    for _ in *min..*max {
+                        splits.push(insts.len());
+                        insts.push(Inst::Jmp(0));
+                        compile(pattern, insts)?;
+                    }
+                    let end = insts.len();
+                    for split in splits {
+                        insts[split] = split_inst(split + 1, end, *reluctant);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn split_inst(body: usize, skip: usize, reluctant: bool) -> Inst {
+    if reluctant {
+        Inst::Split(skip, body)
+    } else {
+        Inst::Split(body, skip)
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::runtime::drop_guard;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::InputColumns;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_functions::aggregates::get_layout_offsets;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::aggregates::AggregateFunctionRef;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_sql::executor::physical_plans::MatchRecognize;
+use databend_common_sql::plans::MatchMeasureFunc;
+
+use super::RowPatternMatcher;
+use crate::pipelines::processors::transforms::group_by::Area;
+use crate::pipelines::processors::transforms::window::SortedPartitions;
+
+struct MatchAggregate {
+    func: AggregateFunctionRef,
+    place: StateAddr,
+    args: Vec<usize>,
+    initialized: bool,
+}
+
+impl MatchAggregate {
+    fn reset(&mut self) {
+        if self.initialized && self.func.need_manual_drop_state() {
+            unsafe {
+                self.func.drop_state(self.place);
+            }
+        }
+        self.func.init_state(self.place);
+        self.initialized = true;
+    }
+}
+
+enum MeasureFunc {
+    First(Option<usize>),
+    Last(Option<usize>),
+    Aggregate(MatchAggregate),
+    MatchNumber,
+    Classifier,
+}
+
+struct Measure {
+    func: MeasureFunc,
+    symbol: Option<usize>,
+    arg: Option<usize>,
+    return_type: DataType,
+}
+
+/// `TransformMatchRecognize` runs the row pattern matching of `MATCH_RECOGNIZE`.
+/// The input is grouped by the partition keys and sorted by the order keys, which
+/// is done by the window partition transforms.
+pub struct TransformMatchRecognize {
+    // Need to hold arena until `drop`.
+    _arena: Area,
+    partitions: SortedPartitions,
+    /// The offset of the `DEFINE` condition of each pattern variable.
+    defines: Vec<Option<usize>>,
+    symbol_names: Vec<String>,
+    matcher: RowPatternMatcher,
+    measures: Vec<Measure>,
+    all_rows: bool,
+    skip_to_next_row: bool,
+}
+
+impl TransformMatchRecognize {
+    pub fn try_create(plan: &MatchRecognize, input_schema: &DataSchema) -> Result<Self> {
+        let offset_of = |index: usize| input_schema.index_of(&index.to_string());
+        let partition_by = plan
+            .partition_by
+            .iter()
+            .map(|index| offset_of(*index))
+            .collect::<Result<Vec<_>>>()?;
+        let defines = plan
+            .symbols
+            .iter()
+            .map(|symbol| symbol.define.map(offset_of).transpose())
+            .collect::<Result<Vec<_>>>()?;
+        let symbol_names = plan.symbols.iter().map(|s| s.name.clone()).collect();
+        let matcher = RowPatternMatcher::try_create(&plan.pattern)?;
+
+        let mut arena = Area::create();
+        let mut measures = Vec::with_capacity(plan.measures.len());
+        for measure in plan.measures.iter() {
+            let arg = measure.arg.map(offset_of).transpose()?;
+            let func = match &measure.func {
+                MatchMeasureFunc::First => MeasureFunc::First(None),
+                MatchMeasureFunc::Last => MeasureFunc::Last(None),
+                MatchMeasureFunc::Aggregate(name) => {
+                    let args = arg.into_iter().collect::<Vec<_>>();
+                    let arg_types = args
+                        .iter()
+                        .map(|offset| input_schema.field(*offset).data_type().clone())
+                        .collect();
+                    let func = AggregateFunctionFactory::instance().get(name, vec![], arg_types)?;
+                    let mut state_offset = Vec::with_capacity(1);
+                    let layout = get_layout_offsets(&[func.clone()], &mut state_offset)?;
+                    let place: StateAddr = arena.alloc_layout(layout).into();
+                    MeasureFunc::Aggregate(MatchAggregate {
+                        func,
+                        place: place.next(state_offset[0]),
+                        args,
+                        initialized: false,
+                    })
+                }
+                MatchMeasureFunc::MatchNumber => MeasureFunc::MatchNumber,
+                MatchMeasureFunc::Classifier => MeasureFunc::Classifier,
+            };
+            measures.push(Measure {
+                func,
+                symbol: measure.symbol,
+                arg,
+                return_type: measure.return_type.clone(),
+            });
+        }
+
+        Ok(Self {
+            _arena: arena,
+            partitions: SortedPartitions::create(partition_by),
+            defines,
+            symbol_names,
+            matcher,
+            measures,
+            all_rows: plan.all_rows,
+            skip_to_next_row: plan.skip_to_next_row,
+        })
+    }
+
+    fn process_partition(&mut self, blocks: Vec<DataBlock>) -> Result<Vec<DataBlock>> {
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let block = DataBlock::concat(&blocks)?;
+        let num_rows = block.num_rows();
+
+        // A row satisfies the `DEFINE` condition only if it evaluates to true,
+        // the pattern variable without a condition matches any row.
+        let defines = self
+            .defines
+            .iter()
+            .map(|define| {
+                define.map(|offset| {
+                    let value = &block.get_by_offset(offset).value;
+                    (0..num_rows)
+                        .map(|row| matches!(value.index(row), Some(ScalarRef::Boolean(true))))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let is_symbol = |symbol: usize, row: usize| {
+            defines[symbol]
+                .as_ref()
+                .map_or(true, |satisfied| satisfied[row])
+        };
+
+        let mut rows = vec![];
+        let mut builders = self
+            .measures
+            .iter()
+            .map(|measure| ColumnBuilder::with_capacity(&measure.return_type, 0))
+            .collect::<Vec<_>>();
+        let mut match_number = 0;
+        let mut start = 0;
+        while start < num_rows {
+            match self.matcher.find(start, num_rows, is_symbol) {
+                // Empty matches are not returned.
+                Some(classifiers) if !classifiers.is_empty() => {
+                    match_number += 1;
+                    self.output_match(
+                        &block,
+                        start,
+                        &classifiers,
+                        match_number,
+                        &mut rows,
+                        &mut builders,
+                    )?;
+                    start += if self.skip_to_next_row {
+                        1
+                    } else {
+                        classifiers.len()
+                    };
+                }
+                _ => start += 1,
+            }
+        }
+
+        if rows.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut output = block.take(&rows, &mut None)?;
+        for (builder, measure) in builders.into_iter().zip(self.measures.iter()) {
+            output.add_column(BlockEntry::new(
+                measure.return_type.clone(),
+                Value::Column(builder.build()),
+            ));
+        }
+        Ok(vec![output])
+    }
+
+    fn output_match(
+        &mut self,
+        block: &DataBlock,
+        start: usize,
+        classifiers: &[usize],
+        match_number: u64,
+        rows: &mut Vec<u32>,
+        builders: &mut [ColumnBuilder],
+    ) -> Result<()> {
+        for measure in self.measures.iter_mut() {
+            match &mut measure.func {
+                MeasureFunc::First(row) | MeasureFunc::Last(row) => *row = None,
+                MeasureFunc::Aggregate(agg) => agg.reset(),
+                _ => {}
+            }
+        }
+
+        for (i, symbol) in classifiers.iter().enumerate() {
+            let row = start + i;
+            for measure in self.measures.iter_mut() {
+                if measure.symbol.is_some_and(|s| s != *symbol) {
+                    continue;
+                }
+                match &mut measure.func {
+                    MeasureFunc::First(first) => {
+                        first.get_or_insert(row);
+                    }
+                    MeasureFunc::Last(last) => *last = Some(row),
+                    MeasureFunc::Aggregate(agg) => {
+                        let args = InputColumns::new_block_proxy(&agg.args, block);
+                        agg.func.accumulate_row(agg.place, args, row)?;
+                    }
+                    _ => {}
+                }
+            }
+
+            // `ALL ROWS PER MATCH` returns the running measures of each row.
+            if !self.all_rows && i + 1 < classifiers.len() {
+                continue;
+            }
+            rows.push(row as u32);
+            for (measure, builder) in self.measures.iter().zip(builders.iter_mut()) {
+                match &measure.func {
+                    MeasureFunc::First(Some(row)) | MeasureFunc::Last(Some(row)) => {
+                        let value = &block.get_by_offset(measure.arg.unwrap()).value;
+                        builder.push(value.index(*row).unwrap());
+                    }
+                    MeasureFunc::First(None) | MeasureFunc::Last(None) => builder.push_default(),
+                    MeasureFunc::Aggregate(agg) => agg.func.merge_result(agg.place, builder)?,
+                    MeasureFunc::MatchNumber => {
+                        builder.push(ScalarRef::Number(NumberScalar::UInt64(match_number)))
+                    }
+                    MeasureFunc::Classifier => {
+                        builder.push(ScalarRef::String(&self.symbol_names[*symbol]))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AccumulatingTransform for TransformMatchRecognize {
+    const NAME: &'static str = "TransformMatchRecognize";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let mut output = vec![];
+        for blocks in self.partitions.push(data) {
+            output.extend(self.process_partition(blocks)?);
+        }
+        Ok(output)
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+        let blocks = self.partitions.take();
+        self.process_partition(blocks)
+    }
+}
+
+impl Drop for TransformMatchRecognize {
+    fn drop(&mut self) {
+        drop_guard(move || {
+            for measure in self.measures.iter() {
+                if let MeasureFunc::Aggregate(agg) = &measure.func {
+                    if agg.initialized && agg.func.need_manual_drop_state() {
+                        unsafe {
+                            agg.func.drop_state(agg.place);
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
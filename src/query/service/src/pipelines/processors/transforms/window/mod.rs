@@ -13,11 +13,17 @@
 // limitations under the License.
 
 mod frame_bound;
+mod gap_fill;
+mod match_recognize;
 mod partition;
+mod sorted_partitions;
 mod transform_window;
 mod window_function;
 
 pub use frame_bound::FrameBound;
+pub use gap_fill::*;
+pub use match_recognize::*;
 pub use partition::*;
+pub use sorted_partitions::SortedPartitions;
 pub use transform_window::*;
 pub use window_function::WindowFunctionInfo;
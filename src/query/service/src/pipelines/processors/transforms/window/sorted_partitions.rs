@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::DataBlock;
+
+/// `SortedPartitions` splits an input grouped by the partition keys into partitions,
+/// which is what the window partition transforms produce. A partition is complete
+/// once the partition keys change, the blocks of the current partition are kept
+/// until then.
+pub struct SortedPartitions {
+    partition_by: Vec<usize>,
+    /// The blocks of the current partition.
+    blocks: Vec<DataBlock>,
+}
+
+impl SortedPartitions {
+    pub fn create(partition_by: Vec<usize>) -> Self {
+        Self {
+            partition_by,
+            blocks: vec![],
+        }
+    }
+
+    pub fn has_partition_keys(&self) -> bool {
+        !self.partition_by.is_empty()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Returns the blocks of each partition completed by `data`.
+    pub fn push(&mut self, data: DataBlock) -> Vec<Vec<DataBlock>> {
+        let data = data.consume_convert_to_full();
+        let num_rows = data.num_rows();
+        let mut partitions = vec![];
+        let mut start = 0;
+        for row in 0..num_rows {
+            let is_new_partition = match row {
+                0 => self.blocks.last().is_some_and(|last| {
+                    !self.is_same_partition(last, last.num_rows() - 1, &data, 0)
+                }),
+                _ => !self.is_same_partition(&data, row - 1, &data, row),
+            };
+            if is_new_partition {
+                if row > start {
+                    self.blocks.push(data.slice(start..row));
+                }
+                partitions.push(self.take());
+                start = row;
+            }
+        }
+        if start < num_rows {
+            self.blocks.push(data.slice(start..num_rows));
+        }
+        partitions
+    }
+
+    /// Takes the blocks of the current partition.
+    pub fn take(&mut self) -> Vec<DataBlock> {
+        std::mem::take(&mut self.blocks)
+    }
+
+    fn is_same_partition(
+        &self,
+        lhs: &DataBlock,
+        lhs_row: usize,
+        rhs: &DataBlock,
+        rhs_row: usize,
+    ) -> bool {
+        self.partition_by.iter().all(|offset| {
+            let lhs = lhs.get_by_offset(*offset).value.index(lhs_row);
+            let rhs = rhs.get_by_offset(*offset).value.index(rhs_row);
+            lhs == rhs
+        })
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::LiteralStringOrVariable;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_sql::binder::resolve_stage_location;
+use databend_common_sql::Planner;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFilesInfo;
+use databend_common_storages_system::PipeHistoryLogElement;
+use databend_common_storages_system::PipeHistoryQueue;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+
+/// Loads the files of the stage into the target table of the pipe, by running the
+/// wrapped `COPY INTO` statement, and records the run in `system.pipe_history`.
+///
+/// The files that were already loaded are skipped by COPY, so a pipe can be executed
+/// repeatedly, even by several nodes at the same time.
+#[async_backtrace::framed]
+pub async fn execute_pipe(
+    ctx: Arc<QueryContext>,
+    pipe: &PipeInfo,
+    prefix: Option<&str>,
+    modified_after: Option<&str>,
+) -> Result<()> {
+    let start_time = Utc::now().timestamp_micros();
+    let res = run_copy(ctx.clone(), pipe, prefix, modified_after).await;
+
+    let copy_status = ctx.get_copy_status();
+    let rows_loaded = copy_status
+        .files
+        .iter()
+        .map(|entry| entry.value().num_rows_loaded as u64)
+        .sum();
+    info!(
+        "pipe {} loaded {} files, {} rows",
+        pipe.name,
+        copy_status.files.len(),
+        rows_loaded
+    );
+    PipeHistoryQueue::instance()?.append_data(PipeHistoryLogElement {
+        pipe_name: pipe.name.clone(),
+        query_id: ctx.get_id(),
+        start_time,
+        end_time: Utc::now().timestamp_micros(),
+        files_loaded: copy_status.files.len() as u64,
+        rows_loaded,
+        error: res.as_ref().err().map(|e| e.message()),
+    })?;
+    res
+}
+
+async fn run_copy(
+    ctx: Arc<QueryContext>,
+    pipe: &PipeInfo,
+    prefix: Option<&str>,
+    modified_after: Option<&str>,
+) -> Result<()> {
+    // The statement is stored with double-quoted identifiers.
+    let tokens = tokenize_sql(&pipe.copy_stmt)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    let Statement::CopyIntoTable(mut copy_stmt) = stmt else {
+        return Err(ErrorCode::IllegalPipe(format!(
+            "invalid COPY statement of pipe {}",
+            pipe.name
+        )));
+    };
+
+    if prefix.is_some() || modified_after.is_some() {
+        let CopyIntoTableSource::Location(FileLocation::Stage(location)) = &mut copy_stmt.src
+        else {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} loads files by a query, PREFIX and MODIFIED_AFTER are not supported",
+                pipe.name
+            )));
+        };
+        if let Some(prefix) = prefix {
+            if !location.ends_with('/') {
+                location.push('/');
+            }
+            location.push_str(prefix.trim_start_matches('/'));
+        }
+        if let Some(modified_after) = modified_after {
+            let modified_after = parse_modified_after(modified_after)?;
+            let location = location.clone();
+            let files =
+                list_files_modified_after(&ctx, &copy_stmt, &location, modified_after).await?;
+            if files.is_empty() {
+                info!(
+                    "pipe {} has no files modified after {}",
+                    pipe.name, modified_after
+                );
+                return Ok(());
+            }
+            copy_stmt.files = Some(files);
+        }
+    }
+
+    let mut planner = Planner::new(ctx.clone());
+    let plan = planner
+        .plan_stmt(&Statement::CopyIntoTable(copy_stmt))
+        .await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
+
+fn parse_modified_after(modified_after: &str) -> Result<DateTime<Utc>> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(modified_after) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(modified_after, "%Y-%m-%d %H:%M:%S")
+        .map(|datetime| datetime.and_utc())
+        .map_err(|e| {
+            ErrorCode::BadArguments(format!(
+                "invalid MODIFIED_AFTER '{}': {}",
+                modified_after, e
+            ))
+        })
+}
+
+/// Lists the files under the location which are modified after the given time,
+/// the paths are relative to the location, as the `FILES` option of COPY.
+async fn list_files_modified_after(
+    ctx: &Arc<QueryContext>,
+    copy_stmt: &CopyIntoTableStmt,
+    location: &str,
+    modified_after: DateTime<Utc>,
+) -> Result<Vec<String>> {
+    let pattern = match &copy_stmt.pattern {
+        None => None,
+        Some(LiteralStringOrVariable::Literal(pattern)) => Some(pattern.clone()),
+        Some(LiteralStringOrVariable::Variable(_)) => {
+            return Err(ErrorCode::IllegalPipe(
+                "PATTERN of pipe can not be a variable when MODIFIED_AFTER is specified",
+            ));
+        }
+    };
+    let (stage_info, path) = resolve_stage_location(ctx.as_ref(), location).await?;
+    let operator = init_stage_operator(&stage_info)?;
+    let files_info = StageFilesInfo {
+        path: path.clone(),
+        files: None,
+        pattern,
+    };
+    let thread_num = ctx.get_settings().get_max_threads()? as usize;
+    let prefix = path.trim_start_matches('/');
+    Ok(files_info
+        .list(&operator, thread_num, None)
+        .await?
+        .into_iter()
+        .filter(|file| file.last_modified > modified_after)
+        .map(|file| {
+            file.path
+                .strip_prefix(prefix)
+                .unwrap_or(&file.path)
+                .trim_start_matches('/')
+                .to_string()
+        })
+        .collect())
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_PUBLIC;
+use log::info;
+use log::warn;
+
+use crate::locks::SchedulerLease;
+use crate::locks::PIPE_SCHEDULER_LEASE;
+use crate::pipes::execute_pipe;
+use crate::tasks::create_session;
+
+const PIPE_INGEST_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically executes the pipes which are created with `AUTO_INGEST = TRUE`
+/// and are not paused.
+///
+/// Every node runs a scheduler, but only the one holding the scheduler lease in the
+/// meta service executes the pipes in a round, the others skip it.
+pub struct PipeScheduler;
+
+impl PipeScheduler {
+    pub fn start(conf: &InnerConfig) {
+        let conf = conf.clone();
+        databend_common_base::runtime::spawn(async move {
+            loop {
+                sleep(PIPE_INGEST_INTERVAL).await;
+                if let Err(cause) = Self::ingest(&conf).await {
+                    warn!("pipe scheduler failed to ingest files: {:?}", cause);
+                }
+            }
+        });
+        info!("Pipe scheduler started");
+    }
+
+    /// Runs a round of the scheduler, it is skipped if another node holds the lease.
+    #[async_backtrace::framed]
+    pub async fn ingest(conf: &InnerConfig) -> Result<()> {
+        let pipes = UserApiProvider::instance()
+            .get_pipes(&conf.query.tenant_id)
+            .await?
+            .into_iter()
+            .filter(|pipe| pipe.auto_ingest && !pipe.execution_paused)
+            .collect::<Vec<_>>();
+        if pipes.is_empty() {
+            return Ok(());
+        }
+
+        let Some(_lease) =
+            SchedulerLease::try_acquire(&conf.query.tenant_id, PIPE_SCHEDULER_LEASE).await?
+        else {
+            return Ok(());
+        };
+
+        for pipe in pipes {
+            if let Err(cause) = Self::ingest_pipe(&pipe).await {
+                warn!("pipe {} failed to ingest files: {:?}", pipe.name, cause);
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads the files with the privileges of the pipe owner.
+    async fn ingest_pipe(pipe: &PipeInfo) -> Result<()> {
+        let owner = if pipe.owner.is_empty() {
+            BUILTIN_ROLE_PUBLIC
+        } else {
+            pipe.owner.as_str()
+        };
+        let session = create_session(owner, "pipe", "PipeIngest").await?;
+        let ctx = session.create_query_context().await?;
+        execute_pipe(ctx, pipe, None, None).await
+    }
+}
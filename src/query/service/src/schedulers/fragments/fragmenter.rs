@@ -26,6 +26,7 @@ use databend_common_sql::executor::physical_plans::ExchangeSource;
 use databend_common_sql::executor::physical_plans::FragmentKind;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::MutationSource;
+use databend_common_sql::executor::physical_plans::RangeJoin;
 use databend_common_sql::executor::physical_plans::Recluster;
 use databend_common_sql::executor::physical_plans::ReplaceInto;
 use databend_common_sql::executor::physical_plans::TableScan;
@@ -242,6 +243,24 @@ impl PhysicalPlanReplacer for Fragmenter {
         }))
     }
 
+    fn replace_range_join(&mut self, plan: &RangeJoin) -> Result<PhysicalPlan> {
+        let mut fragments = vec![];
+        // The right side is sunk before the left side is processed, like the build side of hash join.
+        let right_input = self.replace(plan.right.as_ref())?;
+
+        // Consume current fragments to prevent them being consumed by `left_input`.
+        fragments.append(&mut self.fragments);
+        let left_input = self.replace(plan.left.as_ref())?;
+        fragments.append(&mut self.fragments);
+        self.fragments = fragments;
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            left: Box::new(left_input),
+            right: Box::new(right_input),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_union(&mut self, plan: &UnionAll) -> Result<PhysicalPlan> {
         let mut fragments = vec![];
         let left_input = self.replace(plan.left.as_ref())?;
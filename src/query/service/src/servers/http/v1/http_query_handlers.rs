@@ -12,17 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use arrow_ipc::writer::StreamWriter;
 use databend_common_base::base::mask_connection_info;
 use databend_common_base::headers::HEADER_QUERY_ID;
 use databend_common_base::headers::HEADER_QUERY_PAGE_ROWS;
 use databend_common_base::headers::HEADER_QUERY_STATE;
 use databend_common_base::runtime::drop_guard;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::converts::arrow::table_schema_to_arrow_schema;
+use databend_common_expression::infer_table_schema;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
 use fastrace::func_path;
 use fastrace::prelude::*;
 use highway::HighwayHash;
+use http::header;
+use http::HeaderMap;
 use http::StatusCode;
 use log::error;
 use log::info;
@@ -37,6 +46,7 @@ use poem::web::Json;
 use poem::web::Path;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -44,7 +54,9 @@ use serde::Serialize;
 use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
+use super::query::PageData;
 use super::query::RemoveReason;
+use super::query::ResultFormat;
 use crate::servers::http::error::HttpErrorCode;
 use crate::servers::http::error::QueryError;
 use crate::servers::http::middleware::EndpointKind;
@@ -54,7 +66,6 @@ use crate::servers::http::v1::discovery_nodes;
 use crate::servers::http::v1::list_suggestions;
 use crate::servers::http::v1::login_handler;
 use crate::servers::http::v1::logout_handler;
-use crate::servers::http::v1::query::string_block::StringBlock;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::refresh_handler;
 use crate::servers::http::v1::upload_to_stage;
@@ -65,6 +76,11 @@ use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::HttpHandlerKind;
 use crate::sessions::QueryAffect;
 
+/// Media type of the Arrow IPC stream format, clients send it in `Accept` to get typed results.
+pub const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+/// Schema metadata key of the JSON encoded response in the Arrow IPC stream.
+pub const ARROW_RESPONSE_METADATA_KEY: &str = "response";
+
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
 }
@@ -140,14 +156,17 @@ impl QueryResponse {
         id: String,
         r: HttpQueryResponseInternal,
         is_final: bool,
-    ) -> impl IntoResponse {
+    ) -> Response {
         let state = r.state.clone();
+        // the pages without data are still encoded in the format of the query,
+        // e.g. an Arrow stream of no batches, which carries the response in its schema.
+        let empty = PageData::empty(r.result_format);
         let (data, next_uri) = if is_final {
-            (StringBlock::empty(), None)
+            (empty, None)
         } else {
             match state.state {
                 ExecuteStateKind::Running | ExecuteStateKind::Starting => match r.data {
-                    None => (StringBlock::empty(), Some(make_state_uri(&id))),
+                    None => (empty, Some(make_state_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
@@ -156,9 +175,9 @@ impl QueryResponse {
                         (d.page.data, uri)
                     }
                 },
-                ExecuteStateKind::Failed => (StringBlock::empty(), Some(make_final_uri(&id))),
+                ExecuteStateKind::Failed => (empty, Some(make_final_uri(&id))),
                 ExecuteStateKind::Succeeded => match r.data {
-                    None => (StringBlock::empty(), Some(make_final_uri(&id))),
+                    None => (empty, Some(make_final_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
@@ -179,10 +198,10 @@ impl QueryResponse {
             progresses: state.progresses.clone(),
             running_time_ms: state.running_time_ms,
         };
-        let rows = data.data.len();
+        let rows = data.num_rows();
 
-        Json(QueryResponse {
-            data: data.into(),
+        let response = QueryResponse {
+            data: vec![],
             state: state.state,
             schema: state.schema.clone(),
             session_id: Some(session_id),
@@ -198,10 +217,77 @@ impl QueryResponse {
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.map(QueryError::from_error_code),
             has_result_set: r.state.has_result_set,
+        };
+
+        match data {
+            PageData::Strings(data) => Json(QueryResponse {
+                data: data.into(),
+                ..response
+            })
+            .with_header(HEADER_QUERY_ID, id.clone())
+            .with_header(HEADER_QUERY_STATE, state.state.to_string())
+            .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+            .into_response(),
+            PageData::Blocks(blocks) => {
+                match encode_arrow_stream(&response, &state.data_schema, blocks) {
+                    Ok(body) => Response::builder()
+                        .content_type(ARROW_STREAM_CONTENT_TYPE)
+                        .header(HEADER_QUERY_ID, id.clone())
+                        .header(HEADER_QUERY_STATE, state.state.to_string())
+                        .header(HEADER_QUERY_PAGE_ROWS, rows)
+                        .body(body),
+                    Err(err) => {
+                        error!("{}: fail to encode arrow stream: {:?}", id, err);
+                        PoemError::from(HttpErrorCode::server_error(err)).into_response()
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Encode a result page as an Arrow IPC stream.
+///
+/// The other fields of the response (state, stats, next_uri ...) are carried
+/// in the schema metadata under the key `response`, serialized as JSON.
+fn encode_arrow_stream(
+    response: &QueryResponse,
+    data_schema: &DataSchema,
+    blocks: Vec<DataBlock>,
+) -> databend_common_exception::Result<Vec<u8>> {
+    let table_schema = infer_table_schema(data_schema)?;
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        ARROW_RESPONSE_METADATA_KEY.to_string(),
+        serde_json::to_string(response)?,
+    );
+    let arrow_schema = table_schema_to_arrow_schema(&table_schema).with_metadata(metadata);
+
+    let mut writer = StreamWriter::try_new(Vec::new(), &arrow_schema)?;
+    for block in blocks {
+        let batch = block.to_record_batch(&table_schema)?;
+        writer.write(&batch)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner()?)
+}
+
+/// Returns the result format requested by the `Accept` header.
+fn result_format_of(headers: &HeaderMap) -> ResultFormat {
+    let accept_arrow = headers.get_all(header::ACCEPT).iter().any(|value| {
+        value.to_str().is_ok_and(|value| {
+            value.split(',').any(|media_type| {
+                media_type
+                    .split(';')
+                    .next()
+                    .is_some_and(|t| t.trim().eq_ignore_ascii_case(ARROW_STREAM_CONTENT_TYPE))
+            })
         })
-        .with_header(HEADER_QUERY_ID, id.clone())
-        .with_header(HEADER_QUERY_STATE, state.state.to_string())
-        .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+    });
+    if accept_arrow {
+        ResultFormat::ArrowStream
+    } else {
+        ResultFormat::Json
     }
 }
 
@@ -323,6 +409,7 @@ async fn query_state_handler(
 async fn query_page_handler(
     ctx: &HttpQueryContext,
     Path((query_id, page_no)): Path<(String, usize)>,
+    headers: &HeaderMap,
 ) -> PoemResult<impl IntoResponse> {
     ctx.check_node_id(&query_id)?;
     let root = get_http_tracing_span(func_path!(), ctx, &query_id);
@@ -342,6 +429,18 @@ async fn query_page_handler(
                     ));
                 }
                 query.check_client_session_id(&ctx.client_session_id)?;
+                // the format of pages is decided when the query is started
+                if result_format_of(headers) == ResultFormat::ArrowStream
+                    && query.result_format != ResultFormat::ArrowStream
+                {
+                    return Err(poem::error::Error::from_string(
+                        format!(
+                            "query {} was not started with Accept: {}",
+                            query_id, ARROW_STREAM_CONTENT_TYPE
+                        ),
+                        StatusCode::NOT_ACCEPTABLE,
+                    ));
+                }
                 if let Some(reason) = query.check_removed() {
                     Err(query_id_removed(&query_id, reason))
                 } else {
@@ -364,6 +463,7 @@ async fn query_page_handler(
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
+    headers: &HeaderMap,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<impl IntoResponse> {
     let root = get_http_tracing_span(func_path!(), ctx, &ctx.query_id);
@@ -377,7 +477,7 @@ pub(crate) async fn query_handler(
         let sql = req.sql.clone();
 
         let query = http_query_manager
-            .try_create_query(ctx, req.clone())
+            .try_create_query(ctx, req.clone(), result_format_of(headers))
             .await
             .map_err(|err| err.display_with_sql(&sql));
         match query {
@@ -400,7 +500,7 @@ pub(crate) async fn query_handler(
                         &query.id, &resp.state, rows, next_page, mask_connection_info(&sql)
                     );
                 query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
+                Ok(QueryResponse::from_internal(query.id.to_string(), resp, false))
             }
             Err(e) => {
                 error!("http query fail to start sql, error: {:?}", e);
@@ -27,6 +27,8 @@ pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
+pub use http_query_handlers::ARROW_RESPONSE_METADATA_KEY;
+pub use http_query_handlers::ARROW_STREAM_CONTENT_TYPE;
 pub use query::string_block::StringBlock;
 pub use query::ExecuteStateKind;
 pub use query::ExpiringMap;
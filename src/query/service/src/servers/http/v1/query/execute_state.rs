@@ -23,6 +23,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
@@ -120,6 +121,7 @@ pub struct ExecuteRunning {
     // mainly used to get progress for now
     ctx: Arc<QueryContext>,
     schema: Vec<QueryResponseField>,
+    data_schema: DataSchemaRef,
     has_result_set: bool,
     #[allow(dead_code)]
     queue_guard: AcquireQueueGuard,
@@ -127,6 +129,7 @@ pub struct ExecuteRunning {
 
 pub struct ExecuteStopped {
     pub schema: Vec<QueryResponseField>,
+    pub data_schema: DataSchemaRef,
     pub has_result_set: Option<bool>,
     pub stats: Progresses,
     pub affect: Option<QueryAffect>,
@@ -180,6 +183,7 @@ impl Executor {
             warnings: self.get_warnings(),
             affect: self.get_affect(),
             schema: self.get_schema(),
+            data_schema: self.get_data_schema(),
             has_result_set: self.has_result_set(),
         }
     }
@@ -191,6 +195,14 @@ impl Executor {
         }
     }
 
+    pub fn get_data_schema(&self) -> DataSchemaRef {
+        match &self.state {
+            Starting(_) => Arc::new(DataSchema::empty()),
+            Running(r) => r.data_schema.clone(),
+            Stopped(f) => f.data_schema.clone(),
+        }
+    }
+
     pub fn has_result_set(&self) -> Option<bool> {
         match &self.state {
             Starting(_) => None,
@@ -283,6 +295,7 @@ impl Executor {
                 ExecuteStopped {
                     stats: Default::default(),
                     schema: vec![],
+                    data_schema: Arc::new(DataSchema::empty()),
                     has_result_set: None,
                     reason: reason.clone(),
                     session_state: ExecutorSessionState::new(s.ctx.get_current_session()),
@@ -305,6 +318,7 @@ impl Executor {
                 ExecuteStopped {
                     stats: Progresses::from_context(&r.ctx),
                     schema: r.schema.clone(),
+                    data_schema: r.data_schema.clone(),
                     has_result_set: Some(r.has_result_set),
                     reason: reason.clone(),
                     session_state: ExecutorSessionState::new(r.ctx.get_current_session()),
@@ -358,17 +372,18 @@ impl ExecuteState {
             .await
             .with_context(make_error)?;
         let has_result_set = plan.has_result_set();
-        let schema = if has_result_set {
+        let data_schema = if has_result_set {
             // check has_result_set first for safety
-            QueryResponseField::from_schema(plan.schema())
+            plan.schema()
         } else {
-            vec![]
+            Arc::new(DataSchema::empty())
         };
         let running_state = ExecuteRunning {
             session,
             ctx: ctx.clone(),
             queue_guard,
-            schema,
+            schema: QueryResponseField::from_schema(data_schema.clone()),
+            data_schema,
             has_result_set,
         };
         info!("http query change state to Running");
@@ -31,6 +31,8 @@ use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::tenant::Tenant;
@@ -64,6 +66,7 @@ use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResultFormat;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
@@ -312,6 +315,7 @@ pub struct StageAttachmentConf {
 pub struct ResponseState {
     pub has_result_set: Option<bool>,
     pub schema: Vec<QueryResponseField>,
+    pub data_schema: DataSchemaRef,
     pub running_time_ms: i64,
     pub progresses: Progresses,
     pub state: ExecuteStateKind,
@@ -326,6 +330,7 @@ pub struct HttpQueryResponseInternal {
     pub session: Option<HttpSessionConf>,
     pub state: ResponseState,
     pub node_id: String,
+    pub result_format: ResultFormat,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -348,6 +353,7 @@ pub struct HttpQuery {
     pub(crate) client_session_id: Option<String>,
     pub(crate) session_id: String,
     pub(crate) node_id: String,
+    pub(crate) result_format: ResultFormat,
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
     page_manager: Arc<TokioMutex<PageManager>>,
@@ -404,6 +410,7 @@ impl HttpQuery {
     pub(crate) async fn try_create(
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
+        result_format: ResultFormat,
     ) -> Result<Arc<HttpQuery>> {
         let http_query_manager = HttpQueryManager::instance();
         let session = ctx
@@ -550,6 +557,7 @@ impl HttpQuery {
                     let state = ExecuteStopped {
                         stats: Progresses::default(),
                         schema: vec![],
+                        data_schema: Arc::new(DataSchema::empty()),
                         has_result_set: None,
                         reason: Err(e.clone()),
                         session_state: ExecutorSessionState::new(ctx_clone.get_current_session()),
@@ -571,6 +579,7 @@ impl HttpQuery {
             request.pagination.max_rows_per_page,
             block_receiver,
             format_settings,
+            result_format,
         )));
 
         let query = HttpQuery {
@@ -580,6 +589,7 @@ impl HttpQuery {
             client_session_id: http_ctx.client_session_id.clone(),
             session_id,
             node_id,
+            result_format,
             request,
             state,
             page_manager: data,
@@ -610,6 +620,7 @@ impl HttpQuery {
             session: Some(session),
             node_id: self.node_id.clone(),
             session_id: self.session_id.clone(),
+            result_format: self.result_format,
         })
     }
 
@@ -623,6 +634,7 @@ impl HttpQuery {
             node_id: self.node_id.clone(),
             state,
             session: None,
+            result_format: self.result_format,
         })
     }
 
@@ -38,6 +38,7 @@ use crate::servers::http::v1::query::http_query::ExpireResult;
 use crate::servers::http::v1::query::http_query::HttpQuery;
 use crate::servers::http::v1::query::http_query::ServerInfo;
 use crate::servers::http::v1::query::HttpQueryRequest;
+use crate::servers::http::v1::query::ResultFormat;
 
 #[derive(Clone, Debug, Copy, Eq, PartialEq)]
 pub(crate) enum RemoveReason {
@@ -111,8 +112,9 @@ impl HttpQueryManager {
         self: &Arc<Self>,
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
+        result_format: ResultFormat,
     ) -> Result<Arc<HttpQuery>> {
-        let query = HttpQuery::try_create(ctx, request).await?;
+        let query = HttpQuery::try_create(ctx, request, result_format).await?;
         self.add_query(&query.id, query.clone()).await;
         Ok(query)
     }
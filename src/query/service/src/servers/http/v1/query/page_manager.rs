@@ -29,15 +29,49 @@ use super::string_block::block_to_strings;
 use super::string_block::StringBlock;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
 
+const MAX_PAGE_BYTES: usize = 10 * 1024 * 1024;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum Wait {
     Async,
     Deadline(Instant),
 }
 
+/// The format of the result pages, chosen by the `Accept` header of the request
+/// which starts the query.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResultFormat {
+    #[default]
+    Json,
+    ArrowStream,
+}
+
+#[derive(Clone)]
+pub enum PageData {
+    Strings(StringBlock),
+    /// The blocks are kept as they are, and encoded as Arrow record batches.
+    Blocks(Vec<DataBlock>),
+}
+
+impl PageData {
+    pub fn empty(format: ResultFormat) -> Self {
+        match format {
+            ResultFormat::Json => PageData::Strings(StringBlock::empty()),
+            ResultFormat::ArrowStream => PageData::Blocks(vec![]),
+        }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        match self {
+            PageData::Strings(block) => block.num_rows(),
+            PageData::Blocks(blocks) => blocks.iter().map(|b| b.num_rows()).sum(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Page {
-    pub data: StringBlock,
+    pub data: PageData,
 }
 
 pub struct ResponseData {
@@ -53,8 +87,10 @@ pub struct PageManager {
     block_end: bool,
     last_page: Option<Page>,
     row_buffer: VecDeque<Vec<Option<String>>>,
+    block_buffer: VecDeque<DataBlock>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: Arc<RwLock<Option<FormatSettings>>>,
+    result_format: ResultFormat,
 }
 
 impl PageManager {
@@ -62,6 +98,7 @@ impl PageManager {
         max_rows_per_page: usize,
         block_receiver: SizedChannelReceiver<DataBlock>,
         format_settings: Arc<RwLock<Option<FormatSettings>>>,
+        result_format: ResultFormat,
     ) -> PageManager {
         PageManager {
             total_rows: 0,
@@ -70,9 +107,11 @@ impl PageManager {
             end: false,
             block_end: false,
             row_buffer: Default::default(),
+            block_buffer: Default::default(),
             block_receiver,
             max_rows_per_page,
             format_settings,
+            result_format,
         }
     }
 
@@ -104,7 +143,7 @@ impl PageManager {
                 // but the response may be lost and client will retry,
                 // we simply return an empty page.
                 let page = Page {
-                    data: StringBlock::default(),
+                    data: PageData::empty(self.result_format),
                 };
                 Ok(page)
             }
@@ -151,9 +190,24 @@ impl PageManager {
     }
 
     #[async_backtrace::framed]
-    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(StringBlock, bool)> {
+    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(PageData, bool)> {
+        let data = match self.result_format {
+            ResultFormat::Json => PageData::Strings(self.collect_string_page(tp).await?),
+            ResultFormat::ArrowStream => PageData::Blocks(self.collect_block_page(tp).await),
+        };
+
+        // try to report 'no more data' earlier to client to avoid unnecessary http call
+        if !self.block_end {
+            self.block_end = self.block_receiver.is_empty();
+        }
+        let end = self.block_end && self.row_buffer.is_empty() && self.block_buffer.is_empty();
+        Ok((data, end))
+    }
+
+    #[async_backtrace::framed]
+    async fn collect_string_page(&mut self, tp: &Wait) -> Result<StringBlock> {
         let mut res: Vec<Vec<Option<String>>> = Vec::with_capacity(self.max_rows_per_page);
-        let mut remain_size = 10 * 1024 * 1024;
+        let mut remain_size = MAX_PAGE_BYTES;
         let mut remain_rows = self.max_rows_per_page;
         while remain_rows > 0 && remain_size > 0 {
             if let Some(row) = self.row_buffer.pop_front() {
@@ -172,53 +226,89 @@ impl PageManager {
         }
 
         while remain_rows > 0 && remain_size > 0 {
-            match tp {
-                Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => {
-                        self.append_block(&mut res, block, &mut remain_rows, &mut remain_size)?
-                    }
+            match self.recv_block(tp).await {
+                Some(block) => {
+                    self.append_block(&mut res, block, &mut remain_rows, &mut remain_size)?
+                }
+                None => break,
+            }
+        }
+
+        Ok(StringBlock { data: res })
+    }
+
+    #[async_backtrace::framed]
+    async fn collect_block_page(&mut self, tp: &Wait) -> Vec<DataBlock> {
+        let mut res = vec![];
+        let mut remain_size = MAX_PAGE_BYTES;
+        let mut remain_rows = self.max_rows_per_page;
+        while remain_rows > 0 && remain_size > 0 {
+            let block = match self.block_buffer.pop_front() {
+                Some(block) => block,
+                None => match self.recv_block(tp).await {
+                    Some(block) => block,
                     None => break,
                 },
-                Wait::Deadline(t) => {
-                    let now = Instant::now();
-                    let d = *t - now;
-                    if d.is_zero() {
-                        // timeout() will return Ok if the future completes immediately
-                        break;
+            };
+            let num_rows = block.num_rows();
+            if num_rows == 0 {
+                continue;
+            }
+
+            // Split the block if it exceeds the limits, at least one row is taken.
+            let size = block.memory_size();
+            let mut rows = num_rows.min(remain_rows);
+            if size > remain_size {
+                rows = rows.min((num_rows * remain_size / size).max(1));
+            }
+            let block = if rows < num_rows {
+                self.block_buffer.push_front(block.slice(rows..num_rows));
+                block.slice(0..rows)
+            } else {
+                block
+            };
+            remain_rows -= rows;
+            remain_size = remain_size.saturating_sub(block.memory_size());
+            res.push(block);
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn recv_block(&mut self, tp: &Wait) -> Option<DataBlock> {
+        match tp {
+            Wait::Async => self.block_receiver.try_recv(),
+            Wait::Deadline(t) => {
+                let now = Instant::now();
+                let d = *t - now;
+                if d.is_zero() {
+                    // timeout() will return Ok if the future completes immediately
+                    return None;
+                }
+                match tokio::time::timeout(d, self.block_receiver.recv()).await {
+                    Ok(Some(block)) => {
+                        debug!("http query got new block with {} rows", block.num_rows());
+                        Some(block)
+                    }
+                    Ok(None) => {
+                        info!("http query reach end of blocks");
+                        None
                     }
-                    match tokio::time::timeout(d, self.block_receiver.recv()).await {
-                        Ok(Some(block)) => {
-                            debug!("http query got new block with {} rows", block.num_rows());
-                            self.append_block(&mut res, block, &mut remain_rows, &mut remain_size)?;
-                        }
-                        Ok(None) => {
-                            info!("http query reach end of blocks");
-                            break;
-                        }
-                        Err(_) => {
-                            debug!("http query long pulling timeout");
-                            break;
-                        }
+                    Err(_) => {
+                        debug!("http query long pulling timeout");
+                        None
                     }
                 }
             }
         }
-
-        let block = StringBlock { data: res };
-
-        // try to report 'no more data' earlier to client to avoid unnecessary http call
-        if !self.block_end {
-            self.block_end = self.block_receiver.is_empty();
-        }
-        let end = self.block_end && self.row_buffer.is_empty();
-        Ok((block, end))
     }
 
     #[async_backtrace::framed]
     pub async fn detach(&mut self) {
         self.block_receiver.close();
         self.last_page = None;
-        self.row_buffer.clear()
+        self.row_buffer.clear();
+        self.block_buffer.clear()
     }
 }
 
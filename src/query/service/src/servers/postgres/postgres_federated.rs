@@ -0,0 +1,396 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use regex::Regex;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::postgres::types::oid;
+use crate::servers::postgres::types::PG_TYPES;
+use crate::servers::postgres::POSTGRES_VERSION;
+
+// The oid of the `pg_catalog` namespace.
+const PG_CATALOG_NAMESPACE: u64 = 11;
+
+pub struct PostgresFederated {}
+
+impl PostgresFederated {
+    pub fn create() -> Self {
+        PostgresFederated {}
+    }
+
+    // Build block for show parameter statement.
+    // Format:
+    // |parameter_name|
+    // |value|
+    fn show_parameter_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(vec![TableField::new(name, TableDataType::String)]);
+        let block =
+            DataBlock::new_from_columns(vec![StringType::from_data(vec![value.to_string()])]);
+        Some((schema, block))
+    }
+
+    // Check SHOW parameter.
+    fn federated_show_parameter_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #![allow(clippy::type_complexity)]
+        static SHOW_PARAMETER_RULES: LazyLock<Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)>> =
+            LazyLock::new(|| {
+                vec![
+                    (
+                        Regex::new("(?i)^(SHOW TRANSACTION ISOLATION LEVEL)").unwrap(),
+                        PostgresFederated::show_parameter_block(
+                            "transaction_isolation",
+                            "read committed",
+                        ),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW server_version_num)").unwrap(),
+                        PostgresFederated::show_parameter_block("server_version_num", "150000"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW server_version)").unwrap(),
+                        PostgresFederated::show_parameter_block("server_version", POSTGRES_VERSION),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW standard_conforming_strings)").unwrap(),
+                        PostgresFederated::show_parameter_block(
+                            "standard_conforming_strings",
+                            "on",
+                        ),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW search_path)").unwrap(),
+                        PostgresFederated::show_parameter_block("search_path", "\"$user\", public"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW client_encoding)").unwrap(),
+                        PostgresFederated::show_parameter_block("client_encoding", "UTF8"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW server_encoding)").unwrap(),
+                        PostgresFederated::show_parameter_block("server_encoding", "UTF8"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW DateStyle)").unwrap(),
+                        PostgresFederated::show_parameter_block("DateStyle", "ISO, MDY"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW IntervalStyle)").unwrap(),
+                        PostgresFederated::show_parameter_block("IntervalStyle", "postgres"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW max_identifier_length)").unwrap(),
+                        PostgresFederated::show_parameter_block("max_identifier_length", "63"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW integer_datetimes)").unwrap(),
+                        PostgresFederated::show_parameter_block("integer_datetimes", "on"),
+                    ),
+                ]
+            });
+
+        FederatedHelper::block_match_rule(query, &SHOW_PARAMETER_RULES)
+    }
+
+    // Check for SET or others query, this is the final check of the federated query.
+    fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #![allow(clippy::type_complexity)]
+        static MIXED_RULES: LazyLock<Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)>> =
+            LazyLock::new(|| {
+                vec![
+                    // JDBC, psycopg and psql.
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?extra_float_digits(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?application_name(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?client_encoding(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?client_min_messages(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?datestyle(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?intervalstyle(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?bytea_output(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?standard_conforming_strings(.*))")
+                            .unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?search_path(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?statement_timeout(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?lock_timeout(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET (SESSION |LOCAL )?NAMES(.*))").unwrap(),
+                        None,
+                    ),
+                    (
+                        Regex::new("(?i)^(SET SESSION CHARACTERISTICS(.*))").unwrap(),
+                        None,
+                    ),
+                    (Regex::new("(?i)^(SET TRANSACTION(.*))").unwrap(), None),
+                    (Regex::new("(?i)^(DISCARD ALL)").unwrap(), None),
+                    (Regex::new("(?i)^(DEALLOCATE(.*))").unwrap(), None),
+                ]
+            });
+
+        FederatedHelper::block_match_rule(query, &MIXED_RULES)
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        let query = query.trim().trim_end_matches(';').trim_end();
+
+        let show_parameter = self
+            .federated_show_parameter_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if show_parameter.is_some() {
+            return show_parameter;
+        }
+
+        self.federated_mixed_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+    }
+
+    /// Rewrites the PostgreSQL specific statements into the Databend ones, returns `None`
+    /// if the query is not changed.
+    ///
+    /// - `SET TIME ZONE 'x'` is rewritten to `SET timezone = 'x'`.
+    /// - The tables of `pg_catalog` are replaced by the subqueries over the system tables,
+    ///   which are used by the drivers and the BI tools to discover the schema.
+    pub fn rewrite(&self, query: &str, dialect: Dialect) -> Result<Option<String>> {
+        static SET_TIME_ZONE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new("(?is)^\\s*SET\\s+(SESSION\\s+)?TIME\\s+ZONE\\s+(.+)$").unwrap()
+        });
+        if let Some(caps) = SET_TIME_ZONE.captures(query) {
+            let value = caps[2].trim().trim_end_matches(';').trim_end();
+            let value =
+                if value.eq_ignore_ascii_case("LOCAL") || value.eq_ignore_ascii_case("DEFAULT") {
+                    "'UTC'"
+                } else {
+                    value
+                };
+            return Ok(Some(format!("SET timezone = {}", value)));
+        }
+
+        let lower = query.to_lowercase();
+        if !lower.contains("pg_") && !lower.contains("current_schema") {
+            return Ok(None);
+        }
+
+        let tokens = tokenize_sql(query)?;
+        let (mut stmt, _) = parse_sql(&tokens, dialect)?;
+        if !matches!(stmt, Statement::Query(_)) {
+            return Ok(None);
+        }
+
+        let mut rewriter = PgCatalogRewriter {
+            dialect,
+            rewritten: false,
+        };
+        stmt.drive_mut(&mut rewriter);
+        if rewriter.rewritten {
+            Ok(Some(stmt.to_string()))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(TableReference(enter), FunctionCall(enter))]
+struct PgCatalogRewriter {
+    dialect: Dialect,
+    rewritten: bool,
+}
+
+impl PgCatalogRewriter {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        let TableReference::Table {
+            span,
+            catalog: None,
+            database,
+            table,
+            alias,
+            temporal: None,
+            ..
+        } = table_ref
+        else {
+            return;
+        };
+        if !database
+            .as_ref()
+            .map_or(true, |db| db.name.eq_ignore_ascii_case("pg_catalog"))
+        {
+            return;
+        }
+        let Some(sql) = pg_catalog_query(&table.name.to_lowercase()) else {
+            return;
+        };
+        let Ok(tokens) = tokenize_sql(&sql) else {
+            return;
+        };
+        let Ok((Statement::Query(subquery), _)) = parse_sql(&tokens, self.dialect) else {
+            return;
+        };
+
+        let alias = alias.clone().unwrap_or_else(|| TableAlias {
+            name: Identifier::from_name(*span, table.name.to_lowercase()),
+            columns: vec![],
+        });
+        *table_ref = TableReference::Subquery {
+            span: *span,
+            lateral: false,
+            subquery,
+            alias: Some(alias),
+            pivot: None,
+            unpivot: None,
+        };
+        self.rewritten = true;
+    }
+
+    fn enter_function_call(&mut self, func: &mut FunctionCall) {
+        if func.name.name.eq_ignore_ascii_case("current_schema") && func.args.is_empty() {
+            func.name = Identifier::from_name(func.name.span, "current_database");
+            self.rewritten = true;
+        }
+    }
+}
+
+// The query of the `pg_catalog` table over the system tables.
+fn pg_catalog_query(name: &str) -> Option<String> {
+    let sql = match name {
+        "pg_namespace" => format!(
+            "SELECT database_id AS oid, name AS nspname, 10 AS nspowner FROM system.databases \
+            WHERE catalog = 'default' UNION ALL SELECT {PG_CATALOG_NAMESPACE}, 'pg_catalog', 10"
+        ),
+        "pg_database" => "SELECT database_id AS oid, name AS datname, 10 AS datdba, 6 AS encoding, \
+            'C' AS datcollate, 'C' AS datctype, false AS datistemplate, true AS datallowconn \
+            FROM system.databases WHERE catalog = 'default'"
+            .to_string(),
+        "pg_class" => "SELECT t.table_id AS oid, t.name AS relname, d.database_id AS relnamespace, \
+            0 AS reltype, 10 AS relowner, t.num_rows AS reltuples, false AS relhasindex, \
+            'p' AS relpersistence, IF(t.engine = 'VIEW', 'v', 'r') AS relkind, \
+            t.comment AS description FROM system.tables AS t JOIN system.databases AS d \
+            ON t.catalog = d.catalog AND t.database = d.name"
+            .to_string(),
+        "pg_tables" => "SELECT database AS schemaname, name AS tablename, owner AS tableowner, \
+            NULL AS tablespace, false AS hasindexes, false AS hasrules, false AS hastriggers \
+            FROM system.tables WHERE table_type = 'BASE TABLE'"
+            .to_string(),
+        "pg_views" => "SELECT database AS schemaname, name AS viewname, NULL AS viewowner, \
+            view_query AS definition FROM system.views"
+            .to_string(),
+        "pg_attribute" => format!(
+            "SELECT t.table_id AS attrelid, c.name AS attname, {} AS atttypid, \
+            row_number() OVER (PARTITION BY t.table_id) AS attnum, -1 AS atttypmod, \
+            c.is_nullable = 'NO' AS attnotnull, false AS atthasdef, false AS attisdropped \
+            FROM system.columns AS c JOIN system.tables AS t \
+            ON c.database = t.database AND c.table = t.name",
+            column_type_oid("c.data_type")
+        ),
+        "pg_type" => PG_TYPES
+            .iter()
+            .map(|(oid, name, len, array_oid)| {
+                format!(
+                    "SELECT {oid} AS oid, '{name}' AS typname, {PG_CATALOG_NAMESPACE} AS typnamespace, \
+                    {len} AS typlen, 'b' AS typtype, {array_oid} AS typarray, 0 AS typelem, \
+                    0 AS typrelid, 0 AS typbasetype"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" UNION ALL "),
+        _ => return None,
+    };
+    Some(sql)
+}
+
+// The expression that maps the sql name of a column type to the oid of the PostgreSQL type,
+// which is consistent with `type_oid`.
+fn column_type_oid(column: &str) -> String {
+    let types = [
+        ("BOOLEAN", oid::BOOL),
+        ("BINARY", oid::BYTEA),
+        ("TINYINT", oid::INT2),
+        ("TINYINT UNSIGNED", oid::INT2),
+        ("SMALLINT", oid::INT2),
+        ("SMALLINT UNSIGNED", oid::INT4),
+        ("INT", oid::INT4),
+        ("INT UNSIGNED", oid::INT8),
+        ("BIGINT", oid::INT8),
+        ("BIGINT UNSIGNED", oid::NUMERIC),
+        ("FLOAT", oid::FLOAT4),
+        ("DOUBLE", oid::FLOAT8),
+        ("DATE", oid::DATE),
+        ("TIMESTAMP", oid::TIMESTAMP),
+        ("VARIANT", oid::JSON),
+    ];
+    let mut expr = format!("CASE {column}");
+    for (name, oid) in types {
+        expr.push_str(&format!(" WHEN '{name}' THEN {oid}"));
+    }
+    expr.push_str(&format!(
+        " ELSE IF({column} LIKE 'DECIMAL%', {}, {}) END",
+        oid::NUMERIC,
+        oid::TEXT
+    ));
+    expr
+}
@@ -0,0 +1,787 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::runtime::ThreadTracker;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::Column;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_io::prelude::FormatSettings;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::StreamExt;
+use log::info;
+use uuid::Uuid;
+
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::servers::postgres::postgres_federated::PostgresFederated;
+use crate::servers::postgres::protocol::read_message;
+use crate::servers::postgres::protocol::ErrorFields;
+use crate::servers::postgres::protocol::FieldDescription;
+use crate::servers::postgres::protocol::FrontendMessage;
+use crate::servers::postgres::protocol::MessageWriter;
+use crate::servers::postgres::protocol::FORMAT_TEXT;
+use crate::servers::postgres::types::oid;
+use crate::servers::postgres::types::param_to_literal;
+use crate::servers::postgres::types::type_len;
+use crate::servers::postgres::types::type_oid;
+use crate::servers::postgres::types::ValueEncoder;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
+
+// The buffered messages are flushed to the client once they exceed the size.
+const FLUSH_THRESHOLD: usize = 64 * 1024;
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<i32>,
+}
+
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+    // The result of a portal which is suspended by the row limit of `Execute`.
+    result: Option<QueryResult>,
+}
+
+enum ResultKind {
+    // The rows are sent to the client.
+    Rows,
+    // The rows are the numbers of the mutated rows, which are reported in the command tag.
+    Mutation,
+    // The number of the written rows is reported in the command tag.
+    Write(Arc<QueryContext>),
+    // Nothing is reported but the command.
+    Command,
+}
+
+struct QueryResult {
+    kind: ResultKind,
+    schema: DataSchemaRef,
+    format: FormatSettings,
+    blocks: SendableDataBlockStream,
+    // The columns of the block being sent and the index of the next row.
+    current: Option<(Vec<Column>, usize, usize)>,
+    rows: usize,
+    tag: String,
+}
+
+impl QueryResult {
+    fn command_tag(&self, rows: u64) -> String {
+        match &self.kind {
+            ResultKind::Rows => format!("SELECT {}", self.rows),
+            ResultKind::Mutation => format!("{} {}", self.tag, rows),
+            ResultKind::Write(ctx) => {
+                let rows = ctx.get_write_progress_value().rows;
+                match self.tag.as_str() {
+                    "COPY" => format!("COPY {}", rows),
+                    _ => format!("INSERT 0 {}", rows),
+                }
+            }
+            ResultKind::Command => self.tag.clone(),
+        }
+    }
+}
+
+pub struct InteractiveWorker<R, W> {
+    session: Arc<Session>,
+    reader: R,
+    writer: MessageWriter<W>,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    // After an error of the extended query, the messages are discarded until `Sync`.
+    ignore_till_sync: bool,
+    keep_alive_task_started: bool,
+}
+
+impl<R, W> InteractiveWorker<R, W>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    pub fn create(session: Arc<Session>, reader: R, writer: MessageWriter<W>) -> Self {
+        InteractiveWorker {
+            session,
+            reader,
+            writer,
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            ignore_till_sync: false,
+            keep_alive_task_started: false,
+        }
+    }
+
+    /// Switches to the database of the startup message. The database which has the same name
+    /// as the user is optional, as it is the default database of the PostgreSQL clients.
+    #[async_backtrace::framed]
+    pub async fn init_database(&mut self, database: &str, user: &str) -> Result<()> {
+        if database.is_empty() {
+            return Ok(());
+        }
+
+        let settings = self.session.get_settings();
+        let query = format!(
+            "USE {}",
+            display_ident(
+                database,
+                settings.get_quoted_ident_case_sensitive()?,
+                settings.get_sql_dialect()?
+            )
+        );
+        match self.execute_query(&query).await {
+            Ok(_) => Ok(()),
+            Err(error) if error.code() == ErrorCode::UNKNOWN_DATABASE && database == user => Ok(()),
+            Err(error) => {
+                self.writer.write_error_response(&ErrorFields {
+                    severity: "FATAL",
+                    code: sql_state(error.code()),
+                    message: &error.message(),
+                });
+                self.writer.flush().await?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Serves the messages of the client until the connection is terminated.
+    #[async_backtrace::framed]
+    pub async fn run(&mut self) -> Result<()> {
+        self.write_ready_for_query();
+        self.writer.flush().await?;
+
+        while let Some(message) = read_message(&mut self.reader).await? {
+            if self.session.is_aborting() {
+                let error = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.write_error(&error);
+                self.writer.flush().await?;
+                return Err(error);
+            }
+
+            if !self.keep_alive_task_started {
+                self.start_keep_alive();
+            }
+
+            if self.ignore_till_sync
+                && !matches!(message, FrontendMessage::Sync | FrontendMessage::Terminate)
+            {
+                continue;
+            }
+
+            let res = match message {
+                FrontendMessage::Query(sql) => {
+                    self.on_query(&sql).await;
+                    self.write_ready_for_query();
+                    self.writer.flush().await?;
+                    Ok(())
+                }
+                FrontendMessage::Parse {
+                    name,
+                    query,
+                    param_types,
+                } => self.on_parse(name, query, param_types),
+                FrontendMessage::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                } => self.on_bind(portal, &statement, &param_formats, &params, result_formats),
+                FrontendMessage::Describe { kind, name } => self.on_describe(kind, &name).await,
+                FrontendMessage::Execute { portal, max_rows } => {
+                    self.on_execute(&portal, max_rows).await
+                }
+                FrontendMessage::Close { kind, name } => {
+                    match kind {
+                        b'S' => self.statements.remove(&name).map(|_| ()),
+                        _ => self.portals.remove(&name).map(|_| ()),
+                    };
+                    self.writer.write_close_complete();
+                    Ok(())
+                }
+                FrontendMessage::Sync => {
+                    self.ignore_till_sync = false;
+                    self.portals.remove("");
+                    self.write_ready_for_query();
+                    self.writer.flush().await?;
+                    Ok(())
+                }
+                FrontendMessage::Flush => self.writer.flush().await,
+                FrontendMessage::Terminate => return Ok(()),
+                // COPY FROM STDIN is not supported, the data is discarded.
+                FrontendMessage::CopyData | FrontendMessage::CopyDone => Ok(()),
+                message => Err(ErrorCode::BadBytes(format!(
+                    "unexpected message {} from client",
+                    message.name()
+                ))),
+            };
+
+            if let Err(error) = res {
+                self.write_error(&error);
+                self.ignore_till_sync = true;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_query(&mut self, sql: &str) {
+        let queries = split_statements(sql);
+        if queries.is_empty() {
+            self.writer.write_empty_query_response();
+            return;
+        }
+
+        // The rest of the statements are skipped after an error, like PostgreSQL does.
+        for query in queries {
+            let res = match self.execute_query(&query).await {
+                Ok(mut result) => {
+                    if let ResultKind::Rows = result.kind {
+                        let fields = field_descriptions(&result.schema, &[]);
+                        self.writer.write_row_description(&fields);
+                    }
+                    self.write_result(&mut result, &[], 0).await.map(|_| ())
+                }
+                Err(error) => Err(error.display_with_sql(&query)),
+            };
+            if let Err(error) = res {
+                self.write_error(&error);
+                break;
+            }
+        }
+    }
+
+    fn on_parse(&mut self, name: String, query: String, param_types: Vec<i32>) -> Result<()> {
+        let query = query.trim().trim_end_matches(';').to_string();
+        let num_params = max_param_position(&query)?.max(param_types.len());
+        let mut param_types = param_types;
+        param_types.resize(num_params, 0);
+        self.statements
+            .insert(name, PreparedStatement { query, param_types });
+        self.writer.write_parse_complete();
+        Ok(())
+    }
+
+    fn on_bind(
+        &mut self,
+        portal: String,
+        statement: &str,
+        param_formats: &[i16],
+        params: &[Option<bytes::Bytes>],
+        result_formats: Vec<i16>,
+    ) -> Result<()> {
+        let Some(stmt) = self.statements.get(statement) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "prepared statement \"{}\" does not exist",
+                statement
+            )));
+        };
+        if params.len() != stmt.param_types.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "bind message supplies {} parameters, but prepared statement \"{}\" requires {}",
+                params.len(),
+                statement,
+                stmt.param_types.len()
+            )));
+        }
+
+        let literals = params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let format = match param_formats {
+                    [] => FORMAT_TEXT,
+                    [format] => *format,
+                    formats => formats.get(i).copied().unwrap_or(FORMAT_TEXT),
+                };
+                param_to_literal(param.as_deref(), stmt.param_types[i], format)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let query = bind_params(&stmt.query, &literals)?;
+
+        self.portals.insert(portal, Portal {
+            query,
+            result_formats,
+            result: None,
+        });
+        self.writer.write_bind_complete();
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_describe(&mut self, kind: u8, name: &str) -> Result<()> {
+        let (query, result_formats) = match kind {
+            b'S' => {
+                let Some(stmt) = self.statements.get(name) else {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" does not exist",
+                        name
+                    )));
+                };
+                // The types of the parameters which are not specified are sent as text.
+                let param_types = stmt
+                    .param_types
+                    .iter()
+                    .map(|ty| if *ty == 0 { oid::TEXT } else { *ty })
+                    .collect::<Vec<_>>();
+                self.writer.write_parameter_description(&param_types);
+                let nulls = vec!["NULL".to_string(); stmt.param_types.len()];
+                (bind_params(&stmt.query, &nulls)?, vec![])
+            }
+            _ => {
+                let Some(portal) = self.portals.get(name) else {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "portal \"{}\" does not exist",
+                        name
+                    )));
+                };
+                (portal.query.clone(), portal.result_formats.clone())
+            }
+        };
+
+        match self.describe_query(&query).await? {
+            Some(schema) => {
+                let fields = field_descriptions(&schema, &result_formats);
+                self.writer.write_row_description(&fields);
+            }
+            None => self.writer.write_no_data(),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_execute(&mut self, name: &str, max_rows: i32) -> Result<()> {
+        let Some(mut portal) = self.portals.remove(name) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "portal \"{}\" does not exist",
+                name
+            )));
+        };
+        if portal.query.is_empty() {
+            self.writer.write_empty_query_response();
+            return Ok(());
+        }
+
+        let mut result = match portal.result.take() {
+            Some(result) => result,
+            None => self
+                .execute_query(&portal.query)
+                .await
+                .map_err(|error| error.display_with_sql(&portal.query))?,
+        };
+        let completed = self
+            .write_result(
+                &mut result,
+                &portal.result_formats,
+                max_rows.max(0) as usize,
+            )
+            .await?;
+        if !completed {
+            portal.result = Some(result);
+            self.portals.insert(name.to_string(), portal);
+        }
+        Ok(())
+    }
+
+    // Writes at most `max_rows` rows of the result if it is not 0, returns `false` if
+    // the result is suspended.
+    #[async_backtrace::framed]
+    async fn write_result(
+        &mut self,
+        result: &mut QueryResult,
+        formats: &[i16],
+        max_rows: usize,
+    ) -> Result<bool> {
+        let mut affected_rows = 0;
+        let mut encoder = ValueEncoder::create(&result.format);
+        let mut sent = 0;
+        loop {
+            let Some((columns, num_rows, offset)) = &mut result.current else {
+                match result.blocks.next().await {
+                    None => break,
+                    Some(block) => {
+                        let block = block?;
+                        let num_rows = block.num_rows();
+                        if num_rows > 0 {
+                            let columns = block
+                                .columns()
+                                .iter()
+                                .map(|entry| entry.to_column(num_rows))
+                                .collect();
+                            result.current = Some((columns, num_rows, 0));
+                        }
+                        continue;
+                    }
+                }
+            };
+
+            match result.kind {
+                ResultKind::Rows => {}
+                ResultKind::Mutation => {
+                    if affected_rows == 0 {
+                        affected_rows = mutation_rows(columns);
+                    }
+                    result.current = None;
+                    continue;
+                }
+                _ => {
+                    result.current = None;
+                    continue;
+                }
+            }
+
+            if max_rows > 0 && sent == max_rows {
+                self.writer.write_portal_suspended();
+                return Ok(false);
+            }
+            self.writer.write_data_row(columns.len(), |i, buf| {
+                let format = match formats {
+                    [] => FORMAT_TEXT,
+                    [format] => *format,
+                    formats => formats.get(i).copied().unwrap_or(FORMAT_TEXT),
+                };
+                encoder.encode(&columns[i], *offset, format, buf)
+            });
+            *offset += 1;
+            if *offset == *num_rows {
+                result.current = None;
+            }
+            sent += 1;
+            result.rows += 1;
+
+            if self.writer.buffered_len() >= FLUSH_THRESHOLD {
+                self.writer.flush().await?;
+            }
+        }
+
+        self.writer
+            .write_command_complete(&result.command_tag(affected_rows));
+        Ok(true)
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_query(&mut self, query: &str) -> Result<QueryResult> {
+        let query_id = Uuid::new_v4().to_string();
+        let mut tracking_payload = ThreadTracker::new_tracking_payload();
+        tracking_payload.query_id = Some(query_id.clone());
+        let _guard = ThreadTracker::tracking(tracking_payload);
+
+        ThreadTracker::tracking_future(async {
+            let query = self.rewrite_query(query)?;
+            let tag = command_keyword(&query);
+            if let Some((schema, data_block)) = PostgresFederated::create().check(&query) {
+                info!("Federated query: {}", query);
+                let kind = match data_block.num_rows() > 0 {
+                    true => ResultKind::Rows,
+                    false => ResultKind::Command,
+                };
+                return Ok(QueryResult {
+                    kind,
+                    schema,
+                    format: self.session.get_format_settings(),
+                    blocks: DataBlockStream::create(None, vec![data_block]).boxed(),
+                    current: None,
+                    rows: 0,
+                    tag,
+                });
+            }
+
+            info!("Normal query: {}", query);
+            let context = self.session.create_query_context().await?;
+            context.set_id(query_id);
+
+            // Use interpreter_plan_sql, we can write the query log if an error occurs.
+            let (plan, _, _guard) = interpreter_plan_sql(context.clone(), &query, true).await?;
+            let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+            let blocks = Self::exec_query(interpreter, &context).await?;
+            let kind = match &plan {
+                Plan::DataMutation { .. } => ResultKind::Mutation,
+                plan if plan.has_result_set() => ResultKind::Rows,
+                Plan::Insert(_)
+                | Plan::InsertMultiTable(_)
+                | Plan::Replace(_)
+                | Plan::CopyIntoTable(_) => ResultKind::Write(context.clone()),
+                _ => ResultKind::Command,
+            };
+            Ok(QueryResult {
+                kind,
+                schema: plan.schema(),
+                format: context.get_format_settings()?,
+                blocks,
+                current: None,
+                rows: 0,
+                tag,
+            })
+        })
+        .await
+    }
+
+    #[async_backtrace::framed]
+    async fn exec_query(
+        interpreter: Arc<dyn Interpreter>,
+        context: &Arc<QueryContext>,
+    ) -> Result<SendableDataBlockStream> {
+        let query_result = context.try_spawn(
+            {
+                let ctx = context.clone();
+                async move {
+                    let mut data_stream = interpreter.execute(ctx.clone()).await?;
+                    let intercepted_stream = async_stream::stream! {
+                        while let Some(item) = data_stream.next().await {
+                            yield item
+                        };
+                    };
+                    Ok::<_, ErrorCode>(intercepted_stream.boxed())
+                }
+            },
+            None,
+        )?;
+
+        query_result.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?
+    }
+
+    // Returns the schema of the rows which the query sends, without executing it.
+    #[async_backtrace::framed]
+    async fn describe_query(&mut self, query: &str) -> Result<Option<DataSchemaRef>> {
+        if query.is_empty() {
+            return Ok(None);
+        }
+        let query = self.rewrite_query(query)?;
+        if let Some((schema, data_block)) = PostgresFederated::create().check(&query) {
+            return Ok((data_block.num_rows() > 0).then_some(schema));
+        }
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner
+            .plan_sql(&query)
+            .await
+            .map_err(|error| error.display_with_sql(&query))?;
+        match plan {
+            Plan::DataMutation { .. } => Ok(None),
+            plan if plan.has_result_set() => Ok(Some(plan.schema())),
+            _ => Ok(None),
+        }
+    }
+
+    fn rewrite_query(&self, query: &str) -> Result<String> {
+        let dialect = self.session.get_settings().get_sql_dialect()?;
+        Ok(PostgresFederated::create()
+            .rewrite(query, dialect)?
+            .unwrap_or_else(|| query.to_string()))
+    }
+
+    fn write_ready_for_query(&mut self) {
+        let status = {
+            let txn_mgr = self.session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_fail() {
+                b'E'
+            } else if txn_mgr.is_active() {
+                b'T'
+            } else {
+                b'I'
+            }
+        };
+        self.writer.write_ready_for_query(status);
+    }
+
+    fn write_error(&mut self, error: &ErrorCode) {
+        info!("Postgres query error: {}", error);
+        self.writer.write_error_response(&ErrorFields {
+            severity: "ERROR",
+            code: sql_state(error.code()),
+            message: &error.message(),
+        });
+    }
+
+    fn start_keep_alive(&mut self) {
+        let tenant = self.session.get_current_tenant();
+        let session_id = self.session.get_id();
+        let Ok(user) = self.session.get_current_user() else {
+            return;
+        };
+        self.keep_alive_task_started = true;
+
+        databend_common_base::runtime::spawn(async move {
+            loop {
+                UserApiProvider::instance()
+                    .client_session_api(&tenant)
+                    .upsert_client_session_id(
+                        &session_id,
+                        &user.name,
+                        Duration::from_secs(3600 + 600),
+                    )
+                    .await
+                    .ok();
+                tokio::time::sleep(Duration::from_secs(3600)).await;
+            }
+        });
+    }
+}
+
+fn field_descriptions(schema: &DataSchemaRef, formats: &[i16]) -> Vec<FieldDescription> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let type_oid = type_oid(field.data_type());
+            let format = match formats {
+                [] => FORMAT_TEXT,
+                [format] => *format,
+                formats => formats.get(i).copied().unwrap_or(FORMAT_TEXT),
+            };
+            FieldDescription {
+                name: field.name().clone(),
+                type_oid,
+                type_len: type_len(type_oid),
+                format,
+            }
+        })
+        .collect()
+}
+
+// The number of the mutated rows is the sum of the counters in the first row.
+fn mutation_rows(columns: &[Column]) -> u64 {
+    columns
+        .iter()
+        .map(|column| match column.index(0) {
+            Some(ScalarRef::Number(NumberScalar::UInt64(n))) => n,
+            _ => 0,
+        })
+        .sum()
+}
+
+// Splits the query string of a simple query message into statements.
+fn split_statements(sql: &str) -> Vec<String> {
+    let Ok(tokens) = tokenize_sql(sql) else {
+        // Let the planner report the error.
+        return vec![sql.to_string()];
+    };
+
+    let mut queries = vec![];
+    let mut start = 0;
+    for token in tokens {
+        if matches!(token.kind, TokenKind::SemiColon | TokenKind::EOI) {
+            let end = token.span.start as usize;
+            let query = sql[start..end].trim();
+            if !query.is_empty() {
+                queries.push(query.to_string());
+            }
+            start = token.span.end as usize;
+        }
+    }
+    queries
+}
+
+// Returns the max position of the parameters `$n` in the query.
+fn max_param_position(query: &str) -> Result<usize> {
+    let mut max_position = 0;
+    for token in tokenize_sql(query)? {
+        if token.kind == TokenKind::ColumnPosition {
+            let position = token.text()[1..].parse::<usize>().unwrap_or(0);
+            max_position = max_position.max(position);
+        }
+    }
+    Ok(max_position)
+}
+
+// Replaces the parameters `$n` in the query with the literals.
+fn bind_params(query: &str, literals: &[String]) -> Result<String> {
+    if literals.is_empty() {
+        return Ok(query.to_string());
+    }
+
+    let mut sql = String::with_capacity(query.len());
+    let mut pos = 0;
+    for token in tokenize_sql(query)? {
+        if token.kind != TokenKind::ColumnPosition {
+            continue;
+        }
+        let position = token.text()[1..].parse::<usize>().unwrap_or(0);
+        let Some(literal) = position.checked_sub(1).and_then(|i| literals.get(i)) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "there is no parameter ${}",
+                position
+            )));
+        };
+        sql.push_str(&query[pos..token.span.start as usize]);
+        sql.push_str(literal);
+        pos = token.span.end as usize;
+    }
+    sql.push_str(&query[pos..]);
+    Ok(sql)
+}
+
+// Returns the command name of the statement, which is the tag of `CommandComplete`.
+fn command_keyword(query: &str) -> String {
+    let mut words = query
+        .split_whitespace()
+        .map(|word| word.trim_end_matches(';').to_uppercase());
+    let Some(first) = words.next() else {
+        return String::new();
+    };
+    match first.as_str() {
+        "CREATE" | "DROP" | "ALTER" | "UNDROP" => {
+            let mut words = words.skip_while(|word| word == "OR" || word == "REPLACE");
+            match words.next() {
+                Some(object) => format!("{} {}", first, object),
+                None => first,
+            }
+        }
+        _ => first,
+    }
+}
+
+// The SQLSTATE of the error.
+fn sql_state(code: u16) -> &'static str {
+    match code {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::UNKNOWN_FUNCTION => "42883",
+        ErrorCode::TABLE_ALREADY_EXISTS => "42P07",
+        ErrorCode::DATABASE_ALREADY_EXISTS => "42P04",
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::BAD_BYTES => "08P01",
+        _ => "XX000",
+    }
+}
@@ -0,0 +1,403 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::Shutdown;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::io::split;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::io::BufReader;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_base::runtime::Runtime;
+use databend_common_base::runtime::Thread;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_config::DATABEND_COMMIT_VERSION;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use databend_storages_common_session::drop_all_temp_tables;
+use log::error;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use rand::Rng;
+use rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::servers::postgres::postgres_interactive_worker::InteractiveWorker;
+use crate::servers::postgres::protocol::read_message;
+use crate::servers::postgres::protocol::read_startup_message;
+use crate::servers::postgres::protocol::ErrorFields;
+use crate::servers::postgres::protocol::FrontendMessage;
+use crate::servers::postgres::protocol::MessageWriter;
+use crate::servers::postgres::protocol::StartupMessage;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+
+/// The sessions which can be canceled by `CancelRequest`, keyed by the process id and
+/// the secret key sent in `BackendKeyData`.
+pub type CancelKeys = Arc<Mutex<HashMap<(i32, i32), String>>>;
+
+pub struct PostgresConnection;
+
+impl PostgresConnection {
+    pub fn run_on_stream(
+        session: Session,
+        stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+        cancel_keys: CancelKeys,
+    ) -> Result<()> {
+        let blocking_stream = Self::convert_stream(stream)?;
+        let io_stream = blocking_stream.try_clone()?;
+        let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
+        let query_executor =
+            Runtime::with_worker_threads(1, Some("postgres-query-executor".to_string()))?;
+        Thread::spawn(move || {
+            let join_handle = query_executor.spawn(async move {
+                if let Err(error) =
+                    Self::run(session, non_blocking_stream, io_stream, tls, cancel_keys).await
+                {
+                    warn!("Postgres connection closed with error: {}", error);
+                }
+            });
+            let _ = futures::executor::block_on(join_handle);
+        });
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(
+        session: Session,
+        mut stream: TcpStream,
+        io_stream: std::net::TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+        cancel_keys: CancelKeys,
+    ) -> Result<()> {
+        loop {
+            match read_startup_message(&mut stream).await? {
+                // The answer of `SSLRequest` and `GSSENCRequest` has no type and length.
+                StartupMessage::SslRequest => match &tls {
+                    Some(config) => {
+                        stream.write_all(b"S").await?;
+                        let client_addr = stream.peer_addr().ok();
+                        let stream = TlsAcceptor::from(config.clone()).accept(stream).await?;
+                        return Self::run_tls(session, stream, client_addr, io_stream, cancel_keys)
+                            .await;
+                    }
+                    None => stream.write_all(b"N").await?,
+                },
+                StartupMessage::GssEncRequest => stream.write_all(b"N").await?,
+                StartupMessage::CancelRequest {
+                    process_id,
+                    secret_key,
+                } => {
+                    Self::cancel(&cancel_keys, process_id, secret_key);
+                    return Ok(());
+                }
+                StartupMessage::Startup { params } => {
+                    let client_addr = stream.peer_addr().ok();
+                    let params = params.into_iter().collect::<HashMap<_, _>>();
+                    return Self::serve_startup(
+                        session,
+                        stream,
+                        false,
+                        params,
+                        client_addr,
+                        io_stream,
+                        cancel_keys,
+                    )
+                    .await;
+                }
+            }
+        }
+    }
+
+    // After the SSL negotiation, the client sends the startup message through the TLS stream.
+    #[async_backtrace::framed]
+    async fn run_tls<S: AsyncRead + AsyncWrite + Unpin>(
+        session: Session,
+        mut stream: S,
+        client_addr: Option<SocketAddr>,
+        io_stream: std::net::TcpStream,
+        cancel_keys: CancelKeys,
+    ) -> Result<()> {
+        let params = match read_startup_message(&mut stream).await? {
+            StartupMessage::Startup { params } => params.into_iter().collect::<HashMap<_, _>>(),
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "expected startup message after SSL negotiation",
+                ));
+            }
+        };
+        Self::serve_startup(
+            session,
+            stream,
+            true,
+            params,
+            client_addr,
+            io_stream,
+            cancel_keys,
+        )
+        .await
+    }
+
+    #[async_backtrace::framed]
+    async fn serve_startup<S: AsyncRead + AsyncWrite + Unpin>(
+        mut session: Session,
+        stream: S,
+        secure: bool,
+        params: HashMap<String, String>,
+        client_addr: Option<SocketAddr>,
+        io_stream: std::net::TcpStream,
+        cancel_keys: CancelKeys,
+    ) -> Result<()> {
+        let (r, w) = split(stream);
+        let mut reader = BufReader::new(r);
+        let mut writer = MessageWriter::new(w);
+
+        let client_ip = client_addr.map(|addr| addr.ip().to_string());
+        if let Err(error) = Self::authenticate(
+            &mut session,
+            &mut reader,
+            &mut writer,
+            &params,
+            secure,
+            client_ip,
+        )
+        .await
+        {
+            let code = match error.code() {
+                ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+                _ => "28000",
+            };
+            writer.write_error_response(&ErrorFields {
+                severity: "FATAL",
+                code,
+                message: &error.message(),
+            });
+            writer.flush().await?;
+            return Err(error);
+        }
+
+        let session = SessionManager::instance().register_session(session)?;
+        info!("Postgres connection coming: {:?}", client_addr);
+        session.attach(client_addr, move || {
+            if let Err(error) = io_stream.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown Postgres session io {}", error);
+            }
+        });
+
+        let (process_id, secret_key) = {
+            let mut rng = rand::thread_rng();
+            (rng.gen_range(1..i32::MAX), rng.gen::<i32>())
+        };
+        cancel_keys
+            .lock()
+            .insert((process_id, secret_key), session.get_id());
+
+        Self::write_parameters(&session, &mut writer, &params)?;
+        writer.write_backend_key_data(process_id, secret_key);
+
+        let mut worker = InteractiveWorker::create(session.clone(), reader, writer);
+        let res = match params.get("database") {
+            Some(database) => {
+                let user = params.get("user").cloned().unwrap_or_default();
+                worker.init_database(database, &user).await
+            }
+            None => Ok(()),
+        };
+        let res = match res {
+            Ok(_) => worker.run().await,
+            Err(error) => Err(error),
+        };
+
+        cancel_keys.lock().remove(&(process_id, secret_key));
+        let tenant = session.get_current_tenant();
+        let session_id = session.get_id();
+        if let Ok(user) = session.get_current_user() {
+            UserApiProvider::instance()
+                .client_session_api(&tenant)
+                .drop_client_session_id(&session_id, &user.name)
+                .await
+                .ok();
+        }
+        if let Err(error) = drop_all_temp_tables(&session_id, session.temp_tbl_mgr()).await {
+            warn!(
+                "Cannot drop temp tables of Postgres session {}: {}",
+                session_id, error
+            );
+        }
+        res
+    }
+
+    /// The authentication method is chosen by the auth type of the user, the password and
+    /// the JWT are sent in cleartext, so they are only accepted through a TLS connection.
+    #[async_backtrace::framed]
+    async fn authenticate<R, W>(
+        session: &mut Session,
+        reader: &mut R,
+        writer: &mut MessageWriter<W>,
+        params: &HashMap<String, String>,
+        secure: bool,
+        client_ip: Option<String>,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let Some(user) = params.get("user") else {
+            return Err(ErrorCode::AuthenticateFailure(
+                "no PostgreSQL user name specified in startup packet",
+            ));
+        };
+
+        let tenant = session.get_current_tenant();
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, UserIdentity::new(user, "%"), client_ip.as_deref())
+            .await?;
+
+        if matches!(user_info.auth_info, AuthInfo::None) {
+            let credential = Credential::Password {
+                name: user.clone(),
+                password: None,
+                client_ip,
+            };
+            AuthMgr::instance().auth(session, &credential, true).await?;
+            writer.write_authentication_ok();
+            return Ok(());
+        }
+
+        if !secure {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "user '{}' must connect with SSL, the password is not sent in plaintext",
+                user
+            )));
+        }
+
+        writer.write_authentication_cleartext_password();
+        writer.flush().await?;
+        let password = match read_message(reader).await? {
+            Some(FrontendMessage::Password(password)) => password,
+            Some(message) => {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "expected password response, got message {}",
+                    message.name()
+                )));
+            }
+            None => {
+                return Err(ErrorCode::AuthenticateFailure(
+                    "connection closed during authentication",
+                ));
+            }
+        };
+
+        let credential = match user_info.auth_info {
+            AuthInfo::JWT => Credential::Jwt {
+                token: password,
+                client_ip,
+            },
+            _ => Credential::Password {
+                name: user.clone(),
+                password: Some(password.into_bytes()),
+                client_ip,
+            },
+        };
+        AuthMgr::instance().auth(session, &credential, true).await?;
+
+        // The user of the JWT is the subject of the token, which must be the user to login.
+        let authed_user = session.get_current_user()?.name;
+        if &authed_user != user {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "the token is issued to user '{}', not '{}'",
+                authed_user, user
+            )));
+        }
+        writer.write_authentication_ok();
+        Ok(())
+    }
+
+    fn write_parameters<W: AsyncWrite + Unpin>(
+        session: &Arc<Session>,
+        writer: &mut MessageWriter<W>,
+        params: &HashMap<String, String>,
+    ) -> Result<()> {
+        let user = session.get_current_user()?;
+        let timezone = session.get_settings().get_timezone()?;
+        let version = format!(
+            "{} (Databend Query {})",
+            POSTGRES_VERSION, *DATABEND_COMMIT_VERSION
+        );
+        let is_superuser = match session.get_current_role() {
+            Some(role) if role.name == BUILTIN_ROLE_ACCOUNT_ADMIN => "on",
+            _ => "off",
+        };
+        let application_name = params
+            .get("application_name")
+            .map(|name| name.as_str())
+            .unwrap_or_default();
+
+        writer.write_parameter_status("server_version", &version);
+        writer.write_parameter_status("server_encoding", "UTF8");
+        writer.write_parameter_status("client_encoding", "UTF8");
+        writer.write_parameter_status("DateStyle", "ISO, MDY");
+        writer.write_parameter_status("IntervalStyle", "postgres");
+        writer.write_parameter_status("integer_datetimes", "on");
+        writer.write_parameter_status("standard_conforming_strings", "on");
+        writer.write_parameter_status("TimeZone", &timezone);
+        writer.write_parameter_status("application_name", application_name);
+        writer.write_parameter_status("is_superuser", is_superuser);
+        writer.write_parameter_status("session_authorization", &user.name);
+        Ok(())
+    }
+
+    fn cancel(cancel_keys: &CancelKeys, process_id: i32, secret_key: i32) {
+        let Some(session_id) = cancel_keys.lock().get(&(process_id, secret_key)).cloned() else {
+            warn!(
+                "Postgres cancel request with unknown key, process id: {}",
+                process_id
+            );
+            return;
+        };
+        if let Some(session) = SessionManager::instance().get_session_by_id(&session_id) {
+            info!("Postgres cancel request of session {}", session_id);
+            session.force_kill_query(ErrorCode::AbortedQuery(
+                "canceling statement due to user request",
+            ));
+        }
+    }
+
+    fn convert_stream(stream: TcpStream) -> Result<std::net::TcpStream> {
+        let stream = stream.into_std().map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+        stream.set_nonblocking(false).map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+
+        Ok(stream)
+    }
+}
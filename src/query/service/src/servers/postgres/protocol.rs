@@ -0,0 +1,459 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use bytes::Buf;
+use bytes::BufMut;
+use bytes::Bytes;
+use bytes::BytesMut;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+pub const PROTOCOL_VERSION_3: i32 = 196608;
+pub const SSL_REQUEST_CODE: i32 = 80877103;
+pub const GSSENC_REQUEST_CODE: i32 = 80877104;
+pub const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// Messages larger than this are rejected to protect the server from broken clients.
+const MAX_MESSAGE_SIZE: usize = 1024 * 1024 * 1024;
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+pub enum StartupMessage {
+    Startup { params: Vec<(String, String)> },
+    SslRequest,
+    GssEncRequest,
+    CancelRequest { process_id: i32, secret_key: i32 },
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<i32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Bytes>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    Password(String),
+    CopyData,
+    CopyDone,
+    CopyFail(String),
+}
+
+impl FrontendMessage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FrontendMessage::Query(_) => "Query",
+            FrontendMessage::Parse { .. } => "Parse",
+            FrontendMessage::Bind { .. } => "Bind",
+            FrontendMessage::Describe { .. } => "Describe",
+            FrontendMessage::Execute { .. } => "Execute",
+            FrontendMessage::Close { .. } => "Close",
+            FrontendMessage::Sync => "Sync",
+            FrontendMessage::Flush => "Flush",
+            FrontendMessage::Terminate => "Terminate",
+            FrontendMessage::Password(_) => "PasswordMessage",
+            FrontendMessage::CopyData => "CopyData",
+            FrontendMessage::CopyDone => "CopyDone",
+            FrontendMessage::CopyFail(_) => "CopyFail",
+        }
+    }
+}
+
+/// Read the first message of a connection, which has no type byte.
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let len = reader.read_i32().await? as usize;
+    if !(8..=10000).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of startup packet: {}",
+            len
+        )));
+    }
+    let mut buf = vec![0; len - 4];
+    reader.read_exact(&mut buf).await?;
+    let mut buf = Bytes::from(buf);
+
+    let code = buf.get_i32();
+    match code {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => {
+            let process_id = get_i32(&mut buf)?;
+            let secret_key = get_i32(&mut buf)?;
+            Ok(StartupMessage::CancelRequest {
+                process_id,
+                secret_key,
+            })
+        }
+        PROTOCOL_VERSION_3 => {
+            let mut params = vec![];
+            loop {
+                let key = get_cstring(&mut buf)?;
+                if key.is_empty() {
+                    break;
+                }
+                let value = get_cstring(&mut buf)?;
+                params.push((key, value));
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        _ => Err(ErrorCode::BadBytes(format!(
+            "unsupported frontend protocol {}.{}: server supports 3.0",
+            code >> 16,
+            code & 0xffff
+        ))),
+    }
+}
+
+/// Read a regular message, returns `None` if the client closed the connection.
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = reader.read_i32().await? as usize;
+    if !(4..=MAX_MESSAGE_SIZE).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid message length {} of message type '{}'",
+            len, tag as char
+        )));
+    }
+    let mut buf = vec![0; len - 4];
+    reader.read_exact(&mut buf).await?;
+    let mut buf = Bytes::from(buf);
+
+    let message = match tag {
+        b'Q' => FrontendMessage::Query(get_cstring(&mut buf)?),
+        b'P' => {
+            let name = get_cstring(&mut buf)?;
+            let query = get_cstring(&mut buf)?;
+            let num_params = get_i16(&mut buf)?;
+            let mut param_types = Vec::with_capacity(num_params.max(0) as usize);
+            for _ in 0..num_params {
+                param_types.push(get_i32(&mut buf)?);
+            }
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = get_cstring(&mut buf)?;
+            let statement = get_cstring(&mut buf)?;
+            let param_formats = get_i16_array(&mut buf)?;
+            let num_params = get_i16(&mut buf)?;
+            let mut params = Vec::with_capacity(num_params.max(0) as usize);
+            for _ in 0..num_params {
+                let len = get_i32(&mut buf)?;
+                if len < 0 {
+                    params.push(None);
+                } else {
+                    let len = len as usize;
+                    if buf.remaining() < len {
+                        return Err(unexpected_end());
+                    }
+                    params.push(Some(buf.split_to(len)));
+                }
+            }
+            let result_formats = get_i16_array(&mut buf)?;
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => {
+            let kind = get_u8(&mut buf)?;
+            let name = get_cstring(&mut buf)?;
+            FrontendMessage::Describe { kind, name }
+        }
+        b'E' => {
+            let portal = get_cstring(&mut buf)?;
+            let max_rows = get_i32(&mut buf)?;
+            FrontendMessage::Execute { portal, max_rows }
+        }
+        b'C' => {
+            let kind = get_u8(&mut buf)?;
+            let name = get_cstring(&mut buf)?;
+            FrontendMessage::Close { kind, name }
+        }
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        b'p' => FrontendMessage::Password(get_cstring(&mut buf)?),
+        b'd' => FrontendMessage::CopyData,
+        b'c' => FrontendMessage::CopyDone,
+        b'f' => FrontendMessage::CopyFail(get_cstring(&mut buf)?),
+        _ => {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid frontend message type '{}'",
+                tag as char
+            )));
+        }
+    };
+    Ok(Some(message))
+}
+
+fn unexpected_end() -> ErrorCode {
+    ErrorCode::BadBytes("unexpected end of frontend message")
+}
+
+fn get_u8(buf: &mut Bytes) -> Result<u8> {
+    if buf.remaining() < 1 {
+        return Err(unexpected_end());
+    }
+    Ok(buf.get_u8())
+}
+
+fn get_i16(buf: &mut Bytes) -> Result<i16> {
+    if buf.remaining() < 2 {
+        return Err(unexpected_end());
+    }
+    Ok(buf.get_i16())
+}
+
+fn get_i32(buf: &mut Bytes) -> Result<i32> {
+    if buf.remaining() < 4 {
+        return Err(unexpected_end());
+    }
+    Ok(buf.get_i32())
+}
+
+fn get_i16_array(buf: &mut Bytes) -> Result<Vec<i16>> {
+    let len = get_i16(buf)?;
+    let mut values = Vec::with_capacity(len.max(0) as usize);
+    for _ in 0..len {
+        values.push(get_i16(buf)?);
+    }
+    Ok(values)
+}
+
+fn get_cstring(buf: &mut Bytes) -> Result<String> {
+    let Some(pos) = buf.iter().position(|b| *b == 0) else {
+        return Err(ErrorCode::BadBytes(
+            "invalid string in frontend message: missing terminator",
+        ));
+    };
+    let s = buf.split_to(pos);
+    buf.advance(1);
+    String::from_utf8(s.to_vec())
+        .map_err(|_| ErrorCode::BadBytes("invalid UTF-8 string in frontend message"))
+}
+
+/// The description of a column in `RowDescription`.
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: i32,
+    pub type_len: i16,
+    pub format: i16,
+}
+
+/// The fields of `ErrorResponse`.
+pub struct ErrorFields<'a> {
+    pub severity: &'a str,
+    pub code: &'a str,
+    pub message: &'a str,
+}
+
+/// Buffers the backend messages, they are sent to the client on `flush`.
+pub struct MessageWriter<W> {
+    writer: W,
+    buf: BytesMut,
+}
+
+impl<W: AsyncWrite + Unpin> MessageWriter<W> {
+    pub fn new(writer: W) -> Self {
+        MessageWriter {
+            writer,
+            buf: BytesMut::with_capacity(8192),
+        }
+    }
+
+    pub fn buffered_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub async fn flush(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.writer.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    // Write a message with the given type, the length is filled after the body is written.
+    fn write_message(&mut self, tag: u8, body: impl FnOnce(&mut BytesMut)) {
+        self.buf.put_u8(tag);
+        let len_pos = self.buf.len();
+        self.buf.put_i32(0);
+        body(&mut self.buf);
+        let len = (self.buf.len() - len_pos) as i32;
+        self.buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    pub fn write_authentication_ok(&mut self) {
+        self.write_message(b'R', |buf| buf.put_i32(0));
+    }
+
+    pub fn write_authentication_cleartext_password(&mut self) {
+        self.write_message(b'R', |buf| buf.put_i32(3));
+    }
+
+    pub fn write_parameter_status(&mut self, name: &str, value: &str) {
+        self.write_message(b'S', |buf| {
+            put_cstring(buf, name);
+            put_cstring(buf, value);
+        });
+    }
+
+    pub fn write_backend_key_data(&mut self, process_id: i32, secret_key: i32) {
+        self.write_message(b'K', |buf| {
+            buf.put_i32(process_id);
+            buf.put_i32(secret_key);
+        });
+    }
+
+    pub fn write_ready_for_query(&mut self, status: u8) {
+        self.write_message(b'Z', |buf| buf.put_u8(status));
+    }
+
+    pub fn write_row_description(&mut self, fields: &[FieldDescription]) {
+        self.write_message(b'T', |buf| {
+            buf.put_i16(fields.len() as i16);
+            for field in fields {
+                put_cstring(buf, &field.name);
+                // table oid and column attribute number
+                buf.put_i32(0);
+                buf.put_i16(0);
+                buf.put_i32(field.type_oid);
+                buf.put_i16(field.type_len);
+                // type modifier
+                buf.put_i32(-1);
+                buf.put_i16(field.format);
+            }
+        });
+    }
+
+    /// Each value is written by `f` into the buffer, returns `false` for NULL.
+    pub fn write_data_row(
+        &mut self,
+        num_columns: usize,
+        mut f: impl FnMut(usize, &mut BytesMut) -> bool,
+    ) {
+        self.write_message(b'D', |buf| {
+            buf.put_i16(num_columns as i16);
+            for i in 0..num_columns {
+                let len_pos = buf.len();
+                buf.put_i32(0);
+                if f(i, buf) {
+                    let len = (buf.len() - len_pos - 4) as i32;
+                    buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+                } else {
+                    buf[len_pos..len_pos + 4].copy_from_slice(&(-1i32).to_be_bytes());
+                }
+            }
+        });
+    }
+
+    pub fn write_command_complete(&mut self, tag: &str) {
+        self.write_message(b'C', |buf| put_cstring(buf, tag));
+    }
+
+    pub fn write_empty_query_response(&mut self) {
+        self.write_message(b'I', |_| {});
+    }
+
+    pub fn write_error_response(&mut self, fields: &ErrorFields) {
+        self.write_message(b'E', |buf| {
+            buf.put_u8(b'S');
+            put_cstring(buf, fields.severity);
+            buf.put_u8(b'V');
+            put_cstring(buf, fields.severity);
+            buf.put_u8(b'C');
+            put_cstring(buf, fields.code);
+            buf.put_u8(b'M');
+            put_cstring(buf, fields.message);
+            buf.put_u8(0);
+        });
+    }
+
+    pub fn write_parse_complete(&mut self) {
+        self.write_message(b'1', |_| {});
+    }
+
+    pub fn write_bind_complete(&mut self) {
+        self.write_message(b'2', |_| {});
+    }
+
+    pub fn write_close_complete(&mut self) {
+        self.write_message(b'3', |_| {});
+    }
+
+    pub fn write_no_data(&mut self) {
+        self.write_message(b'n', |_| {});
+    }
+
+    pub fn write_portal_suspended(&mut self) {
+        self.write_message(b's', |_| {});
+    }
+
+    pub fn write_parameter_description(&mut self, type_oids: &[i32]) {
+        self.write_message(b't', |buf| {
+            buf.put_i16(type_oids.len() as i16);
+            for oid in type_oids {
+                buf.put_i32(*oid);
+            }
+        });
+    }
+}
+
+fn put_cstring(buf: &mut BytesMut, s: &str) {
+    // A string in the protocol can not contain the terminator.
+    buf.put_slice(s.replace('\0', "").as_bytes());
+    buf.put_u8(0);
+}
@@ -0,0 +1,472 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mapping between Databend data types and PostgreSQL types, and the text and binary
+//! representations of the values.
+
+use bytes::BufMut;
+use bytes::BytesMut;
+use chrono::DateTime;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::date::date_to_string;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::ScalarRef;
+use databend_common_formats::field_encoder::FieldEncoderValues;
+use databend_common_io::prelude::FormatSettings;
+
+use crate::servers::postgres::protocol::FORMAT_BINARY;
+
+pub mod oid {
+    pub const BOOL: i32 = 16;
+    pub const BYTEA: i32 = 17;
+    pub const INT8: i32 = 20;
+    pub const INT2: i32 = 21;
+    pub const INT4: i32 = 23;
+    pub const TEXT: i32 = 25;
+    pub const OID: i32 = 26;
+    pub const JSON: i32 = 114;
+    pub const FLOAT4: i32 = 700;
+    pub const FLOAT8: i32 = 701;
+    pub const UNKNOWN: i32 = 705;
+    pub const BPCHAR: i32 = 1042;
+    pub const VARCHAR: i32 = 1043;
+    pub const DATE: i32 = 1082;
+    pub const TIMESTAMP: i32 = 1114;
+    pub const TIMESTAMPTZ: i32 = 1184;
+    pub const INTERVAL: i32 = 1186;
+    pub const NUMERIC: i32 = 1700;
+    pub const JSONB: i32 = 3802;
+}
+
+/// The types known by the server: oid, name, length and the oid of the array type.
+pub const PG_TYPES: &[(i32, &str, i16, i32)] = &[
+    (oid::BOOL, "bool", 1, 1000),
+    (oid::BYTEA, "bytea", -1, 1001),
+    (oid::INT8, "int8", 8, 1016),
+    (oid::INT2, "int2", 2, 1005),
+    (oid::INT4, "int4", 4, 1007),
+    (oid::TEXT, "text", -1, 1009),
+    (oid::OID, "oid", 4, 1028),
+    (oid::JSON, "json", -1, 199),
+    (oid::FLOAT4, "float4", 4, 1021),
+    (oid::FLOAT8, "float8", 8, 1022),
+    (oid::UNKNOWN, "unknown", -2, 0),
+    (oid::BPCHAR, "bpchar", -1, 1014),
+    (oid::VARCHAR, "varchar", -1, 1015),
+    (oid::DATE, "date", 4, 1182),
+    (oid::TIMESTAMP, "timestamp", 8, 1115),
+    (oid::TIMESTAMPTZ, "timestamptz", 8, 1185),
+    (oid::INTERVAL, "interval", 16, 1187),
+    (oid::NUMERIC, "numeric", -1, 1231),
+    (oid::JSONB, "jsonb", -1, 3807),
+];
+
+// Days and microseconds between the unix epoch and the PostgreSQL epoch 2000-01-01.
+const PG_EPOCH_DAYS: i32 = 10957;
+const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// Returns the oid of the PostgreSQL type which the values of the data type are sent as.
+///
+/// The nested and the semi-structured types are sent as text.
+pub fn type_oid(data_type: &DataType) -> i32 {
+    match data_type.remove_nullable() {
+        DataType::Boolean => oid::BOOL,
+        DataType::Binary => oid::BYTEA,
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => oid::INT2,
+            NumberDataType::UInt16 | NumberDataType::Int32 => oid::INT4,
+            NumberDataType::UInt32 | NumberDataType::Int64 => oid::INT8,
+            NumberDataType::UInt64 => oid::NUMERIC,
+            NumberDataType::Float32 => oid::FLOAT4,
+            NumberDataType::Float64 => oid::FLOAT8,
+        },
+        DataType::Decimal(_) => oid::NUMERIC,
+        DataType::Date => oid::DATE,
+        DataType::Timestamp => oid::TIMESTAMP,
+        DataType::Interval => oid::INTERVAL,
+        DataType::Variant => oid::JSON,
+        _ => oid::TEXT,
+    }
+}
+
+pub fn type_len(type_oid: i32) -> i16 {
+    PG_TYPES
+        .iter()
+        .find(|(oid, ..)| *oid == type_oid)
+        .map(|(_, _, len, _)| *len)
+        .unwrap_or(-1)
+}
+
+/// Encodes the values of the result set.
+pub struct ValueEncoder {
+    timezone: Tz,
+    field_encoder: FieldEncoderValues,
+    buf: Vec<u8>,
+}
+
+impl ValueEncoder {
+    pub fn create(format: &FormatSettings) -> Self {
+        ValueEncoder {
+            timezone: format.timezone,
+            field_encoder: FieldEncoderValues::create_for_mysql_handler(
+                format.timezone,
+                format.geometry_format,
+            ),
+            buf: Vec::new(),
+        }
+    }
+
+    /// Writes the value at `row_index` into `out`, returns `false` if the value is NULL.
+    pub fn encode(
+        &mut self,
+        column: &Column,
+        row_index: usize,
+        format: i16,
+        out: &mut BytesMut,
+    ) -> bool {
+        let value = unsafe { column.index_unchecked(row_index) };
+        if format == FORMAT_BINARY {
+            match value {
+                ScalarRef::Null => return false,
+                ScalarRef::Boolean(v) => out.put_u8(v as u8),
+                ScalarRef::Number(number) => match number {
+                    NumberScalar::Int8(v) => out.put_i16(v as i16),
+                    NumberScalar::UInt8(v) => out.put_i16(v as i16),
+                    NumberScalar::Int16(v) => out.put_i16(v),
+                    NumberScalar::UInt16(v) => out.put_i32(v as i32),
+                    NumberScalar::Int32(v) => out.put_i32(v),
+                    NumberScalar::UInt32(v) => out.put_i64(v as i64),
+                    NumberScalar::Int64(v) => out.put_i64(v),
+                    NumberScalar::UInt64(v) => encode_numeric(&v.to_string(), out),
+                    NumberScalar::Float32(v) => out.put_f32(v.0),
+                    NumberScalar::Float64(v) => out.put_f64(v.0),
+                },
+                ScalarRef::Decimal(v) => encode_numeric(&v.to_string(), out),
+                ScalarRef::Date(v) => out.put_i32(v - PG_EPOCH_DAYS),
+                ScalarRef::Timestamp(v) => {
+                    // `timestamp` is sent as the wall clock time in the session time zone,
+                    // the same as the text representation.
+                    let offset = DateTime::from_timestamp_micros(v)
+                        .map(|dt| {
+                            self.timezone
+                                .offset_from_utc_datetime(&dt.naive_utc())
+                                .fix()
+                                .local_minus_utc() as i64
+                        })
+                        .unwrap_or_default();
+                    out.put_i64(v + offset * 1_000_000 - PG_EPOCH_MICROS)
+                }
+                ScalarRef::Interval(v) => {
+                    out.put_i64(v.microseconds());
+                    out.put_i32(v.days());
+                    out.put_i32(v.months());
+                }
+                ScalarRef::String(v) => out.put_slice(v.as_bytes()),
+                ScalarRef::Binary(v) => out.put_slice(v),
+                // The binary representation of the text types is the same as the text.
+                _ => return self.encode_text(column, row_index, value, out),
+            }
+            true
+        } else {
+            self.encode_text(column, row_index, value, out)
+        }
+    }
+
+    fn encode_text(
+        &mut self,
+        column: &Column,
+        row_index: usize,
+        value: ScalarRef,
+        out: &mut BytesMut,
+    ) -> bool {
+        match value {
+            ScalarRef::Null => return false,
+            ScalarRef::Boolean(v) => out.put_u8(if v { b't' } else { b'f' }),
+            ScalarRef::String(v) => out.put_slice(v.as_bytes()),
+            ScalarRef::Binary(v) => {
+                out.put_slice(b"\\x");
+                out.put_slice(hex::encode(v).as_bytes());
+            }
+            ScalarRef::Bitmap(_) => out.put_slice(b"<bitmap binary>"),
+            _ => {
+                self.buf.clear();
+                self.field_encoder
+                    .write_field(column, row_index, &mut self.buf, false);
+                out.put_slice(&self.buf);
+            }
+        }
+        true
+    }
+}
+
+/// Writes the binary representation of `numeric` from the decimal string.
+fn encode_numeric(value: &str, out: &mut BytesMut) {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(v) => (true, v),
+        None => (false, value),
+    };
+    let (int_part, frac_part) = value.split_once('.').unwrap_or((value, ""));
+
+    // Group the digits by 4 from the decimal point, which are the base 10000 digits.
+    let mut int_digits = String::new();
+    int_digits.extend(std::iter::repeat('0').take((4 - int_part.len() % 4) % 4));
+    int_digits.push_str(int_part);
+    let mut frac_digits = frac_part.to_string();
+    frac_digits.extend(std::iter::repeat('0').take((4 - frac_part.len() % 4) % 4));
+
+    let to_groups = |s: &str| -> Vec<i16> {
+        s.as_bytes()
+            .chunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0i16, |acc, c| acc * 10 + (c - b'0') as i16)
+            })
+            .collect()
+    };
+    let int_groups = to_groups(&int_digits);
+    let mut digits = int_groups.clone();
+    digits.extend(to_groups(&frac_digits));
+
+    let mut weight = int_groups.len() as i16 - 1;
+    let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..leading_zeros);
+    weight -= leading_zeros as i16;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    out.put_i16(digits.len() as i16);
+    out.put_i16(weight);
+    out.put_u16(if negative && !digits.is_empty() {
+        0x4000
+    } else {
+        0
+    });
+    out.put_i16(frac_part.len() as i16);
+    for digit in digits {
+        out.put_i16(digit);
+    }
+}
+
+/// Decodes the binary representation of `numeric` into the decimal string.
+fn decode_numeric(data: &[u8]) -> Result<String> {
+    let invalid = || ErrorCode::BadBytes("invalid binary representation of numeric");
+    if data.len() < 8 {
+        return Err(invalid());
+    }
+    let read_i16 = |pos: usize| i16::from_be_bytes([data[pos], data[pos + 1]]);
+    let ndigits = read_i16(0) as usize;
+    let weight = read_i16(2) as i32;
+    let sign = read_i16(4) as u16;
+    let dscale = read_i16(6).max(0) as usize;
+    if data.len() != 8 + ndigits * 2 {
+        return Err(invalid());
+    }
+    if sign == 0xC000 {
+        return Ok("CAST('NaN' AS DOUBLE)".to_string());
+    }
+    let digits = (0..ndigits)
+        .map(|i| read_i16(8 + i * 2))
+        .collect::<Vec<_>>();
+
+    let mut int_part = String::new();
+    for i in 0..=weight.max(-1) {
+        let digit = digits.get(i as usize).copied().unwrap_or(0);
+        if int_part.is_empty() {
+            if digit != 0 {
+                int_part.push_str(&digit.to_string());
+            }
+        } else {
+            int_part.push_str(&format!("{:04}", digit));
+        }
+    }
+    if int_part.is_empty() {
+        int_part.push('0');
+    }
+
+    let mut frac_part = String::new();
+    let mut i = weight + 1;
+    while frac_part.len() < dscale {
+        let digit = if i < 0 {
+            0
+        } else {
+            digits.get(i as usize).copied().unwrap_or(0)
+        };
+        frac_part.push_str(&format!("{:04}", digit));
+        i += 1;
+    }
+    frac_part.truncate(dscale);
+
+    let mut value = String::new();
+    if sign == 0x4000 {
+        value.push('-');
+    }
+    value.push_str(&int_part);
+    if !frac_part.is_empty() {
+        value.push('.');
+        value.push_str(&frac_part);
+    }
+    Ok(value)
+}
+
+fn quote_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in value.chars() {
+        match c {
+            '\'' => quoted.push_str("''"),
+            '\\' => quoted.push_str("\\\\"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+fn is_number_literal(value: &str) -> bool {
+    let value = value.trim();
+    !value.is_empty()
+        && value
+            .trim_start_matches(['+', '-'])
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+        && value.parse::<f64>().is_ok()
+}
+
+/// Converts the value of a bound parameter into the SQL literal which replaces the placeholder.
+pub fn param_to_literal(value: Option<&[u8]>, type_oid: i32, format: i16) -> Result<String> {
+    let Some(value) = value else {
+        return Ok("NULL".to_string());
+    };
+
+    if format == FORMAT_BINARY {
+        let invalid = || {
+            ErrorCode::BadBytes(format!(
+                "invalid binary representation of parameter with type oid {}",
+                type_oid
+            ))
+        };
+        return match type_oid {
+            oid::BOOL => match value {
+                [0] => Ok("FALSE".to_string()),
+                [1] => Ok("TRUE".to_string()),
+                _ => Err(invalid()),
+            },
+            oid::INT2 => {
+                Ok(i16::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string())
+            }
+            oid::INT4 => {
+                Ok(i32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string())
+            }
+            oid::OID => {
+                Ok(u32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string())
+            }
+            oid::INT8 => {
+                Ok(i64::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string())
+            }
+            oid::FLOAT4 => {
+                let v = f32::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+                Ok(float_literal(v as f64))
+            }
+            oid::FLOAT8 => {
+                let v = f64::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+                Ok(float_literal(v))
+            }
+            oid::NUMERIC => decode_numeric(value),
+            oid::BYTEA => Ok(format!("FROM_HEX('{}')", hex::encode(value))),
+            oid::DATE => {
+                let days = i32::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+                Ok(format!(
+                    "CAST('{}' AS DATE)",
+                    date_to_string(days + PG_EPOCH_DAYS, Tz::UTC)
+                ))
+            }
+            oid::TIMESTAMP | oid::TIMESTAMPTZ => {
+                let micros = i64::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+                Ok(format!(
+                    "CAST('{}' AS TIMESTAMP)",
+                    timestamp_to_string(micros + PG_EPOCH_MICROS, Tz::UTC)
+                ))
+            }
+            oid::TEXT | oid::VARCHAR | oid::BPCHAR | oid::UNKNOWN | oid::JSON | 0 => {
+                let value = std::str::from_utf8(value).map_err(|_| invalid())?;
+                Ok(quote_string(value))
+            }
+            oid::JSONB => {
+                // The binary representation of jsonb is a version number followed by the text.
+                let value = value.strip_prefix(&[1]).ok_or_else(invalid)?;
+                let value = std::str::from_utf8(value).map_err(|_| invalid())?;
+                Ok(quote_string(value))
+            }
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "binary format of parameter with type oid {} is not supported",
+                type_oid
+            ))),
+        };
+    }
+
+    let value = std::str::from_utf8(value)
+        .map_err(|_| ErrorCode::BadBytes("invalid UTF-8 string in parameter"))?;
+    match type_oid {
+        oid::BOOL => match value.trim().to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok("TRUE".to_string()),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "invalid input syntax for type boolean: \"{}\"",
+                value
+            ))),
+        },
+        oid::INT2 | oid::INT4 | oid::INT8 | oid::OID | oid::FLOAT4 | oid::FLOAT8 | oid::NUMERIC => {
+            if is_number_literal(value) {
+                Ok(value.trim().to_string())
+            } else {
+                // e.g. 'NaN' and 'Infinity'
+                Ok(format!("CAST({} AS DOUBLE)", quote_string(value)))
+            }
+        }
+        oid::BYTEA => match value.strip_prefix("\\x") {
+            Some(hex) => Ok(format!("FROM_HEX({})", quote_string(hex))),
+            None => Ok(format!("TO_BINARY({})", quote_string(value))),
+        },
+        oid::DATE => Ok(format!("CAST({} AS DATE)", quote_string(value))),
+        oid::TIMESTAMP | oid::TIMESTAMPTZ => {
+            Ok(format!("CAST({} AS TIMESTAMP)", quote_string(value)))
+        }
+        _ => Ok(quote_string(value)),
+    }
+}
+
+fn float_literal(v: f64) -> String {
+    if v.is_finite() {
+        format!("{:?}", v)
+    } else if v.is_nan() {
+        "CAST('NaN' AS DOUBLE)".to_string()
+    } else if v > 0.0 {
+        "CAST('Infinity' AS DOUBLE)".to_string()
+    } else {
+        "CAST('-Infinity' AS DOUBLE)".to_string()
+    }
+}
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::cmp::min;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -56,6 +57,7 @@ use databend_common_catalog::table_context::ContextError;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::table_context::WorkloadGroupStatus;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -92,6 +94,7 @@ use databend_common_pipeline_core::InputError;
 use databend_common_pipeline_core::LockGuard;
 use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
+use databend_common_storage::init_stage_operator;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
 use databend_common_storage::FileStatus;
@@ -106,6 +109,7 @@ use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_orc::OrcTable;
 use databend_common_storages_parquet::ParquetRSTable;
 use databend_common_storages_result_cache::ResultScan;
+use databend_common_storages_stage::read_avro_schema;
 use databend_common_storages_stage::StageTable;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_users::GrantObjectVisibilityChecker;
@@ -132,17 +136,20 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::WorkloadGroupManager;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
 const MYSQL_VERSION: &str = "8.0.26";
 const CLICKHOUSE_VERSION: &str = "8.12.14";
+const POSTGRES_VERSION: &str = "15.0";
 const COPIED_FILES_FILTER_BATCH_SIZE: usize = 1000;
 
 #[derive(Clone)]
 pub struct QueryContext {
     version: String,
     mysql_version: String,
+    postgres_version: String,
     clickhouse_version: String,
     block_threshold: Arc<RwLock<BlockThresholds>>,
     partition_queue: Arc<RwLock<VecDeque<PartInfoPtr>>>,
@@ -172,6 +179,10 @@ impl QueryContext {
             partition_queue: Arc::new(RwLock::new(VecDeque::new())),
             version: format!("Databend Query {}", *DATABEND_COMMIT_VERSION),
             mysql_version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
+            postgres_version: format!(
+                "PostgreSQL {} (Databend Query {})",
+                POSTGRES_VERSION, *DATABEND_COMMIT_VERSION
+            ),
             clickhouse_version: CLICKHOUSE_VERSION.to_string(),
             shared,
             query_settings,
@@ -615,6 +626,22 @@ impl TableContext for QueryContext {
         );
     }
 
+    fn set_table_commit_options(&self, table_id: u64, options: BTreeMap<String, String>) {
+        self.shared
+            .table_commit_options
+            .lock()
+            .insert(table_id, options);
+    }
+
+    fn get_table_commit_options(&self, table_id: u64) -> BTreeMap<String, String> {
+        self.shared
+            .table_commit_options
+            .lock()
+            .get(&table_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     fn attach_query_str(&self, kind: QueryKind, query: String) {
         self.shared.attach_query_str(kind, query);
     }
@@ -721,6 +748,7 @@ impl TableContext for QueryContext {
         match session.get_type() {
             SessionType::ClickHouseHttpHandler => self.clickhouse_version.clone(),
             SessionType::MySQL => self.mysql_version.clone(),
+            SessionType::Postgres => self.postgres_version.clone(),
             _ => self.version.clone(),
         }
     }
@@ -843,11 +871,12 @@ impl TableContext for QueryContext {
     }
 
     fn get_queued_queries(&self) -> Vec<ProcessInfo> {
-        let queries = QueriesQueueManager::instance()
+        let mut queries = QueriesQueueManager::instance()
             .list()
             .iter()
             .map(|x| x.query_id.clone())
             .collect::<HashSet<_>>();
+        queries.extend(WorkloadGroupManager::instance().queued_queries());
 
         SessionManager::instance()
             .processes_info()
@@ -859,6 +888,10 @@ impl TableContext for QueryContext {
             .collect::<Vec<_>>()
     }
 
+    fn get_workload_groups_status(&self) -> HashMap<String, WorkloadGroupStatus> {
+        WorkloadGroupManager::instance().status()
+    }
+
     // Get Stage Attachment.
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.shared.get_stage_attachment()
@@ -1341,7 +1374,29 @@ impl TableContext for QueryContext {
                 };
                 OrcTable::try_create(info).await
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::Avro(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) => files
+                        .first()
+                        .cloned()
+                        .ok_or_else(|| ErrorCode::BadArguments("no file found"))?,
+                    None => files_info.first_file(&operator).await?,
+                };
+                let schema = Arc::new(read_avro_schema(&operator, &first_file.path).await?);
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    duplicated_files_detected: vec![],
+                    is_select: true,
+                    default_values: None,
+                    copy_into_location_options: Default::default(),
+                };
+                StageTable::try_create(info)
+            }
+            FileFormatParams::NdJson(..) | FileFormatParams::Xml(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -1395,7 +1450,7 @@ impl TableContext for QueryContext {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, ORC, Avro, NDJson, XML, CSV, and TSV. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
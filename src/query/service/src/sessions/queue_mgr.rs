@@ -53,6 +53,7 @@ use tokio::sync::Semaphore;
 use tokio::time::error::Elapsed;
 
 use crate::sessions::QueryContext;
+use crate::sessions::WorkloadGroupGuard;
 
 pub trait QueueData: Send + Sync + 'static {
     type Key: Send + Sync + Eq + Hash + Display + Clone + 'static;
@@ -80,6 +81,7 @@ pub(crate) struct Inner<Data: QueueData> {
 pub struct QueueManager<Data: QueueData> {
     semaphore: Arc<Semaphore>,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
+    record_metrics: bool,
 }
 
 impl<Data: QueueData> QueueManager<Data> {
@@ -93,7 +95,17 @@ impl<Data: QueueData> QueueManager<Data> {
         GlobalInstance::get::<Arc<Self>>()
     }
 
-    pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
+    pub fn create(permits: usize) -> Arc<QueueManager<Data>> {
+        Self::create_inner(permits, true)
+    }
+
+    /// Create a queue which is not reported to the session queue metrics,
+    /// e.g. the queue of a workload group, which is acquired before the global queue.
+    pub fn create_without_metrics(permits: usize) -> Arc<QueueManager<Data>> {
+        Self::create_inner(permits, false)
+    }
+
+    fn create_inner(mut permits: usize, record_metrics: bool) -> Arc<QueueManager<Data>> {
         if permits == 0 {
             permits = usize::MAX >> 4;
         }
@@ -101,9 +113,16 @@ impl<Data: QueueData> QueueManager<Data> {
         Arc::new(QueueManager {
             queue: Mutex::new(HashMap::new()),
             semaphore: Arc::new(Semaphore::new(permits)),
+            record_metrics,
         })
     }
 
+    fn set_queued_queries(&self, queue_len: usize) {
+        if self.record_metrics {
+            set_session_queued_queries(queue_len);
+        }
+    }
+
     /// The length of the queue.
     pub fn length(&self) -> usize {
         let queue = self.queue.lock();
@@ -120,13 +139,13 @@ impl<Data: QueueData> QueueManager<Data> {
         if let Some(inner) = queue.remove(&key) {
             let queue_len = queue.len();
             drop(queue);
-            set_session_queued_queries(queue_len);
+            self.set_queued_queries(queue_len);
             inner.data.exit_wait_pending(inner.instant.elapsed());
             inner.is_abort.store(true, Ordering::SeqCst);
             inner.waker.wake();
             true
         } else {
-            set_session_queued_queries(queue.len());
+            self.set_queued_queries(queue.len());
             false
         }
     }
@@ -150,12 +169,15 @@ impl<Data: QueueData> QueueManager<Data> {
                 Ok(v) => {
                     info!("finished acquiring from queue, length: {}", self.length());
 
-                    inc_session_running_acquired_queries();
-                    record_session_queue_acquire_duration_ms(
-                        start_time.elapsed().unwrap_or_default(),
-                    );
+                    if self.record_metrics {
+                        inc_session_running_acquired_queries();
+                        record_session_queue_acquire_duration_ms(
+                            start_time.elapsed().unwrap_or_default(),
+                        );
+                    }
                     Ok(v)
                 }
+                Err(e) if !self.record_metrics => Err(e),
                 Err(e) => {
                     match e.code() {
                         ErrorCode::ABORTED_QUERY => {
@@ -186,7 +208,7 @@ impl<Data: QueueData> QueueManager<Data> {
             queue.len()
         };
 
-        set_session_queued_queries(queue_len);
+        self.set_queued_queries(queue_len);
         key
     }
 
@@ -196,7 +218,7 @@ impl<Data: QueueData> QueueManager<Data> {
         let queue_len = queue.len();
 
         drop(queue);
-        set_session_queued_queries(queue_len);
+        self.set_queued_queries(queue_len);
         match inner {
             None => None,
             Some(inner) => {
@@ -210,11 +232,14 @@ impl<Data: QueueData> QueueManager<Data> {
 pub struct AcquireQueueGuard {
     #[allow(dead_code)]
     permit: Option<OwnedSemaphorePermit>,
+    record_metrics: bool,
+    #[allow(dead_code)]
+    workload_group: Option<Box<WorkloadGroupGuard>>,
 }
 
 impl Drop for AcquireQueueGuard {
     fn drop(&mut self) {
-        if self.permit.is_some() {
+        if self.permit.is_some() && self.record_metrics {
             dec_session_running_acquired_queries();
         }
     }
@@ -222,7 +247,17 @@ impl Drop for AcquireQueueGuard {
 
 impl AcquireQueueGuard {
     pub fn create(permit: Option<OwnedSemaphorePermit>) -> Self {
-        AcquireQueueGuard { permit }
+        AcquireQueueGuard {
+            permit,
+            record_metrics: true,
+            workload_group: None,
+        }
+    }
+
+    /// Hold the admission of the workload group until the query finished.
+    pub fn with_workload_group(mut self, guard: Option<WorkloadGroupGuard>) -> Self {
+        self.workload_group = guard.map(Box::new);
+        self
     }
 }
 
@@ -288,7 +323,11 @@ where T: Future<
                 }
 
                 Poll::Ready(match res {
-                    Ok(Ok(v)) => Ok(AcquireQueueGuard::create(Some(v))),
+                    Ok(Ok(v)) => Ok(AcquireQueueGuard {
+                        permit: Some(v),
+                        record_metrics: this.manager.record_metrics,
+                        workload_group: None,
+                    }),
                     Ok(Err(_)) => Err(ErrorCode::TokioError("acquire queue failure.")),
                     Err(_elapsed) => Err(ErrorCode::Timeout("query queuing timeout")),
                 })
@@ -314,6 +353,7 @@ where T: Future<
     }
 }
 
+#[derive(Clone)]
 pub struct QueryEntry {
     ctx: Arc<QueryContext>,
     pub query_id: String,
@@ -381,7 +381,7 @@ impl Session {
     pub fn get_temp_table_prefix(&self) -> Result<String> {
         let typ = self.typ.read().clone();
         let session_id = match typ {
-            SessionType::MySQL => self.id.clone(),
+            SessionType::MySQL | SessionType::Postgres => self.id.clone(),
             SessionType::HTTPQuery => {
                 if let Some(id) = self.get_client_session_id() {
                     id
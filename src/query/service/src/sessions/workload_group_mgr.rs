@@ -0,0 +1,277 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::MemStat;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_context::WorkloadGroupStatus;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::principal::DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT;
+use databend_common_users::UserApiProvider;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::sessions::AcquireQueueGuard;
+use crate::sessions::QueriesQueueManager;
+use crate::sessions::QueryContext;
+use crate::sessions::QueryEntry;
+
+/// How long a loaded workload group is reused before it is loaded from meta again, so that
+/// not every query of the group pays a round trip to meta. ALTER and DROP WORKLOAD GROUP on
+/// this node take effect at once, the other nodes see the change within this interval.
+const WORKLOAD_GROUP_RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The runtime resources of a workload group on the current node.
+pub struct WorkloadGroupResource {
+    pub meta: WorkloadGroup,
+    /// The admission queue of the group, gated by `max_concurrency`.
+    pub queue: Arc<QueriesQueueManager>,
+    /// The parent memory tracker of all the running queries of the group.
+    pub mem_stat: Arc<MemStat>,
+    loaded_at: Instant,
+    stale: AtomicBool,
+}
+
+impl WorkloadGroupResource {
+    fn create(meta: WorkloadGroup, previous: Option<&Arc<WorkloadGroupResource>>) -> Self {
+        // The queries admitted by the previous queue keep their permits until they finish.
+        let queue = match previous {
+            Some(previous) if previous.meta.max_concurrency == meta.max_concurrency => {
+                previous.queue.clone()
+            }
+            _ => QueriesQueueManager::create_without_metrics(
+                meta.max_concurrency.unwrap_or_default() as usize,
+            ),
+        };
+        let mem_stat = match previous {
+            Some(previous) => previous.mem_stat.clone(),
+            None => MemStat::create(format!("WorkloadGroupMemStat-{}", meta.name)),
+        };
+        mem_stat.set_limit(meta.memory_limit.unwrap_or_default() as i64);
+
+        WorkloadGroupResource {
+            meta,
+            queue,
+            mem_stat,
+            loaded_at: Instant::now(),
+            stale: AtomicBool::new(false),
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        !self.stale.load(Ordering::Relaxed)
+            && self.loaded_at.elapsed() < WORKLOAD_GROUP_RELOAD_INTERVAL
+    }
+
+    /// Scale a share of cpu by the weight of the group, relative to the default weight.
+    pub fn scale_by_cpu_weight(&self, value: u64) -> u64 {
+        let scaled = value.saturating_mul(self.meta.cpu_weight) / DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT;
+        scaled.max(1)
+    }
+}
+
+/// A query admitted by a workload group.
+pub struct WorkloadGroupQuery {
+    pub group: Arc<WorkloadGroupResource>,
+    /// The memory tracker of the query, limited by `query_memory_limit`.
+    pub mem_stat: Arc<MemStat>,
+}
+
+/// Holds the admission of a query in its workload group until the query finishes.
+pub struct WorkloadGroupGuard {
+    query_id: String,
+    #[allow(dead_code)]
+    queue_guard: AcquireQueueGuard,
+    manager: Arc<WorkloadGroupManager>,
+}
+
+impl Drop for WorkloadGroupGuard {
+    fn drop(&mut self) {
+        self.manager.queries.lock().remove(&self.query_id);
+    }
+}
+
+pub struct WorkloadGroupManager {
+    groups: Mutex<HashMap<String, Arc<WorkloadGroupResource>>>,
+    queries: Mutex<HashMap<String, Arc<WorkloadGroupQuery>>>,
+}
+
+impl WorkloadGroupManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Self::create());
+        Ok(())
+    }
+
+    pub fn create() -> Arc<WorkloadGroupManager> {
+        Arc::new(WorkloadGroupManager {
+            groups: Mutex::new(HashMap::new()),
+            queries: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupManager> {
+        GlobalInstance::get()
+    }
+
+    /// Resolve the workload group of the current query, see [`Self::bound_group`].
+    ///
+    /// The group is loaded from meta at most once per `WORKLOAD_GROUP_RELOAD_INTERVAL`.
+    #[async_backtrace::framed]
+    pub async fn resolve(
+        &self,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<Option<Arc<WorkloadGroupResource>>> {
+        let user = ctx.get_current_user()?;
+        let Some(name) = Self::bound_group(&user, ctx.get_current_role().as_ref()) else {
+            return Ok(None);
+        };
+
+        if let Some(group) = self.groups.lock().get(&name) {
+            if group.is_fresh() {
+                return Ok(Some(group.clone()));
+            }
+        }
+
+        let tenant = ctx.get_tenant();
+        let meta = match UserApiProvider::instance()
+            .get_workload_group(&tenant, &name)
+            .await
+        {
+            Ok(meta) => meta,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP => {
+                warn!(
+                    "workload group {} of user {} is not exist, skip it",
+                    name,
+                    user.identity().display()
+                );
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(Some(self.load(meta)))
+    }
+
+    /// The workload group bound to the user takes precedence over the group bound to the role.
+    pub fn bound_group(user: &UserInfo, role: Option<&RoleInfo>) -> Option<String> {
+        match user.option.workload_group() {
+            Some(name) => Some(name.clone()),
+            None => role.and_then(|r| r.workload_group.clone()),
+        }
+    }
+
+    /// Load the workload group from its meta. The running queries keep the resources they
+    /// were admitted with, the new queries are admitted by the new limits.
+    pub fn load(&self, meta: WorkloadGroup) -> Arc<WorkloadGroupResource> {
+        let mut groups = self.groups.lock();
+        let previous = groups.get(&meta.name);
+        if previous.map_or(true, |previous| previous.meta != meta) {
+            info!("load workload group: {:?}", meta);
+        }
+
+        let resource = Arc::new(WorkloadGroupResource::create(meta, previous));
+        groups.insert(resource.meta.name.clone(), resource.clone());
+        resource
+    }
+
+    /// Load the workload group from meta again on its next query, e.g. after it is altered.
+    pub fn invalidate(&self, name: &str) {
+        if let Some(group) = self.groups.lock().get(name) {
+            group.stale.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Wait in the queue of the workload group, and then register the query to the group.
+    #[async_backtrace::framed]
+    pub async fn acquire(
+        self: &Arc<Self>,
+        group: Arc<WorkloadGroupResource>,
+        entry: QueryEntry,
+    ) -> Result<WorkloadGroupGuard> {
+        let query_id = entry.query_id.clone();
+        let queue_guard = group.queue.acquire(entry).await?;
+
+        let mem_stat = MemStat::create_child(format!("QueryExecutionMemStat-{}", query_id), vec![
+            group.mem_stat.clone(),
+        ]);
+        mem_stat.set_limit(group.meta.query_memory_limit.unwrap_or_default() as i64);
+
+        self.queries.lock().insert(
+            query_id.clone(),
+            Arc::new(WorkloadGroupQuery { group, mem_stat }),
+        );
+
+        Ok(WorkloadGroupGuard {
+            query_id,
+            queue_guard,
+            manager: self.clone(),
+        })
+    }
+
+    /// Get the workload group of a running query.
+    pub fn get_query(&self, query_id: &str) -> Option<Arc<WorkloadGroupQuery>> {
+        self.queries.lock().get(query_id).cloned()
+    }
+
+    /// Remove a query from the queue of its workload group, returns false if it is not queued.
+    pub fn remove(&self, query_id: &str) -> bool {
+        let groups = self.groups.lock().values().cloned().collect::<Vec<_>>();
+        groups
+            .iter()
+            .any(|group| group.queue.remove(query_id.to_string()))
+    }
+
+    /// The ids of the queries waiting in the queues of workload groups.
+    pub fn queued_queries(&self) -> Vec<String> {
+        let groups = self.groups.lock().values().cloned().collect::<Vec<_>>();
+        groups
+            .iter()
+            .flat_map(|group| group.queue.list())
+            .map(|entry| entry.query_id.clone())
+            .collect()
+    }
+
+    pub fn status(&self) -> HashMap<String, WorkloadGroupStatus> {
+        let groups = self.groups.lock().clone();
+        let mut status = groups
+            .iter()
+            .map(|(name, group)| {
+                (name.clone(), WorkloadGroupStatus {
+                    running_queries: 0,
+                    queued_queries: group.queue.length() as u64,
+                    memory_usage: group.mem_stat.get_memory_usage(),
+                })
+            })
+            .collect::<HashMap<_, _>>();
+
+        for query in self.queries.lock().values() {
+            if let Some(group_status) = status.get_mut(&query.group.meta.name) {
+                group_status.running_queries += 1;
+            }
+        }
+        status
+    }
+}
@@ -430,6 +430,12 @@ async fn show_account_grants(
                     privileges.push(get_priv_str(&grant_entry));
                     grant_list.push(format!("{} TO {}", grant_entry, identity));
                 }
+                GrantObject::Secret(secret_name) => {
+                    object_name.push(secret_name.to_string());
+                    object_id.push(None);
+                    privileges.push(get_priv_str(&grant_entry));
+                    grant_list.push(format!("{} TO {}", grant_entry, identity));
+                }
                 GrantObject::Global => {
                     // grant all on *.* to a
                     object_name.push("*.*".to_string());
@@ -491,6 +497,15 @@ async fn show_account_grants(
                         privileges.push("OWNERSHIP".to_string());
                         grant_list.push(format!("GRANT OWNERSHIP ON UDF {} TO {}", name, identity));
                     }
+                    OwnershipObject::Secret { name } => {
+                        object_name.push(name.to_string());
+                        object_id.push(None);
+                        privileges.push("OWNERSHIP".to_string());
+                        grant_list.push(format!(
+                            "GRANT OWNERSHIP ON SECRET {} TO {}",
+                            name, identity
+                        ));
+                    }
                 }
             }
         }
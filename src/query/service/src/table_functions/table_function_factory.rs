@@ -28,6 +28,8 @@ use databend_common_storages_fuse::table_functions::FuseStatisticsFunc;
 use databend_common_storages_fuse::table_functions::FuseTimeTravelSizeFunc;
 use databend_common_storages_fuse::table_functions::FuseVacuumTemporaryTable;
 use databend_common_storages_fuse::table_functions::TableFunctionTemplate;
+use databend_common_storages_iceberg::IcebergManifestFunc;
+use databend_common_storages_iceberg::IcebergSnapshotFunc;
 use databend_common_storages_stream::stream_status_table_func::StreamStatusTable;
 use databend_storages_common_table_meta::table_id_ranges::SYS_TBL_FUC_ID_END;
 use databend_storages_common_table_meta::table_id_ranges::SYS_TBL_FUNC_ID_BEGIN;
@@ -304,6 +306,22 @@ impl TableFunctionFactory {
             ),
         );
 
+        creators.insert(
+            "iceberg_snapshot".to_string(),
+            (
+                next_id(),
+                Arc::new(TableFunctionTemplate::<IcebergSnapshotFunc>::create),
+            ),
+        );
+
+        creators.insert(
+            "iceberg_manifest".to_string(),
+            (
+                next_id(),
+                Arc::new(TableFunctionTemplate::<IcebergManifestFunc>::create),
+            ),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
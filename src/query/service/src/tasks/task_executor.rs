@@ -0,0 +1,231 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::principal::TaskInfo;
+use databend_common_meta_app::principal::TaskRunInfo;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_PUBLIC;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+use uuid::Uuid;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// The runs of the tasks are kept in the meta service for 7 days.
+const TASK_RUN_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Runs the task `root`, then the tasks which run after it, a task is triggered
+/// once all of its predecessors succeeded in this run of the graph.
+#[async_backtrace::framed]
+pub async fn execute_task_graph(tenant: &Tenant, root: TaskInfo) -> Result<()> {
+    let root_task_id = root.task_id;
+    let mut succeeded = HashSet::new();
+    let mut triggered = HashSet::from([root.task_name.clone()]);
+    let mut pending = VecDeque::from([root]);
+    while let Some(task) = pending.pop_front() {
+        if !execute_task(tenant, &task, root_task_id).await? {
+            continue;
+        }
+        succeeded.insert(task.task_name.clone());
+
+        for child in UserApiProvider::instance().get_tasks(tenant).await? {
+            if child.status == TaskStatus::Started
+                && child.after.contains(&task.task_name)
+                && child.after.iter().all(|name| succeeded.contains(name))
+                && triggered.insert(child.task_name.clone())
+            {
+                pending.push_back(child);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Executes the task with the privileges of its owner and records the run.
+///
+/// Returns whether the task succeeded, a task skipped by its `WHEN` condition
+/// is not recorded and is not considered succeeded.
+async fn execute_task(tenant: &Tenant, task: &TaskInfo, root_task_id: u64) -> Result<bool> {
+    let user_api = UserApiProvider::instance();
+    let session = create_task_session(task).await?;
+
+    let mut run = TaskRunInfo {
+        run_id: Uuid::new_v4().simple().to_string(),
+        task: task.clone(),
+        query_id: "".to_string(),
+        state: TaskRunState::Executing,
+        error_code: 0,
+        error_message: None,
+        root_task_id,
+        scheduled_at: Utc::now(),
+        completed_at: None,
+    };
+    let res = match check_condition(&session, task).await {
+        Ok(false) => {
+            info!(
+                "skip task {}, the condition {:?} is not met",
+                task.task_name, task.when_condition
+            );
+            return Ok(false);
+        }
+        Ok(true) => {
+            user_api
+                .upsert_task_run(tenant, run.clone(), TASK_RUN_TTL)
+                .await?;
+            run_statements(&session, task, &mut run.query_id).await
+        }
+        Err(cause) => Err(cause),
+    };
+
+    run.completed_at = Some(Utc::now());
+    match &res {
+        Ok(_) => run.state = TaskRunState::Succeeded,
+        Err(cause) => {
+            warn!("task {} failed: {:?}", task.task_name, cause);
+            run.state = TaskRunState::Failed;
+            run.error_code = cause.code() as i64;
+            run.error_message = Some(cause.message());
+        }
+    }
+    user_api.upsert_task_run(tenant, run, TASK_RUN_TTL).await?;
+
+    // Suspend the task if it failed too many times in a row.
+    let succeeded = res.is_ok();
+    user_api
+        .update_task(tenant, &task.task_name, true, |task| {
+            if succeeded {
+                task.consecutive_failures = 0;
+                return Ok(());
+            }
+            task.consecutive_failures += 1;
+            if let Some(num_failures) = task.suspend_task_after_num_failures {
+                if num_failures > 0 && task.consecutive_failures >= num_failures {
+                    info!(
+                        "suspend task {} after {} consecutive failures",
+                        task.task_name, task.consecutive_failures
+                    );
+                    task.status = TaskStatus::Suspended;
+                    task.next_scheduled_at = None;
+                    task.last_suspended_at = Some(Utc::now());
+                }
+            }
+            Ok(())
+        })
+        .await?;
+    Ok(succeeded)
+}
+
+async fn create_task_session(task: &TaskInfo) -> Result<Arc<Session>> {
+    let owner = if task.owner.is_empty() {
+        BUILTIN_ROLE_PUBLIC
+    } else {
+        task.owner.as_str()
+    };
+    let session = create_session(owner, "task", "TaskRun").await?;
+    let session_params = task
+        .session_params
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<HashMap<_, _>>();
+    session
+        .get_settings()
+        .set_batch_settings(&session_params, false)?;
+    Ok(session)
+}
+
+/// Creates a session of the background user `<tenant>-<cluster>-<user_suffix>`,
+/// which is granted the role `role` only, e.g. the user running tasks or pipes.
+pub(crate) async fn create_session(
+    role: &str,
+    user_suffix: &str,
+    session_type: &str,
+) -> Result<Arc<Session>> {
+    let config = GlobalConfig::instance();
+    let mut user = UserInfo::new_no_auth(
+        format!(
+            "{}-{}-{user_suffix}",
+            config.query.tenant_id.tenant_name(),
+            config.query.cluster_id
+        )
+        .as_str(),
+        "0.0.0.0",
+    );
+    user.grants.grant_role(role.to_string());
+
+    let session_manager = SessionManager::instance();
+    let session = session_manager
+        .create_session(SessionType::HTTPAPI(session_type.to_string()))
+        .await?;
+    let session = session_manager.register_session(session)?;
+    session
+        .set_authed_user(user, Some(role.to_string()))
+        .await?;
+    Ok(session)
+}
+
+async fn check_condition(session: &Arc<Session>, task: &TaskInfo) -> Result<bool> {
+    let Some(condition) = &task.when_condition else {
+        return Ok(true);
+    };
+    let blocks = run_query(session, &format!("SELECT {}", condition))
+        .await?
+        .1;
+    Ok(blocks
+        .first()
+        .and_then(|block| block.get_by_offset(0).value.index(0))
+        .is_some_and(|value| matches!(value, ScalarRef::Boolean(true))))
+}
+
+async fn run_statements(
+    session: &Arc<Session>,
+    task: &TaskInfo,
+    query_id: &mut String,
+) -> Result<()> {
+    for sql in task.statements() {
+        let (id, _) = run_query(session, &sql).await?;
+        *query_id = id;
+    }
+    Ok(())
+}
+
+async fn run_query(session: &Arc<Session>, sql: &str) -> Result<(String, Vec<DataBlock>)> {
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    Ok((ctx.get_id(), blocks))
+}
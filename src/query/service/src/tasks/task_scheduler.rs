@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_users::UserApiProvider;
+use log::info;
+use log::warn;
+
+use crate::locks::SchedulerLease;
+use crate::locks::TASK_SCHEDULER_LEASE;
+use crate::tasks::execute_task_graph;
+
+/// The scheduler sleeps until the earliest task is due, but at least `MIN_SCHEDULE_INTERVAL`.
+const MIN_SCHEDULE_INTERVAL: Duration = Duration::from_secs(1);
+/// The scheduler sleeps at most `MAX_SCHEDULE_INTERVAL`, so that the tasks created or
+/// resumed in the meantime are picked up.
+const MAX_SCHEDULE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Triggers the scheduled tasks of the tenant when cloud control is not configured.
+///
+/// Every node runs a scheduler, but only the one holding the scheduler lease in the meta
+/// service triggers the tasks in a round, so that a task is not triggered by several
+/// nodes at the same time. A run of a task graph holds the lease of its root task until
+/// it finishes, so the next run is skipped on all the nodes while it is executing.
+pub struct TaskScheduler {
+    conf: InnerConfig,
+}
+
+impl TaskScheduler {
+    pub fn create(conf: &InnerConfig) -> Self {
+        TaskScheduler { conf: conf.clone() }
+    }
+
+    pub fn start(conf: &InnerConfig) -> Result<()> {
+        if conf.query.cloud_control_grpc_server_address.is_some() {
+            // The tasks are scheduled by cloud control.
+            return Ok(());
+        }
+
+        let scheduler = TaskScheduler::create(conf);
+        databend_common_base::runtime::spawn(async move {
+            let mut interval = MIN_SCHEDULE_INTERVAL;
+            loop {
+                sleep(interval).await;
+                interval = match scheduler.schedule().await {
+                    Ok(next_scheduled_at) => Self::interval_until(next_scheduled_at),
+                    Err(cause) => {
+                        warn!("task scheduler failed to schedule tasks: {:?}", cause);
+                        MAX_SCHEDULE_INTERVAL
+                    }
+                };
+            }
+        });
+        info!("Task scheduler started");
+        Ok(())
+    }
+
+    fn interval_until(next_scheduled_at: Option<DateTime<Utc>>) -> Duration {
+        next_scheduled_at
+            .and_then(|time| (time - Utc::now()).to_std().ok())
+            .unwrap_or(MAX_SCHEDULE_INTERVAL)
+            .clamp(MIN_SCHEDULE_INTERVAL, MAX_SCHEDULE_INTERVAL)
+    }
+
+    /// Runs a round of the scheduler, it is skipped if another node holds the lease.
+    ///
+    /// Returns the time when the next task is due, if it is known.
+    #[async_backtrace::framed]
+    pub async fn schedule(&self) -> Result<Option<DateTime<Utc>>> {
+        let tenant = &self.conf.query.tenant_id;
+        let user_api = UserApiProvider::instance();
+        let now = Utc::now();
+        let (tasks, waiting): (Vec<_>, Vec<_>) = user_api
+            .get_tasks(tenant)
+            .await?
+            .into_iter()
+            .filter(|task| task.schedule.is_some() && task.status == TaskStatus::Started)
+            .partition(|task| !task.next_scheduled_at.is_some_and(|time| time > now));
+        let mut next_due = waiting
+            .iter()
+            .filter_map(|task| task.next_scheduled_at)
+            .min();
+        if tasks.is_empty() {
+            return Ok(next_due);
+        }
+
+        let Some(_lease) = SchedulerLease::try_acquire(tenant, TASK_SCHEDULER_LEASE).await? else {
+            return Ok(next_due);
+        };
+
+        for task in tasks {
+            let Some(schedule) = &task.schedule else {
+                continue;
+            };
+            let next_scheduled_at = schedule.next_run_after(now);
+            if next_scheduled_at.is_none() {
+                warn!("task {} has no upcoming schedule", task.task_name);
+            }
+            let updated = user_api
+                .update_task(tenant, &task.task_name, true, |task| {
+                    task.next_scheduled_at = next_scheduled_at;
+                    Ok(())
+                })
+                .await?;
+            if let Some(time) = next_scheduled_at {
+                next_due = Some(next_due.map_or(time, |due| due.min(time)));
+            }
+            // The task is dropped, or it is just resumed and waits for the next schedule.
+            if updated.is_none() || task.next_scheduled_at.is_none() {
+                continue;
+            }
+
+            // A run is skipped if the previous run of the graph has not finished,
+            // no matter which node is executing it.
+            let lease_name = format!("{}/{}", TASK_SCHEDULER_LEASE, task.task_name);
+            let Some(run_lease) = SchedulerLease::try_acquire(tenant, &lease_name).await? else {
+                info!(
+                    "skip task {}, the previous run is still executing",
+                    task.task_name
+                );
+                continue;
+            };
+            let tenant = tenant.clone();
+            databend_common_base::runtime::spawn(async move {
+                let task_name = task.task_name.clone();
+                if let Err(cause) = execute_task_graph(&tenant, task).await {
+                    warn!("failed to execute task {}: {:?}", task_name, cause);
+                }
+                drop(run_lease);
+            });
+        }
+        Ok(next_due)
+    }
+}
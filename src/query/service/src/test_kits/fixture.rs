@@ -346,6 +346,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
+            sequences: vec![],
         }
     }
 
@@ -370,6 +371,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            sequences: vec![],
         }
     }
 
@@ -405,6 +407,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            sequences: vec![],
         }
     }
 
@@ -440,6 +443,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            sequences: vec![],
         }
     }
 
@@ -484,6 +488,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            sequences: vec![],
         }
     }
 
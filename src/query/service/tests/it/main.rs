@@ -30,10 +30,12 @@ mod frame;
 mod metrics;
 mod parquet_rs;
 mod pipelines;
+mod pipes;
 mod servers;
 mod sessions;
 mod spillers;
 mod sql;
 mod storages;
 mod table_functions;
+mod tasks;
 mod tests;
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_PUBLIC;
+use databend_query::locks::SchedulerLease;
+use databend_query::locks::PIPE_SCHEDULER_LEASE;
+use databend_query::pipes::PipeScheduler;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pipe_scheduler() -> Result<()> {
+    let config = ConfigBuilder::create().build();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let count_rows = format!("SELECT count(*) FROM {db}.t");
+
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t(a int)"))
+        .await?;
+    fixture.execute_command("CREATE STAGE pipe_stage").await?;
+    fixture
+        .execute_command(
+            "COPY INTO @pipe_stage FROM (SELECT number::int AS a FROM numbers(10)) FILE_FORMAT = (TYPE = PARQUET)",
+        )
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE PIPE p AUTO_INGEST = TRUE AS COPY INTO {db}.t FROM @pipe_stage FILE_FORMAT = (TYPE = PARQUET)"
+        ))
+        .await?;
+
+    // The round is skipped while another node holds the scheduler lease.
+    let tenant = &config.query.tenant_id;
+    let lease = SchedulerLease::try_acquire(tenant, PIPE_SCHEDULER_LEASE).await?;
+    assert!(lease.is_some());
+    let other = SchedulerLease::try_acquire(tenant, PIPE_SCHEDULER_LEASE).await?;
+    assert!(other.is_none());
+    PipeScheduler::ingest(&config).await?;
+    assert_eq!(query_count(&fixture, &count_rows).await?, 0);
+
+    // The lease is released in background after it is dropped.
+    drop(lease);
+    for _ in 0..20 {
+        PipeScheduler::ingest(&config).await?;
+        if query_count(&fixture, &count_rows).await? > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    assert_eq!(query_count(&fixture, &count_rows).await?, 10);
+
+    // The files are loaded with the privileges of the pipe owner.
+    let now = Utc::now();
+    UserApiProvider::instance()
+        .add_pipe(
+            &config.query.tenant_id,
+            PipeInfo {
+                name: "p_public".to_string(),
+                copy_stmt: format!(
+                    "COPY INTO {db}.t FROM @pipe_stage FILE_FORMAT = (TYPE = PARQUET) FORCE = TRUE"
+                ),
+                auto_ingest: true,
+                execution_paused: false,
+                comment: "".to_string(),
+                created_on: now,
+                updated_on: now,
+                owner: BUILTIN_ROLE_PUBLIC.to_string(),
+            },
+            &CreateOption::Create,
+        )
+        .await?;
+    fixture.execute_command("DROP PIPE p").await?;
+    PipeScheduler::ingest(&config).await?;
+    let denied = query_count(
+        &fixture,
+        "SELECT count(*) FROM system.pipe_history WHERE pipe_name = 'p_public' AND error LIKE '%Permission denied%'",
+    )
+    .await?;
+    assert_eq!(denied, 1);
+    assert_eq!(query_count(&fixture, &count_rows).await?, 10);
+
+    Ok(())
+}
+
+async fn query_count(fixture: &TestFixture, sql: &str) -> Result<u64> {
+    let blocks: Vec<DataBlock> = fixture.execute_query(sql).await?.try_collect().await?;
+    match blocks
+        .first()
+        .and_then(|block| block.get_by_offset(0).value.index(0))
+    {
+        Some(ScalarRef::Number(NumberScalar::UInt64(n))) => Ok(n),
+        other => panic!("unexpected count result {:?}", other),
+    }
+}
@@ -17,6 +17,9 @@ use std::fs::File;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use databend_common_base::base::get_free_tcp_port;
@@ -32,11 +35,14 @@ use databend_common_users::CustomClaims;
 use databend_common_users::EnsureUser;
 use databend_query::servers::http::error::QueryError;
 use databend_query::servers::http::middleware::json_response;
+use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::query_route;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::ARROW_RESPONSE_METADATA_KEY;
+use databend_query::servers::http::v1::ARROW_STREAM_CONTENT_TYPE;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
@@ -1757,3 +1763,158 @@ async fn test_null_response() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_stream_response() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint()?;
+
+    let sql = "select number::int as a, number::string as b from numbers(3) order by a";
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 5}});
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, ARROW_STREAM_CONTENT_TYPE)
+        .typed_header(headers::Authorization::basic("root", ""))
+        .body(serde_json::to_vec(&json)?);
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some(ARROW_STREAM_CONTENT_TYPE),
+        "{:?}",
+        response.headers()
+    );
+
+    let body = response.into_body().into_vec().await.unwrap();
+    let reader = StreamReader::try_new(body.as_slice(), None)?;
+    let schema = reader.schema();
+    let result: QueryResponse =
+        serde_json::from_str(&schema.metadata()[ARROW_RESPONSE_METADATA_KEY])?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.schema.len(), 2);
+    assert!(result.data.is_empty());
+
+    let mut a = vec![];
+    let mut b = vec![];
+    for batch in reader {
+        let batch = batch?;
+        a.extend(
+            batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .values()
+                .iter()
+                .copied(),
+        );
+        b.extend(
+            batch
+                .column(1)
+                .as_string::<i64>()
+                .iter()
+                .map(|v| v.unwrap().to_string()),
+        );
+    }
+    assert_eq!(a, vec![0, 1, 2]);
+    assert_eq!(b, vec!["0", "1", "2"]);
+
+    // pages of a json query can not be fetched as arrow
+    let (status, result) = post_sql_to_endpoint(&ep, "select 1", 1).await?;
+    assert_eq!(status, StatusCode::OK);
+    let basic = headers::Authorization::basic("root", "");
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(make_page_uri(&result.id, 0).parse().unwrap())
+                .method(Method::GET)
+                .header(header::ACCEPT, ARROW_STREAM_CONTENT_TYPE)
+                .typed_header(basic)
+                .finish(),
+        )
+        .await
+        .unwrap_or_else(|err| err.into_response());
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    Ok(())
+}
+
+async fn post_arrow_query(ep: &EndpointType, sql: &str) -> Result<(QueryResponse, usize)> {
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 5}});
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, ARROW_STREAM_CONTENT_TYPE)
+        .typed_header(headers::Authorization::basic("root", ""))
+        .body(serde_json::to_vec(&json)?);
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some(ARROW_STREAM_CONTENT_TYPE),
+        "{:?}",
+        response.headers()
+    );
+
+    let body = response.into_body().into_vec().await.unwrap();
+    let reader = StreamReader::try_new(body.as_slice(), None)?;
+    let result: QueryResponse =
+        serde_json::from_str(&reader.schema().metadata()[ARROW_RESPONSE_METADATA_KEY])?;
+    let mut rows = 0;
+    for batch in reader {
+        rows += batch?.num_rows();
+    }
+    Ok((result, rows))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_stream_empty_response() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint()?;
+
+    // a failed query still returns an arrow stream, the error is in the metadata
+    let (result, rows) = post_arrow_query(&ep, "select * from t_not_exists").await?;
+    assert_eq!(result.state, ExecuteStateKind::Failed);
+    assert!(result.error.is_some());
+    assert_eq!(result.next_uri, Some(make_final_uri(&result.id)));
+    assert_eq!(rows, 0);
+
+    // a query without rows returns an arrow stream of no batches
+    let (result, rows) =
+        post_arrow_query(&ep, "select number from numbers(3) where number > 10").await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.state, ExecuteStateKind::Succeeded);
+    assert_eq!(result.schema.len(), 1);
+    assert_eq!(result.next_uri, Some(make_final_uri(&result.id)));
+    assert_eq!(rows, 0);
+
+    // so does the final page
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(make_final_uri(&result.id).parse().unwrap())
+                .method(Method::GET)
+                .header(header::ACCEPT, ARROW_STREAM_CONTENT_TYPE)
+                .typed_header(headers::Authorization::basic("root", ""))
+                .finish(),
+        )
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.content_type(), Some(ARROW_STREAM_CONTENT_TYPE));
+    let body = response.into_body().into_vec().await.unwrap();
+    let reader = StreamReader::try_new(body.as_slice(), None)?;
+    let result: QueryResponse =
+        serde_json::from_str(&reader.schema().metadata()[ARROW_RESPONSE_METADATA_KEY])?;
+    assert_eq!(result.next_uri, None);
+    assert_eq!(reader.count(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_query::servers::PostgresFederated;
+
+#[test]
+fn test_postgres_federated() -> Result<()> {
+    let federated = PostgresFederated::create();
+
+    //
+    {
+        let query = "select 1";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    // show parameter
+    {
+        let query = "SHOW TRANSACTION ISOLATION LEVEL";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+------------------+",
+                "| Column 0         |",
+                "+------------------+",
+                "| 'read committed' |",
+                "+------------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // ignored set
+    {
+        let query = "SET extra_float_digits = 3";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((schema, block)) = result {
+            assert_eq!(schema.num_fields(), 0);
+            assert_eq!(block.num_rows(), 0);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_postgres_rewrite() -> Result<()> {
+    let federated = PostgresFederated::create();
+
+    let query = federated.rewrite("select 1", Dialect::PostgreSQL)?;
+    assert!(query.is_none());
+
+    let query = federated.rewrite("SET TIME ZONE 'Asia/Shanghai'", Dialect::PostgreSQL)?;
+    assert_eq!(query.as_deref(), Some("SET timezone = 'Asia/Shanghai'"));
+
+    let query = federated.rewrite(
+        "SELECT relname FROM pg_catalog.pg_class WHERE relkind = 'r'",
+        Dialect::PostgreSQL,
+    )?;
+    assert!(query.is_some_and(|query| query.contains("system.tables")));
+
+    let query = federated.rewrite("SELECT current_schema()", Dialect::PostgreSQL)?;
+    assert_eq!(query.as_deref(), Some("SELECT current_database()"));
+
+    Ok(())
+}
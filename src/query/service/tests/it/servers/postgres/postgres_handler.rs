@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::TestFixture;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+
+use crate::tests::tls_constants::*;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_and_extended_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler =
+        PostgresHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let client = create_client(runnable_server.port(), "root", "").await?;
+
+    let messages = client
+        .simple_query("SELECT 1, 'a'; SELECT 2")
+        .await
+        .map_err(to_error_code)?;
+    let rows = messages
+        .iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some(row.get(0).unwrap_or_default().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec!["1", "2"]);
+
+    let rows = client
+        .query("SELECT $1 || 'b'", &[&"a"])
+        .await
+        .map_err(to_error_code)?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<_, String>(0), "ab");
+
+    let error = client.simple_query("SELECT * FROM not_exists").await;
+    assert_eq!(
+        error.err().and_then(|e| e.code().cloned()),
+        Some(tokio_postgres::error::SqlState::UNDEFINED_TABLE)
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_password_requires_tls() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("CREATE USER pg_user IDENTIFIED BY 'pg_password'")
+        .await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler =
+        PostgresHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let error = create_client(runnable_server.port(), "pg_user", "pg_password").await;
+    assert!(error.is_err());
+    assert!(
+        error
+            .err()
+            .unwrap()
+            .message()
+            .contains("must connect with SSL")
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_ssl_request() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let tls_config = MySQLTlsConfig::new(TEST_SERVER_CERT.to_string(), TEST_SERVER_KEY.to_string());
+    let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs, tls_config)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    assert_eq!(ssl_request(runnable_server.port()).await?, b'S');
+
+    let mut handler =
+        PostgresHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    assert_eq!(ssl_request(runnable_server.port()).await?, b'N');
+
+    Ok(())
+}
+
+// Send the `SSLRequest` and return the answer of the server.
+async fn ssl_request(port: u16) -> Result<u8> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+    stream.write_i32(8).await?;
+    stream.write_i32(80877103).await?;
+    Ok(stream.read_u8().await?)
+}
+
+async fn create_client(port: u16, user: &str, password: &str) -> Result<tokio_postgres::Client> {
+    let (client, connection) = tokio_postgres::connect(
+        &format!(
+            "host=127.0.0.1 port={} user={} password={}",
+            port, user, password
+        ),
+        NoTls,
+    )
+    .await
+    .map_err(to_error_code)?;
+    databend_common_base::runtime::spawn(async move {
+        let _ = connection.await;
+    });
+    Ok(client)
+}
+
+fn to_error_code(error: tokio_postgres::Error) -> ErrorCode {
+    ErrorCode::Internal(format!("postgres client error: {}", error))
+}
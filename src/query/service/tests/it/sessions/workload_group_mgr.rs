@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::principal::DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT;
+use databend_common_sql::Planner;
+use databend_query::sessions::QueryEntry;
+use databend_query::sessions::WorkloadGroupGuard;
+use databend_query::sessions::WorkloadGroupManager;
+use databend_query::sessions::WorkloadGroupResource;
+use databend_query::test_kits::TestFixture;
+
+const MB: i64 = 1024 * 1024;
+
+fn workload_group(name: &str) -> WorkloadGroup {
+    WorkloadGroup {
+        name: name.to_string(),
+        max_concurrency: None,
+        query_memory_limit: None,
+        memory_limit: None,
+        cpu_weight: DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT,
+        comment: String::new(),
+        created_on: Default::default(),
+        updated_on: Default::default(),
+    }
+}
+
+async fn query_entry(fixture: &TestFixture) -> Result<QueryEntry> {
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (_, extras) = planner.plan_sql("select * from numbers(1)").await?;
+    QueryEntry::create_entry(&ctx, &extras, true)
+}
+
+async fn acquire(
+    manager: &Arc<WorkloadGroupManager>,
+    group: &Arc<WorkloadGroupResource>,
+    entry: QueryEntry,
+) -> Result<WorkloadGroupGuard> {
+    tokio::time::timeout(
+        Duration::from_secs(5),
+        manager.acquire(group.clone(), entry),
+    )
+    .await
+    .expect("the query is not admitted by the workload group")
+}
+
+#[test]
+fn test_workload_group_binding() {
+    let mut role = RoleInfo::new("analyst");
+    let mut user = UserInfo::new_no_auth("u1", "%");
+    assert_eq!(WorkloadGroupManager::bound_group(&user, Some(&role)), None);
+
+    role.workload_group = Some("bi".to_string());
+    assert_eq!(WorkloadGroupManager::bound_group(&user, None), None);
+    assert_eq!(
+        WorkloadGroupManager::bound_group(&user, Some(&role)),
+        Some("bi".to_string())
+    );
+
+    // The group bound to the user takes precedence over the group bound to the role.
+    user.option.set_workload_group(Some("etl".to_string()));
+    assert_eq!(
+        WorkloadGroupManager::bound_group(&user, Some(&role)),
+        Some("etl".to_string())
+    );
+    assert_eq!(
+        WorkloadGroupManager::bound_group(&user, None),
+        Some("etl".to_string())
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_workload_group_max_concurrency() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let manager = WorkloadGroupManager::create();
+    let group = manager.load(WorkloadGroup {
+        max_concurrency: Some(1),
+        ..workload_group("etl")
+    });
+
+    let first = query_entry(&fixture).await?;
+    let first_id = first.query_id.clone();
+    let first_guard = acquire(&manager, &group, first).await?;
+
+    let second = query_entry(&fixture).await?;
+    let second_id = second.query_id.clone();
+    let handle = databend_common_base::runtime::spawn({
+        let manager = manager.clone();
+        let group = group.clone();
+        async move { acquire(&manager, &group, second).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(manager.queued_queries(), vec![second_id.clone()]);
+    assert!(manager.get_query(&first_id).is_some());
+    assert!(manager.get_query(&second_id).is_none());
+    let status = manager.status();
+    assert_eq!(status["etl"].running_queries, 1);
+    assert_eq!(status["etl"].queued_queries, 1);
+
+    // The queued query is admitted once the running query finishes.
+    drop(first_guard);
+    let _second_guard = handle.await.unwrap()?;
+    assert!(manager.queued_queries().is_empty());
+    assert!(manager.get_query(&first_id).is_none());
+    assert!(manager.get_query(&second_id).is_some());
+    assert_eq!(manager.status()["etl"].running_queries, 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_workload_group_memory_limit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let manager = WorkloadGroupManager::create();
+    let group = manager.load(WorkloadGroup {
+        query_memory_limit: Some(512 * MB as u64),
+        memory_limit: Some(768 * MB as u64),
+        ..workload_group("etl")
+    });
+
+    let first = query_entry(&fixture).await?;
+    let first_id = first.query_id.clone();
+    let _first_guard = acquire(&manager, &group, first).await?;
+    let second = query_entry(&fixture).await?;
+    let second_id = second.query_id.clone();
+    let _second_guard = acquire(&manager, &group, second).await?;
+
+    let first_query = manager.get_query(&first_id).unwrap();
+    let second_query = manager.get_query(&second_id).unwrap();
+
+    // A single query is limited by the query_memory_limit.
+    let mem_stat = &first_query.mem_stat;
+    assert!(mem_stat.record_memory::<true>(600 * MB, 600 * MB).is_err());
+    assert_eq!(group.mem_stat.get_memory_usage(), 0);
+    assert!(mem_stat.record_memory::<true>(400 * MB, 400 * MB).is_ok());
+
+    // The running queries of the group together are limited by the memory_limit.
+    let mem_stat = &second_query.mem_stat;
+    assert!(mem_stat.record_memory::<true>(400 * MB, 400 * MB).is_err());
+    assert!(mem_stat.record_memory::<true>(300 * MB, 300 * MB).is_ok());
+    assert_eq!(group.mem_stat.get_memory_usage(), 700 * MB);
+    assert_eq!(manager.status()["etl"].memory_usage, 700 * MB);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_workload_group_reload_with_running_queries() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let manager = WorkloadGroupManager::create();
+    let meta = WorkloadGroup {
+        max_concurrency: Some(1),
+        memory_limit: Some(512 * MB as u64),
+        ..workload_group("etl")
+    };
+    let group = manager.load(meta.clone());
+    // Loading the same meta again keeps the queue.
+    assert!(Arc::ptr_eq(&group.queue, &manager.load(meta).queue));
+
+    let first = query_entry(&fixture).await?;
+    let first_id = first.query_id.clone();
+    let first_guard = acquire(&manager, &group, first).await?;
+
+    // ALTER WORKLOAD GROUP etl SET max_concurrency = 2, memory_limit = '1GB'
+    let altered = manager.load(WorkloadGroup {
+        max_concurrency: Some(2),
+        memory_limit: Some(1024 * MB as u64),
+        ..workload_group("etl")
+    });
+    assert!(!Arc::ptr_eq(&group.queue, &altered.queue));
+    // The memory of the running queries is still accounted to the group.
+    assert!(Arc::ptr_eq(&group.mem_stat, &altered.mem_stat));
+
+    // The running query keeps the group it was admitted with.
+    let first_query = manager.get_query(&first_id).unwrap();
+    assert_eq!(first_query.group.meta.max_concurrency, Some(1));
+    assert!(Arc::ptr_eq(&first_query.group, &group));
+
+    // The new queries are admitted by the new limits.
+    let second = query_entry(&fixture).await?;
+    let second_id = second.query_id.clone();
+    let _second_guard = acquire(&manager, &altered, second).await?;
+    let third = query_entry(&fixture).await?;
+    let _third_guard = acquire(&manager, &altered, third).await?;
+    assert_eq!(manager.status()["etl"].running_queries, 3);
+
+    let second_query = manager.get_query(&second_id).unwrap();
+    assert!(
+        second_query
+            .mem_stat
+            .record_memory::<true>(768 * MB, 768 * MB)
+            .is_ok()
+    );
+
+    drop(first_guard);
+    assert!(manager.get_query(&first_id).is_none());
+    assert_eq!(manager.status()["etl"].running_queries, 2);
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::locks::SchedulerLease;
+use databend_query::locks::TASK_SCHEDULER_LEASE;
+use databend_query::tasks::TaskScheduler;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_execute_local_task_graph() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t(a int)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE TASK root_task SCHEDULE = 1 MINUTE AS INSERT INTO {db}.t VALUES(1)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE TASK child_task AFTER 'root_task' AS INSERT INTO {db}.t VALUES(2)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE TASK skipped_task AFTER 'root_task' WHEN 1 = 2 AS INSERT INTO {db}.t VALUES(3)"
+        ))
+        .await?;
+
+    // A task with a predecessor can not be scheduled.
+    let res = fixture
+        .execute_command("CREATE TASK err_task SCHEDULE = 1 MINUTE AFTER 'root_task' AS SELECT 1")
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::ILLEGAL_TASK);
+
+    let res = fixture
+        .execute_command("CREATE TASK root_task SCHEDULE = 1 MINUTE AS SELECT 1")
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::TASK_ALREADY_EXISTS);
+
+    // Only the started tasks are triggered after their predecessors.
+    fixture
+        .execute_command("ALTER TASK child_task RESUME")
+        .await?;
+    fixture
+        .execute_command("ALTER TASK skipped_task RESUME")
+        .await?;
+    fixture.execute_command("EXECUTE TASK root_task").await?;
+
+    expects_ok(
+        "check rows inserted by the tasks",
+        fixture
+            .execute_query(&format!("SELECT a FROM {db}.t ORDER BY a"))
+            .await,
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 1        |",
+            "| 2        |",
+            "+----------+",
+        ],
+    )
+    .await?;
+
+    expects_ok(
+        "check task history",
+        fixture
+            .execute_query("SELECT name, state FROM system.task_history ORDER BY name")
+            .await,
+        vec![
+            "+--------------+-------------+",
+            "| Column 0     | Column 1    |",
+            "+--------------+-------------+",
+            "| 'child_task' | 'SUCCEEDED' |",
+            "| 'root_task'  | 'SUCCEEDED' |",
+            "+--------------+-------------+",
+        ],
+    )
+    .await?;
+
+    expects_ok(
+        "check tasks",
+        fixture
+            .execute_query("SELECT name, state, schedule FROM system.tasks ORDER BY name")
+            .await,
+        vec![
+            "+----------------+-------------+----------------------+",
+            "| Column 0       | Column 1    | Column 2             |",
+            "+----------------+-------------+----------------------+",
+            "| 'child_task'   | 'Started'   | NULL                 |",
+            "| 'root_task'    | 'Suspended' | 'INTERVAL 60 SECOND' |",
+            "| 'skipped_task' | 'Started'   | NULL                 |",
+            "+----------------+-------------+----------------------+",
+        ],
+    )
+    .await?;
+
+    fixture.execute_command("DROP TASK child_task").await?;
+    fixture
+        .execute_command("DROP TASK IF EXISTS child_task")
+        .await?;
+    let res = fixture.execute_command("DESC TASK child_task").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_TASK);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_task_scheduler() -> Result<()> {
+    let config = ConfigBuilder::create().build();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let count_rows = format!("SELECT count(*) FROM {db}.t");
+
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t(a int)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE TASK scheduled_task SCHEDULE = 1 SECOND AS INSERT INTO {db}.t VALUES(1)"
+        ))
+        .await?;
+    fixture
+        .execute_command("ALTER TASK scheduled_task RESUME")
+        .await?;
+    let scheduler = TaskScheduler::create(&config);
+
+    // The round is skipped while another node holds the scheduler lease.
+    let lease = SchedulerLease::try_acquire(&config.query.tenant_id, TASK_SCHEDULER_LEASE).await?;
+    assert!(lease.is_some());
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    scheduler.schedule().await?;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(query_count(&fixture, &count_rows).await?, 0);
+
+    // The lease is released in background after it is dropped.
+    drop(lease);
+    for _ in 0..20 {
+        scheduler.schedule().await?;
+        if query_count(&fixture, &count_rows).await? > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    assert!(query_count(&fixture, &count_rows).await? > 0);
+
+    fixture
+        .execute_command("ALTER TASK scheduled_task SUSPEND")
+        .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_check_task_schedule() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    fixture
+        .execute_command("CREATE TASK cron_task SCHEDULE = USING CRON '0 30 9 * * *' 'Asia/Shanghai' AS SELECT 1")
+        .await?;
+
+    let res = fixture
+        .execute_command("CREATE TASK err_task SCHEDULE = USING CRON '0 30 9 * *' AS SELECT 1")
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SEMANTIC_ERROR);
+
+    let res = fixture
+        .execute_command(
+            "CREATE TASK err_task SCHEDULE = USING CRON '0 30 9 * * *' 'Mars/Olympus' AS SELECT 1",
+        )
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SEMANTIC_ERROR);
+
+    // The cron expression has no upcoming time.
+    let res = fixture
+        .execute_command("ALTER TASK cron_task SET SCHEDULE = USING CRON '0 0 0 1 1 * 2000'")
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SEMANTIC_ERROR);
+
+    Ok(())
+}
+
+async fn query_count(fixture: &TestFixture, sql: &str) -> Result<u64> {
+    let blocks: Vec<DataBlock> = fixture.execute_query(sql).await?.try_collect().await?;
+    match blocks
+        .first()
+        .and_then(|block| block.get_by_offset(0).value.index(0))
+    {
+        Some(ScalarRef::Number(NumberScalar::UInt64(n))) => Ok(n),
+        other => panic!("unexpected count result {:?}", other),
+    }
+}
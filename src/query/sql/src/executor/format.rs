@@ -47,8 +47,10 @@ use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::ExpressionScan;
 use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::FragmentKind;
+use crate::executor::physical_plans::GapFill;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationManipulate;
@@ -354,6 +356,8 @@ fn to_format_tree(
         PhysicalPlan::WindowPartition(plan) => {
             window_partition_to_format_tree(plan, metadata, profs)
         }
+        PhysicalPlan::MatchRecognize(plan) => match_recognize_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::GapFill(plan) => gap_fill_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RowFetch(plan) => row_fetch_to_format_tree(plan, metadata, profs),
@@ -774,6 +778,12 @@ fn table_scan_to_format_tree(
         .as_ref()
         .and_then(|extras| extras.agg_index.as_ref());
 
+    let vector_index = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_index.as_ref());
+
     let mut children = vec![
         FormatTreeNode::new(format!("table: {table_name}")),
         FormatTreeNode::new(format!(
@@ -803,6 +813,14 @@ fn table_scan_to_format_tree(
         }
     }
 
+    // Vector index
+    if let Some(vector_index) = vector_index {
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [name: {}, distance: {}, limit: {}]",
+            vector_index.index_name, vector_index.distance, vector_index.limit
+        )));
+    }
+
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -1308,6 +1326,89 @@ fn window_partition_to_format_tree(
     ))
 }
 
+fn match_recognize_to_format_tree(
+    plan: &MatchRecognize,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|&index| metadata.column(index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!(
+            "pattern: ({})",
+            plan.pattern.display(&plan.symbols)
+        )),
+        FormatTreeNode::new(format!(
+            "rows per match: {}",
+            if plan.all_rows { "ALL ROWS" } else { "ONE ROW" }
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "MatchRecognize".to_string(),
+        children,
+    ))
+}
+
+fn gap_fill_to_format_tree(
+    plan: &GapFill,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|&index| metadata.column(index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!(
+            "time column: {}",
+            metadata.column(plan.time_column).name()
+        )),
+        FormatTreeNode::new(format!("bucket width: {}", plan.width)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "GapFill".to_string(),
+        children,
+    ))
+}
+
 fn limit_to_format_tree(
     plan: &Limit,
     metadata: &Metadata,
@@ -1431,6 +1532,8 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Asof => "AsofJoin".to_string(),
+            RangeJoinType::Spatial => "SpatialJoin".to_string(),
         },
         children,
     ))
@@ -1606,6 +1709,18 @@ fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<S
         );
     }
 
+    // vector index pruning status.
+    if info.pruning_stats.blocks_vector_index_pruning_before > 0 {
+        if !blocks_pruning_description.is_empty() {
+            blocks_pruning_description += ", ";
+        }
+        blocks_pruning_description += &format!(
+            "vector pruning: {} to {}",
+            info.pruning_stats.blocks_vector_index_pruning_before,
+            info.pruning_stats.blocks_vector_index_pruning_after
+        );
+    }
+
     // Combine segment pruning and blocks pruning descriptions if any
     if info.pruning_stats.segments_range_pruning_before > 0
         || !blocks_pruning_description.is_empty()
@@ -55,8 +55,10 @@ use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::ExpressionScan;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::GapFill;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::ProjectSet;
@@ -86,6 +88,8 @@ pub enum PhysicalPlan {
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
     Window(Window),
+    MatchRecognize(MatchRecognize),
+    GapFill(GapFill),
     Sort(Sort),
     WindowPartition(WindowPartition),
     Limit(Limit),
@@ -206,6 +210,16 @@ impl PhysicalPlan {
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::MatchRecognize(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::GapFill(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::Sort(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -421,6 +435,8 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(v) => v.plan_id,
             PhysicalPlan::Window(v) => v.plan_id,
             PhysicalPlan::WindowPartition(v) => v.plan_id,
+            PhysicalPlan::MatchRecognize(v) => v.plan_id,
+            PhysicalPlan::GapFill(v) => v.plan_id,
             PhysicalPlan::Sort(v) => v.plan_id,
             PhysicalPlan::Limit(v) => v.plan_id,
             PhysicalPlan::RowFetch(v) => v.plan_id,
@@ -476,6 +492,8 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Window(plan) => plan.output_schema(),
             PhysicalPlan::WindowPartition(plan) => plan.output_schema(),
+            PhysicalPlan::MatchRecognize(plan) => plan.output_schema(),
+            PhysicalPlan::GapFill(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
             PhysicalPlan::RowFetch(plan) => plan.output_schema(),
@@ -538,6 +556,8 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
             PhysicalPlan::Window(_) => "Window".to_string(),
             PhysicalPlan::WindowPartition(_) => "WindowPartition".to_string(),
+            PhysicalPlan::MatchRecognize(_) => "MatchRecognize".to_string(),
+            PhysicalPlan::GapFill(_) => "GapFill".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
             PhysicalPlan::RowFetch(_) => "RowFetch".to_string(),
@@ -601,6 +621,8 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::WindowPartition(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::MatchRecognize(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::GapFill(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::RowFetch(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -666,6 +688,8 @@ impl PhysicalPlan {
             PhysicalPlan::EvalScalar(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Window(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::WindowPartition(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::MatchRecognize(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::GapFill(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Sort(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Limit(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Exchange(plan) => plan.input.try_find_single_data_source(),
@@ -825,6 +849,10 @@ impl PhysicalPlan {
 
                 format!("partition by {}, order by {}", partition_by, order_by)
             }
+            PhysicalPlan::MatchRecognize(v) => {
+                format!("pattern ({})", v.pattern.display(&v.symbols))
+            }
+            PhysicalPlan::GapFill(v) => format!("bucket width {}", v.width),
             PhysicalPlan::RowFetch(v) => {
                 let table_schema = v.source.source_info.schema();
                 let projected_schema = v.cols_to_fetch.project_schema(&table_schema);
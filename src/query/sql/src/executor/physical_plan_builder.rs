@@ -102,6 +102,14 @@ impl PhysicalPlanBuilder {
             RelOperator::Window(window) => {
                 self.build_window(s_expr, window, required, stat_info).await
             }
+            RelOperator::MatchRecognize(match_recognize) => {
+                self.build_match_recognize(s_expr, match_recognize, required, stat_info)
+                    .await
+            }
+            RelOperator::GapFill(gap_fill) => {
+                self.build_gap_fill(s_expr, gap_fill, required, stat_info)
+                    .await
+            }
             RelOperator::Sort(sort) => self.build_sort(s_expr, sort, required, stat_info).await,
             RelOperator::Limit(limit) => self.build_limit(s_expr, limit, required, stat_info).await,
             RelOperator::Exchange(exchange) => {
@@ -48,8 +48,10 @@ use crate::executor::physical_plans::Exchange;
 use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::GapFill;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MatchRecognize;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationSource;
@@ -81,6 +83,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Window(plan) => self.replace_window(plan),
             PhysicalPlan::WindowPartition(plan) => self.replace_window_partition(plan),
+            PhysicalPlan::MatchRecognize(plan) => self.replace_match_recognize(plan),
+            PhysicalPlan::GapFill(plan) => self.replace_gap_fill(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
             PhysicalPlan::RowFetch(plan) => self.replace_row_fetch(plan),
@@ -246,6 +250,38 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_match_recognize(&mut self, plan: &MatchRecognize) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            partition_by: plan.partition_by.clone(),
+            symbols: plan.symbols.clone(),
+            pattern: plan.pattern.clone(),
+            measures: plan.measures.clone(),
+            all_rows: plan.all_rows,
+            skip_to_next_row: plan.skip_to_next_row,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_gap_fill(&mut self, plan: &GapFill) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::GapFill(GapFill {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            partition_by: plan.partition_by.clone(),
+            time_column: plan.time_column,
+            width: plan.width,
+            start: plan.start,
+            end: plan.end,
+            columns: plan.columns.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_hash_join(&mut self, plan: &HashJoin) -> Result<PhysicalPlan> {
         let build = self.replace(&plan.build)?;
         let probe = self.replace(&plan.probe)?;
@@ -685,6 +721,12 @@ impl PhysicalPlan {
                 PhysicalPlan::WindowPartition(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MatchRecognize(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::GapFill(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Sort(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
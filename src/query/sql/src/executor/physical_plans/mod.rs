@@ -33,9 +33,11 @@ mod physical_exchange_sink;
 mod physical_exchange_source;
 mod physical_expression_scan;
 mod physical_filter;
+mod physical_gap_fill;
 mod physical_hash_join;
 mod physical_join;
 mod physical_limit;
+mod physical_match_recognize;
 mod physical_materialized_cte;
 mod physical_multi_table_insert;
 mod physical_mutation;
@@ -81,9 +83,11 @@ pub use physical_exchange_sink::ExchangeSink;
 pub use physical_exchange_source::ExchangeSource;
 pub use physical_expression_scan::ExpressionScan;
 pub use physical_filter::Filter;
+pub use physical_gap_fill::GapFill;
 pub use physical_hash_join::HashJoin;
 pub use physical_join::PhysicalJoinType;
 pub use physical_limit::Limit;
+pub use physical_match_recognize::MatchRecognize;
 pub use physical_materialized_cte::MaterializedCte;
 pub use physical_multi_table_insert::*;
 pub use physical_mutation::*;
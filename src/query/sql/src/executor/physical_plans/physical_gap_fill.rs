@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::GapFillColumn;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GapFill {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub partition_by: Vec<IndexType>,
+    pub time_column: IndexType,
+    pub width: months_days_micros,
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub columns: Vec<GapFillColumn>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl GapFill {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let fields = self
+            .columns
+            .iter()
+            .map(|column| DataField::new(&column.index.to_string(), column.return_type.clone()))
+            .collect::<Vec<_>>();
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_gap_fill(
+        &mut self,
+        s_expr: &SExpr,
+        gap_fill: &crate::plans::GapFill,
+        _required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns, every output column is computed from its input column.
+        let required = gap_fill.used_columns();
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        Ok(PhysicalPlan::GapFill(GapFill {
+            plan_id: 0,
+            input: Box::new(input),
+            partition_by: gap_fill.partition_by.clone(),
+            time_column: gap_fill.time_column,
+            width: gap_fill.width,
+            start: gap_fill.start,
+            end: gap_fill.end,
+            columns: gap_fill.columns.clone(),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -32,10 +32,18 @@ pub enum PhysicalJoinType {
     Hash,
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
+    Asof,
+    // The arg is the index of the spatial predicate in non-equi conditions
+    SpatialJoin(usize),
 }
 
 // Choose physical join type by join conditions
 pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+    if matches!(join.join_type, JoinType::Asof | JoinType::LeftAsof) {
+        // The match condition of asof join is the only non-equi condition, checked by binder
+        return Ok(PhysicalJoinType::Asof);
+    }
+
     if !join.equi_conditions.is_empty() {
         // Contain equi condition, use hash join
         return Ok(PhysicalJoinType::Hash);
@@ -46,8 +54,13 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         return Ok(PhysicalJoinType::Hash);
     }
 
-    let left_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
-    let right_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+    let probe_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+    let build_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+    if let Some(index) = join.spatial_join_condition(&probe_prop, &build_prop) {
+        return Ok(PhysicalJoinType::SpatialJoin(index));
+    }
+
+    let (left_prop, right_prop) = (build_prop, probe_prop);
     let mut range_conditions = vec![];
     let mut other_conditions = vec![];
     for condition in join.non_equi_conditions.iter() {
@@ -168,6 +181,14 @@ impl PhysicalPlanBuilder {
                 self.build_range_join(s_expr, left_required, right_required, range, other)
                     .await
             }
+            PhysicalJoinType::Asof => {
+                self.build_asof_join(join, s_expr, left_required, right_required)
+                    .await
+            }
+            PhysicalJoinType::SpatialJoin(index) => {
+                self.build_spatial_join(join, s_expr, left_required, right_required, index)
+                    .await
+            }
         }
     }
 }
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::MatchMeasure;
+use crate::plans::MatchSymbol;
+use crate::plans::RowPattern;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchRecognize {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub partition_by: Vec<IndexType>,
+    pub symbols: Vec<MatchSymbol>,
+    pub pattern: RowPattern,
+    pub measures: Vec<MatchMeasure>,
+    pub all_rows: bool,
+    pub skip_to_next_row: bool,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MatchRecognize {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(input_schema.fields().len() + self.measures.len());
+        fields.extend_from_slice(input_schema.fields());
+        for measure in self.measures.iter() {
+            fields.push(DataField::new(
+                &measure.index.to_string(),
+                measure.return_type.clone(),
+            ));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_match_recognize(
+        &mut self,
+        s_expr: &SExpr,
+        match_recognize: &crate::plans::MatchRecognize,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        for measure in match_recognize.measures.iter() {
+            required.remove(&measure.index);
+        }
+        required.extend(match_recognize.used_columns());
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        Ok(PhysicalPlan::MatchRecognize(MatchRecognize {
+            plan_id: 0,
+            input: Box::new(input),
+            partition_by: match_recognize.partition_by.clone(),
+            symbols: match_recognize.symbols.clone(),
+            pattern: match_recognize.pattern.clone(),
+            measures: match_recognize.measures.clone(),
+            all_rows: match_recognize.all_rows,
+            skip_to_next_row: match_recognize.skip_to_next_row,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
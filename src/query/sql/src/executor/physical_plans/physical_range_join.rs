@@ -15,6 +15,7 @@
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
@@ -29,6 +30,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -44,7 +46,7 @@ pub struct RangeJoin {
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    // Now only support inner join, will support left/right join later
+    // Inner join, or asof/left asof join
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -55,7 +57,16 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        let right_fields = self.right.output_schema()?.fields().clone();
+        if self.join_type == JoinType::LeftAsof {
+            fields.extend(
+                right_fields
+                    .iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(right_fields);
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -64,13 +75,19 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // The conditions are the equi conditions ("eq") followed by the match condition
+    Asof,
+    // The condition is the geometries of both sides and the spatial predicate, the right side
+    // is indexed by the bounding boxes of its geometries
+    Spatial,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte", "eq" for the equi conditions of asof join,
+    // or the spatial predicate of spatial join
     pub operator: String,
 }
 
@@ -140,6 +157,133 @@ impl PhysicalPlanBuilder {
             stat_info: Some(self.build_plan_stat_info(s_expr)?),
         }))
     }
+
+    pub async fn build_asof_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let mut conditions = Vec::with_capacity(join.equi_conditions.len() + 1);
+        for condition in join.equi_conditions.iter() {
+            let (left, right) = cast_to_common_type(&condition.left, &condition.right)?;
+            conditions.push(RangeJoinCondition {
+                left_expr: resolve_scalar(&left, &left_schema)?,
+                right_expr: resolve_scalar(&right, &right_schema)?,
+                operator: "eq".to_string(),
+            });
+        }
+        let match_condition = join.non_equi_conditions.first().ok_or_else(|| {
+            ErrorCode::Internal("Asof join must have a match condition".to_string())
+        })?;
+        conditions.push(resolve_range_condition(
+            match_condition,
+            &left_schema,
+            &right_schema,
+            &left_prop,
+            &right_prop,
+        )?);
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: 0,
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions,
+            other_conditions: vec![],
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+
+    pub async fn build_spatial_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        condition_index: usize,
+    ) -> Result<PhysicalPlan> {
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+        let merged_schema = DataSchemaRefExt::create(
+            left_schema
+                .fields()
+                .iter()
+                .chain(right_schema.fields())
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+
+        let ScalarExpr::FunctionCall(func) = &join.non_equi_conditions[condition_index] else {
+            unreachable!()
+        };
+        let (mut left, mut right) = (&func.arguments[0], &func.arguments[1]);
+        if matches!(
+            JoinPredicate::new(left, &left_prop, &right_prop),
+            JoinPredicate::Right(_)
+        ) {
+            std::mem::swap(&mut left, &mut right);
+        }
+        let condition = RangeJoinCondition {
+            left_expr: resolve_scalar(left, &left_schema)?,
+            right_expr: resolve_scalar(right, &right_schema)?,
+            operator: func.func_name.clone(),
+        };
+
+        // The bounding box index only finds the candidates, the spatial predicate
+        // is still evaluated with the other conditions.
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: 0,
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions: vec![condition],
+            other_conditions: join
+                .non_equi_conditions
+                .iter()
+                .map(|scalar| resolve_scalar(scalar, &merged_schema))
+                .collect::<Result<_>>()?,
+            join_type: JoinType::Inner,
+            range_join_type: RangeJoinType::Spatial,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
+// Try to find common type for left_expr/right_expr
+fn cast_to_common_type(arg1: &ScalarExpr, arg2: &ScalarExpr) -> Result<(ScalarExpr, ScalarExpr)> {
+    let arg1_data_type = arg1.data_type()?;
+    let arg2_data_type = arg2.data_type()?;
+    if arg1_data_type.eq(&arg2_data_type) {
+        return Ok((arg1.clone(), arg2.clone()));
+    }
+    let common_type = common_super_type(
+        arg1_data_type.clone(),
+        arg2_data_type.clone(),
+        &BUILTIN_FUNCTIONS.default_cast_rules,
+    )
+    .ok_or_else(|| {
+        ErrorCode::IllegalDataType(format!(
+            "Cannot find common type for {arg1_data_type} and {arg2_data_type}"
+        ))
+    })?;
+    Ok((wrap_cast(arg1, &common_type), wrap_cast(arg2, &common_type)))
 }
 
 fn resolve_range_condition(
@@ -154,25 +298,7 @@ fn resolve_range_condition(
             let mut left = None;
             let mut right = None;
             let mut opposite = false;
-            let mut arg1 = func.arguments[0].clone();
-            let mut arg2 = func.arguments[1].clone();
-            // Try to find common type for left_expr/right_expr
-            let arg1_data_type = arg1.data_type()?;
-            let arg2_data_type = arg2.data_type()?;
-            if arg1_data_type.ne(&arg2_data_type) {
-                let common_type = common_super_type(
-                    arg1_data_type.clone(),
-                    arg2_data_type.clone(),
-                    &BUILTIN_FUNCTIONS.default_cast_rules,
-                )
-                .ok_or_else(|| {
-                    ErrorCode::IllegalDataType(format!(
-                        "Cannot find common type for {arg1_data_type} and {arg2_data_type}"
-                    ))
-                })?;
-                arg1 = wrap_cast(&arg1, &common_type);
-                arg2 = wrap_cast(&arg2, &common_type);
-            };
+            let (arg1, arg2) = cast_to_common_type(&func.arguments[0], &func.arguments[1])?;
             for (idx, arg) in [arg1, arg2].iter().enumerate() {
                 let join_predicate = JoinPredicate::new(arg, left_prop, right_prop);
                 match join_predicate {
@@ -80,6 +80,18 @@ impl Binder {
                 alias,
             } => self.bind_location(bind_context, location, options, alias),
             TableReference::Join { join, .. } => self.bind_join(bind_context, join),
+            TableReference::MatchRecognize {
+                span,
+                table,
+                match_recognize,
+                alias,
+            } => self.bind_match_recognize(bind_context, span, table, match_recognize, alias),
+            TableReference::GapFill {
+                span: _,
+                table,
+                gap_fill,
+                alias,
+            } => self.bind_gap_fill(bind_context, table, gap_fill, alias),
         }
     }
 }
@@ -0,0 +1,275 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::GapFill;
+use databend_common_ast::ast::GapFillMethod;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::utils::date_helper::TimeBucket;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::wrap_cast;
+use crate::binder::Binder;
+use crate::binder::ColumnBinding;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::GapFillColumn;
+use crate::plans::GapFillKind;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::BindContext;
+use crate::TypeChecker;
+
+impl Binder {
+    /// Bind `<table> GAP_FILL (...)`.
+    ///
+    /// The table is sorted by the window partition operator on the partition keys and
+    /// the time column, then `GapFill` replaces the time column with the start of its
+    /// bucket and fills the missing buckets. Every column of the table is replaced by
+    /// an output column, which is nullable unless it is a partition key.
+    pub(crate) fn bind_gap_fill(
+        &mut self,
+        bind_context: &mut BindContext,
+        table: &TableReference,
+        gap_fill: &GapFill,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let (child, input_context) = self.bind_table_reference(bind_context, table)?;
+
+        // 1. Resolve the columns.
+        let time_column = self.resolve_gap_fill_column(&input_context, &gap_fill.time_column)?;
+        if !time_column
+            .data_type
+            .remove_nullable()
+            .is_date_or_date_time()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "Time column {} of GAP_FILL must be DATE or TIMESTAMP, but got {}",
+                time_column.column_name, time_column.data_type
+            ))
+            .set_span(gap_fill.time_column.span));
+        }
+        let mut kinds = vec![(time_column.index, GapFillKind::Time)];
+        let mut partition_by = Vec::with_capacity(gap_fill.partition_by.len());
+        for ident in gap_fill.partition_by.iter() {
+            let column = self.resolve_gap_fill_column(&input_context, ident)?;
+            partition_by.push(column.clone());
+            kinds.push((column.index, GapFillKind::Partition));
+        }
+        for fill in gap_fill.fill.iter() {
+            let column = self.resolve_gap_fill_column(&input_context, &fill.column)?;
+            let kind = match fill.method {
+                GapFillMethod::Locf => GapFillKind::Locf,
+                GapFillMethod::Interpolate => {
+                    if !column.data_type.remove_nullable().is_numeric() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "GAP_FILL can only interpolate numbers, but column {} is {}",
+                            column.column_name, column.data_type
+                        ))
+                        .set_span(fill.column.span));
+                    }
+                    GapFillKind::Interpolate
+                }
+            };
+            kinds.push((column.index, kind));
+        }
+        for (i, ident) in gap_fill
+            .partition_by
+            .iter()
+            .chain(gap_fill.fill.iter().map(|fill| &fill.column))
+            .enumerate()
+        {
+            let index = kinds[i + 1].0;
+            if kinds[..i + 1].iter().any(|(other, _)| *other == index) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Column {} is used more than once in GAP_FILL",
+                    self.normalize_identifier(ident).name
+                ))
+                .set_span(ident.span));
+            }
+        }
+
+        // 2. Evaluate the bucket width and the range.
+        let width = match self.fold_gap_fill_constant(&gap_fill.width, &DataType::Interval)? {
+            Scalar::Interval(width) => width,
+            _ => {
+                return Err(
+                    ErrorCode::SemanticError("Bucket width of GAP_FILL can not be NULL")
+                        .set_span(gap_fill.width.span()),
+                );
+            }
+        };
+        let tz = self.ctx.get_function_context()?.tz;
+        TimeBucket::try_create(width, None, tz)
+            .map_err(|err| ErrorCode::SemanticError(err).set_span(gap_fill.width.span()))?;
+        let start = gap_fill
+            .start
+            .as_ref()
+            .map(|expr| self.fold_gap_fill_timestamp(expr))
+            .transpose()?;
+        let end = gap_fill
+            .end
+            .as_ref()
+            .map(|expr| self.fold_gap_fill_timestamp(expr))
+            .transpose()?;
+
+        // 3. Sort the table by the partition keys and the time column.
+        let default_nulls_first = self.ctx.get_settings().get_nulls_first();
+        let sort_items = partition_by
+            .iter()
+            .chain(std::iter::once(&time_column))
+            .map(|column| SortItem {
+                index: column.index,
+                asc: true,
+                nulls_first: default_nulls_first(true),
+            })
+            .collect();
+        let window_partition = partition_by
+            .iter()
+            .map(|column| ScalarItem {
+                scalar: BoundColumnRef {
+                    span: None,
+                    column: column.clone(),
+                }
+                .into(),
+                index: column.index,
+            })
+            .collect();
+        let sort = Sort {
+            items: sort_items,
+            limit: None,
+            after_exchange: None,
+            pre_projection: None,
+            window_partition,
+        };
+        let child = SExpr::create_unary(Arc::new(sort.into()), Arc::new(child));
+
+        // 4. Replace the columns of the table with the output columns.
+        let mut output_context = input_context.clone();
+        let mut columns = Vec::with_capacity(input_context.columns.len());
+        for (input, output) in input_context
+            .columns
+            .iter()
+            .zip(output_context.columns.iter_mut())
+        {
+            let kind = kinds
+                .iter()
+                .find(|(index, _)| *index == input.index)
+                .map_or(GapFillKind::Null, |(_, kind)| *kind);
+            let return_type = match kind {
+                GapFillKind::Time if input.data_type.is_nullable() => {
+                    DataType::Timestamp.wrap_nullable()
+                }
+                GapFillKind::Time => DataType::Timestamp,
+                GapFillKind::Partition => *input.data_type.clone(),
+                _ => input.data_type.wrap_nullable(),
+            };
+            let column = self.create_derived_column_binding(
+                input.column_name.clone(),
+                return_type.clone(),
+                None,
+            );
+            columns.push(GapFillColumn {
+                index: column.index,
+                arg: input.index,
+                kind,
+                return_type: return_type.clone(),
+            });
+            output.index = column.index;
+            output.data_type = Box::new(return_type);
+            output.table_index = None;
+        }
+        let plan = crate::plans::GapFill {
+            partition_by: partition_by.iter().map(|column| column.index).collect(),
+            time_column: time_column.index,
+            width,
+            start,
+            end,
+            columns,
+        };
+        let s_expr = SExpr::create_unary(Arc::new(plan.into()), Arc::new(child));
+
+        if let Some(alias) = alias {
+            output_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        Ok((s_expr, output_context))
+    }
+
+    fn resolve_gap_fill_column(
+        &self,
+        bind_context: &BindContext,
+        ident: &Identifier,
+    ) -> Result<ColumnBinding> {
+        let name = self.normalize_identifier(ident).name;
+        let mut columns = bind_context
+            .columns
+            .iter()
+            .filter(|column| column.column_name == name);
+        match (columns.next(), columns.next()) {
+            (Some(column), None) => Ok(column.clone()),
+            (Some(_), Some(_)) => Err(ErrorCode::SemanticError(format!(
+                "Column {name} of GAP_FILL is ambiguous"
+            ))
+            .set_span(ident.span)),
+            (None, _) => Err(ErrorCode::SemanticError(format!(
+                "Column {name} of GAP_FILL does not exist"
+            ))
+            .set_span(ident.span)),
+        }
+    }
+
+    fn fold_gap_fill_timestamp(&self, expr: &Expr) -> Result<i64> {
+        match self.fold_gap_fill_constant(expr, &DataType::Timestamp)? {
+            Scalar::Timestamp(ts) => Ok(ts),
+            _ => Err(
+                ErrorCode::SemanticError("Range of GAP_FILL can not be NULL").set_span(expr.span()),
+            ),
+        }
+    }
+
+    fn fold_gap_fill_constant(&self, expr: &Expr, data_type: &DataType) -> Result<Scalar> {
+        let mut type_checker = TypeChecker::try_create(
+            &mut BindContext::new(),
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+        )?;
+        let box (scalar, _) = type_checker.resolve(expr)?;
+        let scalar = wrap_cast(&scalar, data_type);
+        let (new_expr, _) = ConstantFolder::fold(
+            &scalar.as_expr()?,
+            &self.ctx.get_function_context()?,
+            &BUILTIN_FUNCTIONS,
+        );
+        match new_expr {
+            databend_common_expression::Expr::Constant { scalar, .. } => Ok(scalar),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "Argument {expr} of GAP_FILL must be a constant"
+            ))
+            .set_span(expr.span())),
+        }
+    }
+}
@@ -101,12 +101,14 @@ impl Binder {
             &right_column_bindings,
             &join.op,
             &join.condition,
+            join.match_condition.as_deref(),
         )?;
 
         let join_conditions = self.generate_join_condition(
             &mut bind_context,
             &join.op,
             &join.condition,
+            join.match_condition.as_deref(),
             &left_column_bindings,
             &right_column_bindings,
         )?;
@@ -144,6 +146,7 @@ impl Binder {
             &right_context.columns,
             &join_op,
             &join_condition,
+            None,
         )?;
 
         let mut bind_context = bind_context.replace();
@@ -152,6 +155,7 @@ impl Binder {
             &mut bind_context,
             &join_op,
             &join_condition,
+            None,
             &left_context.columns,
             &right_context.columns,
         )?;
@@ -174,6 +178,7 @@ impl Binder {
         bind_context: &mut BindContext,
         join_op: &JoinOperator,
         join_condition: &JoinCondition,
+        match_condition: Option<&Expr>,
         left_column_bindings: &[ColumnBinding],
         right_column_bindings: &[ColumnBinding],
     ) -> Result<JoinConditions> {
@@ -202,6 +207,16 @@ impl Binder {
             join_op,
         )?;
 
+        if let Some(match_condition) = match_condition {
+            if !non_equi_conditions.is_empty() || !other_conditions.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "asof join only supports equi conditions in ON, the inequality should be in MATCH_CONDITION".to_string(),
+                ));
+            }
+            non_equi_conditions
+                .push(join_condition_resolver.resolve_match_condition(match_condition)?);
+        }
+
         Ok(JoinConditions {
             left_conditions: left_join_conditions,
             right_conditions: right_join_conditions,
@@ -268,6 +283,11 @@ impl Binder {
             if join_type == JoinType::Cross {
                 join_type = JoinType::Inner;
             }
+            if matches!(join_type, JoinType::Asof | JoinType::LeftAsof) {
+                return Err(ErrorCode::SemanticError(
+                    "asof join does not support lateral subquery".to_string(),
+                ));
+            }
             is_lateral = true;
         }
 
@@ -353,7 +373,9 @@ impl Binder {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                     }
-                    JoinType::Full => non_equi_conditions.push(predicate.clone()),
+                    JoinType::Full | JoinType::Asof | JoinType::LeftAsof => {
+                        non_equi_conditions.push(predicate.clone())
+                    }
                 },
                 JoinPredicate::Left(_) => {
                     need_push_down = true;
@@ -406,10 +428,16 @@ impl Binder {
         right_column_bindings: &[ColumnBinding],
         join_op: &JoinOperator,
         join_condition: &JoinCondition,
+        match_condition: Option<&Expr>,
     ) -> Result<()> {
         check_duplicate_join_tables(left_column_bindings, right_column_bindings)?;
 
         match join_op {
+            JoinOperator::Asof | JoinOperator::LeftAsof if match_condition.is_none() => {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain MATCH_CONDITION".to_string(),
+                ));
+            }
             JoinOperator::LeftOuter | JoinOperator::RightOuter | JoinOperator::FullOuter
                 if join_condition == &JoinCondition::None =>
             {
@@ -437,7 +465,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_column_bindings {
                 bind_context.add_column_binding(column.clone());
             }
@@ -874,6 +902,52 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(false)
     }
 
+    // The match condition of asof join must be a comparison between the left and right tables.
+    fn resolve_match_condition(&mut self, condition: &Expr) -> Result<ScalarExpr> {
+        let mut join_context = self.join_context.replace();
+        wrap_nullable_for_column(
+            &JoinOperator::Inner,
+            self.left_column_bindings,
+            self.right_column_bindings,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(condition)?;
+        self.check_join_allowed_scalar_expr(&vec![predicate.clone()])?;
+
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        if let ScalarExpr::FunctionCall(func) = &predicate {
+            if func.arguments.len() == 2
+                && matches!(func.func_name.as_str(), "gt" | "gte" | "lt" | "lte")
+            {
+                let arg1_used_columns = func.arguments[0].used_columns();
+                let arg2_used_columns = func.arguments[1].used_columns();
+                let is_left =
+                    |columns: &ColumnSet| !columns.is_empty() && columns.is_subset(&left_columns);
+                let is_right =
+                    |columns: &ColumnSet| !columns.is_empty() && columns.is_subset(&right_columns);
+                if (is_left(&arg1_used_columns) && is_right(&arg2_used_columns))
+                    || (is_right(&arg1_used_columns) && is_left(&arg2_used_columns))
+                {
+                    return Ok(predicate);
+                }
+            }
+        }
+        Err(ErrorCode::SemanticError(
+            "MATCH_CONDITION should be a comparison (>, >=, <, <=) between the left and right tables"
+                .to_string(),
+        )
+        .set_span(condition.span()))
+    }
+
     fn left_right_columns(&self) -> Result<(ColumnSet, ColumnSet)> {
         let left_columns: ColumnSet =
             self.left_column_bindings
@@ -915,6 +989,8 @@ fn join_type(join_type: &JoinOperator) -> JoinType {
         JoinOperator::RightSemi => JoinType::RightSemi,
         JoinOperator::LeftAnti => JoinType::LeftAnti,
         JoinOperator::RightAnti => JoinType::RightAnti,
+        JoinOperator::Asof => JoinType::Asof,
+        JoinOperator::LeftAsof => JoinType::LeftAsof,
     }
 }
 
@@ -0,0 +1,658 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::AfterMatchSkip;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Indirection;
+use databend_common_ast::ast::MatchPattern;
+use databend_common_ast::ast::MatchRecognize;
+use databend_common_ast::ast::OrderByExpr;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::RowsPerMatch;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowDesc;
+use databend_common_ast::ast::WindowSpec;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+
+use crate::binder::Binder;
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::planner::binder::scalar::ScalarBinder;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::MatchMeasureFunc;
+use crate::plans::MatchSymbol;
+use crate::plans::RowPattern;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::BindContext;
+use crate::NameResolutionContext;
+
+/// The aggregate functions which can be used as measures of `MATCH_RECOGNIZE`.
+const MATCH_AGGREGATE_FUNCTIONS: [&str; 5] = ["count", "sum", "avg", "min", "max"];
+
+impl Binder {
+    /// Bind `<table> MATCH_RECOGNIZE (...)`.
+    ///
+    /// The partition keys, order keys, `DEFINE` conditions and the arguments of the
+    /// measures are evaluated by a derived query over the table, in which `PREV` and
+    /// `NEXT` are rewritten to `LAG` and `LEAD` over the partitions. The derived query
+    /// is sorted by the window partition operator and then matched by `MatchRecognize`,
+    /// the measures are finally evaluated over the measure primitives of each match.
+    pub(crate) fn bind_match_recognize(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        table: &TableReference,
+        match_recognize: &MatchRecognize,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        // 1. Collect the pattern variables.
+        let mut symbols = vec![];
+        let pattern = self.bind_row_pattern(&match_recognize.pattern, &mut symbols)?;
+        let mut defines = vec![None; symbols.len()];
+        for definition in match_recognize.definitions.iter() {
+            let name = self.normalize_identifier(&definition.symbol).name;
+            let Some(symbol) = symbols.iter().position(|s| s == &name) else {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Pattern variable {name} in DEFINE is not used in PATTERN"
+                ))
+                .set_span(definition.symbol.span));
+            };
+            if defines[symbol].is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Pattern variable {name} is defined more than once"
+                ))
+                .set_span(definition.symbol.span));
+            }
+            defines[symbol] = Some(definition.expr.clone());
+        }
+
+        // 2. Extract the measure primitives.
+        let mut measure_rewriter = MeasureRewriter::new(&symbols, &self.name_resolution_ctx);
+        let mut measure_exprs = Vec::with_capacity(match_recognize.measures.len());
+        for measure in match_recognize.measures.iter() {
+            let mut expr = measure.expr.clone();
+            expr.drive_mut(&mut measure_rewriter);
+            measure_exprs.push(expr);
+        }
+        let MeasureRewriter {
+            primitives, error, ..
+        } = measure_rewriter;
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        // 3. Build the derived query.
+        let mut select_list = vec![SelectTarget::StarColumns {
+            qualified: vec![Indirection::Star(None)],
+            column_filter: None,
+        }];
+        for (i, expr) in match_recognize.partition_by.iter().enumerate() {
+            select_list.push(aliased_target(expr.clone(), format!("__mr_partition_{i}")));
+        }
+        for (i, order_by) in match_recognize.order_by.iter().enumerate() {
+            select_list.push(aliased_target(
+                order_by.expr.clone(),
+                format!("__mr_order_{i}"),
+            ));
+        }
+        let mut define_positions = Vec::with_capacity(defines.len());
+        for (symbol, define) in defines.into_iter().enumerate() {
+            let Some(mut expr) = define else {
+                define_positions.push(None);
+                continue;
+            };
+            let mut define_rewriter = DefineRewriter {
+                qualifier: SymbolQualifierRewriter::new(&symbols, &self.name_resolution_ctx),
+                partition_by: &match_recognize.partition_by,
+                order_by: &match_recognize.order_by,
+            };
+            expr.drive_mut(&mut define_rewriter);
+            if let Some(err) = define_rewriter.qualifier.error {
+                return Err(err);
+            }
+            if let Some(other) = define_rewriter
+                .qualifier
+                .referenced
+                .iter()
+                .find(|s| **s != symbol)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "DEFINE condition of {} can not reference pattern variable {}",
+                    symbols[symbol], symbols[*other]
+                ))
+                .set_span(expr.span()));
+            }
+            define_positions.push(Some(select_list.len()));
+            select_list.push(aliased_target(expr, format!("__mr_define_{symbol}")));
+        }
+        let mut arg_positions = Vec::with_capacity(primitives.len());
+        for (i, primitive) in primitives.iter().enumerate() {
+            match &primitive.arg {
+                Some(arg) => {
+                    arg_positions.push(Some(select_list.len()));
+                    select_list.push(aliased_target(arg.clone(), format!("__mr_arg_{i}")));
+                }
+                None => arg_positions.push(None),
+            }
+        }
+        let num_targets = select_list.len();
+        let derived_query = Query {
+            span: *span,
+            with: None,
+            body: SetExpr::Select(Box::new(SelectStmt {
+                span: *span,
+                hints: None,
+                distinct: false,
+                top_n: None,
+                select_list,
+                from: vec![table.clone()],
+                selection: None,
+                group_by: None,
+                having: None,
+                window_list: None,
+                qualify: None,
+            })),
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            ignore_result: false,
+        };
+        let mut new_bind_context = BindContext::with_parent(
+            bind_context
+                .parent
+                .clone()
+                .unwrap_or_else(|| Box::new(BindContext::new())),
+        );
+        let (derived, derived_context) = self.bind_query(&mut new_bind_context, &derived_query)?;
+
+        // The star is expanded to the input columns, followed by the synthetic columns.
+        let num_input_columns = derived_context.columns.len() + 1 - num_targets;
+        let column_at =
+            |position: usize| &derived_context.columns[num_input_columns + position - 1];
+        let input_columns = derived_context.columns[..num_input_columns].to_vec();
+
+        // 4. Sort the derived query by the partition and order keys.
+        let num_partitions = match_recognize.partition_by.len();
+        let partition_by = (0..num_partitions)
+            .map(|i| column_at(1 + i).index)
+            .collect::<Vec<_>>();
+        let default_nulls_first = self.ctx.get_settings().get_nulls_first();
+        let mut sort_items = partition_by
+            .iter()
+            .map(|index| SortItem {
+                index: *index,
+                asc: true,
+                nulls_first: default_nulls_first(true),
+            })
+            .collect::<Vec<_>>();
+        let mut order_by = Vec::with_capacity(match_recognize.order_by.len());
+        for (i, order) in match_recognize.order_by.iter().enumerate() {
+            let asc = order.asc.unwrap_or(true);
+            order_by.push(SortItem {
+                index: column_at(1 + num_partitions + i).index,
+                asc,
+                nulls_first: order
+                    .nulls_first
+                    .unwrap_or_else(|| default_nulls_first(asc)),
+            });
+        }
+        sort_items.extend(order_by.iter().cloned());
+        let child = if !sort_items.is_empty() {
+            let window_partition = (0..num_partitions)
+                .map(|i| {
+                    let column = column_at(1 + i);
+                    ScalarItem {
+                        scalar: BoundColumnRef {
+                            span: None,
+                            column: column.clone(),
+                        }
+                        .into(),
+                        index: column.index,
+                    }
+                })
+                .collect();
+            let sort = Sort {
+                items: sort_items,
+                limit: None,
+                after_exchange: None,
+                pre_projection: None,
+                window_partition,
+            };
+            SExpr::create_unary(Arc::new(sort.into()), Arc::new(derived))
+        } else {
+            derived
+        };
+
+        // 5. Bind the measure primitives.
+        let mut measures = Vec::with_capacity(primitives.len());
+        let mut measure_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        for column in input_columns.iter() {
+            measure_context.add_column_binding(column.clone());
+        }
+        for (i, (primitive, position)) in primitives.iter().zip(arg_positions.iter()).enumerate() {
+            let arg = position.map(column_at);
+            let return_type = match &primitive.func {
+                MatchMeasureFunc::First | MatchMeasureFunc::Last => {
+                    arg.unwrap().data_type.wrap_nullable()
+                }
+                MatchMeasureFunc::Aggregate(name) => {
+                    let arg_types = arg.iter().map(|arg| *arg.data_type.clone()).collect();
+                    AggregateFunctionFactory::instance()
+                        .get(name, vec![], arg_types)?
+                        .return_type()?
+                }
+                MatchMeasureFunc::MatchNumber => DataType::Number(NumberDataType::UInt64),
+                MatchMeasureFunc::Classifier => DataType::String,
+            };
+            let column = self.create_derived_column_binding(
+                format!("__mr_measure_{i}"),
+                return_type.clone(),
+                None,
+            );
+            measures.push(crate::plans::MatchMeasure {
+                index: column.index,
+                func: primitive.func.clone(),
+                symbol: primitive.symbol,
+                arg: arg.map(|arg| arg.index),
+                return_type,
+            });
+            measure_context.add_column_binding(column);
+        }
+
+        let symbols = symbols
+            .into_iter()
+            .zip(define_positions)
+            .map(|(name, position)| MatchSymbol {
+                name,
+                define: position.map(|position| column_at(position).index),
+            })
+            .collect();
+        let plan = crate::plans::MatchRecognize {
+            partition_by: partition_by.clone(),
+            order_by,
+            symbols,
+            pattern,
+            measures,
+            all_rows: match_recognize.rows_per_match == RowsPerMatch::AllRows,
+            skip_to_next_row: match_recognize.after_match_skip == AfterMatchSkip::ToNextRow,
+        };
+        let mut s_expr = SExpr::create_unary(Arc::new(plan.into()), Arc::new(child));
+
+        // 6. Evaluate the measures.
+        let mut output_context = derived_context.clone();
+        output_context.columns = match match_recognize.rows_per_match {
+            RowsPerMatch::OneRow => match_recognize
+                .partition_by
+                .iter()
+                .zip(partition_by.iter())
+                .enumerate()
+                .map(|(i, (expr, index))| {
+                    let name = match expr {
+                        Expr::ColumnRef { column, .. } => match &column.column {
+                            ColumnID::Name(name) => self.normalize_identifier(name).name,
+                            ColumnID::Position(_) => expr.to_string(),
+                        },
+                        _ => expr.to_string(),
+                    };
+                    let column = column_at(1 + i);
+                    ColumnBindingBuilder::new(
+                        name,
+                        *index,
+                        column.data_type.clone(),
+                        Visibility::Visible,
+                    )
+                    .build()
+                })
+                .collect(),
+            RowsPerMatch::AllRows => input_columns,
+        };
+        let mut items = Vec::with_capacity(measure_exprs.len());
+        for (measure, expr) in match_recognize.measures.iter().zip(measure_exprs.iter()) {
+            let mut scalar_binder = ScalarBinder::new(
+                &mut measure_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                self.m_cte_bound_ctx.clone(),
+                self.ctes_map.clone(),
+            );
+            let (scalar, data_type) = scalar_binder.bind(expr)?;
+            let name = self.normalize_identifier(&measure.alias).name;
+            let column = self.create_derived_column_binding(name, data_type, Some(scalar.clone()));
+            items.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            output_context.columns.push(column);
+        }
+        if !items.is_empty() {
+            s_expr = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr));
+        }
+
+        if let Some(alias) = alias {
+            output_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        Ok((s_expr, output_context))
+    }
+
+    fn bind_row_pattern(
+        &self,
+        pattern: &MatchPattern,
+        symbols: &mut Vec<String>,
+    ) -> Result<RowPattern> {
+        Ok(match pattern {
+            MatchPattern::Symbol(ident) => {
+                let name = self.normalize_identifier(ident).name;
+                let symbol = match symbols.iter().position(|s| s == &name) {
+                    Some(symbol) => symbol,
+                    None => {
+                        symbols.push(name);
+                        symbols.len() - 1
+                    }
+                };
+                RowPattern::Symbol(symbol)
+            }
+            MatchPattern::Concat(patterns) => RowPattern::Concat(
+                patterns
+                    .iter()
+                    .map(|pattern| self.bind_row_pattern(pattern, symbols))
+                    .collect::<Result<_>>()?,
+            ),
+            MatchPattern::Alternation(patterns) => RowPattern::Alternation(
+                patterns
+                    .iter()
+                    .map(|pattern| self.bind_row_pattern(pattern, symbols))
+                    .collect::<Result<_>>()?,
+            ),
+            MatchPattern::Quantified {
+                pattern,
+                min,
+                max,
+                reluctant,
+            } => {
+                if max.is_some_and(|max| max < *min) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Invalid quantifier {{{min},{}}} in PATTERN",
+                        max.unwrap()
+                    )));
+                }
+                RowPattern::Quantified {
+                    pattern: Box::new(self.bind_row_pattern(pattern, symbols)?),
+                    min: *min,
+                    max: *max,
+                    reluctant: *reluctant,
+                }
+            }
+        })
+    }
+}
+
+fn aliased_target(expr: Expr, name: String) -> SelectTarget {
+    SelectTarget::AliasedExpr {
+        expr: Box::new(expr),
+        alias: Some(Identifier::from_name(None, name)),
+    }
+}
+
+/// Strip the pattern variable qualifiers of column references, like `A.price`,
+/// and record the referenced pattern variables.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct SymbolQualifierRewriter<'a> {
+    symbols: &'a [String],
+    name_resolution_ctx: &'a NameResolutionContext,
+    referenced: Vec<usize>,
+    error: Option<ErrorCode>,
+}
+
+impl<'a> SymbolQualifierRewriter<'a> {
+    fn new(symbols: &'a [String], name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            symbols,
+            name_resolution_ctx,
+            referenced: vec![],
+            error: None,
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::ColumnRef {
+                column:
+                    ColumnRef {
+                        database: None,
+                        table: table @ Some(_),
+                        ..
+                    },
+                ..
+            } => {
+                let name =
+                    normalize_identifier(table.as_ref().unwrap(), self.name_resolution_ctx).name;
+                if let Some(symbol) = self.symbols.iter().position(|s| s == &name) {
+                    *table = None;
+                    if !self.referenced.contains(&symbol) {
+                        self.referenced.push(symbol);
+                    }
+                }
+            }
+            Expr::Subquery { span, .. }
+            | Expr::Exists { span, .. }
+            | Expr::InSubquery { span, .. } => {
+                self.error.get_or_insert_with(|| {
+                    ErrorCode::SemanticError("MATCH_RECOGNIZE does not support subquery")
+                        .set_span(*span)
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Rewrite `PREV(x [, n])` and `NEXT(x [, n])` in the `DEFINE` condition to
+/// `LAG` and `LEAD` over the partitions of `MATCH_RECOGNIZE`.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct DefineRewriter<'a> {
+    qualifier: SymbolQualifierRewriter<'a>,
+    partition_by: &'a [Expr],
+    order_by: &'a [OrderByExpr],
+}
+
+impl DefineRewriter<'_> {
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if let Expr::FunctionCall {
+            func: FunctionCall { name, window, .. },
+            ..
+        } = expr
+        {
+            let func_name = name.name.to_lowercase();
+            if window.is_none() && (func_name == "prev" || func_name == "next") {
+                let new_name = if func_name == "prev" { "lag" } else { "lead" };
+                *name = Identifier::from_name(name.span, new_name);
+                *window = Some(WindowDesc {
+                    ignore_nulls: None,
+                    window: Window::WindowSpec(WindowSpec {
+                        existing_window_name: None,
+                        partition_by: self.partition_by.to_vec(),
+                        order_by: self.order_by.to_vec(),
+                        window_frame: None,
+                    }),
+                });
+            }
+        }
+        self.qualifier.enter_expr(expr);
+    }
+}
+
+struct MeasurePrimitive {
+    func: MatchMeasureFunc,
+    symbol: Option<usize>,
+    arg: Option<Expr>,
+}
+
+/// Replace the measure primitives, such as `FIRST(A.price)`, `count(*)` and
+/// `CLASSIFIER()`, with the columns computed by `MatchRecognize`. A column
+/// reference out of the primitives means the value of the last row.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct MeasureRewriter<'a> {
+    symbols: &'a [String],
+    name_resolution_ctx: &'a NameResolutionContext,
+    primitives: Vec<MeasurePrimitive>,
+    error: Option<ErrorCode>,
+}
+
+impl<'a> MeasureRewriter<'a> {
+    fn new(symbols: &'a [String], name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            symbols,
+            name_resolution_ctx,
+            primitives: vec![],
+            error: None,
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        let span = expr.span();
+        let primitive = match expr {
+            Expr::ColumnRef { .. } => self.primitive(MatchMeasureFunc::Last, Some(expr.clone())),
+            Expr::CountAll { window: None, .. } => Ok(MeasurePrimitive {
+                func: MatchMeasureFunc::Aggregate("count".to_string()),
+                symbol: None,
+                arg: None,
+            }),
+            Expr::FunctionCall {
+                func:
+                    FunctionCall {
+                        distinct,
+                        name,
+                        args,
+                        params,
+                        window,
+                        lambda,
+                    },
+                ..
+            } => {
+                let func_name = name.name.to_lowercase();
+                let func = match func_name.as_str() {
+                    "first" => MatchMeasureFunc::First,
+                    "last" => MatchMeasureFunc::Last,
+                    "match_number" => MatchMeasureFunc::MatchNumber,
+                    "classifier" => MatchMeasureFunc::Classifier,
+                    other if MATCH_AGGREGATE_FUNCTIONS.contains(&other) => {
+                        MatchMeasureFunc::Aggregate(func_name.clone())
+                    }
+                    other => {
+                        if AggregateFunctionFactory::instance().contains(other) || window.is_some()
+                        {
+                            self.error.get_or_insert_with(|| {
+                                ErrorCode::SemanticError(format!(
+                                    "Function {other} is not supported in MEASURES"
+                                ))
+                                .set_span(span)
+                            });
+                        }
+                        return;
+                    }
+                };
+                let expected_args = match &func {
+                    MatchMeasureFunc::MatchNumber | MatchMeasureFunc::Classifier => 0,
+                    _ => 1,
+                };
+                if *distinct
+                    || !params.is_empty()
+                    || window.is_some()
+                    || lambda.is_some()
+                    || args.len() != expected_args
+                {
+                    Err(ErrorCode::SemanticError(format!(
+                        "Invalid arguments of {func_name} in MEASURES"
+                    ))
+                    .set_span(span))
+                } else {
+                    self.primitive(func, args.first().cloned())
+                }
+            }
+            _ => return,
+        };
+
+        match primitive {
+            Ok(primitive) => {
+                let name = format!("__mr_measure_{}", self.primitives.len());
+                self.primitives.push(primitive);
+                *expr = Expr::ColumnRef {
+                    span,
+                    column: ColumnRef {
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(Identifier::from_name(span, name)),
+                    },
+                };
+            }
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+    }
+
+    fn primitive(&self, func: MatchMeasureFunc, arg: Option<Expr>) -> Result<MeasurePrimitive> {
+        let Some(mut arg) = arg else {
+            return Ok(MeasurePrimitive {
+                func,
+                symbol: None,
+                arg: None,
+            });
+        };
+        let mut rewriter = SymbolQualifierRewriter::new(self.symbols, self.name_resolution_ctx);
+        arg.drive_mut(&mut rewriter);
+        if let Some(err) = rewriter.error {
+            return Err(err);
+        }
+        if rewriter.referenced.len() > 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "Argument of {func} can not reference more than one pattern variable"
+            ))
+            .set_span(arg.span()));
+        }
+        Ok(MeasurePrimitive {
+            func,
+            symbol: rewriter.referenced.first().copied(),
+            arg: Some(arg),
+        })
+    }
+}
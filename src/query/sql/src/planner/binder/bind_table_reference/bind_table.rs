@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::SampleConfig;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TemporalClause;
 use databend_common_ast::ast::WithOptions;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
@@ -27,13 +30,22 @@ use databend_common_catalog::table_with_options::get_with_opt_consume;
 use databend_common_catalog::table_with_options::get_with_opt_max_batch_size;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_storages_view::view_table::QUERY;
 use databend_storages_common_table_meta::table::get_change_type;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::ScalarExpr;
 use crate::BindContext;
+use crate::ScalarBinder;
 
 impl Binder {
     /// Bind a base table.
@@ -253,6 +265,7 @@ impl Binder {
                 }
             }
             _ => {
+                let ttl = table_meta.options().get(OPT_KEY_TTL).cloned();
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -264,13 +277,16 @@ impl Binder {
                     false,
                 );
 
-                let (s_expr, mut bind_context) = self.bind_base_table(
+                let (mut s_expr, mut bind_context) = self.bind_base_table(
                     bind_context,
                     database.as_str(),
                     table_index,
                     None,
                     sample,
                 )?;
+                if let Some(ttl) = ttl {
+                    s_expr = self.bind_ttl_filter(&mut bind_context, &ttl, s_expr)?;
+                }
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -279,6 +295,91 @@ impl Binder {
         }
     }
 
+    /// Hides the rows expired by the TTL of the table. The filter is pushed down to the scan,
+    /// so the blocks whose rows are all expired are pruned by the column statistics.
+    fn bind_ttl_filter(
+        &mut self,
+        bind_context: &mut BindContext,
+        ttl: &str,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let tokens = tokenize_sql(ttl)?;
+        let ast = parse_expr(&tokens, self.dialect)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (ttl, data_type) = scalar_binder.bind(&ast)?;
+
+        // The visible rows change as time goes by, so the result can't be cached.
+        self.ctx.set_cacheable(false);
+        let func_ctx = self.ctx.get_function_context()?;
+        let now: ScalarExpr = ConstantExpr {
+            span: None,
+            value: Scalar::Timestamp(func_ctx.now.timestamp_micros()),
+        }
+        .into();
+        let (left, right) = match split_column_plus_interval(&ttl) {
+            // `column + interval > now` is rewritten to `column > now - interval`, so that
+            // the blocks can be pruned by the min/max of the column.
+            Some((column, interval)) => {
+                let expiry = ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "minus".to_string(),
+                    params: vec![],
+                    arguments: vec![now, interval],
+                });
+                let expr = expiry.as_expr()?;
+                let expiry = match ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS).0 {
+                    databend_common_expression::Expr::Constant { scalar, .. } => ConstantExpr {
+                        span: None,
+                        value: scalar,
+                    }
+                    .into(),
+                    _ => expiry,
+                };
+                (column, expiry)
+            }
+            None => (ttl.clone(), now),
+        };
+        let mut predicate = ScalarExpr::FunctionCall(FunctionCall {
+            span: None,
+            func_name: "gt".to_string(),
+            params: vec![],
+            arguments: vec![left, right],
+        });
+        if data_type.is_nullable() {
+            // Rows without a TTL value never expire.
+            predicate = ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "or".to_string(),
+                params: vec![],
+                arguments: vec![
+                    predicate,
+                    ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "is_null".to_string(),
+                        params: vec![],
+                        arguments: vec![ttl],
+                    }),
+                ],
+            });
+        }
+
+        let filter = Filter {
+            predicates: vec![predicate],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
     pub(crate) fn check_view_dep(
         bind_context: &BindContext,
         database: &str,
@@ -302,3 +403,36 @@ impl Binder {
         }
     }
 }
+
+/// Splits the TTL expression of the form `column + <constant interval>` into the column
+/// and the interval.
+fn split_column_plus_interval(ttl: &ScalarExpr) -> Option<(ScalarExpr, ScalarExpr)> {
+    let ScalarExpr::FunctionCall(FunctionCall {
+        func_name,
+        arguments,
+        ..
+    }) = ttl
+    else {
+        return None;
+    };
+    if func_name != "plus" || arguments.len() != 2 {
+        return None;
+    }
+    match (&arguments[0], &arguments[1]) {
+        (
+            column @ ScalarExpr::BoundColumnRef(_),
+            interval @ ScalarExpr::ConstantExpr(ConstantExpr {
+                value: Scalar::Interval(_),
+                ..
+            }),
+        )
+        | (
+            interval @ ScalarExpr::ConstantExpr(ConstantExpr {
+                value: Scalar::Interval(_),
+                ..
+            }),
+            column @ ScalarExpr::BoundColumnRef(_),
+        ) => Some((column.clone(), interval.clone())),
+        _ => None,
+    }
+}
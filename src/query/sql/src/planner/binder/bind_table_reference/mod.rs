@@ -13,8 +13,10 @@
 // limitations under the License.
 
 mod bind;
+mod bind_gap_fill;
 mod bind_join;
 mod bind_location;
+mod bind_match_recognize;
 mod bind_subquery;
 mod bind_table;
 mod bind_table_function;
@@ -54,13 +54,16 @@ use crate::binder::CteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::planner::query_executor::QueryExecutor;
+use crate::plans::AlterRolePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSecretPlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescUserPlan;
 use crate::plans::DropConnectionPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSecretPlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropUserPlan;
 use crate::plans::MaterializedCte;
@@ -70,6 +73,7 @@ use crate::plans::RewriteKind;
 use crate::plans::ShowConnectionsPlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowSecretsPlan;
 use crate::plans::UseDatabasePlan;
 use crate::plans::Visitor;
 use crate::BindContext;
@@ -320,9 +324,9 @@ impl<'a> Binder {
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
-            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(bind_context, stmt).await?,
-            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(bind_context, stmt).await?,
-            Statement::RefreshInvertedIndex(stmt) => self.bind_refresh_inverted_index(bind_context, stmt).await?,
+            Statement::CreateTableIndex(stmt) => self.bind_create_table_index(bind_context, stmt).await?,
+            Statement::DropTableIndex(stmt) => self.bind_drop_table_index(bind_context, stmt).await?,
+            Statement::RefreshTableIndex(stmt) => self.bind_refresh_table_index(bind_context, stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -365,6 +369,11 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::AlterRole(stmt) => Plan::AlterRole(Box::new(AlterRolePlan {
+                if_exists: stmt.if_exists,
+                role_name: stmt.role_name.to_string(),
+                action: stmt.action.clone(),
+            })),
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -479,6 +488,19 @@ impl<'a> Binder {
             })),
             Statement::ShowConnections(_) => Plan::ShowConnections(Box::new(ShowConnectionsPlan{})),
 
+            // Secrets
+            Statement::CreateSecret(stmt) => Plan::CreateSecret(Box::new(CreateSecretPlan {
+                create_option: stmt.create_option.clone().into(),
+                name: self.normalize_identifier(&stmt.name).name,
+                value: stmt.value.clone(),
+                comment: stmt.comment.clone(),
+            })),
+            Statement::DropSecret(stmt) => Plan::DropSecret(Box::new(DropSecretPlan {
+                if_exists: stmt.if_exists,
+                name: self.normalize_identifier(&stmt.name).name,
+            })),
+            Statement::ShowSecrets(_) => Plan::ShowSecrets(Box::new(ShowSecretsPlan{})),
+
             // UDFs
             Statement::CreateUDF(stmt) => self.bind_create_udf(stmt).await?,
             Statement::AlterUDF(stmt) => self.bind_alter_udf(stmt).await?,
@@ -554,6 +576,16 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
+            Statement::ShowWorkloadGroups{ show_options } => self.bind_show_workload_groups(bind_context, show_options).await?,
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -581,19 +613,16 @@ impl<'a> Binder {
 
             // Dynamic Table
             Statement::CreateDynamicTable(stmt) => self.bind_create_dynamic_table(stmt).await?,
-
-            Statement::CreatePipe(_) => {
-                todo!()
-            }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
+            Statement::AlterDynamicTable(stmt) => self.bind_alter_dynamic_table(stmt).await?,
+            Statement::ShowDynamicTables(stmt) => {
+                self.bind_show_dynamic_tables(bind_context, stmt).await?
             }
+
+            // Pipes
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DescribePipe(stmt) => self.bind_describe_pipe(stmt).await?,
+            Statement::AlterPipe(stmt) => self.bind_alter_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
             Statement::CreateNotification(stmt) => {
                 self.bind_create_notification(stmt).await?
             }
@@ -609,12 +638,30 @@ impl<'a> Binder {
             Statement::CreateSequence(stmt) => {
                 self.bind_create_sequence(stmt).await?
             }
+            Statement::AlterSequence(stmt) => {
+                self.bind_alter_sequence(stmt).await?
+            }
             Statement::DropSequence(stmt) => {
                 self.bind_drop_sequence(stmt).await?
             }
+            Statement::ShowSequences { show_options } => {
+                self.bind_show_sequences(bind_context, show_options).await?
+            }
+            Statement::DescSequence(stmt) => {
+                self.bind_desc_sequence(bind_context, stmt).await?
+            }
             Statement::Begin => Plan::Begin,
             Statement::Commit => Plan::Commit,
             Statement::Abort => Plan::Abort,
+            Statement::Savepoint { name } => Plan::Savepoint {
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
+            Statement::RollbackToSavepoint { name } => Plan::RollbackToSavepoint {
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
+            Statement::ReleaseSavepoint { name } => Plan::ReleaseSavepoint {
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
             Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
             Statement::SetPriority {priority, object_id} => {
                 self.bind_set_priority(priority, object_id).await?
@@ -67,6 +67,7 @@ use parking_lot::RwLock;
 use crate::binder::bind_query::MaxColumnPosition;
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::parse_sequence_default;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::Plan;
@@ -577,7 +578,18 @@ impl<'a> Binder {
         );
         let mut values = Vec::with_capacity(data_schema.fields.len());
         for field in &data_schema.fields {
-            let expr = scalar_binder.get_default_value(field, data_schema).await?;
+            // The values of `AUTOINCREMENT` columns are read from the files, the missing ones take
+            // the default value of the type. Values are allocated when the column is not copied.
+            let expr = match field.default_expr() {
+                Some(default_expr) if parse_sequence_default(default_expr).is_some() => {
+                    databend_common_expression::Expr::Constant {
+                        span: None,
+                        scalar: Scalar::default_value(field.data_type()),
+                        data_type: field.data_type().clone(),
+                    }
+                }
+                _ => scalar_binder.get_default_value(field, data_schema).await?,
+            };
             values.push(expr.as_remote_expr());
         }
         Ok(values)
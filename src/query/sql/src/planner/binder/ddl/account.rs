@@ -187,6 +187,7 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(GrantObject::UDF(udf.clone())),
             AccountMgrLevel::Stage(stage) => Ok(GrantObject::Stage(stage.clone())),
+            AccountMgrLevel::Secret(secret) => Ok(GrantObject::Secret(secret.clone())),
         }
     }
 
@@ -247,6 +248,7 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(vec![GrantObject::UDF(udf.clone())]),
             AccountMgrLevel::Stage(stage) => Ok(vec![GrantObject::Stage(stage.clone())]),
+            AccountMgrLevel::Secret(secret) => Ok(vec![GrantObject::Secret(secret.clone())]),
         }
     }
 
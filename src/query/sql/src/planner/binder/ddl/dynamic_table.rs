@@ -14,8 +14,13 @@
 
 use std::collections::BTreeMap;
 
+use databend_common_ast::ast::AlterDynamicTableAction;
+use databend_common_ast::ast::AlterDynamicTableStmt;
 use databend_common_ast::ast::CreateDynamicTableStmt;
 use databend_common_ast::ast::CreateTableSource;
+use databend_common_ast::ast::DynamicTableRef;
+use databend_common_ast::ast::ShowDynamicTablesStmt;
+use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::TypeName;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
@@ -23,18 +28,28 @@ use databend_common_exception::Result;
 use databend_common_expression::infer_schema_type;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::DBIdTableName;
 use databend_common_meta_app::storage::StorageParams;
 use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_INITIALIZE;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+use derive_visitor::DriveMut;
+use log::debug;
 
+use crate::normalize_identifier;
 use crate::plans::CreateDynamicTablePlan;
 use crate::plans::Plan;
+use crate::plans::RefreshDynamicTablePlan;
+use crate::plans::RewriteKind;
 use crate::BindContext;
 use crate::Binder;
+use crate::SelectBuilder;
+use crate::ViewRewriter;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_create_dynamic_table(
@@ -60,6 +75,14 @@ impl Binder {
         let (catalog_name, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        // Qualify the table references in the query with the database of the dynamic table,
+        // so that the refreshes are not affected by the current database of the session.
+        let mut as_query = *as_query.clone();
+        let mut visitor = ViewRewriter {
+            current_database: database.clone(),
+        };
+        as_query.drive_mut(&mut visitor);
+
         let mut options: BTreeMap<String, String> = BTreeMap::new();
         {
             // If table is TRANSIENT, set a flag in table option
@@ -69,6 +92,8 @@ impl Binder {
 
             options.insert(OPT_KEY_AS_QUERY.to_owned(), format!("{as_query}"));
             options.insert(OPT_KEY_TARGET_LAG.to_owned(), format!("{target_lag}"));
+            options.insert(OPT_KEY_REFRESH_MODE.to_owned(), format!("{refresh_mode}"));
+            options.insert(OPT_KEY_INITIALIZE.to_owned(), format!("{initialize}"));
 
             let catalog = self.ctx.get_catalog(&catalog_name).await?;
             let db = catalog
@@ -143,7 +168,7 @@ impl Binder {
         }
 
         let mut init_bind_context = BindContext::new();
-        let (_, bind_context) = self.bind_query(&mut init_bind_context, as_query)?;
+        let (_, bind_context) = self.bind_query(&mut init_bind_context, &as_query)?;
         let query_fields = bind_context
             .columns
             .iter()
@@ -204,4 +229,110 @@ impl Binder {
         };
         Ok(Plan::CreateDynamicTable(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_dynamic_table(
+        &mut self,
+        stmt: &AlterDynamicTableStmt,
+    ) -> Result<Plan> {
+        let AlterDynamicTableStmt { table, action } = stmt;
+
+        let (catalog, database, table, table_id) = match table {
+            DynamicTableRef::Name {
+                catalog,
+                database,
+                table,
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                (catalog, database, table, None)
+            }
+            // The table is referred by id in the task which refreshes it, so that
+            // the task keeps working after the table is renamed.
+            DynamicTableRef::Id { table_id } => {
+                let catalog = self.ctx.get_current_catalog();
+                let ctl = self.ctx.get_catalog(&catalog).await?;
+                let Some(DBIdTableName { db_id, table_name }) =
+                    ctl.get_db_id_table_name_by_id(*table_id).await?
+                else {
+                    return Err(ErrorCode::UnknownTableId(format!(
+                        "Unknown dynamic table id {}",
+                        table_id
+                    )));
+                };
+                let database = ctl.get_db_name_by_id(db_id).await?;
+                (catalog, database, table_name, Some(*table_id))
+            }
+        };
+
+        match action {
+            AlterDynamicTableAction::Refresh => Ok(Plan::RefreshDynamicTable(Box::new(
+                RefreshDynamicTablePlan {
+                    catalog,
+                    database,
+                    table,
+                    table_id,
+                },
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_dynamic_tables(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ShowDynamicTablesStmt,
+    ) -> Result<Plan> {
+        let ShowDynamicTablesStmt {
+            catalog,
+            database,
+            limit,
+        } = stmt;
+
+        let database = self.check_database_exist(catalog, database).await?;
+
+        let mut select_builder = SelectBuilder::from("system.dynamic_tables");
+        select_builder
+            .with_column("created_on")
+            .with_column("name")
+            .with_column("database")
+            .with_column("catalog")
+            .with_column("target_lag")
+            .with_column("refresh_mode")
+            .with_column("refreshed_on")
+            .with_column("rows")
+            .with_column("owner")
+            .with_column("text");
+
+        select_builder
+            .with_order_by("catalog")
+            .with_order_by("database")
+            .with_order_by("name");
+
+        select_builder.with_filter(format!("database = '{database}'"));
+        if let Some(catalog) = catalog {
+            let catalog = normalize_identifier(catalog, &self.name_resolution_ctx).name;
+            select_builder.with_filter(format!("catalog = '{catalog}'"));
+        }
+        if let Some(limit) = limit {
+            match limit {
+                ShowLimit::Like { pattern } => {
+                    select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                }
+                ShowLimit::Where { selection } => {
+                    select_builder.with_filter(format!("({selection})"));
+                }
+            }
+        }
+
+        let query = select_builder.build();
+        debug!("show dynamic tables rewrite to: {:?}", query);
+
+        self.bind_rewrite_to_query(
+            bind_context,
+            query.as_str(),
+            RewriteKind::ShowDynamicTables(database),
+        )
+        .await
+    }
 }
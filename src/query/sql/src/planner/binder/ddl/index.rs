@@ -18,21 +18,24 @@ use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use databend_common_ast::ast::CreateIndexStmt;
-use databend_common_ast::ast::CreateInvertedIndexStmt;
+use databend_common_ast::ast::CreateTableIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
-use databend_common_ast::ast::DropInvertedIndexStmt;
+use databend_common_ast::ast::DropTableIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
-use databend_common_ast::ast::RefreshInvertedIndexStmt;
+use databend_common_ast::ast::RefreshTableIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableIndexType;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ColumnId;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchemaRef;
@@ -41,6 +44,7 @@ use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::TableIndexType as MetaTableIndexType;
 use databend_storages_common_table_meta::meta::Location;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
@@ -88,6 +92,18 @@ static INDEX_RECORD_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     r
 });
 
+// valid values for vector index option distance
+static INDEX_DISTANCE_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert("cosine");
+    r.insert("l2");
+    r
+});
+
+// default values of vector index options
+const DEFAULT_VECTOR_INDEX_M: usize = 16;
+const DEFAULT_VECTOR_INDEX_EF_CONSTRUCT: usize = 100;
+
 fn is_valid_tokenizer_values<S: AsRef<str>>(opt_val: S) -> bool {
     INDEX_TOKENIZER_VALUES.contains(opt_val.as_ref())
 }
@@ -96,6 +112,10 @@ fn is_valid_filter_values<S: AsRef<str>>(opt_val: S) -> bool {
     INDEX_FILTER_VALUES.contains(opt_val.as_ref())
 }
 
+fn is_valid_distance_values<S: AsRef<str>>(opt_val: S) -> bool {
+    INDEX_DISTANCE_VALUES.contains(opt_val.as_ref())
+}
+
 fn is_valid_index_record_values<S: AsRef<str>>(opt_val: S) -> bool {
     INDEX_RECORD_VALUES.contains(opt_val.as_ref())
 }
@@ -410,13 +430,14 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+    pub(in crate::planner::binder) async fn bind_create_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &CreateInvertedIndexStmt,
+        stmt: &CreateTableIndexStmt,
     ) -> Result<Plan> {
-        let CreateInvertedIndexStmt {
+        let CreateTableIndexStmt {
             create_option,
+            index_type,
             index_name,
             catalog,
             database,
@@ -430,36 +451,56 @@ impl Binder {
             self.normalize_object_identifier_triple(catalog, database, table);
 
         let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        let type_name = index_type.to_string().to_lowercase();
 
         if table.is_read_only() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is read-only, creating inverted index not allowed",
-                table.name()
+                "Table {} is read-only, creating {} index not allowed",
+                table.name(),
+                type_name
             )));
         }
 
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support create {} index",
+                table.engine(),
+                type_name
             )));
         }
         if table.is_temp() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is temporary table, creating inverted index not allowed",
-                table.name()
+                "Table {} is temporary table, creating {} index not allowed",
+                table.name(),
+                type_name
             )));
         }
         let table_schema = table.schema();
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
-        let column_ids = self
-            .validate_inverted_index_columns(table_schema, columns)
-            .await?;
-        let index_options = self.validate_inverted_index_options(index_options).await?;
+        let (column_ids, index_options) = match index_type {
+            TableIndexType::Inverted => {
+                let column_ids = self
+                    .validate_inverted_index_columns(table_schema, columns)
+                    .await?;
+                let index_options = self.validate_inverted_index_options(index_options).await?;
+                (column_ids, index_options)
+            }
+            TableIndexType::Vector => {
+                let column_ids = self.validate_vector_index_columns(table_schema, columns)?;
+                let index_options = self.validate_vector_index_options(index_options)?;
+                (column_ids, index_options)
+            }
+            TableIndexType::Aggregating => {
+                return Err(ErrorCode::UnsupportedIndex(
+                    "Aggregating index can not be created on table columns",
+                ));
+            }
+        };
 
         let plan = CreateTableIndexPlan {
             create_option: create_option.clone().into(),
+            index_type: *index_type,
             catalog,
             index_name,
             column_ids,
@@ -558,14 +599,79 @@ impl Binder {
         Ok(options)
     }
 
+    pub(in crate::planner::binder) fn validate_vector_index_columns(
+        &self,
+        table_schema: TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<Vec<ColumnId>> {
+        let [column] = columns else {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index must be created on exactly one column, but got {} columns",
+                columns.len()
+            )));
+        };
+        let field = table_schema.field_with_name(&column.name).map_err(|_| {
+            ErrorCode::UnsupportedIndex(format!("Table does not have column {}", column))
+        })?;
+        match field.data_type.remove_nullable() {
+            TableDataType::Array(box TableDataType::Number(NumberDataType::Float32)) => {
+                Ok(vec![field.column_id])
+            }
+            _ => Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index currently only support Array(Float32) type, but the type of column {} is {}",
+                column, field.data_type
+            ))),
+        }
+    }
+
+    pub(in crate::planner::binder) fn validate_vector_index_options(
+        &self,
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut m = DEFAULT_VECTOR_INDEX_M;
+        let mut ef_construct = DEFAULT_VECTOR_INDEX_EF_CONSTRUCT;
+        let mut distance = "cosine".to_string();
+        for (opt, val) in index_options.iter() {
+            let key = opt.to_lowercase();
+            match key.as_str() {
+                "m" => {
+                    m = parse_vector_index_option(&key, val, 2, 128)?;
+                }
+                "ef_construct" => {
+                    ef_construct = parse_vector_index_option(&key, val, 4, 2048)?;
+                }
+                "distance" => {
+                    let value = val.to_lowercase();
+                    if !is_valid_distance_values(&value) {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid index distance",
+                        )));
+                    }
+                    distance = value;
+                }
+                _ => {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "index option `{key}` is invalid key for create vector index statement",
+                    )));
+                }
+            }
+        }
+        let mut options = BTreeMap::new();
+        options.insert("m".to_string(), m.to_string());
+        options.insert("ef_construct".to_string(), ef_construct.to_string());
+        options.insert("distance".to_string(), distance);
+        Ok(options)
+    }
+
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+    pub(in crate::planner::binder) async fn bind_drop_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &DropInvertedIndexStmt,
+        stmt: &DropTableIndexStmt,
     ) -> Result<Plan> {
-        let DropInvertedIndexStmt {
+        let DropTableIndexStmt {
             if_exists,
+            index_type,
             index_name,
             catalog,
             database,
@@ -578,15 +684,18 @@ impl Binder {
         let table = self.ctx.get_table(&catalog, &database, &table).await?;
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support {} index",
+                table.engine(),
+                index_type.to_string().to_lowercase()
             )));
         }
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
+        check_table_index_type(table.as_ref(), &index_name, index_type)?;
 
         let plan = DropTableIndexPlan {
             if_exists: *if_exists,
+            index_type: *index_type,
             catalog,
             index_name,
             table_id,
@@ -595,12 +704,13 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+    pub(in crate::planner::binder) async fn bind_refresh_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &RefreshInvertedIndexStmt,
+        stmt: &RefreshTableIndexStmt,
     ) -> Result<Plan> {
-        let RefreshInvertedIndexStmt {
+        let RefreshTableIndexStmt {
+            index_type,
             index_name,
             catalog,
             database,
@@ -611,6 +721,8 @@ impl Binder {
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let index_name = self.normalize_object_identifier(index_name);
+        let table_ref = self.ctx.get_table(&catalog, &database, &table).await?;
+        check_table_index_type(table_ref.as_ref(), &index_name, index_type)?;
 
         let plan = RefreshTableIndexPlan {
             catalog,
@@ -622,3 +734,37 @@ impl Binder {
         Ok(Plan::RefreshTableIndex(Box::new(plan)))
     }
 }
+
+fn parse_vector_index_option(key: &str, val: &str, min: usize, max: usize) -> Result<usize> {
+    match val.parse::<usize>() {
+        Ok(v) if (min..=max).contains(&v) => Ok(v),
+        _ => Err(ErrorCode::IndexOptionInvalid(format!(
+            "value `{val}` is invalid index {key}, must be an integer between {min} and {max}",
+        ))),
+    }
+}
+
+// The index of the table with the name must be the same type as in the statement.
+fn check_table_index_type(
+    table: &dyn Table,
+    index_name: &str,
+    index_type: &TableIndexType,
+) -> Result<()> {
+    let Some(index) = table.get_table_info().meta.indexes.get(index_name) else {
+        return Ok(());
+    };
+    let matched = matches!(
+        (index_type, index.index_type),
+        (TableIndexType::Inverted, MetaTableIndexType::Inverted)
+            | (TableIndexType::Vector, MetaTableIndexType::Vector)
+    );
+    if !matched {
+        return Err(ErrorCode::UnsupportedIndex(format!(
+            "Index {} is a {} index, not a {} index",
+            index_name,
+            index.index_type.to_string().to_lowercase(),
+            index_type.to_string().to_lowercase()
+        )));
+    }
+    Ok(())
+}
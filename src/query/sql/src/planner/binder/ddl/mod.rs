@@ -24,6 +24,7 @@ mod index;
 mod network_policy;
 mod notification;
 mod password_policy;
+mod pipe;
 mod procedure;
 mod role;
 mod sequence;
@@ -33,3 +34,4 @@ mod table;
 mod task;
 mod view;
 mod virtual_column;
+mod workload_group;
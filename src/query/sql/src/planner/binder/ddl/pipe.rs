@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::AlterPipeStmt;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CreatePipeStmt;
+use databend_common_ast::ast::DescribePipeStmt;
+use databend_common_ast::ast::DropPipeStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::Identifier;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        let mut copy_stmt = copy_stmt.clone();
+        if let CopyIntoTableSource::Location(FileLocation::Uri(_)) = &copy_stmt.src {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} can only load files from a stage",
+                name
+            )));
+        }
+        // Files loaded by the pipe are deduplicated by the copied file info of the table,
+        // which is bypassed by `FORCE = TRUE`.
+        if copy_stmt.force {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} does not support FORCE = TRUE",
+                name
+            )));
+        }
+
+        // Qualify the target table, so that the pipe is not affected by the current
+        // catalog and database of the session which executes it.
+        let (catalog, database, table) = self.normalize_object_identifier_triple(
+            &copy_stmt.dst.catalog,
+            &copy_stmt.dst.database,
+            &copy_stmt.dst.table,
+        );
+        self.ctx.get_table(&catalog, &database, &table).await?;
+        copy_stmt.dst.catalog = Some(Identifier::from_name_with_quoted(None, catalog, Some('"')));
+        copy_stmt.dst.database = Some(Identifier::from_name_with_quoted(None, database, Some('"')));
+        copy_stmt.dst.table = Identifier::from_name_with_quoted(None, table, Some('"'));
+
+        let create_option = if *if_not_exists {
+            CreateOption::CreateIfNotExists
+        } else {
+            CreateOption::Create
+        };
+        Ok(Plan::CreatePipe(Box::new(CreatePipePlan {
+            create_option,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+            auto_ingest: *auto_ingest,
+            comment: comments.clone(),
+            copy_stmt: copy_stmt.to_string(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropPipe(Box::new(DropPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_describe_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+
+        Ok(Plan::DescPipe(Box::new(DescPipePlan {
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        if let AlterPipeOptions::Set {
+            execution_paused: None,
+            comments: None,
+        } = options
+        {
+            return Err(ErrorCode::SemanticError(
+                "ALTER PIPE SET requires at least one option",
+            ));
+        }
+
+        Ok(Plan::AlterPipe(Box::new(AlterPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.clone(),
+            options: options.clone(),
+        })))
+    }
+}
@@ -12,15 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::AlterSequenceStmt;
 use databend_common_ast::ast::CreateSequenceStmt;
+use databend_common_ast::ast::DescSequenceStmt;
 use databend_common_ast::ast::DropSequenceStmt;
+use databend_common_ast::ast::ShowOptions;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::schema::SequenceIdent;
 
+use crate::binder::show::get_show_options;
+use crate::plans::AlterSequencePlan;
 use crate::plans::CreateSequencePlan;
 use crate::plans::DropSequencePlan;
 use crate::plans::Plan;
+use crate::plans::RewriteKind;
+use crate::BindContext;
 use crate::Binder;
+use crate::AUTO_INCREMENT_SEQUENCE_PREFIX;
+
+const SHOW_SEQUENCE_COLUMNS: &str =
+    "name, start, increment, cache, current, created_on, updated_on, comment";
 
 impl Binder {
     #[async_backtrace::framed]
@@ -31,20 +44,101 @@ impl Binder {
         let CreateSequenceStmt {
             create_option,
             sequence,
+            start,
+            increment,
+            cache,
             comment,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
         let sequence = self.normalize_object_identifier(sequence);
+        check_sequence_options(&sequence, *increment, *cache)?;
+        if sequence.starts_with(AUTO_INCREMENT_SEQUENCE_PREFIX) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Sequence name prefix `{AUTO_INCREMENT_SEQUENCE_PREFIX}` is reserved for AUTOINCREMENT columns"
+            )));
+        }
 
         let plan = CreateSequencePlan {
             create_option: create_option.clone().into(),
             ident: SequenceIdent::new(tenant, sequence),
+            start: start.unwrap_or(1),
+            step: increment.unwrap_or(1),
+            cache: cache.unwrap_or(1),
             comment: comment.clone(),
         };
         Ok(Plan::CreateSequence(plan.into()))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_sequence(
+        &mut self,
+        stmt: &AlterSequenceStmt,
+    ) -> Result<Plan> {
+        let AlterSequenceStmt {
+            if_exists,
+            sequence,
+            restart,
+            increment,
+            cache,
+            comment,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let sequence = self.normalize_object_identifier(sequence);
+        check_sequence_options(&sequence, *increment, *cache)?;
+
+        let plan = AlterSequencePlan {
+            if_exists: *if_exists,
+            ident: SequenceIdent::new(tenant, sequence),
+            restart: *restart,
+            step: *increment,
+            cache: *cache,
+            comment: comment.clone(),
+        };
+        Ok(Plan::AlterSequence(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_sequences(
+        &mut self,
+        bind_context: &mut BindContext,
+        show_options: &Option<ShowOptions>,
+    ) -> Result<Plan> {
+        let (show_limit, limit_str) = get_show_options(show_options, None);
+        let query = format!(
+            "SELECT {} FROM system.sequences {} ORDER BY name {}",
+            SHOW_SEQUENCE_COLUMNS, show_limit, limit_str,
+        );
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowSequences)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_sequence(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &DescSequenceStmt,
+    ) -> Result<Plan> {
+        let tenant = self.ctx.get_tenant();
+        let sequence = self.normalize_object_identifier(&stmt.sequence);
+
+        // Report an unknown sequence instead of returning an empty result.
+        let req = GetSequenceReq {
+            ident: SequenceIdent::new(tenant, sequence.clone()),
+        };
+        self.ctx.get_default_catalog()?.get_sequence(req).await?;
+
+        let query = format!(
+            "SELECT {} FROM system.sequences WHERE name = '{}'",
+            SHOW_SEQUENCE_COLUMNS,
+            sequence.replace('\'', "''"),
+        );
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::DescribeSequence)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_sequence(
         &mut self,
@@ -65,3 +159,21 @@ impl Binder {
         Ok(Plan::DropSequence(plan.into()))
     }
 }
+
+fn check_sequence_options(
+    sequence: &str,
+    increment: Option<i64>,
+    cache: Option<u64>,
+) -> Result<()> {
+    if increment == Some(0) {
+        return Err(ErrorCode::SemanticError(format!(
+            "INCREMENT of sequence {sequence} must be greater than 0"
+        )));
+    }
+    if cache == Some(0) {
+        return Err(ErrorCode::SemanticError(format!(
+            "CACHE of sequence {sequence} must be greater than 0"
+        )));
+    }
+    Ok(())
+}
@@ -32,6 +32,7 @@ use databend_common_ast::ast::DescribeTableStmt;
 use databend_common_ast::ast::DropTableStmt;
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InvertedIndexDefinition;
 use databend_common_ast::ast::ModifyColumnAction;
@@ -78,7 +79,9 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_view::view_table::QUERY;
@@ -92,6 +95,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use derive_visitor::DriveMut;
 use log::debug;
 use opendal::Operator;
@@ -113,6 +117,7 @@ use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -122,6 +127,7 @@ use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
+use crate::plans::ModifyTableTtlPlan;
 use crate::plans::OptimizeCompactBlock;
 use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
@@ -145,6 +151,10 @@ use crate::plans::VacuumTemporaryFilesPlan;
 use crate::BindContext;
 use crate::Planner;
 use crate::SelectBuilder;
+use crate::AUTO_INCREMENT_SEQUENCE_PREFIX;
+
+/// The number of values an `AUTOINCREMENT` sequence allocates per meta-service round trip.
+const AUTO_INCREMENT_SEQUENCE_CACHE: u64 = 1000;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -427,6 +437,7 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            ttl,
             as_query,
             table_type,
             engine,
@@ -590,6 +601,14 @@ impl Binder {
             }
         };
 
+        // `AUTOINCREMENT` columns take their values from a sequence owned by the table.
+        let (schema, sequences) = match &source {
+            Some(CreateTableSource::Columns(columns, _)) => {
+                self.analyze_auto_increment_columns(&database, &table, columns, schema)?
+            }
+            _ => (schema, vec![]),
+        };
+
         if engine == Engine::Memory {
             let catalog = self.ctx.get_catalog(&catalog).await?;
             let db = catalog
@@ -676,6 +695,17 @@ impl Binder {
             }
         }
 
+        if let Some(ttl) = ttl {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::IllegalTableTtl(format!(
+                    "TTL is only supported for FUSE engine, but got {}",
+                    engine
+                )));
+            }
+            let ttl = self.analyze_ttl(ttl, schema.clone())?;
+            options.insert(OPT_KEY_TTL.to_owned(), ttl);
+        }
+
         let plan = CreateTablePlan {
             create_option: create_option.clone().into(),
             tenant: self.ctx.get_tenant(),
@@ -698,6 +728,7 @@ impl Binder {
                 None
             },
             inverted_indexes,
+            sequences,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -762,6 +793,7 @@ impl Binder {
             cluster_key: None,
             as_select: None,
             inverted_indexes: None,
+            sequences: vec![],
         })))
     }
 
@@ -999,6 +1031,26 @@ impl Binder {
                     table,
                 },
             ))),
+            AlterTableAction::ModifyTtl { ttl } => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let ttl = self.analyze_ttl(ttl, schema)?;
+                Ok(Plan::ModifyTableTtl(Box::new(ModifyTableTtlPlan {
+                    catalog,
+                    database,
+                    table,
+                    ttl: Some(ttl),
+                })))
+            }
+            AlterTableAction::DropTtl => Ok(Plan::ModifyTableTtl(Box::new(ModifyTableTtlPlan {
+                catalog,
+                database,
+                table,
+                ttl: None,
+            }))),
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -1408,6 +1460,11 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::AutoIncrement { .. } => {
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an AUTOINCREMENT column".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1436,6 +1493,16 @@ impl Binder {
                             parse_default_expr_to_string(self.ctx.clone(), &field, default_expr)?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::AutoIncrement { .. } => {
+                        if !matches!(
+                            schema_data_type.remove_nullable(),
+                            TableDataType::Number(ty) if ty.is_integer()
+                        ) {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "AUTOINCREMENT column `{name}` must be an integer type, but got {schema_data_type}"
+                            )));
+                        }
+                    }
                     _ => has_computed = true,
                 }
             }
@@ -1491,6 +1558,51 @@ impl Binder {
         Ok((schema, fields_comments))
     }
 
+    fn analyze_auto_increment_columns(
+        &self,
+        database: &str,
+        table: &str,
+        columns: &[ColumnDefinition],
+        schema: TableSchemaRef,
+    ) -> Result<(TableSchemaRef, Vec<CreateSequencePlan>)> {
+        let mut fields = schema.fields().clone();
+        let mut sequences = vec![];
+        for column in columns {
+            let Some(ColumnExpr::AutoIncrement { start, increment }) = &column.expr else {
+                continue;
+            };
+            if *increment == 0 || *increment > i64::MAX as u64 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Invalid AUTOINCREMENT increment {increment} of column `{}`",
+                    column.name
+                )));
+            }
+            let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+            // The name is unique, so the counter survives renaming the table and never
+            // clashes with the sequences of other tables or users.
+            let sequence = format!(
+                "{AUTO_INCREMENT_SEQUENCE_PREFIX}{}",
+                Uuid::new_v4().simple()
+            );
+            let field = fields
+                .iter_mut()
+                .find(|field| field.name() == &name)
+                .ok_or_else(|| ErrorCode::SemanticError(format!("Unknown column `{name}`")))?;
+            *field = field
+                .clone()
+                .with_default_expr(Some(format!("nextval(`{}`)", sequence.replace('`', "``"))));
+            sequences.push(CreateSequencePlan {
+                create_option: CreateOption::Create,
+                ident: SequenceIdent::new(self.ctx.get_tenant(), sequence),
+                start: *start,
+                step: *increment as i64,
+                cache: AUTO_INCREMENT_SEQUENCE_CACHE,
+                comment: Some(format!("AUTOINCREMENT of {database}.{table}.{name}")),
+            });
+        }
+        Ok((TableSchemaRefExt::create(fields), sequences))
+    }
+
     #[async_backtrace::framed]
     async fn analyze_inverted_indexes(
         &self,
@@ -1514,6 +1626,7 @@ impl Binder {
                 .await?;
 
             let inverted_index = TableIndex {
+                index_type: TableIndexType::Inverted,
                 name: name.clone(),
                 column_ids,
                 sync_creation: inverted_index_def.sync_creation,
@@ -1636,18 +1749,7 @@ impl Binder {
         }
 
         // Build a temporary BindContext to resolve the expr
-        let mut bind_context = BindContext::new();
-        for (index, field) in schema.fields().iter().enumerate() {
-            let column = ColumnBindingBuilder::new(
-                field.name().clone(),
-                index,
-                Box::new(DataType::from(field.data_type())),
-                Visibility::Visible,
-            )
-            .build();
-
-            bind_context.add_column_binding(column);
-        }
+        let mut bind_context = Self::schema_bind_context(&schema);
         let mut scalar_binder = ScalarBinder::new(
             &mut bind_context,
             self.ctx.clone(),
@@ -1697,6 +1799,72 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    /// Checks the TTL expression is a deterministic DATE or TIMESTAMP expression
+    /// over the columns of the table, and returns its normalized text.
+    fn analyze_ttl(&mut self, ttl: &Expr, schema: TableSchemaRef) -> Result<String> {
+        let mut bind_context = Self::schema_bind_context(&schema);
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        scalar_binder.forbid_udf();
+
+        let (scalar, _) = scalar_binder.bind(ttl)?;
+        if scalar.used_columns().is_empty() || !scalar.evaluable() {
+            return Err(ErrorCode::IllegalTableTtl(format!(
+                "TTL expression `{:#}` is invalid, it must refer to the columns of the table",
+                ttl
+            )));
+        }
+
+        let expr = scalar.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::IllegalTableTtl(format!(
+                "TTL expression `{:#}` is not deterministic",
+                ttl
+            )));
+        }
+
+        let data_type = expr.data_type();
+        if !matches!(
+            data_type.remove_nullable(),
+            DataType::Timestamp | DataType::Date
+        ) {
+            return Err(ErrorCode::IllegalTableTtl(format!(
+                "TTL expression `{:#}` must be of type DATE or TIMESTAMP, but got {}",
+                ttl, data_type
+            )));
+        }
+
+        let mut ttl = ttl.clone();
+        let mut normalizer = IdentifierNormalizer {
+            ctx: &self.name_resolution_ctx,
+        };
+        ttl.drive_mut(&mut normalizer);
+        Ok(format!("{:#}", &ttl))
+    }
+
+    fn schema_bind_context(schema: &TableSchemaRef) -> BindContext {
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        bind_context
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
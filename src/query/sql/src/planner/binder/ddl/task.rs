@@ -12,10 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::str::FromStr;
-
-use chrono_tz;
-use cron;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_ast::ast::AlterTaskStmt;
 use databend_common_ast::ast::CreateTaskStmt;
@@ -30,6 +26,7 @@ use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::TaskSchedule;
 
 use crate::plans::AlterTaskPlan;
 use crate::plans::CreateTaskPlan;
@@ -73,21 +70,11 @@ fn verify_scheduler_option(schedule_opts: &Option<ScheduleOptions>) -> Result<()
     }
     let schedule_opts = schedule_opts.clone().unwrap();
     if let ScheduleOptions::CronExpression(cron_expr, time_zone) = &schedule_opts {
-        if cron::Schedule::from_str(cron_expr).is_err() {
-            return Err(ErrorCode::SemanticError(format!(
-                "invalid cron expression {}",
-                cron_expr
-            )));
-        }
-        if let Some(time_zone) = time_zone
-            && !time_zone.is_empty()
-            && chrono_tz::Tz::from_str(time_zone).is_err()
-        {
-            return Err(ErrorCode::SemanticError(format!(
-                "invalid time zone {}",
-                time_zone
-            )));
-        }
+        let schedule = TaskSchedule::Cron {
+            expr: cron_expr.clone(),
+            time_zone: time_zone.clone().filter(|tz| !tz.is_empty()),
+        };
+        schedule.check().map_err(ErrorCode::SemanticError)?;
     }
 
     // ONLY allow milliseconds_interval value between
@@ -16,6 +16,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Expr as AExpr;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -33,6 +36,7 @@ use indexmap::IndexMap;
 use crate::binder::wrap_cast;
 use crate::evaluator::BlockOperator;
 use crate::evaluator::CompoundBlockOperator;
+use crate::parse_sequence_default;
 use crate::plans::walk_expr_mut;
 use crate::plans::ConstantExpr;
 use crate::plans::VisitorMut;
@@ -126,13 +130,24 @@ impl BindContext {
         let mut rewriter = ExprValuesRewriter::new(ctx.clone());
         for (i, expr) in exprs.iter().enumerate() {
             // `DEFAULT` in insert values will be parsed as `Expr::ColumnRef`.
+            let mut sequence_default = None;
             if let AExpr::ColumnRef { column, .. } = expr {
                 if column.column.name().eq_ignore_ascii_case("default") {
                     let field = schema.field(i);
-                    map_exprs.push(scalar_binder.get_default_value(field, schema).await?);
-                    continue;
+                    match field.default_expr() {
+                        // The next value of an `AUTOINCREMENT` column is bound as `nextval(..)`.
+                        Some(default_expr) if parse_sequence_default(default_expr).is_some() => {
+                            let tokens = tokenize_sql(default_expr)?;
+                            sequence_default = Some(parse_expr(&tokens, Dialect::default())?);
+                        }
+                        _ => {
+                            map_exprs.push(scalar_binder.get_default_value(field, schema).await?);
+                            continue;
+                        }
+                    }
                 }
             }
+            let expr = sequence_default.as_ref().unwrap_or(expr);
 
             let (mut scalar, data_type) = scalar_binder.bind(expr)?;
 
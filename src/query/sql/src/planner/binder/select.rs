@@ -31,9 +31,11 @@ use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 
 use super::sort::OrderItem;
+use super::window::WindowRewriter;
 use super::Finder;
 use crate::binder::bind_table_reference::JoinConditions;
 use crate::binder::scalar_common::split_conjunctions;
+use crate::binder::ColumnBinding;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::ExprContext;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
@@ -44,11 +46,17 @@ use crate::planner::binder::Binder;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::Filter;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::ScalarExpr;
-use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
+use crate::plans::VisitorMut as _;
+use crate::plans::WindowFunc;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::ColumnEntry;
 use crate::IndexType;
 use crate::Visibility;
@@ -155,26 +163,30 @@ impl Binder {
         }
 
         match (op, all) {
-            (SetOperator::Intersect, false) => {
+            (SetOperator::Intersect, _) => {
                 // Transfer Intersect to Semi join
-                self.bind_intersect(
+                self.bind_intersect_or_except(
                     left.span(),
                     right.span(),
                     left_bind_context,
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    JoinType::LeftSemi,
+                    !all,
                 )
             }
-            (SetOperator::Except, false) => {
+            (SetOperator::Except, _) => {
                 // Transfer Except to Anti join
-                self.bind_except(
+                self.bind_intersect_or_except(
                     left.span(),
                     right.span(),
                     left_bind_context,
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    JoinType::LeftAnti,
+                    !all,
                 )
             }
             (SetOperator::Union, true) => self.bind_union(
@@ -197,9 +209,6 @@ impl Binder {
                 true,
                 cte_name,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
@@ -312,6 +321,7 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftSemi,
+            true,
         )
     }
 
@@ -332,9 +342,20 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftAnti,
+            true,
         )
     }
 
+    // For `INTERSECT ALL` and `EXCEPT ALL`, each row of both sides is numbered within the
+    // group of its duplicates, so a row that appears m times in the left side and n times
+    // in the right side is joined with the number in [1, min(m, n)]. Then the semi join
+    // keeps min(m, n) copies of the row, and the anti join keeps max(m - n, 0) copies.
+    //
+    // The numbering is a `row_number()` partitioned by all the columns instead of a counting
+    // aggregation, which would have to expand the counts back into rows. The window is planned
+    // as a `WindowPartition`, which is shuffled by the partition keys in cluster mode and spills
+    // its partitions to storage when the memory is limited, and the join is the same hash join
+    // as `INTERSECT` and `EXCEPT`, so both sides run distributed and spill like the aggregation.
     #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect_or_except(
         &mut self,
@@ -345,33 +366,44 @@ impl Binder {
         left_expr: SExpr,
         right_expr: SExpr,
         join_type: JoinType,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let left_expr = self.bind_distinct(
-            left_span,
-            &left_context,
-            left_context.all_column_bindings(),
-            &mut HashMap::new(),
-            left_expr,
-        )?;
-        let mut left_conditions = Vec::with_capacity(left_context.columns.len());
-        let mut right_conditions = Vec::with_capacity(right_context.columns.len());
-        assert_eq!(left_context.columns.len(), right_context.columns.len());
-        for (left_column, right_column) in left_context
-            .columns
-            .iter()
-            .zip(right_context.columns.iter())
-        {
+        let mut left_columns = left_context.columns.clone();
+        let mut right_columns = right_context.columns.clone();
+        let (left_expr, right_expr) = if distinct {
+            let left_expr = self.bind_distinct(
+                left_span,
+                &left_context,
+                left_context.all_column_bindings(),
+                &mut HashMap::new(),
+                left_expr,
+            )?;
+            (left_expr, right_expr)
+        } else {
+            let (left_expr, left_row_number) =
+                self.bind_duplicate_row_number(left_span, &left_context, left_expr)?;
+            let (right_expr, right_row_number) =
+                self.bind_duplicate_row_number(right_span, &right_context, right_expr)?;
+            left_columns.push(left_row_number);
+            right_columns.push(right_row_number);
+            (left_expr, right_expr)
+        };
+
+        let mut left_conditions = Vec::with_capacity(left_columns.len());
+        let mut right_conditions = Vec::with_capacity(right_columns.len());
+        assert_eq!(left_columns.len(), right_columns.len());
+        for (left_column, right_column) in left_columns.into_iter().zip(right_columns) {
             left_conditions.push(
                 BoundColumnRef {
                     span: left_span,
-                    column: left_column.clone(),
+                    column: left_column,
                 }
                 .into(),
             );
             right_conditions.push(
                 BoundColumnRef {
                     span: right_span,
-                    column: right_column.clone(),
+                    column: right_column,
                 }
                 .into(),
             );
@@ -384,7 +416,62 @@ impl Binder {
         };
         let s_expr =
             self.bind_join_with_type(join_type, join_conditions, left_expr, right_expr, None)?;
-        Ok((s_expr, left_context))
+        if distinct {
+            return Ok((s_expr, left_context));
+        }
+
+        // `INTERSECT ALL` and `EXCEPT ALL` compare the rows with NULLs as equal values,
+        // so the duplicates with NULLs are numbered and counted like the other rows.
+        let mut join: Join = s_expr.plan().clone().try_into()?;
+        for condition in join.equi_conditions.iter_mut() {
+            condition.is_null_equal = true;
+        }
+        Ok((s_expr.replace_plan(Arc::new(join.into())), left_context))
+    }
+
+    // Add `row_number() over (partition by <all columns>)` to the set operation child.
+    fn bind_duplicate_row_number(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        child: SExpr,
+    ) -> Result<(SExpr, ColumnBinding)> {
+        let mut window = WindowFunc {
+            span,
+            display_name: "row_number()".to_string(),
+            partition_by: bind_context
+                .columns
+                .iter()
+                .map(|column| {
+                    BoundColumnRef {
+                        span,
+                        column: column.clone(),
+                    }
+                    .into()
+                })
+                .collect(),
+            func: WindowFuncType::RowNumber,
+            order_by: vec![],
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::CurrentRow,
+            },
+        };
+        let mut window_context = BindContext::new();
+        let mut rewriter = WindowRewriter::new(&mut window_context, self.metadata.clone());
+        rewriter.visit_window_function(&mut window)?;
+
+        let window_info = &window_context.windows.window_functions[0];
+        let s_expr = self.bind_window_function(window_info, child)?;
+        let column = ColumnBindingBuilder::new(
+            window.display_name,
+            window_info.index,
+            Box::new(window.func.return_type()),
+            Visibility::InVisible,
+        )
+        .build();
+        Ok((s_expr, column))
     }
 
     #[allow(clippy::type_complexity)]
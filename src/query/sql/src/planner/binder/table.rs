@@ -556,6 +556,7 @@ impl Binder {
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Version(v) => Ok(NavigationPoint::Version(*v)),
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::try_create(
                     bind_context,
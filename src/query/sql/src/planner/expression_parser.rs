@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr as AExpr;
 use databend_common_ast::parser::parse_comma_separated_exprs;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::plan::Filters;
@@ -38,6 +41,7 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
@@ -50,6 +54,7 @@ use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::ExprContext;
 use crate::planner::binder::BindContext;
+use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
 use crate::BaseTableColumn;
@@ -684,11 +689,58 @@ impl Table for DummyTable {
     }
 }
 
+/// Returns the sequence name if the default expr is `nextval(<sequence>)`.
+///
+/// Such defaults are generated by `AUTOINCREMENT`/`IDENTITY` columns, the values are
+/// allocated from the sequence in batches when the rows are written.
+pub fn parse_sequence_default(default_expr: &str) -> Option<String> {
+    let tokens = tokenize_sql(default_expr).ok()?;
+    let AExpr::FunctionCall { func, .. } = parse_expr(&tokens, Default::default()).ok()? else {
+        return None;
+    };
+    if !func.name.name.eq_ignore_ascii_case("nextval") || func.args.len() != 1 {
+        return None;
+    }
+    match &func.args[0] {
+        AExpr::ColumnRef {
+            column:
+                ColumnRef {
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(ident),
+                },
+            ..
+        } => Some(normalize_identifier(ident, &NameResolutionContext::default()).name),
+        _ => None,
+    }
+}
+
+/// The name prefix of the sequences owned by `AUTOINCREMENT` columns.
+///
+/// Users can't create sequences with this prefix, so the sequences of a table
+/// can be told apart from the user sequences referenced by `DEFAULT nextval(...)`.
+pub const AUTO_INCREMENT_SEQUENCE_PREFIX: &str = "_auto_increment_";
+
+/// Returns the sequences owned by the `AUTOINCREMENT` columns of the table,
+/// they are dropped along with the table.
+pub fn auto_increment_sequences(schema: &TableSchema) -> Vec<String> {
+    schema
+        .fields()
+        .iter()
+        .filter_map(|f| parse_sequence_default(f.default_expr()?))
+        .filter(|sequence| sequence.starts_with(AUTO_INCREMENT_SEQUENCE_PREFIX))
+        .collect()
+}
+
 pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Result<Scalar> {
     let data_type = field.data_type();
     let data_type = DataType::from(data_type);
 
     match field.default_expr() {
+        // The values of sequence columns are filled on write, old rows read the type default.
+        Some(default_expr) if parse_sequence_default(default_expr).is_some() => {
+            Ok(Scalar::default_value(&data_type))
+        }
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
             let mut exprs = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
@@ -71,6 +71,7 @@ impl Plan {
             Plan::DropTableColumn(_) => Ok("DropTableColumn".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
+            Plan::ModifyTableTtl(_) => Ok("ModifyTableTtl".to_string()),
             Plan::ReclusterTable { .. } => Ok("ReclusterTable".to_string()),
             Plan::TruncateTable(_) => Ok("TruncateTable".to_string()),
             Plan::OptimizePurge(_) => Ok("OptimizePurge".to_string()),
@@ -94,6 +95,13 @@ impl Plan {
 
             // Dynamic Tables
             Plan::CreateDynamicTable(_) => Ok("CreateDynamicTable".to_string()),
+            Plan::RefreshDynamicTable(_) => Ok("RefreshDynamicTable".to_string()),
+
+            // Pipes
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescPipe(_) => Ok("DescPipe".to_string()),
 
             // Indexes
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
@@ -139,6 +147,7 @@ impl Plan {
             Plan::DescUser(_) => Ok("DescUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
+            Plan::AlterRole(_) => Ok("AlterRole".to_string()),
             Plan::Presign(_) => Ok("Presign".to_string()),
 
             Plan::Set(_) => Ok("Set".to_string()),
@@ -168,6 +177,9 @@ impl Plan {
             Plan::AlterPasswordPolicy(_) => Ok("AlterPasswordPolicy".to_string()),
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
 
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
@@ -182,9 +194,15 @@ impl Plan {
             Plan::DescConnection(_) => Ok("DescConnection".to_string()),
             Plan::DropConnection(_) => Ok("DropConnection".to_string()),
             Plan::ShowConnections(_) => Ok("ShowConnections".to_string()),
+            Plan::CreateSecret(_) => Ok("CreateSecret".to_string()),
+            Plan::DropSecret(_) => Ok("DropSecret".to_string()),
+            Plan::ShowSecrets(_) => Ok("ShowSecrets".to_string()),
             Plan::Begin => Ok("Begin".to_string()),
             Plan::Commit => Ok("commit".to_string()),
             Plan::Abort => Ok("Abort".to_string()),
+            Plan::Savepoint { .. } => Ok("Savepoint".to_string()),
+            Plan::RollbackToSavepoint { .. } => Ok("RollbackToSavepoint".to_string()),
+            Plan::ReleaseSavepoint { .. } => Ok("ReleaseSavepoint".to_string()),
 
             // Notification
             Plan::CreateNotification(_) => Ok("CreateNotification".to_string()),
@@ -202,6 +220,7 @@ impl Plan {
 
             // sequence
             Plan::CreateSequence(_) => Ok("CreateSequence".to_string()),
+            Plan::AlterSequence(_) => Ok("AlterSequence".to_string()),
             Plan::DropSequence(_) => Ok("DropSequence".to_string()),
 
             Plan::SetPriority(_) => Ok("SetPriority".to_string()),
@@ -27,9 +27,11 @@ use crate::plans::ConstantTableScan;
 use crate::plans::EvalScalar;
 use crate::plans::Exchange;
 use crate::plans::Filter;
+use crate::plans::GapFill;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::Limit;
+use crate::plans::MatchRecognize;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::Scan;
@@ -147,6 +149,8 @@ pub(super) fn to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = Inde
         RelOperator::Filter(op) => filter_to_format_tree(id_humanizer, op),
         RelOperator::Aggregate(op) => aggregate_to_format_tree(id_humanizer, op),
         RelOperator::Window(op) => window_to_format_tree(id_humanizer, op),
+        RelOperator::MatchRecognize(op) => match_recognize_to_format_tree(id_humanizer, op),
+        RelOperator::GapFill(op) => gap_fill_to_format_tree(id_humanizer, op),
         RelOperator::Udf(op) => udf_to_format_tree(id_humanizer, op),
         RelOperator::AsyncFunction(op) => async_func_to_format_tree(id_humanizer, op),
         RelOperator::Sort(op) => sort_to_format_tree(id_humanizer, op),
@@ -211,6 +215,8 @@ fn format_join(op: &Join) -> String {
         JoinType::RightMark => "RightMark".to_string(),
         JoinType::LeftSingle => "LeftSingle".to_string(),
         JoinType::RightSingle => "RightSingle".to_string(),
+        JoinType::Asof => "Asof".to_string(),
+        JoinType::LeftAsof => "LeftAsof".to_string(),
     };
 
     format!("Join({})", join_type)
@@ -296,6 +302,77 @@ fn window_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexTyp
     ])
 }
 
+fn match_recognize_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
+    id_humanizer: &I,
+    op: &MatchRecognize,
+) -> FormatTreeNode {
+    let partition_by_items = op
+        .partition_by
+        .iter()
+        .map(|index| format!("{} (#{})", id_humanizer.humanize_column_id(*index), index))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let measures = op
+        .measures
+        .iter()
+        .map(|measure| {
+            let symbol = measure.symbol.map_or("".to_string(), |symbol| {
+                format!("{}.", op.symbols[symbol].name)
+            });
+            let arg = measure.arg.map_or("".to_string(), |arg| {
+                format!("{} (#{})", id_humanizer.humanize_column_id(arg), arg)
+            });
+            format!("{}({symbol}{arg}) AS (#{})", measure.func, measure.index)
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    FormatTreeNode::with_children("MatchRecognize".to_string(), vec![
+        FormatTreeNode::new(format!("partition items: [{}]", partition_by_items)),
+        FormatTreeNode::new(format!("pattern: ({})", op.pattern.display(&op.symbols))),
+        FormatTreeNode::new(format!("measures: [{}]", measures)),
+    ])
+}
+
+fn gap_fill_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
+    id_humanizer: &I,
+    op: &GapFill,
+) -> FormatTreeNode {
+    let partition_by_items = op
+        .partition_by
+        .iter()
+        .map(|index| format!("{} (#{})", id_humanizer.humanize_column_id(*index), index))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let columns = op
+        .columns
+        .iter()
+        .map(|column| {
+            format!(
+                "{}({} (#{})) AS (#{})",
+                column.kind,
+                id_humanizer.humanize_column_id(column.arg),
+                column.arg,
+                column.index
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    FormatTreeNode::with_children("GapFill".to_string(), vec![
+        FormatTreeNode::new(format!("partition items: [{}]", partition_by_items)),
+        FormatTreeNode::new(format!(
+            "time column: {} (#{})",
+            id_humanizer.humanize_column_id(op.time_column),
+            op.time_column
+        )),
+        FormatTreeNode::new(format!("bucket width: {}", op.width)),
+        FormatTreeNode::new(format!("columns: [{}]", columns)),
+    ])
+}
+
 fn udf_to_format_tree<I: IdHumanizer<ColumnId = IndexType, TableId = IndexType>>(
     _id_humanizer: &I,
     op: &Udf,
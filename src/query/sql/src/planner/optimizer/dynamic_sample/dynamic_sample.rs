@@ -148,6 +148,8 @@ pub async fn dynamic_sample(
         | RelOperator::Sort(_)
         | RelOperator::Exchange(_)
         | RelOperator::Window(_)
+        | RelOperator::MatchRecognize(_)
+        | RelOperator::GapFill(_)
         | RelOperator::Udf(_)
         | RelOperator::AsyncFunction(_) => {
             dynamic_sample(ctx, metadata, s_expr.child(0)?, sample_executor).await
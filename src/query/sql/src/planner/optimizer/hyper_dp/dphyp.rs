@@ -183,6 +183,8 @@ impl DPhpy {
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
+                        | RelOperator::MatchRecognize(_)
+                        | RelOperator::GapFill(_)
                         | RelOperator::Udf(_)
                 ) {
                     left_is_subquery = true;
@@ -195,6 +197,8 @@ impl DPhpy {
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
+                        | RelOperator::MatchRecognize(_)
+                        | RelOperator::GapFill(_)
                         | RelOperator::Udf(_)
                 ) {
                     right_is_subquery = true;
@@ -250,6 +254,8 @@ impl DPhpy {
             | RelOperator::Limit(_)
             | RelOperator::EvalScalar(_)
             | RelOperator::Window(_)
+            | RelOperator::MatchRecognize(_)
+            | RelOperator::GapFill(_)
             | RelOperator::Udf(_)
             | RelOperator::Filter(_) => {
                 if join_child {
@@ -158,7 +158,10 @@ pub fn try_push_down_filter_join(s_expr: &SExpr, metadata: MetadataRef) -> Resul
                 }
             }
             JoinPredicate::Right(_) => {
-                if matches!(
+                if matches!(join.join_type, JoinType::Asof | JoinType::LeftAsof) {
+                    // Filtering the right side changes the closest row matched by asof join
+                    original_predicates.push(predicate);
+                } else if matches!(
                     join.join_type,
                     JoinType::Left | JoinType::LeftSingle | JoinType::Full
                 ) {
@@ -124,6 +124,8 @@ fn find_group_by_keys(child: &SExpr, group_by_keys: &mut HashSet<IndexType>) ->
             }
         }
         RelOperator::Sort(_)
+        | RelOperator::MatchRecognize(_)
+        | RelOperator::GapFill(_)
         | RelOperator::Limit(_)
         | RelOperator::Exchange(_)
         | RelOperator::UnionAll(_)
@@ -0,0 +1,268 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::Result;
+use databend_common_expression::type_check;
+use databend_common_expression::types::number::NumberColumn;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableIndexType;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///           [Filter]
+///               \
+///               Scan
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///           [Filter]
+///               \
+///               Scan(padding vector index)
+///
+/// The rule is applied if the first sort item is the ascending distance
+/// between a column with a vector index and a constant vector, computed
+/// by the distance function of the index, the nearest vectors searched
+/// by the index are used to prune the blocks of the table.
+/// The filters must be pushed down to the scan, as the nearest rows which
+/// do not match them are skipped when pruning the blocks.
+pub struct RuleUseVectorIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    matchers: Vec<Matcher>,
+}
+
+impl RuleUseVectorIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::UseVectorIndex,
+            metadata,
+            matchers: vec![
+                Matcher::MatchOp {
+                    op_type: RelOp::Sort,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::EvalScalar,
+                        children: vec![Matcher::MatchOp {
+                            op_type: RelOp::Scan,
+                            children: vec![],
+                        }],
+                    }],
+                },
+                Matcher::MatchOp {
+                    op_type: RelOp::Sort,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::EvalScalar,
+                        children: vec![Matcher::MatchOp {
+                            op_type: RelOp::Filter,
+                            children: vec![Matcher::MatchOp {
+                                op_type: RelOp::Scan,
+                                children: vec![],
+                            }],
+                        }],
+                    }],
+                },
+            ],
+        }
+    }
+
+    fn find_vector_index(
+        &self,
+        scan: &Scan,
+        scalar: &ScalarExpr,
+        limit: usize,
+    ) -> Result<Option<VectorIndexInfo>> {
+        let ScalarExpr::FunctionCall(func) = scalar else {
+            return Ok(None);
+        };
+        let distance = match func.func_name.as_str() {
+            "cosine_distance" => "cosine",
+            "l2_distance" => "l2",
+            _ => return Ok(None),
+        };
+        let [lhs, rhs] = func.arguments.as_slice() else {
+            return Ok(None);
+        };
+        // The column may be casted to match the type of the query vector.
+        let (column, query) = match (remove_cast(lhs), remove_cast(rhs)) {
+            (ScalarExpr::BoundColumnRef(column), _) => (column, rhs),
+            (_, ScalarExpr::BoundColumnRef(column)) => (column, lhs),
+            _ => return Ok(None),
+        };
+        if column.column.table_index != Some(scan.table_index) || !query.used_columns().is_empty() {
+            return Ok(None);
+        }
+
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(BaseTableColumn {
+            column_name,
+            column_id: Some(column_id),
+            path_indices: None,
+            ..
+        }) = metadata.column(column.column.index)
+        else {
+            return Ok(None);
+        };
+        let table = metadata.table(scan.table_index).table();
+        let Some(index) = table.get_table_info().meta.indexes.values().find(|index| {
+            index.index_type == TableIndexType::Vector
+                && index.column_ids == [*column_id]
+                && index.options.get("distance").map(String::as_str) == Some(distance)
+        }) else {
+            return Ok(None);
+        };
+
+        let Some(query_values) = eval_query_vector(query)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(VectorIndexInfo {
+            index_name: index.name.clone(),
+            index_version: index.version.clone(),
+            column_name: column_name.clone(),
+            distance: distance.to_string(),
+            query_values,
+            limit,
+        }))
+    }
+}
+
+impl Rule for RuleUseVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let (Some(limit), Some(sort_item)) = (sort.limit, sort.items.first()) else {
+            return Ok(());
+        };
+        // The rows with null distance must not be placed before the nearest rows.
+        if !sort_item.asc || sort_item.nulls_first {
+            return Ok(());
+        }
+
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let Some(item) = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == sort_item.index)
+        else {
+            return Ok(());
+        };
+
+        let mut filter_expr = None;
+        let mut scan_expr = eval_scalar_expr.child(0)?;
+        if let RelOperator::Filter(_) = scan_expr.plan() {
+            filter_expr = Some(scan_expr);
+            scan_expr = scan_expr.child(0)?;
+        }
+        let mut scan: Scan = scan_expr.plan().clone().try_into()?;
+        if scan.vector_index.is_some() || scan.inverted_index.is_some() {
+            return Ok(());
+        }
+        // The pruner only skips the nearest rows not matching the pushed down filters.
+        if let Some(filter_expr) = filter_expr {
+            let filter: Filter = filter_expr.plan().clone().try_into()?;
+            let pushed_down = scan.push_down_predicates.as_deref().unwrap_or_default();
+            if !filter
+                .predicates
+                .iter()
+                .all(|predicate| pushed_down.contains(predicate))
+            {
+                return Ok(());
+            }
+        }
+        let Some(vector_index) = self.find_vector_index(&scan, &item.scalar, limit)? else {
+            return Ok(());
+        };
+        scan.vector_index = Some(vector_index);
+
+        let mut child = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        if let Some(filter_expr) = filter_expr {
+            child = filter_expr.replace_children(vec![Arc::new(child)]);
+        }
+        let child = eval_scalar_expr.replace_children(vec![Arc::new(child)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(child)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
+
+fn remove_cast(scalar: &ScalarExpr) -> &ScalarExpr {
+    match scalar {
+        ScalarExpr::CastExpr(cast) => remove_cast(&cast.argument),
+        _ => scalar,
+    }
+}
+
+// Evaluate the constant query vector as `Array(Float32)`.
+fn eval_query_vector(query: &ScalarExpr) -> Result<Option<Vec<F32>>> {
+    let query = ScalarExpr::CastExpr(CastExpr {
+        span: None,
+        is_try: false,
+        argument: Box::new(query.clone()),
+        target_type: Box::new(DataType::Array(Box::new(DataType::Number(
+            NumberDataType::Float32,
+        )))),
+    });
+    let Ok(expr) = type_check::check(&query.as_raw_expr(), &BUILTIN_FUNCTIONS) else {
+        return Ok(None);
+    };
+    let (expr, _) = ConstantFolder::fold(&expr, &FunctionContext::default(), &BUILTIN_FUNCTIONS);
+    match expr {
+        Expr::Constant {
+            scalar: Scalar::Array(Column::Number(NumberColumn::Float32(values))),
+            ..
+        } if !values.is_empty() => Ok(Some(values.iter().copied().collect())),
+        _ => Ok(None),
+    }
+}
@@ -331,6 +331,8 @@ impl SExpr {
             RelOperator::Limit(_)
             | RelOperator::UnionAll(_)
             | RelOperator::Sort(_)
+            | RelOperator::MatchRecognize(_)
+            | RelOperator::GapFill(_)
             | RelOperator::DummyTableScan(_)
             | RelOperator::CteScan(_)
             | RelOperator::MaterializedCte(_)
@@ -431,6 +433,8 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Exchange(_)
         | RelOperator::UnionAll(_)
         | RelOperator::Sort(_)
+        | RelOperator::MatchRecognize(_)
+        | RelOperator::GapFill(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::CteScan(_)
         | RelOperator::MaterializedCte(_)
@@ -41,3 +41,12 @@ pub struct CreateDynamicTablePlan {
     pub refresh_mode: RefreshMode,
     pub initialize: InitializeMode,
 }
+
+#[derive(Clone, Debug)]
+pub struct RefreshDynamicTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The id of the table if it is referred by id.
+    pub table_id: Option<u64>,
+}
@@ -57,6 +57,7 @@ pub struct RefreshIndexPlan {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateTableIndexPlan {
     pub create_option: CreateOption,
+    pub index_type: TableIndexType,
     pub catalog: String,
     pub index_name: String,
     pub column_ids: Vec<ColumnId>,
@@ -69,6 +70,7 @@ pub struct CreateTableIndexPlan {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropTableIndexPlan {
     pub if_exists: bool,
+    pub index_type: TableIndexType,
     pub catalog: String,
     pub index_name: String,
     pub table_id: MetaId,
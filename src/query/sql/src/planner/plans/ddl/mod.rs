@@ -21,7 +21,9 @@ mod dynamic_table;
 mod file_format;
 mod index;
 mod notification;
+mod pipe;
 mod procedure;
+mod secret;
 mod sequence;
 mod stage;
 mod stream;
@@ -40,7 +42,9 @@ pub use dynamic_table::*;
 pub use file_format::*;
 pub use index::*;
 pub use notification::*;
+pub use pipe::*;
 pub use procedure::*;
+pub use secret::*;
 pub use sequence::*;
 pub use stage::*;
 pub use stream::*;
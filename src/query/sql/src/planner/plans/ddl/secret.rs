@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::schema::CreateOption;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct CreateSecretPlan {
+    pub create_option: CreateOption,
+    pub name: String,
+    pub value: String,
+    pub comment: Option<String>,
+}
+
+// The secret string is never printed.
+impl Debug for CreateSecretPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreateSecretPlan")
+            .field("create_option", &self.create_option)
+            .field("name", &self.name)
+            .field("comment", &self.comment)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropSecretPlan {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowSecretsPlan {}
+
+impl ShowSecretsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("comment", DataType::String.wrap_nullable()),
+            DataField::new("created_on", DataType::Timestamp),
+        ])
+    }
+}
@@ -19,6 +19,20 @@ use databend_common_meta_app::schema::SequenceIdent;
 pub struct CreateSequencePlan {
     pub create_option: CreateOption,
     pub ident: SequenceIdent,
+    pub start: u64,
+    pub step: i64,
+    pub cache: u64,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterSequencePlan {
+    pub if_exists: bool,
+    pub ident: SequenceIdent,
+    /// `Some(None)` restarts the sequence from its start value.
+    pub restart: Option<Option<u64>>,
+    pub step: Option<i64>,
+    pub cache: Option<u64>,
     pub comment: Option<String>,
 }
 
@@ -34,6 +34,7 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::LockGuard;
 
+use crate::plans::CreateSequencePlan;
 use crate::plans::Plan;
 
 pub type TableOptions = BTreeMap<String, String>;
@@ -55,6 +56,8 @@ pub struct CreateTablePlan {
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
+    /// The sequences backing `AUTOINCREMENT` columns, created along with the table.
+    pub sequences: Vec<CreateSequencePlan>,
 }
 
 impl CreateTablePlan {
@@ -478,3 +481,19 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Modify or drop the TTL of the table.
+#[derive(Clone, Debug)]
+pub struct ModifyTableTtlPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The normalized TTL expression, `None` drops the TTL.
+    pub ttl: Option<String>,
+}
+
+impl ModifyTableTtlPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::DataType;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `GAP_FILL` buckets the time column by `width`, and emits a row for each missing
+/// bucket between the first and the last bucket of a partition, or between `start`
+/// and `end` if they are given. Its input must be grouped by `partition_by` and
+/// sorted by the time column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GapFill {
+    pub partition_by: Vec<IndexType>,
+    pub time_column: IndexType,
+    pub width: months_days_micros,
+    pub start: Option<i64>,
+    /// The end is exclusive.
+    pub end: Option<i64>,
+    /// Each input column is replaced by an output column, which is nullable
+    /// unless it is the time column or a partition key.
+    pub columns: Vec<GapFillColumn>,
+}
+
+impl GapFill {
+    pub fn used_columns(&self) -> ColumnSet {
+        self.columns.iter().map(|column| column.arg).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GapFillColumn {
+    pub index: IndexType,
+    pub arg: IndexType,
+    pub kind: GapFillKind,
+    pub return_type: DataType,
+}
+
+/// The value of a column in the rows of the missing buckets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GapFillKind {
+    /// The start of the bucket.
+    Time,
+    /// The partition key.
+    Partition,
+    Null,
+    /// The last non-null value before the bucket.
+    Locf,
+    /// The linear interpolation of the non-null values around the bucket.
+    Interpolate,
+}
+
+impl Display for GapFillKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            GapFillKind::Time => write!(f, "time"),
+            GapFillKind::Partition => write!(f, "partition"),
+            GapFillKind::Null => write!(f, "null"),
+            GapFillKind::Locf => write!(f, "locf"),
+            GapFillKind::Interpolate => write!(f, "interpolate"),
+        }
+    }
+}
+
+impl Operator for GapFill {
+    fn rel_op(&self) -> RelOp {
+        RelOp::GapFill
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        if self.partition_by.is_empty() {
+            required.distribution = Distribution::Serial;
+        }
+        Ok(required)
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        let mut required = required.clone();
+        if self.partition_by.is_empty() {
+            required.distribution = Distribution::Serial;
+        }
+        Ok(vec![vec![required]])
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        let output_columns: ColumnSet = self.columns.iter().map(|column| column.index).collect();
+
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        let mut used_columns = self.used_columns();
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+}
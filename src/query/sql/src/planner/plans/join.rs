@@ -27,6 +27,7 @@ use databend_common_storage::Histogram;
 use databend_common_storage::HistogramBucket;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 
+use crate::binder::JoinPredicate;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -44,6 +45,16 @@ use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
 use crate::IndexType;
 
+/// Spatial predicates that imply the bounding boxes of their arguments intersect,
+/// so the candidates can be found by a bounding box index.
+pub const SPATIAL_JOIN_PREDICATES: &[&str] = &[
+    "st_contains",
+    "st_within",
+    "st_intersects",
+    "st_touches",
+    "st_covers",
+];
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum JoinType {
     Cross,
@@ -63,6 +74,10 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Asof Join matches each row of the left side with at most one row of the right side,
+    /// the closest one satisfying the match condition among the rows with equal keys.
+    Asof,
+    LeftAsof,
 }
 
 impl JoinType {
@@ -140,6 +155,12 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
@@ -420,6 +441,39 @@ impl Join {
             .any(|condition| condition.is_null_equal)
     }
 
+    /// Returns the index of the non-equi condition that can be evaluated by a spatial join,
+    /// which is a spatial predicate whose arguments come from different sides of the join.
+    pub fn spatial_join_condition(
+        &self,
+        left_prop: &RelationalProperty,
+        right_prop: &RelationalProperty,
+    ) -> Option<usize> {
+        if !self.equi_conditions.is_empty()
+            || self.build_side_cache_info.is_some()
+            || !matches!(self.join_type, JoinType::Inner | JoinType::Cross)
+        {
+            return None;
+        }
+        self.non_equi_conditions.iter().position(|condition| {
+            let ScalarExpr::FunctionCall(func) = condition else {
+                return false;
+            };
+            if func.arguments.len() != 2
+                || !SPATIAL_JOIN_PREDICATES.contains(&func.func_name.as_str())
+            {
+                return false;
+            }
+            matches!(
+                (
+                    JoinPredicate::new(&func.arguments[0], left_prop, right_prop),
+                    JoinPredicate::new(&func.arguments[1], left_prop, right_prop),
+                ),
+                (JoinPredicate::Left(_), JoinPredicate::Right(_))
+                    | (JoinPredicate::Right(_), JoinPredicate::Left(_))
+            )
+        })
+    }
+
     pub fn derive_join_stats(
         &self,
         left_stat_info: Arc<StatInfo>,
@@ -450,9 +504,14 @@ impl Join {
                     + f64::max(right_cardinality, inner_join_cardinality)
                     - inner_join_cardinality
             }
-            JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
+            JoinType::LeftSemi | JoinType::Asof => {
+                f64::min(left_cardinality, inner_join_cardinality)
+            }
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
+            JoinType::LeftSingle
+            | JoinType::RightMark
+            | JoinType::LeftAnti
+            | JoinType::LeftAsof => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
@@ -587,16 +646,34 @@ impl Operator for Join {
         let probe_physical_prop = rel_expr.derive_physical_prop_child(0)?;
         let build_physical_prop = rel_expr.derive_physical_prop_child(1)?;
 
-        // if join/probe side is Serial or this is a non-equi join, we use Serial distribution
+        // if join/probe side is Serial, we use Serial distribution
         if probe_physical_prop.distribution == Distribution::Serial
             || build_physical_prop.distribution == Distribution::Serial
-            || (self.equi_conditions.is_empty() && !self.non_equi_conditions.is_empty())
         {
-            // TODO(leiysky): we can enforce redistribution here
             required.distribution = Distribution::Serial;
             return Ok(required);
         }
 
+        if self.equi_conditions.is_empty() && !self.non_equi_conditions.is_empty() {
+            let left_prop = rel_expr.derive_relational_prop_child(0)?;
+            let right_prop = rel_expr.derive_relational_prop_child(1)?;
+            if self
+                .spatial_join_condition(&left_prop, &right_prop)
+                .is_some()
+            {
+                // Spatial join builds the index on the broadcast build side
+                required.distribution = if child_index == 1 {
+                    Distribution::Broadcast
+                } else {
+                    Distribution::Any
+                };
+            } else {
+                // TODO(leiysky): we can enforce redistribution here
+                required.distribution = Distribution::Serial;
+            }
+            return Ok(required);
+        }
+
         // Try to use broadcast join
         if !matches!(
             self.join_type,
@@ -0,0 +1,237 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::SortItem;
+use crate::IndexType;
+
+/// `MATCH_RECOGNIZE` finds the rows of each partition, in the order of `order_by`,
+/// that match `pattern`. Its input must be grouped by `partition_by` and sorted.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchRecognize {
+    pub partition_by: Vec<IndexType>,
+    pub order_by: Vec<SortItem>,
+    pub symbols: Vec<MatchSymbol>,
+    pub pattern: RowPattern,
+    pub measures: Vec<MatchMeasure>,
+    /// `ALL ROWS PER MATCH` returns every matched row with running measures,
+    /// `ONE ROW PER MATCH` returns the last row of each match.
+    pub all_rows: bool,
+    /// `AFTER MATCH SKIP TO NEXT ROW` starts the next match at the row after
+    /// the first row of the current match instead of after its last row.
+    pub skip_to_next_row: bool,
+}
+
+impl MatchRecognize {
+    pub fn used_columns(&self) -> ColumnSet {
+        let mut used_columns = ColumnSet::new();
+        used_columns.extend(self.partition_by.iter().copied());
+        used_columns.extend(self.order_by.iter().map(|item| item.index));
+        used_columns.extend(self.symbols.iter().filter_map(|symbol| symbol.define));
+        used_columns.extend(self.measures.iter().filter_map(|measure| measure.arg));
+        used_columns
+    }
+}
+
+/// A pattern variable, the variable without `DEFINE` condition matches any row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchSymbol {
+    pub name: String,
+    pub define: Option<IndexType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RowPattern {
+    /// Index of the pattern variable in `MatchRecognize::symbols`.
+    Symbol(usize),
+    Concat(Vec<RowPattern>),
+    Alternation(Vec<RowPattern>),
+    Quantified {
+        pattern: Box<RowPattern>,
+        min: u64,
+        max: Option<u64>,
+        reluctant: bool,
+    },
+}
+
+impl RowPattern {
+    pub fn display<'a>(&'a self, symbols: &'a [MatchSymbol]) -> RowPatternDisplay<'a> {
+        RowPatternDisplay {
+            pattern: self,
+            symbols,
+        }
+    }
+}
+
+pub struct RowPatternDisplay<'a> {
+    pattern: &'a RowPattern,
+    symbols: &'a [MatchSymbol],
+}
+
+impl Display for RowPatternDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self.pattern {
+            RowPattern::Symbol(symbol) => write!(f, "{}", self.symbols[*symbol].name),
+            RowPattern::Concat(patterns) | RowPattern::Alternation(patterns) => {
+                let separator = if matches!(self.pattern, RowPattern::Concat(_)) {
+                    " "
+                } else {
+                    " | "
+                };
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "{separator}")?;
+                    }
+                    match pattern {
+                        RowPattern::Concat(_) | RowPattern::Alternation(_) => {
+                            write!(f, "({})", pattern.display(self.symbols))?
+                        }
+                        _ => write!(f, "{}", pattern.display(self.symbols))?,
+                    }
+                }
+                Ok(())
+            }
+            RowPattern::Quantified {
+                pattern,
+                min,
+                max,
+                reluctant,
+            } => {
+                match pattern.as_ref() {
+                    RowPattern::Symbol(_) => write!(f, "{}", pattern.display(self.symbols))?,
+                    _ => write!(f, "({})", pattern.display(self.symbols))?,
+                }
+                match max {
+                    Some(max) => write!(f, "{{{min},{max}}}")?,
+                    None => write!(f, "{{{min},}}")?,
+                }
+                if *reluctant {
+                    write!(f, "?")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A measure primitive computed over the rows of a match, such as `FIRST(A.price)`
+/// or `count(B.price)`. The measures of `MATCH_RECOGNIZE` are expressions of them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatchMeasure {
+    pub index: IndexType,
+    pub func: MatchMeasureFunc,
+    /// Only the rows mapped to this pattern variable are considered.
+    pub symbol: Option<usize>,
+    pub arg: Option<IndexType>,
+    pub return_type: DataType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MatchMeasureFunc {
+    First,
+    Last,
+    Aggregate(String),
+    MatchNumber,
+    Classifier,
+}
+
+impl Display for MatchMeasureFunc {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MatchMeasureFunc::First => write!(f, "first"),
+            MatchMeasureFunc::Last => write!(f, "last"),
+            MatchMeasureFunc::Aggregate(name) => write!(f, "{name}"),
+            MatchMeasureFunc::MatchNumber => write!(f, "match_number"),
+            MatchMeasureFunc::Classifier => write!(f, "classifier"),
+        }
+    }
+}
+
+impl Operator for MatchRecognize {
+    fn rel_op(&self) -> RelOp {
+        RelOp::MatchRecognize
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        if self.partition_by.is_empty() {
+            required.distribution = Distribution::Serial;
+        }
+        Ok(required)
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        let mut required = required.clone();
+        if self.partition_by.is_empty() {
+            required.distribution = Distribution::Serial;
+        }
+        Ok(vec![vec![required]])
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        let mut output_columns = input_prop.output_columns.clone();
+        output_columns.extend(self.measures.iter().map(|measure| measure.index));
+
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        let mut used_columns = self.used_columns();
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+}
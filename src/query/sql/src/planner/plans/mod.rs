@@ -27,11 +27,13 @@ mod eval_scalar;
 mod exchange;
 mod expression_scan;
 mod filter;
+mod gap_fill;
 mod insert;
 mod insert_multi_table;
 mod join;
 mod kill;
 mod limit;
+mod match_recognize;
 mod materialized_cte;
 mod mutation;
 mod mutation_source;
@@ -69,11 +71,13 @@ pub use eval_scalar::*;
 pub use exchange::*;
 pub use expression_scan::*;
 pub use filter::*;
+pub use gap_fill::*;
 pub use insert::*;
 pub use insert_multi_table::*;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use match_recognize::*;
 pub use materialized_cte::MaterializedCte;
 pub use mutation::MatchedEvaluator;
 pub use mutation::Mutation;
@@ -36,8 +36,10 @@ use crate::plans::EvalScalar;
 use crate::plans::Exchange;
 use crate::plans::ExpressionScan;
 use crate::plans::Filter;
+use crate::plans::GapFill;
 use crate::plans::Join;
 use crate::plans::Limit;
+use crate::plans::MatchRecognize;
 use crate::plans::Mutation;
 use crate::plans::OptimizeCompactBlock;
 use crate::plans::ProjectSet;
@@ -109,6 +111,8 @@ pub enum RelOp {
     UnionAll,
     DummyTableScan,
     Window,
+    MatchRecognize,
+    GapFill,
     ProjectSet,
     MaterializedCte,
     ConstantTableScan,
@@ -141,6 +145,8 @@ pub enum RelOperator {
     UnionAll(UnionAll),
     DummyTableScan(DummyTableScan),
     Window(Window),
+    MatchRecognize(MatchRecognize),
+    GapFill(GapFill),
     ProjectSet(ProjectSet),
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
@@ -170,6 +176,8 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::MatchRecognize(rel_op) => rel_op.rel_op(),
+            RelOperator::GapFill(rel_op) => rel_op.rel_op(),
             RelOperator::CteScan(rel_op) => rel_op.rel_op(),
             RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
@@ -199,6 +207,8 @@ impl Operator for RelOperator {
             RelOperator::UnionAll(rel_op) => rel_op.arity(),
             RelOperator::DummyTableScan(rel_op) => rel_op.arity(),
             RelOperator::Window(rel_op) => rel_op.arity(),
+            RelOperator::MatchRecognize(rel_op) => rel_op.arity(),
+            RelOperator::GapFill(rel_op) => rel_op.arity(),
             RelOperator::ProjectSet(rel_op) => rel_op.arity(),
             RelOperator::MaterializedCte(rel_op) => rel_op.arity(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.arity(),
@@ -228,6 +238,8 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::GapFill(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
@@ -257,6 +269,8 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::GapFill(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
@@ -286,6 +300,8 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::MatchRecognize(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::GapFill(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::CteScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_stats(rel_expr),
@@ -342,6 +358,12 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::MatchRecognize(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::GapFill(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -427,6 +449,12 @@ impl Operator for RelOperator {
             RelOperator::Window(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::MatchRecognize(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
+            RelOperator::GapFill(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -751,6 +779,46 @@ impl TryFrom<RelOperator> for ProjectSet {
     }
 }
 
+impl From<MatchRecognize> for RelOperator {
+    fn from(v: MatchRecognize) -> Self {
+        Self::MatchRecognize(v)
+    }
+}
+
+impl TryFrom<RelOperator> for MatchRecognize {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::MatchRecognize(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to MatchRecognize",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
+impl From<GapFill> for RelOperator {
+    fn from(v: GapFill) -> Self {
+        Self::GapFill(v)
+    }
+}
+
+impl TryFrom<RelOperator> for GapFill {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::GapFill(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to GapFill",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
 impl From<ConstantTableScan> for RelOperator {
     fn from(value: ConstantTableScan) -> Self {
         Self::ConstantTableScan(value)
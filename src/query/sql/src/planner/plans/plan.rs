@@ -34,12 +34,16 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterPipePlan;
+use crate::plans::AlterRolePlan;
+use crate::plans::AlterSequencePlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallProcedurePlan;
 use crate::plans::CopyIntoTableMode;
@@ -54,8 +58,10 @@ use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePipePlan;
 use crate::plans::CreateProcedurePlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSecretPlan;
 use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateStreamPlan;
@@ -66,11 +72,13 @@ use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescNotificationPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescPipePlan;
 use crate::plans::DescUserPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -84,8 +92,10 @@ use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPipePlan;
 use crate::plans::DropProcedurePlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSecretPlan;
 use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
@@ -98,6 +108,7 @@ use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::Exchange;
 use crate::plans::ExecuteImmediatePlan;
 use crate::plans::ExecuteTaskPlan;
@@ -109,9 +120,11 @@ use crate::plans::InsertMultiTable;
 use crate::plans::KillPlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
+use crate::plans::ModifyTableTtlPlan;
 use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
 use crate::plans::PresignPlan;
+use crate::plans::RefreshDynamicTablePlan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshTableIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
@@ -136,6 +149,7 @@ use crate::plans::ShowCreateTablePlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowSecretsPlan;
 use crate::plans::ShowTasksPlan;
 use crate::plans::SystemPlan;
 use crate::plans::TruncateTablePlan;
@@ -210,6 +224,7 @@ pub enum Plan {
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    ModifyTableTtl(Box<ModifyTableTtlPlan>),
     ReclusterTable {
         s_expr: Box<SExpr>,
         is_final: bool,
@@ -284,6 +299,7 @@ pub enum Plan {
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
+    AlterRole(Box<AlterRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
     RevokePriv(Box<RevokePrivilegePlan>),
@@ -307,6 +323,11 @@ pub enum Plan {
     DropConnection(Box<DropConnectionPlan>),
     ShowConnections(Box<ShowConnectionsPlan>),
 
+    // Secret
+    CreateSecret(Box<CreateSecretPlan>),
+    DropSecret(Box<DropSecretPlan>),
+    ShowSecrets(Box<ShowSecretsPlan>),
+
     // Presign
     Presign(Box<PresignPlan>),
 
@@ -335,6 +356,11 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Workload group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -344,11 +370,27 @@ pub enum Plan {
     ExecuteTask(Box<ExecuteTaskPlan>),
 
     CreateDynamicTable(Box<CreateDynamicTablePlan>),
+    RefreshDynamicTable(Box<RefreshDynamicTablePlan>),
+
+    // Pipe
+    CreatePipe(Box<CreatePipePlan>),
+    AlterPipe(Box<AlterPipePlan>),
+    DropPipe(Box<DropPipePlan>),
+    DescPipe(Box<DescPipePlan>),
 
     // Txn
     Begin,
     Commit,
     Abort,
+    Savepoint {
+        name: String,
+    },
+    RollbackToSavepoint {
+        name: String,
+    },
+    ReleaseSavepoint {
+        name: String,
+    },
 
     // Notifications
     CreateNotification(Box<CreateNotificationPlan>),
@@ -366,6 +408,7 @@ pub enum Plan {
 
     // sequence
     CreateSequence(Box<CreateSequencePlan>),
+    AlterSequence(Box<AlterSequencePlan>),
     DropSequence(Box<DropSequencePlan>),
 
     // Dictionary
@@ -394,6 +437,7 @@ pub enum RewriteKind {
     ShowDictionaries(String),
 
     ShowStreams(String),
+    ShowDynamicTables(String),
 
     ShowFunctions,
     ShowUserFunctions,
@@ -405,6 +449,9 @@ pub enum RewriteKind {
     ListStage,
     ShowRoles,
     ShowPasswordPolicies,
+    ShowWorkloadGroups,
+    ShowSequences,
+    DescribeSequence,
     ShowGrants,
 
     Call,
@@ -482,9 +529,11 @@ impl Plan {
             Plan::DescribeTask(plan) => plan.schema(),
             Plan::ShowTasks(plan) => plan.schema(),
             Plan::ExecuteTask(plan) => plan.schema(),
+            Plan::DescPipe(plan) => plan.schema(),
             Plan::DescNotification(plan) => plan.schema(),
             Plan::DescConnection(plan) => plan.schema(),
             Plan::ShowConnections(plan) => plan.schema(),
+            Plan::ShowSecrets(plan) => plan.schema(),
             Plan::ExecuteImmediate(plan) => plan.schema(),
             Plan::CallProcedure(plan) => plan.schema(),
             Plan::InsertMultiTable(plan) => plan.schema(),
@@ -30,6 +30,7 @@ use databend_common_expression::Scalar;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserApiProvider;
 use educe::Educe;
 use enum_as_inner::EnumAsInner;
 use itertools::Itertools;
@@ -796,6 +797,10 @@ pub enum AsyncFunctionArgument {
     // The dictionary argument is connection URL of remote source, like Redis, MySQL ...
     // Used by `dict_get` function to connect source and read data.
     DictGetFunction(DictGetFunctionArgument),
+    // The argument of secret function is secret name.
+    // Used by `secret` function to read the secret value from meta,
+    // so the value never appears in the SQL text or the plan.
+    SecretFunction(String),
 }
 
 #[derive(Clone, Debug, Educe, serde::Serialize, serde::Deserialize)]
@@ -860,6 +865,12 @@ impl AsyncFunctionCall {
             AsyncFunctionArgument::DictGetFunction(_dict_get_function_argument) => {
                 Err(ErrorCode::Internal("Cannot generate dict_get function"))
             }
+            AsyncFunctionArgument::SecretFunction(secret_name) => {
+                let secret = UserApiProvider::instance()
+                    .get_secret(&tenant, secret_name)
+                    .await?;
+                Ok(Scalar::String(secret.value))
+            }
         }
     }
 }
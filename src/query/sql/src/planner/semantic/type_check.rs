@@ -86,19 +86,26 @@ use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_functions::is_builtin_function;
 use databend_common_functions::ASYNC_FUNCTIONS;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_functions::ENCRYPTION_FUNCTIONS;
 use databend_common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use databend_common_functions::GENERAL_SEARCH_FUNCTIONS;
 use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
+use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
 use databend_common_meta_app::schema::DictionaryIdentity;
 use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_storage::init_stage_operator;
+use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use derive_visitor::Drive;
 use derive_visitor::Visitor;
 use indexmap::IndexMap;
@@ -1020,12 +1027,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr)?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit)?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -1732,6 +1734,32 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the k and counters of approx_top_k to params
+        let params = if func_name.eq_ignore_ascii_case("approx_top_k")
+            && (arguments.len() == 2 || arguments.len() == 3)
+            && params.is_empty()
+        {
+            let mut new_params = Vec::with_capacity(arguments.len() - 1);
+            for (argument, arg_type) in arguments.iter().zip(arg_types.iter()).skip(1) {
+                let value = ConstantExpr::try_from(argument.clone());
+                let is_positive_integer = match &value {
+                    Ok(v) => v.value.is_positive(),
+                    Err(_) => false,
+                } && arg_type.is_integer();
+                if !is_positive_integer {
+                    return Err(ErrorCode::SemanticError(
+                        "The k and counters of `approx_top_k` must be constant positive ints",
+                    ));
+                }
+                new_params.push(value.unwrap().value);
+            }
+            arguments.truncate(1);
+            arg_types.truncate(1);
+            new_params
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -2510,6 +2538,9 @@ impl<'a> TypeChecker<'a> {
         let mut index_schema = None;
         let mut index_options = BTreeMap::new();
         for table_index in table_indexes.values() {
+            if table_index.index_type != TableIndexType::Inverted {
+                continue;
+            }
             if column_ids
                 .iter()
                 .all(|id| table_index.column_ids.contains(id))
@@ -2673,7 +2704,28 @@ impl<'a> TypeChecker<'a> {
         let mut args = vec![];
         let mut arg_types = vec![];
 
-        for argument in arguments {
+        for (i, argument) in arguments.iter().enumerate() {
+            // The passphrase of encryption functions can be a secret stored in meta.
+            if i == 1 && ENCRYPTION_FUNCTIONS.contains(&func_name) {
+                if let Expr::FunctionCall {
+                    span: secret_span,
+                    func:
+                        ASTFunctionCall {
+                            name,
+                            args: secret_args,
+                            ..
+                        },
+                } = argument
+                {
+                    if name.name.eq_ignore_ascii_case("secret") {
+                        let box (arg, arg_type) =
+                            self.resolve_secret_function(*secret_span, secret_args)?;
+                        args.push(arg);
+                        arg_types.push(arg_type);
+                        continue;
+                    }
+                }
+            }
             let box (arg, mut arg_type) = self.resolve(argument)?;
             if let ScalarExpr::SubqueryExpr(subquery) = &arg {
                 if subquery.typ == SubqueryType::Scalar && !arg.data_type()?.is_nullable() {
@@ -2893,6 +2945,32 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolves `INTERVAL <expr> <unit>` to the interval of `expr` units,
+    /// e.g. `INTERVAL 30 DAY` is the same as `INTERVAL '30 day'`.
+    pub fn resolve_interval(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        unit: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if matches!(unit, ASTIntervalKind::Doy | ASTIntervalKind::Dow) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Unsupported interval unit {unit}, only these interval units are supported: [year, quarter, month, week, day, hour, minute, second]"
+            ))
+            .set_span(span));
+        }
+        let box (value, _) = self.resolve(expr)?;
+        let (value, _) =
+            *self.resolve_scalar_function_call(span, "to_string", vec![], vec![value])?;
+        let unit = ConstantExpr {
+            span,
+            value: Scalar::String(format!(" {}", unit.to_string().to_lowercase())),
+        };
+        let (interval, _) =
+            *self.resolve_scalar_function_call(span, "concat", vec![], vec![value, unit.into()])?;
+        self.resolve_scalar_function_call(span, "to_interval", vec![], vec![interval])
+    }
+
     pub fn resolve_date_arith(
         &mut self,
         span: Span,
@@ -3876,6 +3954,13 @@ impl<'a> TypeChecker<'a> {
         let result = match func_name {
             "nextval" => self.resolve_nextval_async_function(span, func_name, arguments)?,
             "dict_get" => self.resolve_dict_get_async_function(span, func_name, arguments)?,
+            "secret" => {
+                return Err(ErrorCode::SemanticError(
+                    "secret() can only be used as the passphrase of encryption functions"
+                        .to_string(),
+                )
+                .set_span(span));
+            }
             _ => {
                 return Err(ErrorCode::SemanticError(format!(
                     "cannot find async function {}",
@@ -3951,6 +4036,83 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((async_func.into(), return_type)))
     }
 
+    fn resolve_secret_function(
+        &mut self,
+        span: Span,
+        arguments: &[Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let secret_name = match arguments {
+            [
+                Expr::Literal {
+                    value: Literal::String(name),
+                    ..
+                },
+            ] => name.clone(),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "secret function need one string literal argument".to_string(),
+                )
+                .set_span(span));
+            }
+        };
+
+        databend_common_base::runtime::block_on(self.check_secret_access(&secret_name))
+            .map_err(|e| e.set_span(span))?;
+
+        // Only the name of the secret is displayed, its value is read at execution.
+        let display_name = format!("secret('{}')", secret_name);
+        let return_type = DataType::String;
+        let func_arg = AsyncFunctionArgument::SecretFunction(secret_name);
+
+        let async_func = AsyncFunctionCall {
+            span,
+            func_name: "secret".to_string(),
+            display_name,
+            return_type: Box::new(return_type.clone()),
+            arguments: vec![],
+            func_arg,
+        };
+
+        self.bind_context.have_async_func = true;
+        Ok(Box::new((async_func.into(), return_type)))
+    }
+
+    /// The secret can be read by the roles which own it or have the USAGE privilege on it.
+    async fn check_secret_access(&self, secret_name: &str) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        UserApiProvider::instance()
+            .get_secret(&tenant, secret_name)
+            .await?;
+
+        // The secret created before ownership was recorded is owned by account_admin.
+        let owner = RoleCacheManager::instance()
+            .find_object_owner(&tenant, &OwnershipObject::Secret {
+                name: secret_name.to_string(),
+            })
+            .await?
+            .unwrap_or_else(|| BUILTIN_ROLE_ACCOUNT_ADMIN.to_string());
+        let roles = self.ctx.get_all_effective_roles().await?;
+        if roles.iter().any(|role| role.name == owner) {
+            return Ok(());
+        }
+
+        let object = GrantObject::Secret(secret_name.to_string());
+        match self
+            .ctx
+            .validate_privilege(&object, UserPrivilegeType::Usage, false)
+            .await
+        {
+            Err(e) if e.code() == ErrorCode::PERMISSION_DENIED => {
+                Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege USAGE is required on secret {} for user {}",
+                    secret_name,
+                    self.ctx.get_current_user()?.identity().display(),
+                )))
+            }
+            res => res,
+        }
+    }
+
     fn resolve_dict_get_async_function(
         &mut self,
         span: Span,
@@ -4920,6 +5082,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::Variant => TableDataType::Variant,
         TypeName::Geometry => TableDataType::Geometry,
         TypeName::Geography => TableDataType::Geography,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
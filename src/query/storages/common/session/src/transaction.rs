@@ -40,6 +40,8 @@ pub struct TxnManager {
     state: TxnState,
     txn_buffer: TxnBuffer,
     txn_id: String,
+    // savepoints of the transaction, ordered by creation time.
+    savepoints: Vec<Savepoint>,
 }
 
 pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
@@ -65,6 +67,13 @@ pub struct TxnBuffer {
     mutated_temp_tables: HashMap<u64, TempTable>,
 }
 
+#[derive(Debug, Clone)]
+struct Savepoint {
+    name: String,
+    // the buffered state when the savepoint is created.
+    txn_buffer: TxnBuffer,
+}
+
 #[derive(Debug, Clone)]
 pub struct StreamSnapshot {
     pub stream: TableInfo,
@@ -125,6 +134,7 @@ impl TxnManager {
             state: TxnState::AutoCommit,
             txn_buffer: TxnBuffer::default(),
             txn_id: "".to_string(),
+            savepoints: vec![],
         }))
     }
 
@@ -143,6 +153,7 @@ impl TxnManager {
         self.state = TxnState::AutoCommit;
         self.txn_buffer.clear();
         self.txn_id = "".to_string();
+        self.savepoints.clear();
     }
 
     pub fn set_fail(&mut self) {
@@ -171,6 +182,43 @@ impl TxnManager {
         self.state.clone()
     }
 
+    /// Create a savepoint with the current buffered state,
+    /// an existing savepoint with the same name is replaced.
+    pub fn create_savepoint(&mut self, name: &str) {
+        self.savepoints.retain(|s| s.name != name);
+        self.savepoints.push(Savepoint {
+            name: name.to_string(),
+            txn_buffer: self.txn_buffer.clone(),
+        });
+    }
+
+    /// Restore the buffered state to the savepoint, and discard the savepoints created after it.
+    /// A failed transaction becomes active again.
+    ///
+    /// Returns false if the savepoint does not exist.
+    pub fn rollback_to_savepoint(&mut self, name: &str) -> bool {
+        let Some(pos) = self.savepoints.iter().position(|s| s.name == name) else {
+            return false;
+        };
+        self.savepoints.truncate(pos + 1);
+        self.txn_buffer = self.savepoints[pos].txn_buffer.clone();
+        if let TxnState::Fail = self.state {
+            self.state = TxnState::Active;
+        }
+        true
+    }
+
+    /// Remove the savepoint and the savepoints created after it, the buffered state is kept.
+    ///
+    /// Returns false if the savepoint does not exist.
+    pub fn release_savepoint(&mut self, name: &str) -> bool {
+        let Some(pos) = self.savepoints.iter().position(|s| s.name == name) else {
+            return false;
+        };
+        self.savepoints.truncate(pos);
+        true
+    }
+
     pub fn update_multi_table_meta(&mut self, req: UpdateMultiTableMetaReq) {
         self.txn_buffer.update_multi_table_meta(req);
     }
@@ -18,3 +18,7 @@ pub const OPT_KEY_REFRESH_MODE: &str = "refresh_mode";
 pub const OPT_KEY_INITIALIZE: &str = "initialize";
 pub const OPT_KEY_WAREHOUSE: &str = "warehouse";
 pub const OPT_KEY_LIFECYCLE: &str = "lifecycle";
+
+/// The snapshot location of the source table that the dynamic table was last refreshed from,
+/// incremental refresh applies the changes made to the source table since this snapshot.
+pub const OPT_KEY_SOURCE_SNAPSHOT_LOCATION: &str = "source_snapshot_location";
@@ -16,6 +16,13 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::LazyLock;
+
+use crate::table::OPT_KEY_AS_QUERY;
+use crate::table::OPT_KEY_INITIALIZE;
+use crate::table::OPT_KEY_REFRESH_MODE;
+use crate::table::OPT_KEY_SOURCE_SNAPSHOT_LOCATION;
+use crate::table::OPT_KEY_TARGET_LAG;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
@@ -27,6 +34,8 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+/// The normalized expression of `TTL <expr>`, a row is expired once the expression is earlier than now.
+pub const OPT_KEY_TTL: &str = "ttl";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -61,6 +70,12 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_AS_QUERY);
+    r.insert(OPT_KEY_TARGET_LAG);
+    r.insert(OPT_KEY_REFRESH_MODE);
+    r.insert(OPT_KEY_INITIALIZE);
+    r.insert(OPT_KEY_SOURCE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_TTL);
     r
 });
 
@@ -72,6 +87,8 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_SOURCE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_TTL);
     r
 });
 
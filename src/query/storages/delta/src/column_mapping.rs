@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use deltalake::kernel::DataType;
+use deltalake::kernel::MetadataValue;
+use deltalake::kernel::StructField;
+use deltalake::kernel::StructType;
+
+/// The table property of the column mapping mode, the mode is `none`, `name` or `id`.
+pub const COLUMN_MAPPING_MODE: &str = "delta.columnMapping.mode";
+
+/// The field metadata of the physical column name.
+const COLUMN_MAPPING_PHYSICAL_NAME: &str = "delta.columnMapping.physicalName";
+
+/// Returns the schema with the physical column names if column mapping is enabled.
+///
+/// In both `name` and `id` mode, the data files store the columns by their physical
+/// names, which are different from the logical names in the table schema.
+pub fn physical_schema(
+    mode: Option<&str>,
+    schema: &TableSchema,
+    delta_schema: &StructType,
+) -> Option<TableSchema> {
+    if matches!(mode, None | Some("none")) {
+        return None;
+    }
+
+    let fields = schema
+        .fields()
+        .iter()
+        .zip(delta_schema.fields())
+        .map(|(field, delta_field)| {
+            TableField::new(
+                &physical_name(delta_field),
+                physical_type(field.data_type(), delta_field.data_type()),
+            )
+        })
+        .collect();
+    Some(TableSchema::new(fields))
+}
+
+fn physical_name(field: &StructField) -> String {
+    match field.metadata().get(COLUMN_MAPPING_PHYSICAL_NAME) {
+        Some(MetadataValue::String(name)) => name.clone(),
+        _ => field.name().clone(),
+    }
+}
+
+fn physical_type(data_type: &TableDataType, delta_type: &DataType) -> TableDataType {
+    match (data_type, delta_type) {
+        (TableDataType::Nullable(inner), _) => {
+            TableDataType::Nullable(Box::new(physical_type(inner, delta_type)))
+        }
+        (
+            TableDataType::Tuple {
+                fields_name: _,
+                fields_type,
+            },
+            DataType::Struct(struct_type),
+        ) => {
+            let (fields_name, fields_type) = fields_type
+                .iter()
+                .zip(struct_type.fields())
+                .map(|(ty, field)| (physical_name(field), physical_type(ty, field.data_type())))
+                .unzip();
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        (TableDataType::Array(inner), DataType::Array(array_type)) => {
+            TableDataType::Array(Box::new(physical_type(inner, array_type.element_type())))
+        }
+        (TableDataType::Map(inner), DataType::Map(map_type)) => match inner.as_ref() {
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            } if fields_type.len() == 2 => TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: fields_name.clone(),
+                fields_type: vec![
+                    physical_type(&fields_type[0], map_type.key_type()),
+                    physical_type(&fields_type[1], map_type.value_type()),
+                ],
+            })),
+            _ => data_type.clone(),
+        },
+        _ => data_type.clone(),
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use deltalake::kernel::DeletionVectorDescriptor;
+use deltalake::kernel::StorageType;
+use opendal::Operator;
+use roaring::RoaringTreemap;
+
+/// The magic number at the beginning of a serialized deletion vector.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+
+/// The length of the z85 encoded uuid of a deletion vector file.
+const ENCODED_UUID_LEN: usize = 20;
+
+/// The location of the deletion vector of a data file.
+///
+/// A deletion vector marks the rows deleted from the data file by their
+/// positions, the deleted rows must be skipped while reading the file.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub enum DeletionVector {
+    /// The bitmap is encoded inline in the delta log.
+    Inline(String),
+    /// The bitmap is stored in a file relative to the table root.
+    File {
+        path: String,
+        offset: u64,
+        size: u64,
+    },
+}
+
+impl DeletionVector {
+    pub fn try_create(descriptor: &DeletionVectorDescriptor) -> Result<Self> {
+        match descriptor.storage_type {
+            StorageType::Inline => Ok(DeletionVector::Inline(descriptor.path_or_inline_dv.clone())),
+            StorageType::UuidRelativePath => {
+                let encoded = descriptor.path_or_inline_dv.as_str();
+                if encoded.len() < ENCODED_UUID_LEN {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "Invalid deletion vector path: {encoded}"
+                    )));
+                }
+                let (prefix, uuid) = encoded.split_at(encoded.len() - ENCODED_UUID_LEN);
+                let uuid = z85::decode(uuid)
+                    .ok()
+                    .and_then(|bytes| uuid::Uuid::from_slice(&bytes).ok())
+                    .ok_or_else(|| {
+                        ErrorCode::ReadTableDataError(format!(
+                            "Invalid deletion vector path: {encoded}"
+                        ))
+                    })?;
+                let file_name = format!("deletion_vector_{uuid}.bin");
+                let path = if prefix.is_empty() {
+                    file_name
+                } else {
+                    format!("{prefix}/{file_name}")
+                };
+                Ok(DeletionVector::File {
+                    path,
+                    offset: descriptor.offset.unwrap_or_default() as u64,
+                    size: descriptor.size_in_bytes as u64,
+                })
+            }
+            StorageType::Path => Err(ErrorCode::Unimplemented(format!(
+                "Deletion vector with absolute path is not supported: {}",
+                descriptor.path_or_inline_dv
+            ))),
+        }
+    }
+
+    /// Loads the positions of the deleted rows.
+    pub async fn load(&self, op: &Operator) -> Result<RoaringTreemap> {
+        let data = match self {
+            DeletionVector::Inline(encoded) => z85::decode(encoded).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Invalid inline deletion vector {encoded}: {e:?}"
+                ))
+            })?,
+            DeletionVector::File { path, offset, size } => {
+                // The serialized bitmap is prefixed by its size in 4 bytes and
+                // followed by the checksum.
+                let start = offset + 4;
+                op.read_with(path)
+                    .range(start..start + size)
+                    .await?
+                    .to_vec()
+            }
+        };
+
+        if data.len() < 4
+            || u32::from_le_bytes(data[0..4].try_into().unwrap()) != DELETION_VECTOR_MAGIC
+        {
+            return Err(ErrorCode::ReadTableDataError(
+                "Invalid deletion vector: magic number mismatch",
+            ));
+        }
+        RoaringTreemap::deserialize_from(&data[4..]).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot deserialize deletion vector: {e}"))
+        })
+    }
+}
@@ -15,6 +15,8 @@
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod column_mapping;
+mod deletion_vector;
 mod partition;
 mod table;
 mod table_source;
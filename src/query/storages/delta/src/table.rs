@@ -26,8 +26,11 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::AbortChecker;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -56,6 +59,9 @@ use serde::Serialize;
 use tokio::sync::OnceCell;
 use url::Url;
 
+use crate::column_mapping::physical_schema;
+use crate::column_mapping::COLUMN_MAPPING_MODE;
+use crate::deletion_vector::DeletionVector;
 use crate::partition::DeltaPartInfo;
 use crate::table_source::DeltaTableSource;
 
@@ -70,6 +76,12 @@ pub struct DeltaTable {
 #[derive(Serialize, Deserialize)]
 pub struct DeltaTableMeta {
     partition_columns: Vec<String>,
+    /// The version to read, the latest version is read if it's `None`.
+    #[serde(default)]
+    version: Option<i64>,
+    /// The schema with the physical column names, only set if column mapping is enabled.
+    #[serde(default)]
+    physical_schema: Option<TableSchema>,
 }
 
 /// In a delta table, partition columns are not stored in parquet file.
@@ -133,6 +145,14 @@ impl DeltaTable {
 
     #[async_backtrace::framed]
     pub async fn get_meta(table: &deltalake::table::DeltaTable) -> Result<(TableSchema, String)> {
+        let (schema, meta) = Self::build_meta(table)?;
+        let meta = serde_json::to_string(&meta).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
+        })?;
+        Ok((schema, meta))
+    }
+
+    fn build_meta(table: &deltalake::table::DeltaTable) -> Result<(TableSchema, DeltaTableMeta)> {
         let delta_meta = table.get_schema().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
         })?;
@@ -145,29 +165,35 @@ impl DeltaTable {
         let state = table.metadata().map_err(|_| {
             ErrorCode::ReadTableDataError("bug: Delta table current_metadata is None.")
         })?;
+        let schema = TableSchema::try_from(&arrow_schema)?;
+        let mapping_mode = state
+            .configuration
+            .get(COLUMN_MAPPING_MODE)
+            .cloned()
+            .flatten();
         let meta = DeltaTableMeta {
             partition_columns: state.partition_columns.clone(),
+            version: None,
+            physical_schema: physical_schema(mapping_mode.as_deref(), &schema, delta_meta),
         };
-        let meta = serde_json::to_string(&meta).map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
-        })?;
-
-        let schema = TableSchema::try_from(&arrow_schema)?;
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    fn build(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op));
 
-        let mut table = DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
+        DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
             .with_storage_backend(opendal_store, Url::from_directory_path("/").unwrap())
             .build()
             .map_err(|err| {
                 ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
-            })?;
+            })
+    }
 
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
@@ -179,11 +205,47 @@ impl DeltaTable {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
-                Self::load(sp).await
+                match self.meta.version {
+                    Some(version) => {
+                        let mut table = Self::build(sp)?;
+                        table.load_version(version).await.map_err(|err| {
+                            ErrorCode::ReadTableDataError(format!(
+                                "Delta table load version {version} failed: {err:?}"
+                            ))
+                        })?;
+                        Ok(table)
+                    }
+                    None => Self::load(sp).await,
+                }
             })
             .await
     }
 
+    /// Loads the table at the navigation point, a version or the latest version
+    /// committed at or before the time point.
+    #[async_backtrace::framed]
+    async fn load_at(&self, point: &NavigationPoint) -> Result<deltalake::table::DeltaTable> {
+        let sp = self.get_storage_params()?;
+        let mut table = Self::build(sp)?;
+        let res = match point {
+            NavigationPoint::Version(version) => table.load_version(*version as i64).await,
+            NavigationPoint::TimePoint(time_point) => table.load_with_datetime(*time_point).await,
+            _ => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Delta table {} only supports time travel by VERSION or TIMESTAMP",
+                    self.info.name
+                )));
+            }
+        };
+        res.map_err(|err| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "Delta table {} has no historical data at the given point: {err:?}",
+                self.info.name
+            ))
+        })?;
+        Ok(table)
+    }
+
     pub fn do_read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -194,15 +256,40 @@ impl DeltaTable {
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
         let max_threads = std::cmp::min(parts_len, max_threads);
 
-        let table_schema = self.schema();
+        let partition_field_indexes: Result<Vec<FieldIndex>> = self
+            .meta
+            .partition_columns
+            .iter()
+            .map(|name| self.info.meta.schema.index_of(name))
+            .collect();
+        let partition_field_indexes = partition_field_indexes?;
+
+        // The data files store the columns by physical names if column mapping is enabled.
+        let table_schema = match &self.meta.physical_schema {
+            Some(schema) => Arc::new(schema.clone()),
+            None => self.schema(),
+        };
+        let partition_columns = partition_field_indexes
+            .iter()
+            .map(|i| table_schema.field(*i).name.clone())
+            .collect::<Vec<_>>();
         let non_partition_fields = table_schema
             .fields()
             .iter()
-            .filter(|field| !self.meta.partition_columns.contains(&field.name))
-            .cloned()
+            .enumerate()
+            .filter(|(i, _)| !partition_field_indexes.contains(i))
+            .map(|(_, field)| field.clone())
             .collect();
         let table_schema = Arc::new(TableSchema::new(non_partition_fields));
 
+        // The filters are bound to the logical column names, and the deleted rows are
+        // located by their positions in the data file, so the rows can't be filtered
+        // while reading. The filters are still applied after the scan.
+        let has_deletion_vectors = plan.parts.partitions.iter().any(|part| {
+            DeltaPartInfo::from_part(part).is_ok_and(|part| part.deletion_vector.is_some())
+        });
+        let read_all_rows = has_deletion_vectors || self.meta.physical_schema.is_some();
+
         let arrow_schema = table_schema.as_ref().into();
         let leaf_fields = Arc::new(table_schema.leaf_fields());
 
@@ -220,38 +307,49 @@ impl DeltaTable {
             read_options = read_options.with_do_prewhere(false);
         }
 
-        let pruner = ParquetRSPruner::try_create(
-            ctx.get_function_context()?,
-            table_schema.clone(),
-            leaf_fields,
-            &plan.push_downs,
-            read_options,
-            self.meta.partition_columns.clone(),
-        )?;
+        if read_all_rows {
+            read_options = read_options
+                .with_prune_pages(false)
+                .with_prune_row_groups(false)
+                .with_do_prewhere(false);
+        }
+
+        let pruner = if read_all_rows {
+            None
+        } else {
+            Some(ParquetRSPruner::try_create(
+                ctx.get_function_context()?,
+                table_schema.clone(),
+                leaf_fields,
+                &plan.push_downs,
+                read_options,
+                partition_columns.clone(),
+            )?)
+        };
 
         let sp = self.get_storage_params()?;
         let op = init_operator(sp)?;
-        let partition_field_indexes: Result<Vec<FieldIndex>> = self
-            .meta
-            .partition_columns
-            .iter()
-            .map(|name| self.info.meta.schema.index_of(name))
-            .collect();
-        let partition_field_indexes = partition_field_indexes?;
         let push_downs = if let Some(ref p) = plan.push_downs {
+            let mut p = p.clone();
+            if read_all_rows {
+                if let Some(prewhere) = p.prewhere.take() {
+                    p.projection = Some(prewhere.output_columns);
+                }
+                p.filters = None;
+            }
             Some(get_pushdown_without_partition_columns(
-                p.clone(),
+                p,
                 &partition_field_indexes[..],
             )?)
         } else {
             None
         };
         let mut builder =
-            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, arrow_schema)?
+            ParquetRSReaderBuilder::create(ctx.clone(), op.clone(), table_schema, arrow_schema)?
                 .with_options(read_options)
                 .with_push_downs(push_downs.as_ref())
-                .with_pruner(Some(pruner))
-                .with_partition_columns(self.meta.partition_columns.clone());
+                .with_pruner(pruner)
+                .with_partition_columns(partition_columns);
 
         let parquet_reader = Arc::new(builder.build_full_reader()?);
 
@@ -264,6 +362,7 @@ impl DeltaTable {
                     output_schema.clone(),
                     parquet_reader.clone(),
                     self.get_partition_fields()?,
+                    op.clone(),
                 )
             },
             max_threads.max(1),
@@ -290,6 +389,28 @@ impl DeltaTable {
                 ErrorCode::ReadTableDataError(format!("Cannot read file_actions: {e:?}"))
             })?;
 
+        // The partition values are keyed by the physical column names if column
+        // mapping is enabled.
+        if let Some(physical_schema) = &self.meta.physical_schema {
+            let logical_names = partition_fields
+                .iter()
+                .map(|field| {
+                    let index = self.info.meta.schema.index_of(&field.name)?;
+                    Ok((
+                        physical_schema.field(index).name.clone(),
+                        field.name.clone(),
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            for add in adds.iter_mut() {
+                for (physical_name, logical_name) in logical_names.iter() {
+                    if let Some(value) = add.partition_values.remove(physical_name) {
+                        add.partition_values.insert(logical_name.clone(), value);
+                    }
+                }
+            }
+        }
+
         let filter_expression = push_downs.as_ref().and_then(|p| {
             p.filters
                 .as_ref()
@@ -331,11 +452,22 @@ impl DeltaTable {
                         _ => None,
                     }
                     ).unwrap_or(1);
-                read_rows += num_records as usize;
+                // The deleted rows are excluded from the file.
+                let num_records = num_records - add
+                    .deletion_vector
+                    .as_ref()
+                    .map_or(0, |dv| dv.cardinality);
+                read_rows += num_records.max(0) as usize;
                 read_bytes += add.size as usize;
                 let partition_values = get_partition_values(add, &partition_fields)?;
+                let deletion_vector = add
+                    .deletion_vector
+                    .as_ref()
+                    .map(DeletionVector::try_create)
+                    .transpose()?;
                 Ok(Arc::new(Box::new(DeltaPartInfo {
                         partition_values,
+                        deletion_vector,
                         data: ParquetPart::ParquetFiles(
                             ParquetFilesPart {
                                 files: vec![(add.path.clone(), add.size as u64)],
@@ -404,6 +536,36 @@ impl Table for DeltaTable {
         None
     }
 
+    #[async_backtrace::framed]
+    async fn navigate_to(
+        &self,
+        navigation: &TimeNavigation,
+        _abort_checker: AbortChecker,
+    ) -> Result<Arc<dyn Table>> {
+        let TimeNavigation::TimeTravel(point) = navigation else {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Changes query is not supported for delta table {}",
+                self.info.name
+            )));
+        };
+
+        let table = self.load_at(point).await?;
+        let (schema, mut meta) = Self::build_meta(&table)?;
+        meta.version = Some(table.version());
+
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(schema);
+        info.meta.engine_options.insert(
+            OPT_KEY_ENGINE_META.to_string(),
+            serde_json::to_string(&meta)?,
+        );
+        Ok(Arc::new(DeltaTable {
+            info,
+            table: OnceCell::new_with(Some(table)),
+            meta,
+        }))
+    }
+
     fn support_column_projection(&self) -> bool {
         true
     }
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
@@ -36,7 +37,9 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::ParquetFileReader;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSFullReader;
+use opendal::Operator;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
+use roaring::RoaringTreemap;
 
 use crate::partition::DeltaPartInfo;
 
@@ -62,10 +65,15 @@ pub struct DeltaTableSource {
     partition_fields: Vec<TableField>,
     // Used to check schema
     output_schema: DataSchemaRef,
+    // Used to load deletion vectors
+    op: Operator,
 
     // Per partition
     stream: Option<ParquetRecordBatchStream<ParquetFileReader>>,
     partition_block_entries: Vec<BlockEntry>,
+    // The rows deleted from the file and the position of the next row in the file.
+    deleted_rows: Option<RoaringTreemap>,
+    row_offset: u64,
 }
 
 impl DeltaTableSource {
@@ -75,6 +83,7 @@ impl DeltaTableSource {
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
         partition_fields: Vec<TableField>,
+        op: Operator,
     ) -> Result<ProcessorPtr> {
         let output_partition_columns = output_schema
             .fields()
@@ -96,10 +105,13 @@ impl DeltaTableSource {
             output_schema,
             partition_fields,
             output_partition_columns,
+            op,
             stream: None,
             generated_data: None,
             is_finished: false,
             partition_block_entries: vec![],
+            deleted_rows: None,
+            row_offset: 0,
         })))
     }
 }
@@ -153,6 +165,8 @@ impl Processor for DeltaTableSource {
                 .parquet_reader
                 .read_block_from_stream(&mut stream)
                 .await?
+                .map(|b| self.remove_deleted_rows(b))
+                .transpose()?
                 .map(|b| {
                     let mut columns = b.columns().to_vec();
                     for (fi, pi) in self.output_partition_columns.iter() {
@@ -180,6 +194,11 @@ impl Processor for DeltaTableSource {
                         .cloned()
                         .zip(part.partition_values.iter().cloned())
                         .collect::<Vec<_>>();
+                    self.deleted_rows = match &part.deletion_vector {
+                        Some(deletion_vector) => Some(deletion_vector.load(&self.op).await?),
+                        None => None,
+                    };
+                    self.row_offset = 0;
                     self.partition_block_entries = partition_fields
                         .iter()
                         .map(|(f, v)| {
@@ -206,6 +225,26 @@ impl Processor for DeltaTableSource {
     }
 }
 
+impl DeltaTableSource {
+    /// Removes the rows marked as deleted by the deletion vector of the current file.
+    fn remove_deleted_rows(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows() as u64;
+        let offset = self.row_offset;
+        self.row_offset += num_rows;
+
+        let Some(deleted_rows) = &self.deleted_rows else {
+            return Ok(block);
+        };
+        let bitmap = (offset..offset + num_rows)
+            .map(|row| !deleted_rows.contains(row))
+            .collect::<Bitmap>();
+        if bitmap.unset_bits() == 0 {
+            return Ok(block);
+        }
+        block.filter_with_bitmap(&bitmap)
+    }
+}
+
 fn check_block_schema(schema: &DataSchema, mut block: DataBlock) -> Result<DataBlock> {
     // Check if the schema of the data block is matched with the schema of the table.
     if block.num_columns() != schema.num_fields() {
@@ -16,6 +16,8 @@ use std::marker::PhantomData;
 
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_storages_common_table_meta::meta::trim_vacuum2_object_prefix;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
@@ -36,6 +38,7 @@ use crate::index::InvertedIndexFile;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
 static SNAPSHOT_V1: SnapshotVersion = SnapshotVersion::V1(PhantomData);
@@ -206,6 +209,38 @@ impl TableMetaLocationGenerator {
             InvertedIndexFile::VERSION,
         )
     }
+
+    pub fn gen_vector_index_location_from_block_location(
+        loc: &str,
+        index_name: &str,
+        index_version: &str,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_vacuum2_object_prefix(splits[len - 1]);
+        let id: String = block_name.chars().take(32).collect();
+        let short_ver: String = index_version.chars().take(7).collect();
+        format!(
+            "{}/{}/{}/{}/{}.index",
+            prefix, FUSE_TBL_VECTOR_INDEX_PREFIX, index_name, short_ver, id,
+        )
+    }
+
+    pub fn gen_table_index_location_from_block_location(loc: &str, index: &TableIndex) -> String {
+        match index.index_type {
+            TableIndexType::Inverted => Self::gen_inverted_index_location_from_block_location(
+                loc,
+                &index.name,
+                &index.version,
+            ),
+            TableIndexType::Vector => Self::gen_vector_index_location_from_block_location(
+                loc,
+                &index.name,
+                &index.version,
+            ),
+        }
+    }
 }
 
 trait SnapshotLocationCreator {
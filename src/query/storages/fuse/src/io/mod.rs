@@ -37,9 +37,11 @@ pub use segments::SerializedSegment;
 pub use snapshots::SnapshotLiteExtended;
 pub use snapshots::SnapshotsIO;
 pub(crate) use write::block_to_inverted_index;
+pub(crate) use write::column_to_vector_index;
 pub(crate) use write::create_index_schema;
 pub(crate) use write::create_inverted_index_builders;
 pub(crate) use write::create_tokenizer_manager;
+pub(crate) use write::create_vector_index_builders;
 pub use write::serialize_block;
 pub use write::write_data;
 pub use write::BlockBuilder;
@@ -51,4 +53,6 @@ pub use write::CachedMetaWriter;
 pub use write::InvertedIndexBuilder;
 pub use write::InvertedIndexWriter;
 pub use write::MetaWriter;
+pub use write::VectorIndexBuilder;
+pub use write::VectorIndexOptions;
 pub use write::WriteSettings;
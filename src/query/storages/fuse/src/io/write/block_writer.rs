@@ -32,7 +32,9 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_metrics::storage::metrics_inc_block_index_write_bytes;
 use databend_common_metrics::storage::metrics_inc_block_index_write_milliseconds;
 use databend_common_metrics::storage::metrics_inc_block_index_write_nums;
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_generate_milliseconds;
@@ -52,6 +54,8 @@ use databend_storages_common_table_meta::table::TableCompression;
 use opendal::Operator;
 
 use crate::io::block_to_inverted_index;
+use crate::io::write::column_to_vector_index;
+use crate::io::write::VectorIndexOptions;
 use crate::io::write::WriteSettings;
 use crate::io::BlockReader;
 use crate::io::InvertedIndexWriter;
@@ -246,7 +250,7 @@ pub struct InvertedIndexBuilder {
 pub fn create_inverted_index_builders(table_meta: &TableMeta) -> Vec<InvertedIndexBuilder> {
     let mut inverted_index_builders = Vec::with_capacity(table_meta.indexes.len());
     for index in table_meta.indexes.values() {
-        if !index.sync_creation {
+        if !index.sync_creation || index.index_type != TableIndexType::Inverted {
             continue;
         }
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
@@ -322,12 +326,85 @@ impl InvertedIndexState {
     }
 }
 
+#[derive(Clone)]
+pub struct VectorIndexBuilder {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) column_name: String,
+    pub(crate) options: VectorIndexOptions,
+}
+
+pub fn create_vector_index_builders(table_meta: &TableMeta) -> Vec<VectorIndexBuilder> {
+    let mut vector_index_builders = Vec::new();
+    for index in table_meta.indexes.values() {
+        if !index.sync_creation || index.index_type != TableIndexType::Vector {
+            continue;
+        }
+        // ignore invalid index
+        let Some(field) = table_meta
+            .schema
+            .fields
+            .iter()
+            .find(|field| index.column_ids == [field.column_id()])
+        else {
+            continue;
+        };
+        let Ok(options) = VectorIndexOptions::try_create(&index.options) else {
+            continue;
+        };
+        vector_index_builders.push(VectorIndexBuilder {
+            name: index.name.clone(),
+            version: index.version.clone(),
+            column_name: field.name.clone(),
+            options,
+        });
+    }
+    vector_index_builders
+}
+
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl VectorIndexState {
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &Location,
+        vector_index_builder: &VectorIndexBuilder,
+    ) -> Result<Self> {
+        let field_index = source_schema.index_of(&vector_index_builder.column_name)?;
+        let entry = block.get_by_offset(field_index);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        let data = column_to_vector_index(&column, &vector_index_builder.options)?;
+        let size = data.len() as u64;
+
+        let vector_index_location =
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                &block_location.0,
+                &vector_index_builder.name,
+                &vector_index_builder.version,
+            );
+
+        Ok(Self {
+            data,
+            size,
+            location: (vector_index_location, 0),
+        })
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
     pub inverted_index_states: Vec<InvertedIndexState>,
+    pub vector_index_states: Vec<VectorIndexState>,
 }
 
 #[derive(Clone)]
@@ -339,6 +416,7 @@ pub struct BlockBuilder {
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
     pub inverted_index_builders: Vec<InvertedIndexBuilder>,
+    pub vector_index_builders: Vec<VectorIndexBuilder>,
 }
 
 impl BlockBuilder {
@@ -370,6 +448,17 @@ impl BlockBuilder {
             inverted_index_states.push(inverted_index_state);
         }
 
+        let mut vector_index_states = Vec::with_capacity(self.vector_index_builders.len());
+        for vector_index_builder in &self.vector_index_builders {
+            let vector_index_state = VectorIndexState::try_create(
+                &self.source_schema,
+                &data_block,
+                &block_location,
+                vector_index_builder,
+            )?;
+            vector_index_states.push(vector_index_state);
+        }
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -413,6 +502,7 @@ impl BlockBuilder {
             block_meta,
             bloom_index_state,
             inverted_index_states,
+            vector_index_states,
         };
         Ok(serialized)
     }
@@ -427,6 +517,7 @@ impl BlockWriter {
         Self::write_down_data_block(dal, serialized.block_raw_data, &block_meta.location.0).await?;
         Self::write_down_bloom_index_state(dal, serialized.bloom_index_state).await?;
         Self::write_down_inverted_index_state(dal, serialized.inverted_index_states).await?;
+        Self::write_down_vector_index_state(dal, serialized.vector_index_states).await?;
 
         Ok(block_meta)
     }
@@ -481,4 +572,20 @@ impl BlockWriter {
         }
         Ok(())
     }
+    pub async fn write_down_vector_index_state(
+        dal: &Operator,
+        vector_index_states: Vec<VectorIndexState>,
+    ) -> Result<()> {
+        for vector_index_state in vector_index_states {
+            let start = Instant::now();
+
+            let location = &vector_index_state.location.0;
+            let index_size = vector_index_state.size;
+            write_data(vector_index_state.data, dal, location).await?;
+            metrics_inc_block_index_write_nums(1);
+            metrics_inc_block_index_write_bytes(index_size);
+            metrics_inc_block_index_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+        Ok(())
+    }
 }
@@ -15,9 +15,11 @@
 mod block_writer;
 mod inverted_index_writer;
 mod meta_writer;
+mod vector_index_writer;
 mod write_settings;
 
 pub(crate) use block_writer::create_inverted_index_builders;
+pub(crate) use block_writer::create_vector_index_builders;
 pub use block_writer::serialize_block;
 pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
@@ -26,10 +28,13 @@ pub use block_writer::BlockWriter;
 pub use block_writer::BloomIndexBuilder;
 pub use block_writer::BloomIndexState;
 pub use block_writer::InvertedIndexBuilder;
+pub use block_writer::VectorIndexBuilder;
 pub(crate) use inverted_index_writer::block_to_inverted_index;
 pub(crate) use inverted_index_writer::create_index_schema;
 pub(crate) use inverted_index_writer::create_tokenizer_manager;
 pub use inverted_index_writer::InvertedIndexWriter;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
+pub(crate) use vector_index_writer::column_to_vector_index;
+pub use vector_index_writer::VectorIndexOptions;
 pub use write_settings::WriteSettings;
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::Column;
+use databend_common_expression::ScalarRef;
+use databend_common_vector::DistanceType;
+use databend_common_vector::HnswIndex;
+
+const DEFAULT_VECTOR_INDEX_M: usize = 16;
+const DEFAULT_VECTOR_INDEX_EF_CONSTRUCT: usize = 100;
+
+/// The parameters of a vector index, parsed from the index options.
+#[derive(Clone, Copy, Debug)]
+pub struct VectorIndexOptions {
+    pub distance_type: DistanceType,
+    pub m: usize,
+    pub ef_construction: usize,
+}
+
+impl VectorIndexOptions {
+    pub fn try_create(options: &BTreeMap<String, String>) -> Result<Self> {
+        let parse = |key: &str, default: usize| -> Result<usize> {
+            match options.get(key) {
+                Some(val) => val.parse::<usize>().map_err(|_| {
+                    ErrorCode::IndexOptionInvalid(format!(
+                        "value `{val}` is invalid vector index option `{key}`"
+                    ))
+                }),
+                None => Ok(default),
+            }
+        };
+        let distance_type = match options.get("distance") {
+            Some(val) => val.parse::<DistanceType>()?,
+            None => DistanceType::Cosine,
+        };
+        Ok(Self {
+            distance_type,
+            m: parse("m", DEFAULT_VECTOR_INDEX_M)?,
+            ef_construction: parse("ef_construct", DEFAULT_VECTOR_INDEX_EF_CONSTRUCT)?,
+        })
+    }
+}
+
+/// Build the hnsw index of the vectors in a column of `Array(Float32)`.
+///
+/// The dimension of the index is the length of the first vector, null rows
+/// and the vectors of other dimensions are not indexed.
+pub fn column_to_vector_index(column: &Column, options: &VectorIndexOptions) -> Result<Vec<u8>> {
+    let mut index: Option<HnswIndex> = None;
+    for row in 0..column.len() {
+        let Some(ScalarRef::Array(Column::Number(NumberColumn::Float32(values)))) =
+            column.index(row)
+        else {
+            continue;
+        };
+        let vector = values.iter().map(|v| v.0).collect::<Vec<_>>();
+        if vector.is_empty() {
+            continue;
+        }
+        let index = index.get_or_insert_with(|| {
+            HnswIndex::new(
+                vector.len(),
+                options.distance_type,
+                options.m,
+                options.ef_construction,
+            )
+        });
+        if vector.len() == index.dimension() {
+            index.insert(row as u32, &vector)?;
+        }
+    }
+
+    let index = index.unwrap_or_else(|| {
+        HnswIndex::new(0, options.distance_type, options.m, options.ef_construction)
+    });
+    Ok(index.to_bytes())
+}
@@ -209,7 +209,7 @@ impl FuseTable {
         Ok(query)
     }
 
-    async fn optimize_stream_mode(
+    pub async fn optimize_stream_mode(
         &self,
         mode: &StreamMode,
         base_location: &Option<String>,
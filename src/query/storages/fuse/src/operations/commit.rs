@@ -222,10 +222,13 @@ impl FuseTable {
         deduplicated_label: Option<String>,
     ) -> Result<()> {
         // 1. prepare table meta
-        let new_table_meta =
+        let mut new_table_meta =
             Self::build_new_table_meta(&table_info.meta, &snapshot_location, &snapshot)?;
-        // 2. prepare the request
         let table_id = table_info.ident.table_id;
+        new_table_meta
+            .options
+            .extend(ctx.get_table_commit_options(table_id));
+        // 2. prepare the request
         let table_version = table_info.ident.seq;
 
         let mut update_temp_tables = vec![];
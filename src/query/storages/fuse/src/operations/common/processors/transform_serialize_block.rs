@@ -35,6 +35,7 @@ use databend_storages_common_index::BloomIndex;
 use opendal::Operator;
 
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::io::BlockWriter;
@@ -128,6 +129,7 @@ impl TransformSerializeBlock {
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&table.table_info.meta);
 
         let block_builder = BlockBuilder {
             ctx,
@@ -137,6 +139,7 @@ impl TransformSerializeBlock {
             cluster_stats_gen,
             bloom_columns_map,
             inverted_index_builders,
+            vector_index_builders,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -432,10 +432,8 @@ impl FuseTable {
 
                 for idx in inverted_indexes.values() {
                     inverted_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                            loc,
-                            idx.name.as_str(),
-                            idx.version.as_str(),
+                        TableMetaLocationGenerator::gen_table_index_location_from_block_location(
+                            loc, idx,
                         ),
                     );
                 }
@@ -519,10 +517,8 @@ impl FuseTable {
         for idx in inverted_indexes.values() {
             inverted_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(
                 |loc| {
-                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                        loc,
-                        idx.name.as_str(),
-                        idx.version.as_str(),
+                    TableMetaLocationGenerator::gen_table_index_location_from_block_location(
+                        loc, idx,
                     )
                 },
             ));
@@ -26,6 +26,7 @@ use databend_storages_common_table_meta::meta::Location;
 use super::merge_into::MatchedAggregator;
 use super::mutation::SegmentIndex;
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -91,6 +92,7 @@ impl FuseTable {
             .bloom_index_cols()
             .bloom_index_fields(new_schema.clone(), BloomIndex::supported_type)?;
         let inverted_index_builders = create_inverted_index_builders(&self.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&self.table_info.meta);
 
         let block_builder = BlockBuilder {
             ctx: ctx.clone(),
@@ -100,6 +102,7 @@ impl FuseTable {
             cluster_stats_gen,
             bloom_columns_map,
             inverted_index_builders,
+            vector_index_builders,
         };
         let aggregator = MatchedAggregator::create(
             ctx,
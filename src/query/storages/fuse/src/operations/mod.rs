@@ -34,7 +34,9 @@ mod replace;
 mod replace_into;
 mod revert;
 mod truncate;
+mod ttl;
 mod util;
+mod vector_index;
 
 pub use agg_index_sink::AggIndexSink;
 pub use analyze::HistogramInfoSink;
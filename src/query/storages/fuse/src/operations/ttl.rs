@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::parse_exprs;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::Statistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+use log::info;
+
+use crate::io::CachedMetaWriter;
+use crate::io::SegmentsIO;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::statistics::reducers::reduce_block_metas;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Removes the blocks whose rows are all expired by the TTL of the table,
+    /// which is decided by the column statistics, so no data is rewritten.
+    ///
+    /// Returns true if any block is removed.
+    #[async_backtrace::framed]
+    pub async fn remove_expired_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<bool> {
+        let Some(ttl) = self.table_info.options().get(OPT_KEY_TTL) else {
+            return Ok(false);
+        };
+        self.check_mutable()?;
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(false);
+        };
+
+        let func_ctx = ctx.get_function_context()?;
+        let filter = self.not_expired_filter(ctx.clone(), ttl, &func_ctx)?;
+        let range_index = RangeIndex::try_create(
+            func_ctx,
+            &filter,
+            self.schema(),
+            StatisticsOfColumns::default(),
+        )?;
+
+        let default_cluster_key_id = self.cluster_key_id();
+        let thresholds = self.get_block_thresholds();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = Statistics::default();
+        let mut num_removed_blocks = 0;
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segment_infos = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (segment, location) in segment_infos.into_iter().zip(chunk.iter()) {
+                let segment = segment?;
+                if !range_index.apply(&segment.summary.col_stats, |_| false)? {
+                    num_removed_blocks += segment.blocks.len();
+                    continue;
+                }
+
+                let mut blocks = Vec::with_capacity(segment.blocks.len());
+                for block in segment.blocks.iter() {
+                    if range_index.apply(&block.col_stats, |_| false)? {
+                        blocks.push(block.clone());
+                    }
+                }
+                if blocks.len() == segment.blocks.len() {
+                    merge_statistics_mut(&mut summary, &segment.summary, default_cluster_key_id);
+                    segments.push(location.clone());
+                    continue;
+                }
+
+                num_removed_blocks += segment.blocks.len() - blocks.len();
+                if blocks.is_empty() {
+                    continue;
+                }
+                let statistics = reduce_block_metas(&blocks, thresholds, default_cluster_key_id);
+                merge_statistics_mut(&mut summary, &statistics, default_cluster_key_id);
+                let new_segment = SegmentInfo::new(blocks, statistics);
+                let new_location = self.meta_location_generator.gen_segment_info_location();
+                new_segment
+                    .write_meta_through_cache(&self.operator, &new_location)
+                    .await?;
+                segments.push((new_location, SegmentInfo::VERSION));
+            }
+        }
+
+        if num_removed_blocks == 0 {
+            return Ok(false);
+        }
+        info!(
+            "remove {} expired blocks of table {}",
+            num_removed_blocks, self.table_info.desc
+        );
+        self.commit_mutation(&ctx, snapshot, &segments, summary, None)
+            .await?;
+        Ok(true)
+    }
+
+    /// Builds the filter `ttl > now`, rows without a TTL value never expire.
+    ///
+    /// `column + interval > now` is built as `column > now - interval`, because the
+    /// range index can't tell the domain of `column + interval`, which may throw.
+    fn not_expired_filter(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        ttl: &str,
+        func_ctx: &FunctionContext,
+    ) -> Result<Expr<String>> {
+        let schema = self.schema();
+        let mut exprs = parse_exprs(ctx, Arc::new(self.clone()), ttl)?;
+        let ttl = exprs
+            .remove(0)
+            .project_column_ref(|index| schema.field(*index).name().to_string());
+        let now = Expr::Constant {
+            span: None,
+            scalar: Scalar::Timestamp(func_ctx.now.timestamp_micros()),
+            data_type: DataType::Timestamp,
+        };
+
+        let (left, right) = match split_column_plus_interval(&ttl) {
+            Some((column, interval)) => {
+                let expiry =
+                    check_function(None, "minus", &[], &[now, interval], &BUILTIN_FUNCTIONS)?;
+                let (expiry, _) = ConstantFolder::fold(&expiry, func_ctx, &BUILTIN_FUNCTIONS);
+                (column, expiry)
+            }
+            None => (ttl.clone(), now),
+        };
+        let filter = check_function(None, "gt", &[], &[left, right], &BUILTIN_FUNCTIONS)?;
+        if !ttl.data_type().is_nullable() {
+            return Ok(filter);
+        }
+        let is_null = check_function(None, "is_null", &[], &[ttl], &BUILTIN_FUNCTIONS)?;
+        check_function(None, "or", &[], &[filter, is_null], &BUILTIN_FUNCTIONS)
+    }
+}
+
+/// Splits the TTL expression of the form `column + <constant interval>` into the column,
+/// which may be casted to timestamp, and the interval.
+fn split_column_plus_interval(ttl: &Expr<String>) -> Option<(Expr<String>, Expr<String>)> {
+    let Expr::FunctionCall { function, args, .. } = ttl else {
+        return None;
+    };
+    if function.signature.name != "plus" || args.len() != 2 {
+        return None;
+    }
+    let is_column = |expr: &Expr<String>| match expr {
+        Expr::ColumnRef { .. } => true,
+        Expr::Cast { expr, .. } => matches!(expr.as_ref(), Expr::ColumnRef { .. }),
+        _ => false,
+    };
+    let is_interval = |expr: &Expr<String>| {
+        matches!(expr, Expr::Constant {
+            scalar: Scalar::Interval(_),
+            ..
+        })
+    };
+    match (&args[0], &args[1]) {
+        (column, interval) | (interval, column) if is_column(column) && is_interval(interval) => {
+            Some((column.clone(), interval.clone()))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_metrics::storage::metrics_inc_block_index_write_bytes;
+use databend_common_metrics::storage::metrics_inc_block_index_write_milliseconds;
+use databend_common_metrics::storage::metrics_inc_block_index_write_nums;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::Location;
+use opendal::Operator;
+
+use crate::io::column_to_vector_index;
+use crate::io::write_data;
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexOptions;
+use crate::operations::inverted_index::InvertedIndexSink;
+use crate::operations::inverted_index::InvertedIndexSource;
+use crate::FuseTable;
+
+impl FuseTable {
+    // Refresh vector index in the same way as the inverted index,
+    // reads the blocks without the index, and generates the index of each block in parallel.
+    #[inline]
+    #[async_backtrace::framed]
+    pub async fn do_refresh_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index_name: String,
+        index_version: String,
+        index_options: &BTreeMap<String, String>,
+        index_schema: TableSchemaRef,
+        segment_locs: Option<Vec<Location>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let table_schema = &self.get_table_info().meta.schema;
+        let [field] = index_schema.fields.as_slice() else {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Vector index {} must be created on one column",
+                index_name
+            )));
+        };
+        let field_index = table_schema.index_of(field.name())?;
+        let options = VectorIndexOptions::try_create(index_options)?;
+
+        let projection = Projection::Columns(vec![field_index]);
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
+
+        // If no segment locations are specified, iterates through all segments
+        let segment_locs = if let Some(segment_locs) = segment_locs {
+            segment_locs
+                .into_iter()
+                .filter(|s| snapshot.segments.contains(s))
+                .collect()
+        } else {
+            snapshot.segments.clone()
+        };
+
+        if segment_locs.is_empty() {
+            return Ok(());
+        }
+        let operator = self.get_operator_ref();
+
+        // Read the segment infos and collect the block metas that need to generate the index.
+        let mut block_metas = VecDeque::new();
+        for (segment_loc, ver) in &segment_locs {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: segment_loc.to_string(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                let index_location =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &index_name,
+                        &index_version,
+                    );
+                // only generate vector index if it is not exist.
+                if (operator.stat(&index_location).await).is_err() {
+                    block_metas.push_back(block_meta);
+                }
+            }
+        }
+        if block_metas.is_empty() {
+            return Ok(());
+        }
+
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let write_settings = self.get_write_settings();
+        let storage_format = write_settings.storage_format;
+
+        pipeline.add_source(
+            |output| {
+                let inner = InvertedIndexSource::new(
+                    settings,
+                    storage_format,
+                    block_reader.clone(),
+                    block_metas.clone(),
+                );
+                AsyncSourcer::create(ctx.clone(), output, inner)
+            },
+            1,
+        )?;
+
+        let block_nums = block_metas.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(block_nums, max_threads);
+        pipeline.try_resize(max_threads)?;
+        pipeline.add_async_transformer(|| {
+            VectorIndexTransform::new(
+                index_name.clone(),
+                index_version.clone(),
+                options,
+                operator.clone(),
+            )
+        });
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| InvertedIndexSink::try_create(input, block_nums))?;
+
+        Ok(())
+    }
+}
+
+/// `VectorIndexTransform` is used to generate vector index for each blocks.
+pub struct VectorIndexTransform {
+    index_name: String,
+    index_version: String,
+    options: VectorIndexOptions,
+    operator: Operator,
+}
+
+impl VectorIndexTransform {
+    pub fn new(
+        index_name: String,
+        index_version: String,
+        options: VectorIndexOptions,
+        operator: Operator,
+    ) -> Self {
+        Self {
+            index_name,
+            index_version,
+            options,
+            operator,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for VectorIndexTransform {
+    const NAME: &'static str = "VectorIndexTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let block_meta = data_block
+            .get_meta()
+            .and_then(BlockMeta::downcast_ref_from)
+            .unwrap();
+
+        let index_location =
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                &block_meta.location.0,
+                &self.index_name,
+                &self.index_version,
+            );
+
+        let start = Instant::now();
+        let entry = data_block.get_by_offset(0);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, data_block.num_rows());
+        let data = column_to_vector_index(&column, &self.options)?;
+        let index_size = data.len() as u64;
+        write_data(data, &self.operator, &index_location).await?;
+
+        // Perf.
+        {
+            metrics_inc_block_index_write_nums(1);
+            metrics_inc_block_index_write_bytes(index_size);
+            metrics_inc_block_index_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+
+        let new_block = DataBlock::new(vec![], 0);
+        Ok(new_block)
+    }
+}
@@ -63,6 +63,7 @@ use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 use crate::pruning::VirtualColumnPruner;
 use crate::FuseStorageFormat;
 
@@ -80,6 +81,7 @@ pub struct PruningContext {
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
     pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    pub vector_index_pruner: Option<Arc<VectorIndexPruner>>,
     pub virtual_column_pruner: Option<Arc<VirtualColumnPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
@@ -164,6 +166,16 @@ impl PruningContext {
         // inverted index pruner, used to search matched rows in block
         let inverted_index_pruner = InvertedIndexPruner::try_create(ctx, dal.clone(), push_down)?;
 
+        // vector index pruner, used to search the nearest vectors in block
+        let vector_index_pruner = VectorIndexPruner::try_create(
+            ctx,
+            dal.clone(),
+            table_schema.clone(),
+            push_down,
+            storage_format,
+            max_concurrency,
+        )?;
+
         // virtual column pruner, used to read virtual column metas and ignore source columns.
         let virtual_column_pruner =
             VirtualColumnPruner::try_create(dal.clone(), push_down, storage_format)?;
@@ -195,6 +207,7 @@ impl PruningContext {
             page_pruner,
             internal_column_pruner,
             inverted_index_pruner,
+            vector_index_pruner,
             virtual_column_pruner,
             pruning_stats,
         });
@@ -304,7 +317,7 @@ impl FusePruner {
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> vector index pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -439,7 +452,8 @@ impl FusePruner {
             // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
             // will get here, we can prevent other mutations like update and so on.
             // TopN pruner.
-            self.topn_pruning(metas)
+            let metas = self.topn_pruning(metas)?;
+            self.vector_index_pruning(metas).await
         }
     }
 
@@ -511,7 +525,26 @@ impl FusePruner {
         // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
         // will get here, we can prevent other mutations like update and so on.
         // TopN pruner.
-        self.topn_pruning(metas)
+        let metas = self.topn_pruning(metas)?;
+        self.vector_index_pruning(metas).await
+    }
+
+    // vector index pruner:
+    // if the nearest vectors are searched by the vector index, prune the blocks
+    // which can not contain the nearest vectors.
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let Some(vector_index_pruner) = &self.pruning_ctx.vector_index_pruner else {
+            return Ok(metas);
+        };
+        let pruning_stats = &self.pruning_ctx.pruning_stats;
+        pruning_stats.set_blocks_vector_index_pruning_before(metas.len() as u64);
+        let metas = vector_index_pruner.prune(metas).await?;
+        pruning_stats.set_blocks_vector_index_pruning_after(metas.len() as u64);
+        Ok(metas)
     }
 
     // topn pruner:
@@ -554,6 +587,11 @@ impl FusePruner {
         let blocks_inverted_index_pruning_after =
             stats.get_blocks_inverted_index_pruning_after() as usize;
 
+        let blocks_vector_index_pruning_before =
+            stats.get_blocks_vector_index_pruning_before() as usize;
+        let blocks_vector_index_pruning_after =
+            stats.get_blocks_vector_index_pruning_after() as usize;
+
         databend_common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -563,6 +601,8 @@ impl FusePruner {
             blocks_bloom_pruning_after,
             blocks_inverted_index_pruning_before,
             blocks_inverted_index_pruning_after,
+            blocks_vector_index_pruning_before,
+            blocks_vector_index_pruning_after,
         }
     }
 
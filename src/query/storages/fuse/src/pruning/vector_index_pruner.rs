@@ -0,0 +1,264 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_vector::HnswIndex;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use log::warn;
+use opendal::Operator;
+
+use crate::io::BlockReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseStorageFormat;
+
+// The size of the dynamic candidate list used to search the index.
+const DEFAULT_VECTOR_INDEX_EF_SEARCH: usize = 64;
+// If there are filters, more candidates are searched,
+// as some of the nearest rows may be filtered out.
+const FILTER_OVERSAMPLING_FACTOR: usize = 4;
+
+// Each block has a corresponding hnsw index file, the index searches the
+// nearest vectors of the query vector in the block.
+// The k-th smallest distance of the nearest vectors in all the blocks is used
+// as the threshold, if the nearest vector of a block is farther than
+// the threshold, the block can not contain the top k rows and can be pruned.
+// Blocks without index file are always kept.
+// If there are filters, the nearest rows not matching them are skipped before
+// choosing the threshold, and a block is kept if none of its nearest rows match.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    index_name: String,
+    index_version: String,
+    query_values: Vec<f32>,
+    k: usize,
+    filter: Option<VectorIndexFilter>,
+    max_concurrency: usize,
+}
+
+// Reads the columns of the filters in a block to check the nearest rows.
+struct VectorIndexFilter {
+    block_reader: Arc<BlockReader>,
+    expr: Expr,
+    func_ctx: FunctionContext,
+    settings: ReadSettings,
+    storage_format: FuseStorageFormat,
+}
+
+impl VectorIndexPruner {
+    pub fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        dal: Operator,
+        table_schema: TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+        storage_format: FuseStorageFormat,
+        max_concurrency: usize,
+    ) -> Result<Option<Arc<VectorIndexPruner>>> {
+        let Some(push_down) = push_down else {
+            return Ok(None);
+        };
+        let Some(VectorIndexInfo {
+            index_name,
+            index_version,
+            query_values,
+            limit,
+            ..
+        }) = &push_down.vector_index
+        else {
+            return Ok(None);
+        };
+
+        // A constant filter keeps all the rows or none of them, it needs no check.
+        let filter_expr = push_down
+            .filters
+            .as_ref()
+            .map(|filters| filters.filter.as_expr(&BUILTIN_FUNCTIONS))
+            .filter(|expr| !expr.column_refs().is_empty());
+        let filter = match filter_expr {
+            Some(expr) => {
+                // The filters on the columns not stored in the block can not be checked.
+                let Some(mut indices) = expr
+                    .column_refs()
+                    .keys()
+                    .map(|name| table_schema.index_of(name).ok())
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    return Ok(None);
+                };
+                if expr.data_type() != &DataType::Boolean {
+                    return Ok(None);
+                }
+                indices.sort();
+                let block_reader = BlockReader::create(
+                    ctx.clone(),
+                    dal.clone(),
+                    table_schema,
+                    Projection::Columns(indices),
+                    false,
+                    false,
+                    false,
+                )?;
+                let schema = block_reader.schema();
+                let expr = expr.project_column_ref(|name| schema.index_of(name).unwrap());
+                Some(VectorIndexFilter {
+                    block_reader,
+                    expr,
+                    func_ctx: ctx.get_function_context()?,
+                    settings: ReadSettings::from_ctx(ctx)?,
+                    storage_format,
+                })
+            }
+            None => None,
+        };
+
+        Ok(Some(Arc::new(VectorIndexPruner {
+            dal,
+            index_name: index_name.clone(),
+            index_version: index_version.clone(),
+            query_values: query_values.iter().map(|v| v.0).collect(),
+            k: *limit,
+            filter,
+            max_concurrency: max_concurrency.max(1),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        if self.k == 0 || metas.is_empty() {
+            return Ok(metas);
+        }
+
+        let distances = futures::stream::iter(metas.iter())
+            .map(|(_, block_meta)| self.search(block_meta))
+            .buffered(self.max_concurrency)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut all_distances = distances
+            .iter()
+            .flatten()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        // Not enough rows are indexed, all the blocks are needed.
+        if all_distances.len() < self.k {
+            return Ok(metas);
+        }
+        let (_, threshold, _) = all_distances.select_nth_unstable_by(self.k - 1, f32::total_cmp);
+        let threshold = *threshold;
+
+        Ok(metas
+            .into_iter()
+            .zip(distances)
+            .filter(|(_, distances)| match distances {
+                None => true,
+                Some(distances) => distances.first().is_some_and(|d| *d <= threshold),
+            })
+            .map(|(meta, _)| meta)
+            .collect())
+    }
+
+    // Returns the distances of the nearest vectors in the block which match the filters,
+    // or `None` if the block can not be searched by the index.
+    async fn search(&self, block_meta: &BlockMeta) -> Result<Option<Vec<f32>>> {
+        let index_loc = TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+            &block_meta.location.0,
+            &self.index_name,
+            &self.index_version,
+        );
+        let data = match self.dal.read(&index_loc).await {
+            Ok(data) => data.to_vec(),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let index = match HnswIndex::from_bytes(&data) {
+            Ok(index) => index,
+            Err(e) => {
+                warn!("failed to load vector index {}: {}", index_loc, e);
+                return Ok(None);
+            }
+        };
+        // A block without vectors has no rows in top k, the vectors of other dimensions
+        // can not be compared with the query vector.
+        if index.is_empty() {
+            return Ok(Some(vec![]));
+        }
+        if index.dimension() != self.query_values.len() {
+            return Ok(None);
+        }
+
+        let Some(filter) = &self.filter else {
+            let ef = self.k.max(DEFAULT_VECTOR_INDEX_EF_SEARCH);
+            let result = index.search(&self.query_values, self.k, ef)?;
+            return Ok(Some(
+                result.into_iter().map(|(_, distance)| distance).collect(),
+            ));
+        };
+
+        let k = self.k.saturating_mul(FILTER_OVERSAMPLING_FACTOR);
+        let ef = k.max(DEFAULT_VECTOR_INDEX_EF_SEARCH);
+        let result = index.search(&self.query_values, k, ef)?;
+
+        let block = filter
+            .block_reader
+            .read_by_meta(&filter.settings, block_meta, &filter.storage_format)
+            .await?;
+        let evaluator = Evaluator::new(&block, &filter.func_ctx, &BUILTIN_FUNCTIONS);
+        let matched = match evaluator.run(&filter.expr)?.try_downcast::<BooleanType>() {
+            Some(Value::Scalar(true)) => {
+                return Ok(Some(
+                    result.into_iter().map(|(_, distance)| distance).collect(),
+                ));
+            }
+            Some(Value::Scalar(false)) => return Ok(Some(vec![])),
+            Some(Value::Column(bitmap)) => bitmap,
+            None => return Ok(None),
+        };
+        // No rows of the block match the filters.
+        if matched.unset_bits() == matched.len() {
+            return Ok(Some(vec![]));
+        }
+
+        let distances = result
+            .into_iter()
+            .filter(|(row, _)| matched.get_bit(*row as usize))
+            .map(|(_, distance)| distance)
+            .collect::<Vec<_>>();
+        // The nearest matched row of the block is unknown.
+        if distances.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(distances))
+    }
+}
@@ -23,9 +23,13 @@ mod catalog;
 mod database;
 mod partition;
 mod table;
+mod table_functions;
+mod table_sink;
 mod table_source;
 
 pub use catalog::IcebergCatalog;
 pub use catalog::IcebergCreator;
 pub use catalog::ICEBERG_CATALOG;
 pub use table::IcebergTable;
+pub use table_functions::IcebergManifestFunc;
+pub use table_functions::IcebergSnapshotFunc;
@@ -26,8 +26,11 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::AbortChecker;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -36,11 +39,19 @@ use databend_common_expression::TableSchema;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use futures::TryStreamExt;
 use tokio::sync::OnceCell;
 
 use crate::partition::IcebergPartInfo;
+use crate::table_sink::IcebergCommitSink;
+use crate::table_sink::IcebergDataFileWriter;
 use crate::table_source::IcebergTableSource;
 use crate::IcebergCatalog;
 
@@ -50,11 +61,13 @@ pub const ICEBERG_ENGINE: &str = "ICEBERG";
 #[derive(Clone)]
 pub struct IcebergTable {
     info: TableInfo,
-    ctl: IcebergCatalog,
-    database_name: String,
-    table_name: String,
+    pub(crate) ctl: IcebergCatalog,
+    pub(crate) database_name: String,
+    pub(crate) table_name: String,
 
     table: OnceCell<iceberg::table::Table>,
+    // The snapshot to read, `None` means the current snapshot.
+    snapshot_id: Option<i64>,
 }
 
 impl IcebergTable {
@@ -71,6 +84,7 @@ impl IcebergTable {
             database_name: db_name.to_string(),
             table_name: table_name.to_string(),
             table: OnceCell::new(),
+            snapshot_id: None,
         }))
     }
 
@@ -100,9 +114,12 @@ impl IcebergTable {
 
     pub fn get_schema(table: &iceberg::table::Table) -> Result<TableSchema> {
         let meta = table.metadata();
+        Self::convert_schema(meta.current_schema())
+    }
 
+    fn convert_schema(schema: &iceberg::spec::Schema) -> Result<TableSchema> {
         // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta.current_schema().as_ref().try_into().map_err(|e| {
+        let arrow_schema: ArrowSchema = schema.try_into().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
         })?;
         TableSchema::try_from(&arrow_schema)
@@ -139,6 +156,7 @@ impl IcebergTable {
             database_name: database_name.to_string(),
             table_name: table_name.to_string(),
             table: OnceCell::new_with(Some(table)),
+            snapshot_id: None,
         })
     }
 
@@ -160,6 +178,55 @@ impl IcebergTable {
             .await
     }
 
+    /// Returns the snapshot of the table at the navigation point.
+    ///
+    /// The snapshot id is the id of an iceberg snapshot, and the time point
+    /// resolves to the latest snapshot committed at or before it.
+    async fn find_snapshot(&self, point: &NavigationPoint) -> Result<iceberg::spec::SnapshotRef> {
+        let table = self.table().await?;
+        let metadata = table.metadata();
+        match point {
+            NavigationPoint::SnapshotID(id) => {
+                let snapshot_id = id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid iceberg snapshot id '{id}', it must be an integer"
+                    ))
+                })?;
+                metadata
+                    .snapshot_by_id(snapshot_id)
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::TableHistoricalDataNotFound(format!(
+                            "No snapshot found with id {snapshot_id} in iceberg table {}",
+                            self.info.desc
+                        ))
+                    })
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                let timestamp_ms = time_point.timestamp_millis();
+                metadata
+                    .snapshots()
+                    .filter(|snapshot| snapshot.timestamp_ms() <= timestamp_ms)
+                    .max_by_key(|snapshot| (snapshot.timestamp_ms(), snapshot.sequence_number()))
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::TableHistoricalDataNotFound(format!(
+                            "No snapshot found at or before {time_point} in iceberg table {}",
+                            self.info.desc
+                        ))
+                    })
+            }
+            NavigationPoint::Version(_) => Err(ErrorCode::Unimplemented(format!(
+                "Time travel by VERSION is not supported for iceberg table {}, use SNAPSHOT or TIMESTAMP instead",
+                self.info.desc
+            ))),
+            NavigationPoint::StreamInfo(_) => Err(ErrorCode::Unimplemented(format!(
+                "Stream is not supported for iceberg table {}",
+                self.info.desc
+            ))),
+        }
+    }
+
     pub fn do_read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -189,6 +256,9 @@ impl IcebergTable {
         let table = self.table().await?;
 
         let mut scan = table.scan();
+        if let Some(snapshot_id) = self.snapshot_id {
+            scan = scan.snapshot_id(snapshot_id);
+        }
 
         if let Some(push_downs) = &push_downs {
             if let Some(projection) = &push_downs.projection {
@@ -272,10 +342,84 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    /// Appends the data to the iceberg table as parquet data files.
+    ///
+    /// Only appending to unpartitioned tables is supported, INSERT OVERWRITE and
+    /// COPY INTO without FORCE = TRUE are rejected in `commit_insertion`.
+    fn append_data(&self, ctx: Arc<dyn TableContext>, pipeline: &mut Pipeline) -> Result<()> {
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input,
+                output,
+                IcebergDataFileWriter::create(ctx.clone(), self.clone()),
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "INSERT OVERWRITE is not supported for iceberg table {}",
+                self.info.desc
+            )));
+        }
+
+        // The copied files can not be recorded in iceberg tables, refuse the
+        // COPY that relies on them to skip loaded files.
+        if copied_files.is_some_and(|req| req.insert_if_not_exists) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "COPY INTO iceberg table {} without FORCE = TRUE is not supported, the loaded files are not tracked in iceberg tables and can not be skipped",
+                self.info.desc
+            )));
+        }
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                IcebergCommitSink::create(self.clone()),
+            )))
+        })
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
 
+    #[async_backtrace::framed]
+    async fn navigate_to(
+        &self,
+        navigation: &TimeNavigation,
+        _abort_checker: AbortChecker,
+    ) -> Result<Arc<dyn Table>> {
+        let TimeNavigation::TimeTravel(point) = navigation else {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Changes query is not supported for iceberg table {}",
+                self.info.desc
+            )));
+        };
+
+        let snapshot = self.find_snapshot(point).await?;
+        let table = self.table().await?;
+        let schema = snapshot.schema(table.metadata()).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot load schema of snapshot: {e:?}"))
+        })?;
+
+        let mut navigated = self.clone();
+        navigated.info.meta.schema = Arc::new(Self::convert_schema(&schema)?);
+        navigated.snapshot_id = Some(snapshot.snapshot_id());
+        Ok(Arc::new(navigated))
+    }
+
     fn support_column_projection(&self) -> bool {
         true
     }
@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_storages_fuse::table_functions::string_literal;
+use databend_common_storages_fuse::table_functions::SimpleTableFunc;
+use iceberg::spec::ManifestContentType;
+
+use crate::table_functions::get_iceberg_table;
+use crate::table_functions::parse_catalog_db_tb_snapshot_args;
+
+pub struct IcebergManifestArgs {
+    catalog_name: Option<String>,
+    database_name: String,
+    table_name: String,
+    snapshot_id: Option<i64>,
+}
+
+/// Lists the manifests of an iceberg table snapshot, the current snapshot is
+/// used if no snapshot id is given.
+pub struct IcebergManifestFunc {
+    args: IcebergManifestArgs,
+}
+
+impl From<&IcebergManifestArgs> for TableArgs {
+    fn from(args: &IcebergManifestArgs) -> Self {
+        let mut tbl_args = Vec::new();
+        if let Some(catalog_name) = &args.catalog_name {
+            tbl_args.push(string_literal(catalog_name));
+        }
+        tbl_args.push(string_literal(args.database_name.as_str()));
+        tbl_args.push(string_literal(args.table_name.as_str()));
+        if let Some(snapshot_id) = args.snapshot_id {
+            tbl_args.push(Scalar::Number(NumberScalar::Int64(snapshot_id)));
+        }
+        TableArgs::new_positioned(tbl_args)
+    }
+}
+
+#[async_trait::async_trait]
+impl SimpleTableFunc for IcebergManifestFunc {
+    fn table_args(&self) -> Option<TableArgs> {
+        Some((&self.args).into())
+    }
+
+    fn schema(&self) -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("snapshot_id", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("manifest_path", TableDataType::String),
+            TableField::new(
+                "manifest_length",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new("content", TableDataType::String),
+            TableField::new(
+                "partition_spec_id",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new(
+                "sequence_number",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "added_snapshot_id",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new(
+                "added_files_count",
+                TableDataType::Number(NumberDataType::UInt32).wrap_nullable(),
+            ),
+            TableField::new(
+                "existing_files_count",
+                TableDataType::Number(NumberDataType::UInt32).wrap_nullable(),
+            ),
+            TableField::new(
+                "deleted_files_count",
+                TableDataType::Number(NumberDataType::UInt32).wrap_nullable(),
+            ),
+            TableField::new(
+                "added_rows_count",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "existing_rows_count",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "deleted_rows_count",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+        ])
+    }
+
+    async fn apply(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+    ) -> Result<Option<DataBlock>> {
+        let table = get_iceberg_table(
+            ctx,
+            self.args.catalog_name.as_deref(),
+            &self.args.database_name,
+            &self.args.table_name,
+            "iceberg_manifest",
+        )
+        .await?;
+        let metadata = table.metadata();
+        let snapshot = match self.args.snapshot_id {
+            Some(id) => Some(metadata.snapshot_by_id(id).ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "No snapshot found with id {id} in iceberg table {}.{}",
+                    self.args.database_name, self.args.table_name
+                ))
+            })?),
+            None => metadata.current_snapshot(),
+        };
+        let Some(snapshot) = snapshot else {
+            return Ok(Some(DataBlock::empty_with_schema(Arc::new(
+                self.schema().into(),
+            ))));
+        };
+
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), metadata)
+            .await
+            .map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Cannot load manifest list: {e:?}"))
+            })?;
+        let mut manifests = manifest_list.entries().iter().collect::<Vec<_>>();
+        if let Some(limit) = plan.push_downs.as_ref().and_then(|v| v.limit) {
+            manifests.truncate(limit);
+        }
+
+        let len = manifests.len();
+        let mut manifest_paths = Vec::with_capacity(len);
+        let mut manifest_lengths = Vec::with_capacity(len);
+        let mut contents = Vec::with_capacity(len);
+        let mut partition_spec_ids = Vec::with_capacity(len);
+        let mut sequence_numbers = Vec::with_capacity(len);
+        let mut added_snapshot_ids = Vec::with_capacity(len);
+        let mut added_files_counts = Vec::with_capacity(len);
+        let mut existing_files_counts = Vec::with_capacity(len);
+        let mut deleted_files_counts = Vec::with_capacity(len);
+        let mut added_rows_counts = Vec::with_capacity(len);
+        let mut existing_rows_counts = Vec::with_capacity(len);
+        let mut deleted_rows_counts = Vec::with_capacity(len);
+        for m in manifests {
+            manifest_paths.push(m.manifest_path.clone());
+            manifest_lengths.push(m.manifest_length);
+            contents.push(match m.content {
+                ManifestContentType::Data => "data".to_string(),
+                ManifestContentType::Deletes => "deletes".to_string(),
+            });
+            partition_spec_ids.push(m.partition_spec_id);
+            sequence_numbers.push(m.sequence_number);
+            added_snapshot_ids.push(m.added_snapshot_id);
+            added_files_counts.push(m.added_files_count);
+            existing_files_counts.push(m.existing_files_count);
+            deleted_files_counts.push(m.deleted_files_count);
+            added_rows_counts.push(m.added_rows_count);
+            existing_rows_counts.push(m.existing_rows_count);
+            deleted_rows_counts.push(m.deleted_rows_count);
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int64Type::from_data(vec![snapshot.snapshot_id(); len]),
+            StringType::from_data(manifest_paths),
+            Int64Type::from_data(manifest_lengths),
+            StringType::from_data(contents),
+            Int32Type::from_data(partition_spec_ids),
+            Int64Type::from_data(sequence_numbers),
+            Int64Type::from_data(added_snapshot_ids),
+            UInt32Type::from_opt_data(added_files_counts),
+            UInt32Type::from_opt_data(existing_files_counts),
+            UInt32Type::from_opt_data(deleted_files_counts),
+            UInt64Type::from_opt_data(added_rows_counts),
+            UInt64Type::from_opt_data(existing_rows_counts),
+            UInt64Type::from_opt_data(deleted_rows_counts),
+        ])))
+    }
+
+    fn create(func_name: &str, table_args: TableArgs) -> Result<Self>
+    where Self: Sized {
+        let (arg_catalog_name, arg_database_name, arg_table_name, arg_snapshot_id) =
+            parse_catalog_db_tb_snapshot_args(&table_args, func_name)?;
+        Ok(Self {
+            args: IcebergManifestArgs {
+                catalog_name: arg_catalog_name,
+                database_name: arg_database_name,
+                table_name: arg_table_name,
+                snapshot_id: arg_snapshot_id,
+            },
+        })
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_storages_fuse::table_functions::string_literal;
+use databend_common_storages_fuse::table_functions::SimpleTableFunc;
+use iceberg::spec::Operation;
+
+use crate::table_functions::get_iceberg_table;
+use crate::table_functions::parse_catalog_db_tb_args;
+
+pub struct IcebergSnapshotArgs {
+    catalog_name: Option<String>,
+    database_name: String,
+    table_name: String,
+}
+
+/// Lists the snapshots of an iceberg table, the latest snapshot comes first.
+pub struct IcebergSnapshotFunc {
+    args: IcebergSnapshotArgs,
+}
+
+impl From<&IcebergSnapshotArgs> for TableArgs {
+    fn from(args: &IcebergSnapshotArgs) -> Self {
+        let mut tbl_args = Vec::new();
+        if let Some(catalog_name) = &args.catalog_name {
+            tbl_args.push(string_literal(catalog_name));
+        }
+        tbl_args.push(string_literal(args.database_name.as_str()));
+        tbl_args.push(string_literal(args.table_name.as_str()));
+        TableArgs::new_positioned(tbl_args)
+    }
+}
+
+#[async_trait::async_trait]
+impl SimpleTableFunc for IcebergSnapshotFunc {
+    fn table_args(&self) -> Option<TableArgs> {
+        Some((&self.args).into())
+    }
+
+    fn schema(&self) -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("snapshot_id", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new(
+                "parent_snapshot_id",
+                TableDataType::Number(NumberDataType::Int64).wrap_nullable(),
+            ),
+            TableField::new(
+                "sequence_number",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new("timestamp", TableDataType::Timestamp),
+            TableField::new("operation", TableDataType::String),
+            TableField::new("manifest_list", TableDataType::String),
+            TableField::new(
+                "schema_id",
+                TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+            ),
+            TableField::new("summary", TableDataType::String),
+            TableField::new("is_current", TableDataType::Boolean),
+        ])
+    }
+
+    async fn apply(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+    ) -> Result<Option<DataBlock>> {
+        let table = get_iceberg_table(
+            ctx,
+            self.args.catalog_name.as_deref(),
+            &self.args.database_name,
+            &self.args.table_name,
+            "iceberg_snapshot",
+        )
+        .await?;
+        let metadata = table.metadata();
+        let current_snapshot_id = metadata.current_snapshot().map(|s| s.snapshot_id());
+
+        let mut snapshots = metadata.snapshots().collect::<Vec<_>>();
+        snapshots.sort_by_key(|s| std::cmp::Reverse((s.timestamp_ms(), s.sequence_number())));
+        if let Some(limit) = plan.push_downs.as_ref().and_then(|v| v.limit) {
+            snapshots.truncate(limit);
+        }
+
+        let len = snapshots.len();
+        let mut snapshot_ids = Vec::with_capacity(len);
+        let mut parent_snapshot_ids = Vec::with_capacity(len);
+        let mut sequence_numbers = Vec::with_capacity(len);
+        let mut timestamps = Vec::with_capacity(len);
+        let mut operations = Vec::with_capacity(len);
+        let mut manifest_lists = Vec::with_capacity(len);
+        let mut schema_ids = Vec::with_capacity(len);
+        let mut summaries = Vec::with_capacity(len);
+        let mut is_current = Vec::with_capacity(len);
+        for s in snapshots {
+            snapshot_ids.push(s.snapshot_id());
+            parent_snapshot_ids.push(s.parent_snapshot_id());
+            sequence_numbers.push(s.sequence_number());
+            timestamps.push(s.timestamp_ms() * 1000);
+            operations.push(operation_name(&s.summary().operation).to_string());
+            manifest_lists.push(s.manifest_list().to_string());
+            schema_ids.push(s.schema_id());
+            summaries.push(serde_json::to_string(&s.summary().other)?);
+            is_current.push(current_snapshot_id == Some(s.snapshot_id()));
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int64Type::from_data(snapshot_ids),
+            Int64Type::from_opt_data(parent_snapshot_ids),
+            Int64Type::from_data(sequence_numbers),
+            TimestampType::from_data(timestamps),
+            StringType::from_data(operations),
+            StringType::from_data(manifest_lists),
+            Int32Type::from_opt_data(schema_ids),
+            StringType::from_data(summaries),
+            BooleanType::from_data(is_current),
+        ])))
+    }
+
+    fn create(func_name: &str, table_args: TableArgs) -> Result<Self>
+    where Self: Sized {
+        let (arg_catalog_name, arg_database_name, arg_table_name) =
+            parse_catalog_db_tb_args(&table_args, func_name)?;
+        Ok(Self {
+            args: IcebergSnapshotArgs {
+                catalog_name: arg_catalog_name,
+                database_name: arg_database_name,
+                table_name: arg_table_name,
+            },
+        })
+    }
+}
+
+fn operation_name(operation: &Operation) -> &'static str {
+    match operation {
+        Operation::Append => "append",
+        Operation::Replace => "replace",
+        Operation::Overwrite => "overwrite",
+        Operation::Delete => "delete",
+    }
+}
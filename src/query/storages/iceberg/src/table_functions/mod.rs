@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod iceberg_manifest;
+mod iceberg_snapshot;
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Scalar;
+use databend_common_storages_fuse::table_functions::string_value;
+pub use iceberg_manifest::IcebergManifestFunc;
+pub use iceberg_snapshot::IcebergSnapshotFunc;
+
+use crate::IcebergTable;
+
+/// Parses the arguments `[<catalog>,] <database>, <table_name>`, the current
+/// catalog is used if the catalog is omitted.
+fn parse_catalog_db_tb_args(
+    table_args: &TableArgs,
+    func_name: &str,
+) -> Result<(Option<String>, String, String)> {
+    let args = table_args.expect_all_positioned(func_name, None)?;
+    match args.len() {
+        2 => {
+            let db = string_value(&args[0])?;
+            let tbl = string_value(&args[1])?;
+            Ok((None, db, tbl))
+        }
+        3 => {
+            let catalog = string_value(&args[0])?;
+            let db = string_value(&args[1])?;
+            let tbl = string_value(&args[2])?;
+            Ok((Some(catalog), db, tbl))
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "expecting [<catalog>,] <database> and <table_name> (as string literals), but got {:?}",
+            args
+        ))),
+    }
+}
+
+/// Parses the arguments `[<catalog>,] <database>, <table_name> [, <snapshot_id>]`.
+///
+/// With three arguments, a numeric last argument is the snapshot id, otherwise
+/// the arguments are `<catalog>, <database>, <table_name>`.
+fn parse_catalog_db_tb_snapshot_args(
+    table_args: &TableArgs,
+    func_name: &str,
+) -> Result<(Option<String>, String, String, Option<i64>)> {
+    let args = table_args.expect_all_positioned(func_name, None)?;
+    match args.len() {
+        2 => {
+            let db = string_value(&args[0])?;
+            let tbl = string_value(&args[1])?;
+            Ok((None, db, tbl, None))
+        }
+        3 if matches!(args[2], Scalar::Number(_)) => {
+            let db = string_value(&args[0])?;
+            let tbl = string_value(&args[1])?;
+            let snapshot_id = snapshot_id_value(&args[2])?;
+            Ok((None, db, tbl, Some(snapshot_id)))
+        }
+        3 | 4 => {
+            let catalog = string_value(&args[0])?;
+            let db = string_value(&args[1])?;
+            let tbl = string_value(&args[2])?;
+            let snapshot_id = args.get(3).map(snapshot_id_value).transpose()?;
+            Ok((Some(catalog), db, tbl, snapshot_id))
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "expecting [<catalog>,] <database>, <table_name> and [<snapshot_id>], but got {:?}",
+            args
+        ))),
+    }
+}
+
+/// The snapshot id is an integer, or a string of an integer.
+fn snapshot_id_value(value: &Scalar) -> Result<i64> {
+    match value {
+        Scalar::String(s) => s.parse::<i64>().ok(),
+        _ => value.get_i64(),
+    }
+    .ok_or_else(|| {
+        ErrorCode::BadArguments(format!(
+            "Invalid iceberg snapshot id {value}, it must be an integer"
+        ))
+    })
+}
+
+/// Loads the iceberg table from the given catalog, or the current catalog if it is `None`.
+async fn get_iceberg_table(
+    ctx: &Arc<dyn TableContext>,
+    catalog_name: Option<&str>,
+    database_name: &str,
+    table_name: &str,
+    func_name: &str,
+) -> Result<iceberg::table::Table> {
+    let catalog_name = match catalog_name {
+        Some(catalog_name) => catalog_name.to_string(),
+        None => ctx.get_current_catalog(),
+    };
+    let tbl = ctx
+        .get_table(&catalog_name, database_name, table_name)
+        .await?;
+    let table = tbl.as_any().downcast_ref::<IcebergTable>().ok_or_else(|| {
+        ErrorCode::StorageOther(format!(
+            "Invalid table engine, only ICEBERG table supports {func_name}"
+        ))
+    })?;
+    Ok(table.table().await?.clone())
+}
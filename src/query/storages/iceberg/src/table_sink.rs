@@ -0,0 +1,391 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_array::RecordBatch;
+use arrow_cast::cast;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::local_block_meta_serde;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use iceberg::spec::DataFile;
+use iceberg::spec::DataFileFormat;
+use iceberg::transaction::Transaction;
+use iceberg::writer::base_writer::data_file_writer::DataFileWriter;
+use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+use iceberg::writer::base_writer::data_file_writer::DataFileWriterConfig;
+use iceberg::writer::file_writer::location_generator::DefaultFileNameGenerator;
+use iceberg::writer::file_writer::location_generator::DefaultLocationGenerator;
+use iceberg::writer::file_writer::ParquetWriterBuilder;
+use iceberg::writer::IcebergWriter;
+use iceberg::writer::IcebergWriterBuilder;
+use iceberg::ErrorKind;
+use log::info;
+use log::warn;
+use parquet::basic::Compression;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterProperties;
+
+use crate::IcebergTable;
+
+/// The max times to retry the commit if the table is modified concurrently.
+const MAX_COMMIT_RETRIES: usize = 5;
+/// The initial delay before retrying a conflicted commit, doubled on each retry.
+const COMMIT_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+type IcebergParquetWriter =
+    DataFileWriter<ParquetWriterBuilder<DefaultLocationGenerator, DefaultFileNameGenerator>>;
+
+/// The data files written by one [`IcebergDataFileWriter`].
+#[derive(Debug)]
+pub struct IcebergDataFiles {
+    pub files: Vec<DataFile>,
+}
+
+local_block_meta_serde!(IcebergDataFiles);
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFiles {}
+
+/// Writes the input blocks into parquet data files of the iceberg table.
+///
+/// The written data files are sent downstream on finish, they are invisible
+/// until committed by [`IcebergCommitSink`].
+pub struct IcebergDataFileWriter {
+    ctx: Arc<dyn TableContext>,
+    table: IcebergTable,
+    schema: TableSchemaRef,
+    // The arrow schema of the iceberg table, which carries the field ids
+    // required by the iceberg parquet writer.
+    arrow_schema: Option<ArrowSchemaRef>,
+    writer: Option<IcebergParquetWriter>,
+}
+
+impl IcebergDataFileWriter {
+    pub fn create(ctx: Arc<dyn TableContext>, table: IcebergTable) -> Self {
+        let schema = table.schema();
+        Self {
+            ctx,
+            table,
+            schema,
+            arrow_schema: None,
+            writer: None,
+        }
+    }
+
+    async fn init_writer(&mut self) -> Result<()> {
+        let table = self.table.table().await?;
+        let metadata = table.metadata();
+        // The data files are written without partition values, which would
+        // break the partition pruning of partitioned tables.
+        if metadata
+            .default_partition_spec()
+            .is_some_and(|spec| !spec.fields.is_empty())
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Writing to partitioned iceberg table {}.{} is not supported, only unpartitioned iceberg tables can be written",
+                self.table.database_name, self.table.table_name
+            )));
+        }
+        let arrow_schema: ArrowSchema =
+            metadata.current_schema().as_ref().try_into().map_err(|e| {
+                ErrorCode::StorageOther(format!("Cannot convert table schema: {e:?}"))
+            })?;
+
+        let location_generator = DefaultLocationGenerator::new(metadata.clone())
+            .map_err(|e| ErrorCode::StorageOther(format!("{e:?}")))?;
+        // Every writer has its own prefix, the file names are unique across processors.
+        let file_name_generator = DefaultFileNameGenerator::new(
+            format!("{}-{}", self.ctx.get_id(), uuid::Uuid::now_v7().simple()),
+            None,
+            DataFileFormat::Parquet,
+        );
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let parquet_writer = ParquetWriterBuilder::new(
+            props,
+            metadata.current_schema().clone(),
+            table.file_io().clone(),
+            location_generator,
+            file_name_generator,
+        );
+        let writer = DataFileWriterBuilder::new(parquet_writer)
+            .build(DataFileWriterConfig::new(None))
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Cannot create iceberg writer: {e:?}")))?;
+
+        self.arrow_schema = Some(Arc::new(arrow_schema));
+        self.writer = Some(writer);
+        Ok(())
+    }
+
+    fn to_record_batch(
+        &self,
+        block: DataBlock,
+        arrow_schema: ArrowSchemaRef,
+    ) -> Result<RecordBatch> {
+        let batch = block.to_record_batch(&self.schema)?;
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(arrow_schema.fields())
+            .map(|(array, field)| {
+                if array.data_type() == field.data_type() {
+                    Ok(array.clone())
+                } else {
+                    cast(array, field.data_type()).map_err(|e| {
+                        ErrorCode::StorageOther(format!(
+                            "Cannot cast column {} to {}: {e}",
+                            field.name(),
+                            field.data_type()
+                        ))
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(arrow_schema, columns)
+            .map_err(|e| ErrorCode::StorageOther(format!("{e}")))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for IcebergDataFileWriter {
+    const NAME: &'static str = "IcebergDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let progress_values = ProgressValues {
+            rows: data.num_rows(),
+            bytes: data.memory_size(),
+        };
+        if self.writer.is_none() {
+            self.init_writer().await?;
+        }
+        let batch = self.to_record_batch(data, self.arrow_schema.clone().unwrap())?;
+        self.writer
+            .as_mut()
+            .unwrap()
+            .write(batch)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Iceberg write data failed: {e:?}")))?;
+        self.ctx.get_write_progress().incr(&progress_values);
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let Some(mut writer) = self.writer.take() else {
+            return Ok(None);
+        };
+        let files = writer.close().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Iceberg close data file failed: {e:?}"))
+        })?;
+        Ok(Some(DataBlock::empty_with_meta(Box::new(
+            IcebergDataFiles { files },
+        ))))
+    }
+}
+
+/// Appends the data files to the iceberg table in a new snapshot.
+///
+/// The manifest and manifest list are generated by the fast append action, the
+/// commit is retried with backoff on the latest table metadata if it conflicts
+/// with others.
+pub struct IcebergCommitSink {
+    table: IcebergTable,
+    files: Vec<DataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn create(table: IcebergTable) -> Self {
+        Self {
+            table,
+            files: vec![],
+        }
+    }
+
+    async fn try_commit(&self, table: &iceberg::table::Table) -> iceberg::Result<()> {
+        let mut action = Transaction::new(table).fast_append(None, vec![])?;
+        action.add_data_files(self.files.clone())?;
+        let tx = action.apply().await?;
+        tx.commit(self.table.ctl.iceberg_catalog().as_ref()).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(IcebergDataFiles::downcast_from)
+        {
+            self.files.extend(meta.files);
+        }
+        Ok(false)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.files.is_empty() {
+            return Ok(());
+        }
+
+        let mut table = self.table.table().await?.clone();
+        let mut retries = 0;
+        loop {
+            match self.try_commit(&table).await {
+                Ok(_) => {
+                    info!(
+                        "Iceberg table {}.{} appended {} data files",
+                        self.table.database_name,
+                        self.table.table_name,
+                        self.files.len()
+                    );
+                    return Ok(());
+                }
+                Err(e) if is_commit_conflict(&e) && retries < MAX_COMMIT_RETRIES => {
+                    let delay = commit_retry_delay(retries);
+                    retries += 1;
+                    warn!(
+                        "Iceberg table {}.{} commit conflicted, retry {} after {:?}: {e:?}",
+                        self.table.database_name, self.table.table_name, retries, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    table = IcebergTable::load_iceberg_table(
+                        &self.table.ctl,
+                        &self.table.database_name,
+                        &self.table.table_name,
+                    )
+                    .await?;
+                }
+                Err(e) if is_commit_conflict(&e) => {
+                    return Err(ErrorCode::UnresolvableConflict(format!(
+                        "Iceberg table commit failed after {retries} retries: {e:?}"
+                    )));
+                }
+                Err(e) => {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "Iceberg table commit failed: {e:?}"
+                    )));
+                }
+            }
+        }
+    }
+}
+
+/// Whether the commit is rejected because the table has been changed by
+/// others since it was loaded.
+///
+/// The REST catalog replies `409 Conflict` in this case. The pinned iceberg-rust
+/// has no error kind for commit conflicts and does not expose the context of an
+/// error, the error response is converted to a `DataInvalid` error displayed as
+/// `DataInvalid, context: { type: <type>, code: <status>[, stack: ..] } => <message>`,
+/// so the status is taken from the context part of the display only.
+fn is_commit_conflict(e: &iceberg::Error) -> bool {
+    if e.kind() != ErrorKind::DataInvalid {
+        return false;
+    }
+    let display = e.to_string();
+    let Some(context) = display.strip_prefix("DataInvalid, context: { type: ") else {
+        return false;
+    };
+    let Some((_, status)) = context.split_once(", code: ") else {
+        return false;
+    };
+    status.starts_with("409 }") || status.starts_with("409, ")
+}
+
+fn commit_retry_delay(retries: usize) -> Duration {
+    COMMIT_RETRY_BASE_DELAY * 2u32.pow(retries as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use iceberg::ErrorKind;
+
+    use super::commit_retry_delay;
+    use super::is_commit_conflict;
+
+    #[test]
+    fn test_is_commit_conflict() {
+        // The error converted from the error response of the REST catalog.
+        let conflict = iceberg::Error::new(
+            ErrorKind::DataInvalid,
+            "Requirement failed: branch main has changed",
+        )
+        .with_context("type", "CommitFailedException")
+        .with_context("code", "409");
+        assert_eq!(
+            conflict.to_string(),
+            "DataInvalid, context: { type: CommitFailedException, code: 409 } => Requirement failed: branch main has changed"
+        );
+        assert!(is_commit_conflict(&conflict));
+
+        let with_stack = iceberg::Error::new(ErrorKind::DataInvalid, "Commit failed")
+            .with_context("type", "CommitFailedException")
+            .with_context("code", "409")
+            .with_context(
+                "stack",
+                "org.apache.iceberg.exceptions.CommitFailedException",
+            );
+        assert!(is_commit_conflict(&with_stack));
+
+        let not_found = iceberg::Error::new(ErrorKind::DataInvalid, "Table does not exist")
+            .with_context("type", "NoSuchTableException")
+            .with_context("code", "404");
+        assert!(!is_commit_conflict(&not_found));
+
+        // Only the status in the context is matched, not the message.
+        let in_message = iceberg::Error::new(ErrorKind::DataInvalid, "Invalid code: 409 }")
+            .with_context("type", "BadRequestException")
+            .with_context("code", "400");
+        assert!(!is_commit_conflict(&in_message));
+
+        let without_context = iceberg::Error::new(ErrorKind::DataInvalid, "code: 409 }");
+        assert!(!is_commit_conflict(&without_context));
+
+        let other = iceberg::Error::new(ErrorKind::Unexpected, "Failed to send request");
+        assert!(!is_commit_conflict(&other));
+    }
+
+    #[test]
+    fn test_commit_retry_delay() {
+        assert_eq!(commit_retry_delay(0), Duration::from_millis(100));
+        assert_eq!(commit_retry_delay(1), Duration::from_millis(200));
+        assert_eq!(commit_retry_delay(4), Duration::from_millis(1600));
+    }
+}
@@ -23,4 +23,5 @@ mod compression;
 mod read;
 mod stage_table;
 
+pub use read::avro::read_avro_schema;
 pub use stage_table::StageTable;
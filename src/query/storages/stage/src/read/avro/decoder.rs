@@ -0,0 +1,333 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+use std::sync::Arc;
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::AvroFileFormatParams;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_storage::FileParseError;
+use databend_common_storage::FileStatus;
+use databend_storages_common_stage::project_columnar;
+use ethnum::i256;
+
+use crate::read::avro::schema::avro_schema_to_table_schema;
+use crate::read::avro::schema::map_avro_error;
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::BytesBatch;
+
+/// Decodes whole avro object container files into blocks of the load schema.
+///
+/// The bytes of a file are buffered until its end, the records are decoded into
+/// the columns of the writer schema, then cast or filled to the output columns by name.
+pub struct AvroDecoder {
+    load_context: Arc<LoadContext>,
+    params: AvroFileFormatParams,
+    path: String,
+    data: Vec<u8>,
+}
+
+impl AvroDecoder {
+    pub fn create(load_context: Arc<LoadContext>, params: AvroFileFormatParams) -> Self {
+        AvroDecoder {
+            load_context,
+            params,
+            path: String::new(),
+            data: vec![],
+        }
+    }
+
+    fn decode_file(&self, path: &str, data: &[u8]) -> Result<Vec<DataBlock>> {
+        let reader = Reader::new(data).map_err(|e| map_avro_error(e, path))?;
+        let file_schema = Arc::new(
+            avro_schema_to_table_schema(reader.writer_schema())
+                .map_err(|e| e.add_message_back(format!(" (while reading {path})")))?,
+        );
+        let (projection, _) = project_columnar(
+            &file_schema,
+            &self.load_context.schema,
+            &self.params.missing_field_as,
+            &self.load_context.default_values,
+            path,
+        )?;
+        let data_types = file_schema
+            .fields()
+            .iter()
+            .map(|f| DataType::from(f.data_type()))
+            .collect::<Vec<_>>();
+        let new_builders = || {
+            data_types
+                .iter()
+                .map(|ty| ColumnBuilder::with_capacity(ty, 1024))
+                .collect::<Vec<_>>()
+        };
+
+        let max_rows = self
+            .load_context
+            .block_compact_thresholds
+            .min_rows_per_block;
+        let error_handler = &self.load_context.error_handler;
+        let mut file_status = FileStatus::default();
+        let mut builders = new_builders();
+        let mut num_rows = 0;
+        let mut blocks = vec![];
+        for (row, record) in reader.enumerate() {
+            let fields = match record {
+                Ok(AvroValue::Record(fields)) => fields,
+                Ok(record) => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "fail to read avro file {path}: expect record, but got {record:?}"
+                    )));
+                }
+                Err(e) => {
+                    // The following records can not be located after a broken one.
+                    let e = FileParseError::Unexpected {
+                        message: format!("fail to read avro record: {e}"),
+                    };
+                    error_handler.on_error(e, None, &mut file_status, path, row)?;
+                    break;
+                }
+            };
+            if let Err(e) = self.push_record(&mut builders, &data_types, fields) {
+                error_handler.on_error(
+                    e,
+                    Some((&mut builders, num_rows)),
+                    &mut file_status,
+                    path,
+                    row,
+                )?;
+                continue;
+            }
+            num_rows += 1;
+            file_status.num_rows_loaded += 1;
+            if num_rows >= max_rows {
+                let columns = mem::replace(&mut builders, new_builders());
+                blocks.push(self.project(columns, num_rows, &projection)?);
+                num_rows = 0;
+            }
+        }
+        if num_rows > 0 {
+            blocks.push(self.project(builders, num_rows, &projection)?);
+        }
+
+        self.load_context
+            .table_context
+            .add_file_status(path, file_status)?;
+        Ok(blocks)
+    }
+
+    fn push_record(
+        &self,
+        builders: &mut [ColumnBuilder],
+        data_types: &[DataType],
+        fields: Vec<(String, AvroValue)>,
+    ) -> std::result::Result<(), FileParseError> {
+        for ((builder, data_type), (name, value)) in
+            builders.iter_mut().zip(data_types.iter()).zip(fields)
+        {
+            let scalar =
+                avro_value_to_scalar(value, data_type, &self.params.null_if).map_err(|e| {
+                    FileParseError::Unexpected {
+                        message: format!("fail to decode column {name}: {e}"),
+                    }
+                })?;
+            builder.push(scalar.as_ref());
+        }
+        Ok(())
+    }
+
+    fn project(
+        &self,
+        builders: Vec<ColumnBuilder>,
+        num_rows: usize,
+        projection: &[Expr],
+    ) -> Result<DataBlock> {
+        let columns = builders.into_iter().map(|b| b.build()).collect();
+        let block = DataBlock::new_from_columns(columns);
+        let evaluator = Evaluator::new(&block, &self.load_context.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut entries = Vec::with_capacity(projection.len());
+        for (field, expr) in self
+            .load_context
+            .schema
+            .fields()
+            .iter()
+            .zip(projection.iter())
+        {
+            let value = evaluator.run(expr)?;
+            entries.push(BlockEntry::new(field.data_type().into(), value));
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+}
+
+impl AccumulatingTransform for AvroDecoder {
+    const NAME: &'static str = "AvroDecoder";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let batch = data
+            .get_owned_meta()
+            .and_then(BytesBatch::downcast_from)
+            .unwrap();
+        if self.path != batch.path {
+            self.path = batch.path.clone();
+            self.data.clear();
+        }
+        self.data.extend_from_slice(&batch.data);
+        if !batch.is_eof {
+            return Ok(vec![]);
+        }
+
+        let data = mem::take(&mut self.data);
+        self.decode_file(&batch.path, &data)
+    }
+}
+
+/// Converts an avro value to the scalar of the type mapped from its schema.
+fn avro_value_to_scalar(
+    value: AvroValue,
+    data_type: &DataType,
+    null_if: &[String],
+) -> std::result::Result<Scalar, String> {
+    let value = match value {
+        AvroValue::Union(_, value) => *value,
+        value => value,
+    };
+    let scalar = match (value, data_type) {
+        (AvroValue::Null, DataType::Null | DataType::Nullable(_)) => Scalar::Null,
+        (AvroValue::String(v), DataType::Nullable(_)) if null_if.contains(&v) => Scalar::Null,
+        (value, DataType::Nullable(ty)) => return avro_value_to_scalar(value, ty, null_if),
+        (value, DataType::Variant) => {
+            let json = serde_json::Value::try_from(value).map_err(|e| e.to_string())?;
+            Scalar::Variant(jsonb::Value::from(&json).to_vec())
+        }
+        (AvroValue::Boolean(v), DataType::Boolean) => Scalar::Boolean(v),
+        (AvroValue::Int(v) | AvroValue::TimeMillis(v), DataType::Number(NumberDataType::Int32)) => {
+            Scalar::Number(NumberScalar::Int32(v))
+        }
+        (
+            AvroValue::Long(v) | AvroValue::TimeMicros(v),
+            DataType::Number(NumberDataType::Int64),
+        ) => Scalar::Number(NumberScalar::Int64(v)),
+        (AvroValue::Float(v), DataType::Number(NumberDataType::Float32)) => {
+            Scalar::Number(NumberScalar::Float32(v.into()))
+        }
+        (AvroValue::Double(v), DataType::Number(NumberDataType::Float64)) => {
+            Scalar::Number(NumberScalar::Float64(v.into()))
+        }
+        (AvroValue::Bytes(v) | AvroValue::Fixed(_, v), DataType::Binary) => Scalar::Binary(v),
+        (AvroValue::Duration(v), DataType::Binary) => Scalar::Binary(<[u8; 12]>::from(v).to_vec()),
+        (AvroValue::String(v) | AvroValue::Enum(_, v), DataType::String) => Scalar::String(v),
+        (AvroValue::Uuid(v), DataType::String) => Scalar::String(v.to_string()),
+        (AvroValue::BigDecimal(v), DataType::String) => Scalar::String(v.to_string()),
+        (AvroValue::Date(v), DataType::Date) => Scalar::Date(v),
+        (
+            AvroValue::TimestampMillis(v) | AvroValue::LocalTimestampMillis(v),
+            DataType::Timestamp,
+        ) => Scalar::Timestamp(
+            v.checked_mul(1000)
+                .ok_or_else(|| format!("timestamp {v} milliseconds is out of range"))?,
+        ),
+        (
+            AvroValue::TimestampMicros(v) | AvroValue::LocalTimestampMicros(v),
+            DataType::Timestamp,
+        ) => Scalar::Timestamp(v),
+        (AvroValue::TimestampNanos(v) | AvroValue::LocalTimestampNanos(v), DataType::Timestamp) => {
+            Scalar::Timestamp(v / 1000)
+        }
+        (AvroValue::Decimal(v), DataType::Decimal(ty)) => {
+            let bytes = Vec::<u8>::try_from(&v).map_err(|e| e.to_string())?;
+            match ty {
+                DecimalDataType::Decimal128(size) => Scalar::Decimal(DecimalScalar::Decimal128(
+                    i128::from_be_bytes(sign_extend(&bytes)?),
+                    *size,
+                )),
+                DecimalDataType::Decimal256(size) => Scalar::Decimal(DecimalScalar::Decimal256(
+                    i256::from_be_bytes(sign_extend(&bytes)?),
+                    *size,
+                )),
+            }
+        }
+        (AvroValue::Array(values), DataType::Array(ty)) => {
+            Scalar::Array(avro_values_to_column(values, ty)?)
+        }
+        (AvroValue::Map(entries), DataType::Map(ty)) => {
+            let DataType::Tuple(kv_types) = ty.as_ref() else {
+                return Err(format!("invalid map type {data_type}"));
+            };
+            let mut entries = entries.into_iter().collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            let mut builder = ColumnBuilder::with_capacity(ty, entries.len());
+            for (key, value) in entries {
+                let value = avro_value_to_scalar(value, &kv_types[1], &[])?;
+                builder.push(Scalar::Tuple(vec![Scalar::String(key), value]).as_ref());
+            }
+            Scalar::Map(builder.build())
+        }
+        (AvroValue::Record(fields), DataType::Tuple(types)) => Scalar::Tuple(
+            fields
+                .into_iter()
+                .zip(types.iter())
+                .map(|((_, value), ty)| avro_value_to_scalar(value, ty, &[]))
+                .collect::<std::result::Result<Vec<_>, _>>()?,
+        ),
+        (value, data_type) => {
+            return Err(format!("unexpected value {value:?} for type {data_type}"));
+        }
+    };
+    Ok(scalar)
+}
+
+fn avro_values_to_column(
+    values: Vec<AvroValue>,
+    data_type: &DataType,
+) -> std::result::Result<Column, String> {
+    let mut builder = ColumnBuilder::with_capacity(data_type, values.len());
+    for value in values {
+        builder.push(avro_value_to_scalar(value, data_type, &[])?.as_ref());
+    }
+    Ok(builder.build())
+}
+
+/// Avro decimals are big-endian two's-complement integers of variable length.
+fn sign_extend<const N: usize>(bytes: &[u8]) -> std::result::Result<[u8; N], String> {
+    if bytes.len() > N {
+        return Err(format!("decimal of {} bytes is out of range", bytes.len()));
+    }
+    let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+        0xff
+    } else {
+        0
+    };
+    let mut buf = [fill; N];
+    buf[N - bytes.len()..].copy_from_slice(bytes);
+    Ok(buf)
+}
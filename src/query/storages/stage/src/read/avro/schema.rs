@@ -0,0 +1,162 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use apache_avro::schema::Name;
+use apache_avro::schema::NamesRef;
+use apache_avro::schema::ResolvedSchema;
+use apache_avro::Reader;
+use apache_avro::Schema as AvroSchema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use opendal::Operator;
+
+/// Reads the writer schema from the header of an avro object container file.
+#[async_backtrace::framed]
+pub async fn read_avro_schema(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let data = operator.read(path).await?.to_vec();
+    let reader = Reader::new(data.as_slice()).map_err(|e| map_avro_error(e, path))?;
+    avro_schema_to_table_schema(reader.writer_schema())
+        .map_err(|e| e.add_message_back(format!(" (while reading schema of {path})")))
+}
+
+/// Maps the schema of avro records to a table schema, each field of the record is a column.
+///
+/// - `union` of `null` and a single type is mapped to `Nullable`, other unions to `Variant`.
+/// - `record` is mapped to `Tuple` and `map` to `Map(String, _)`.
+/// - logical types `decimal`, `date` and `timestamp-*` are mapped to `Decimal`, `Date` and `Timestamp`.
+pub fn avro_schema_to_table_schema(schema: &AvroSchema) -> Result<TableSchema> {
+    let resolved = ResolvedSchema::try_from(schema)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid avro schema: {e}")))?;
+    let names = resolved.get_names();
+    match schema {
+        AvroSchema::Record(record) => {
+            let mut visiting = vec![record.name.clone()];
+            let fields = record
+                .fields
+                .iter()
+                .map(|field| {
+                    let data_type = avro_type_to_table_type(&field.schema, names, &mut visiting)?;
+                    Ok(TableField::new(&field.name, data_type))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TableSchema::new(fields))
+        }
+        _ => Err(ErrorCode::BadBytes(format!(
+            "the schema of avro file must be a record, but got {}",
+            schema.canonical_form()
+        ))),
+    }
+}
+
+fn avro_type_to_table_type(
+    schema: &AvroSchema,
+    names: &NamesRef,
+    visiting: &mut Vec<Name>,
+) -> Result<TableDataType> {
+    let data_type = match schema {
+        AvroSchema::Null => TableDataType::Null,
+        AvroSchema::Boolean => TableDataType::Boolean,
+        AvroSchema::Int | AvroSchema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        AvroSchema::Long | AvroSchema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+        AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+        AvroSchema::Bytes | AvroSchema::Fixed(_) | AvroSchema::Duration => TableDataType::Binary,
+        AvroSchema::String | AvroSchema::Enum(_) | AvroSchema::Uuid | AvroSchema::BigDecimal => {
+            TableDataType::String
+        }
+        AvroSchema::Date => TableDataType::Date,
+        AvroSchema::TimestampMillis
+        | AvroSchema::TimestampMicros
+        | AvroSchema::TimestampNanos
+        | AvroSchema::LocalTimestampMillis
+        | AvroSchema::LocalTimestampMicros
+        | AvroSchema::LocalTimestampNanos => TableDataType::Timestamp,
+        AvroSchema::Decimal(decimal) => {
+            let size = DecimalSize {
+                precision: u8::try_from(decimal.precision).unwrap_or(u8::MAX),
+                scale: u8::try_from(decimal.scale).unwrap_or(u8::MAX),
+            };
+            TableDataType::Decimal(DecimalDataType::from_size(size)?)
+        }
+        AvroSchema::Array(array) => TableDataType::Array(Box::new(avro_type_to_table_type(
+            &array.items,
+            names,
+            visiting,
+        )?)),
+        AvroSchema::Map(map) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![
+                TableDataType::String,
+                avro_type_to_table_type(&map.types, names, visiting)?,
+            ],
+        })),
+        AvroSchema::Record(record) => {
+            visiting.push(record.name.clone());
+            let mut fields_name = Vec::with_capacity(record.fields.len());
+            let mut fields_type = Vec::with_capacity(record.fields.len());
+            for field in record.fields.iter() {
+                fields_name.push(field.name.clone());
+                fields_type.push(avro_type_to_table_type(&field.schema, names, visiting)?);
+            }
+            visiting.pop();
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        AvroSchema::Union(union) => {
+            let variants = union
+                .variants()
+                .iter()
+                .filter(|variant| !matches!(variant, AvroSchema::Null))
+                .collect::<Vec<_>>();
+            let data_type = match variants.as_slice() {
+                [] => return Ok(TableDataType::Null),
+                [variant] => avro_type_to_table_type(variant, names, visiting)?,
+                _ => TableDataType::Variant,
+            };
+            if union.is_nullable() {
+                data_type.wrap_nullable()
+            } else {
+                data_type
+            }
+        }
+        AvroSchema::Ref { name } => {
+            if visiting.contains(name) {
+                return Err(ErrorCode::BadBytes(format!(
+                    "recursive avro type {} is not supported",
+                    name.fullname(None)
+                )));
+            }
+            let schema = names.get(name).ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "unresolved avro type reference {}",
+                    name.fullname(None)
+                ))
+            })?;
+            avro_type_to_table_type(schema, names, visiting)?
+        }
+    };
+    Ok(data_type)
+}
+
+pub fn map_avro_error(e: apache_avro::Error, path: &str) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read avro file {path}: {e}"))
+}
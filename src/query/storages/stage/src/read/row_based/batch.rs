@@ -83,6 +83,7 @@ impl RowBatchWithPosition {
 pub enum RowBatch {
     Csv(CSVRowBatch),
     NDJson(NdjsonRowBatch),
+    Xml(XmlRowBatch),
 }
 
 impl RowBatch {
@@ -90,6 +91,7 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.rows(),
             RowBatch::NDJson(b) => b.rows(),
+            RowBatch::Xml(b) => b.rows(),
         }
     }
 
@@ -97,6 +99,7 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.size(),
             RowBatch::NDJson(b) => b.size(),
+            RowBatch::Xml(b) => b.size(),
         }
     }
 }
@@ -123,6 +126,31 @@ pub struct NdjsonRowBatch {
     pub start: usize,
     pub row_ends: Vec<usize>,
 }
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct XmlRowBatch {
+    /// the bytes of complete row elements,
+    /// row[i] starts at row_ends[i-1] and ends at row_ends[i]
+    pub data: Vec<u8>,
+    pub row_ends: Vec<usize>,
+}
+
+impl XmlRowBatch {
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        let starts = std::iter::once(0).chain(self.row_ends.iter().copied());
+        starts
+            .zip(self.row_ends.iter().copied())
+            .map(|(start, end)| &self.data[start..end])
+    }
+
+    pub fn rows(&self) -> usize {
+        self.row_ends.len()
+    }
+
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+}
+
 pub struct NdJsonRowBatchIter<'a> {
     first_row: &'a [u8],
     data: &'a [u8],
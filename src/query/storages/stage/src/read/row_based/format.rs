@@ -27,6 +27,7 @@ use crate::read::load_context::LoadContext;
 use crate::read::row_based::formats::CsvInputFormat;
 use crate::read::row_based::formats::NdJsonInputFormat;
 use crate::read::row_based::formats::TsvInputFormat;
+use crate::read::row_based::formats::XmlInputFormat;
 
 pub trait SeparatorState: Send + Sync {
     fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)>;
@@ -58,6 +59,7 @@ pub fn create_row_based_file_format(params: &FileFormatParams) -> Arc<dyn RowBas
         FileFormatParams::Csv(p) => Arc::new(CsvInputFormat { params: p.clone() }),
         FileFormatParams::NdJson(p) => Arc::new(NdJsonInputFormat { params: p.clone() }),
         FileFormatParams::Tsv(p) => Arc::new(TsvInputFormat { params: p.clone() }),
+        FileFormatParams::Xml(p) => Arc::new(XmlInputFormat { params: p.clone() }),
         _ => {
             unreachable!("Unsupported row based file format")
         }
@@ -15,7 +15,9 @@
 mod csv;
 mod ndjson;
 mod tsv;
+mod xml;
 
 pub use csv::CsvInputFormat;
 pub use ndjson::NdJsonInputFormat;
 pub use tsv::TsvInputFormat;
+pub use xml::XmlInputFormat;
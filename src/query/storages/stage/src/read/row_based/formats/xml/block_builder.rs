@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_formats::SeparatedTextDecoder;
+use databend_common_meta_app::principal::XmlFieldSource;
+use databend_common_storage::FileParseError;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::RowDecoder;
+use crate::read::row_based::formats::xml::format::XmlInputFormat;
+use crate::read::row_based::processors::BlockBuilderState;
+use crate::read::row_based::utils::truncate_column_data;
+
+/// An element of the row, with its attributes, child elements and text.
+#[derive(Default)]
+struct XmlNode {
+    attributes: Vec<(String, String)>,
+    children: Vec<(String, XmlNode)>,
+    text: String,
+}
+
+impl XmlNode {
+    fn is_leaf(&self) -> bool {
+        self.attributes.is_empty() && self.children.is_empty()
+    }
+
+    /// Leaf elements are strings, others are objects of the attributes and child elements,
+    /// the elements with the same name are collected into an array.
+    fn to_json(&self) -> serde_json::Value {
+        let text = self.text.trim();
+        if self.is_leaf() {
+            return serde_json::Value::String(text.to_string());
+        }
+
+        let mut object = serde_json::Map::new();
+        for (name, value) in self.attributes.iter() {
+            object.insert(name.clone(), serde_json::Value::String(value.clone()));
+        }
+        for (name, child) in self.children.iter() {
+            let value = child.to_json();
+            match object.get_mut(name) {
+                Some(serde_json::Value::Array(values)) => values.push(value),
+                Some(prev) => *prev = serde_json::Value::Array(vec![prev.take(), value]),
+                None => {
+                    object.insert(name.clone(), value);
+                }
+            }
+        }
+        if !text.is_empty() {
+            object.insert(
+                "$text".to_string(),
+                serde_json::Value::String(text.to_string()),
+            );
+        }
+        serde_json::Value::Object(object)
+    }
+}
+
+enum FieldValue<'a> {
+    Element(&'a XmlNode),
+    Attribute(&'a str),
+}
+
+pub struct XmlDecoder {
+    pub load_context: Arc<LoadContext>,
+    pub fmt: XmlInputFormat,
+    pub field_decoder: SeparatedTextDecoder,
+}
+
+impl XmlDecoder {
+    pub fn create(fmt: XmlInputFormat, load_context: Arc<LoadContext>) -> Self {
+        let field_decoder =
+            SeparatedTextDecoder::create_xml(&fmt.params, &load_context.file_format_options_ext);
+        Self {
+            load_context,
+            fmt,
+            field_decoder,
+        }
+    }
+
+    /// The value of a column is read from the child element with the same name
+    /// or the attribute of the row element, according to `FIELD_SOURCE`.
+    fn find_field<'a>(&self, row: &'a XmlNode, name: &str) -> Option<FieldValue<'a>> {
+        let case_sensitive = self
+            .load_context
+            .file_format_options_ext
+            .ident_case_sensitive;
+        let matches = |key: &str| {
+            if case_sensitive {
+                key == name
+            } else {
+                key.eq_ignore_ascii_case(name)
+            }
+        };
+        let field_source = self.fmt.params.field_source;
+        if field_source != XmlFieldSource::Attribute {
+            if let Some((_, child)) = row.children.iter().find(|(key, _)| matches(key)) {
+                return Some(FieldValue::Element(child));
+            }
+        }
+        if field_source == XmlFieldSource::Element {
+            return None;
+        }
+        row.attributes
+            .iter()
+            .find(|(key, _)| matches(key))
+            .map(|(_, value)| FieldValue::Attribute(value))
+    }
+
+    fn read_row(
+        &self,
+        buf: &[u8],
+        columns: &mut [ColumnBuilder],
+    ) -> std::result::Result<(), FileParseError> {
+        let row = parse_row(buf).map_err(|message| FileParseError::InvalidXmlRow { message })?;
+        if self.load_context.file_format_options_ext.is_select {
+            let value = jsonb::Value::from(&row.to_json()).to_vec();
+            columns[0].push(ScalarRef::Variant(&value));
+            return Ok(());
+        }
+
+        for ((column_index, field), column) in self
+            .load_context
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+        {
+            let data_type = field.data_type().remove_nullable();
+            let text = match self.find_field(&row, field.name()) {
+                None => {
+                    self.load_context
+                        .push_default_value(column, column_index, false)?;
+                    continue;
+                }
+                Some(FieldValue::Element(node)) if data_type == TableDataType::Variant => {
+                    let value = jsonb::Value::from(&node.to_json()).to_vec();
+                    column.push(ScalarRef::Variant(&value));
+                    continue;
+                }
+                Some(FieldValue::Element(node)) => node.text.trim(),
+                Some(FieldValue::Attribute(value)) => value,
+            };
+            if text.is_empty() && data_type != TableDataType::String {
+                self.load_context
+                    .push_default_value(column, column_index, false)?;
+                continue;
+            }
+            self.field_decoder
+                .read_field(column, text.as_bytes())
+                .map_err(|e| FileParseError::ColumnDecodeError {
+                    column_index,
+                    column_name: field.name().to_owned(),
+                    column_type: field.data_type.to_string(),
+                    decode_error: e.message(),
+                    column_data: truncate_column_data(text.to_string()),
+                })?;
+        }
+        Ok(())
+    }
+}
+
+impl RowDecoder for XmlDecoder {
+    fn add(
+        &self,
+        state: &mut BlockBuilderState,
+        batch: RowBatchWithPosition,
+    ) -> Result<Vec<DataBlock>> {
+        let columns = &mut state.mutable_columns;
+        let data = batch.data.into_xml().unwrap();
+        for (row_id, row) in data.iter().enumerate() {
+            if let Err(e) = self.read_row(row, columns) {
+                self.load_context.error_handler.on_error(
+                    e,
+                    Some((columns, state.num_rows)),
+                    &mut state.file_status,
+                    &batch.start_pos.path,
+                    batch.start_pos.rows + row_id,
+                )?
+            } else {
+                state.num_rows += 1;
+                state.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(vec![])
+    }
+}
+
+/// Parses a row element cut by the separator.
+fn parse_row(data: &[u8]) -> std::result::Result<XmlNode, String> {
+    let mut reader = Reader::from_reader(data);
+    let mut stack: Vec<(String, XmlNode)> = vec![];
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("{e} at pos {}", reader.buffer_position()))?;
+        let (name, node) = match event {
+            Event::Start(e) => {
+                stack.push(parse_start(&e)?);
+                continue;
+            }
+            Event::Empty(e) => parse_start(&e)?,
+            Event::End(_) => stack.pop().ok_or("unexpected end tag")?,
+            Event::Text(e) => {
+                if let Some((_, node)) = stack.last_mut() {
+                    node.text
+                        .push_str(&e.unescape().map_err(|e| e.to_string())?);
+                }
+                continue;
+            }
+            Event::CData(e) => {
+                if let Some((_, node)) = stack.last_mut() {
+                    node.text.push_str(&String::from_utf8_lossy(&e));
+                }
+                continue;
+            }
+            Event::Eof => return Err("the row element is not closed".to_string()),
+            _ => continue,
+        };
+        match stack.last_mut() {
+            Some((_, parent)) => parent.children.push((name, node)),
+            None => return Ok(node),
+        }
+    }
+}
+
+fn parse_start(e: &BytesStart) -> std::result::Result<(String, XmlNode), String> {
+    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+    let mut node = XmlNode::default();
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| e.to_string())?;
+        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+        let value = attr.unescape_value().map_err(|e| e.to_string())?;
+        node.attributes.push((key, value.to_string()));
+    }
+    Ok((name, node))
+}
@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_storage::FileStatus;
+
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::batch::XmlRowBatch;
+use crate::read::row_based::format::SeparatorState;
+
+/// Cuts the file into the elements named by `ROW_TAG`, the markups outside them are skipped.
+pub struct XmlRowSeparator {
+    row_tag: Vec<u8>,
+    // remain from last read batch, starts with an incomplete row or markup
+    remain: Vec<u8>,
+    pos: Position,
+}
+
+impl SeparatorState for XmlRowSeparator {
+    fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        self.separate(batch)
+    }
+}
+
+impl XmlRowSeparator {
+    pub fn try_create(row_tag: &str, path: &str) -> Result<Self> {
+        Ok(Self {
+            row_tag: row_tag.as_bytes().to_vec(),
+            remain: vec![],
+            pos: Position::new(path.to_string()),
+        })
+    }
+
+    fn separate(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        let data = if self.remain.is_empty() {
+            batch.data
+        } else {
+            let mut data = std::mem::take(&mut self.remain);
+            data.extend_from_slice(&batch.data);
+            data
+        };
+
+        let mut rows = XmlRowBatch::default();
+        let consumed = self.scan(&data, &mut rows);
+        if batch.is_eof {
+            // an unclosed row is left to the decoder to report
+            let tail = &data[consumed..];
+            if self.starts_with_row(tail) {
+                rows.data.extend_from_slice(tail);
+                rows.row_ends.push(rows.data.len());
+            }
+        } else {
+            self.remain = data[consumed..].to_vec();
+        }
+
+        let batch = if rows.rows() == 0 {
+            vec![]
+        } else {
+            let out_pos = self.pos.clone();
+            self.pos.rows += rows.rows();
+            vec![RowBatchWithPosition::new(RowBatch::Xml(rows), out_pos)]
+        };
+        Ok((batch, FileStatus::default()))
+    }
+
+    /// Appends the complete rows in `data` to `rows`, returns the length of the bytes consumed.
+    fn scan(&self, data: &[u8], rows: &mut XmlRowBatch) -> usize {
+        let mut consumed = 0;
+        let mut depth = 0;
+        let mut row_start = 0;
+        let mut i = 0;
+        while let Some(p) = data[i..].iter().position(|b| *b == b'<') {
+            let start = i + p;
+            let markup = &data[start..];
+            let len = if markup.starts_with(b"<!--") {
+                find(markup, b"-->").map(|e| e + 3)
+            } else if markup.starts_with(b"<![CDATA[") {
+                find(markup, b"]]>").map(|e| e + 3)
+            } else if markup.starts_with(b"<?") {
+                find(markup, b"?>").map(|e| e + 2)
+            } else {
+                tag_len(markup)
+            };
+            let Some(len) = len else {
+                // the markup is incomplete, wait for more data
+                break;
+            };
+            i = start + len;
+
+            let tag = &markup[..len];
+            if let Some(name) = tag.strip_prefix(b"</") {
+                if depth > 0 && tag_name(name) == self.row_tag.as_slice() {
+                    depth -= 1;
+                    if depth == 0 {
+                        rows.data.extend_from_slice(&data[row_start..i]);
+                        rows.row_ends.push(rows.data.len());
+                    }
+                }
+            } else if tag_name(&tag[1..]) == self.row_tag.as_slice() {
+                if depth == 0 {
+                    row_start = start;
+                }
+                if !tag.ends_with(b"/>") {
+                    depth += 1;
+                } else if depth == 0 {
+                    rows.data.extend_from_slice(&data[row_start..i]);
+                    rows.row_ends.push(rows.data.len());
+                }
+            }
+            if depth == 0 {
+                consumed = i;
+            }
+        }
+        if depth > 0 { row_start } else { consumed }
+    }
+
+    fn starts_with_row(&self, data: &[u8]) -> bool {
+        data.iter()
+            .position(|b| *b == b'<')
+            .is_some_and(|p| tag_name(&data[p + 1..]) == self.row_tag.as_slice())
+    }
+}
+
+fn find(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len()).position(|w| w == pattern)
+}
+
+/// The length of a tag, the `>` in the quoted attribute values is skipped.
+fn tag_len(data: &[u8]) -> Option<usize> {
+    let mut quote = None;
+    for (i, b) in data.iter().enumerate() {
+        match (quote, *b) {
+            (None, b'"' | b'\'') => quote = Some(*b),
+            (None, b'>') => return Some(i + 1),
+            (Some(q), b) if q == b => quote = None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn tag_name(data: &[u8]) -> &[u8] {
+    let end = data
+        .iter()
+        .position(|b| b.is_ascii_whitespace() || *b == b'/' || *b == b'>')
+        .unwrap_or(data.len());
+    &data[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn separate(sep: &mut XmlRowSeparator, data: &[u8], is_eof: bool) -> Vec<Vec<u8>> {
+        let input = BytesBatch {
+            data: data.to_vec(),
+            path: "".to_string(),
+            offset: 0,
+            is_eof,
+        };
+        let (batches, _) = sep.append(input).unwrap();
+        batches
+            .into_iter()
+            .flat_map(|b| {
+                let rows = b.data.into_xml().unwrap();
+                rows.iter().map(|r| r.to_vec()).collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_xml_row_separator() -> Result<()> {
+        let mut sep = XmlRowSeparator::try_create("row", "test")?;
+        let rows = separate(
+            &mut sep,
+            b"<?xml version=\"1.0\"?><data><!-- <row> --><row a=\"x>y\"><v>1</v></row><row/><rows>",
+            false,
+        );
+        assert_eq!(rows, vec![
+            b"<row a=\"x>y\"><v>1</v></row>".to_vec(),
+            b"<row/>".to_vec()
+        ]);
+        assert!(sep.remain.is_empty());
+        assert_eq!(sep.pos.rows, 2);
+
+        let rows = separate(&mut sep, b"</rows><row><row>2</row>", false);
+        assert!(rows.is_empty());
+        assert_eq!(sep.remain, b"<row><row>2</row>");
+
+        let rows = separate(&mut sep, b"</row><row", false);
+        assert_eq!(rows, vec![b"<row><row>2</row></row>".to_vec()]);
+        assert_eq!(sep.remain, b"<row");
+
+        let rows = separate(&mut sep, b">3", true);
+        assert_eq!(rows, vec![b"<row>3".to_vec()]);
+        assert_eq!(sep.pos.rows, 4);
+        Ok(())
+    }
+}
@@ -19,4 +19,6 @@ mod processors;
 mod read_pipeline;
 mod utils;
 
+pub(crate) use batch::BytesBatch;
+pub(crate) use processors::BytesReader;
 pub use read_pipeline::RowBasedReadPipelineBuilder;
@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_INITIALIZE;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+use log::warn;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+pub struct DynamicTablesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for DynamicTablesTable {
+    const NAME: &'static str = "system.dynamic_tables";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+
+        let catalog_mgr = CatalogManager::instance();
+        let ctls = catalog_mgr
+            .list_catalogs(&tenant, ctx.session_state())
+            .await?
+            .iter()
+            .map(|e| (e.name(), e.clone()))
+            .collect::<Vec<_>>();
+        let visibility_checker = ctx.get_visibility_checker().await?;
+        let ownership = UserApiProvider::instance()
+            .get_ownerships(&tenant)
+            .await
+            .unwrap_or_default();
+
+        let mut db_names = Vec::new();
+        if let Some(push_downs) = &push_downs {
+            if let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) {
+                let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+                find_eq_filter(&expr, &mut |col_name, scalar| {
+                    if col_name == "database" {
+                        if let Scalar::String(database) = scalar {
+                            if !db_names.contains(database) {
+                                db_names.push(database.clone());
+                            }
+                        }
+                    }
+                    Ok(())
+                });
+            }
+        }
+
+        let mut catalogs = vec![];
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut table_ids = vec![];
+        let mut created_on = vec![];
+        let mut refreshed_on = vec![];
+        let mut target_lag = vec![];
+        let mut refresh_mode = vec![];
+        let mut initialize = vec![];
+        let mut rows = vec![];
+        let mut texts = vec![];
+        let mut comments = vec![];
+        let mut owners = vec![];
+
+        for (ctl_name, ctl) in ctls.iter() {
+            let mut dbs = Vec::new();
+            for db in db_names.iter() {
+                match ctl.get_database(&tenant, db.as_str()).await {
+                    Ok(database) => dbs.push(database),
+                    Err(err) => {
+                        let msg = format!("Failed to get database: {}, {}", db, err);
+                        warn!("{}", msg);
+                        ctx.push_warning(msg);
+                    }
+                }
+            }
+
+            if dbs.is_empty() {
+                dbs = match ctl.list_databases(&tenant).await {
+                    Ok(dbs) => dbs,
+                    Err(err) => {
+                        let msg =
+                            format!("List databases failed on catalog {}: {}", ctl.name(), err);
+                        warn!("{}", msg);
+                        ctx.push_warning(msg);
+
+                        vec![]
+                    }
+                }
+            }
+
+            for db in dbs {
+                let db_id = db.get_db_info().database_id.db_id;
+                let db_name = db.name();
+                if !visibility_checker.check_database_visibility(ctl_name, db_name, db_id) {
+                    continue;
+                }
+
+                let tables = match ctl.list_tables(&tenant, db_name).await {
+                    Ok(tables) => tables,
+                    Err(err) => {
+                        let msg =
+                            format!("Failed to list tables in database: {}, {}", db_name, err);
+                        warn!("{}", msg);
+                        ctx.push_warning(msg);
+
+                        continue;
+                    }
+                };
+
+                for table in tables {
+                    let options = table.options();
+                    let Some(lag) = options.get(OPT_KEY_TARGET_LAG) else {
+                        continue;
+                    };
+                    let t_id = table.get_id();
+                    if !visibility_checker.check_table_visibility(
+                        ctl_name,
+                        db_name,
+                        table.name(),
+                        db_id,
+                        t_id,
+                    ) {
+                        continue;
+                    }
+
+                    let info = table.get_table_info();
+                    catalogs.push(ctl_name.clone());
+                    databases.push(db_name.to_owned());
+                    names.push(table.name().to_owned());
+                    table_ids.push(t_id);
+                    created_on.push(info.meta.created_on.timestamp_micros());
+                    refreshed_on.push(info.meta.updated_on.timestamp_micros());
+                    target_lag.push(lag.clone());
+                    refresh_mode.push(
+                        options
+                            .get(OPT_KEY_REFRESH_MODE)
+                            .cloned()
+                            .unwrap_or_default(),
+                    );
+                    initialize.push(options.get(OPT_KEY_INITIALIZE).cloned().unwrap_or_default());
+                    rows.push(info.meta.statistics.number_of_rows);
+                    texts.push(options.get(OPT_KEY_AS_QUERY).cloned().unwrap_or_default());
+                    comments.push(info.meta.comment.clone());
+                    owners.push(
+                        ownership
+                            .get(&OwnershipObject::Table {
+                                catalog_name: ctl_name.to_string(),
+                                db_id,
+                                table_id: t_id,
+                            })
+                            .map(|role| role.to_string()),
+                    );
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(catalogs),
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            UInt64Type::from_data(table_ids),
+            TimestampType::from_data(created_on),
+            TimestampType::from_data(refreshed_on),
+            StringType::from_data(target_lag),
+            StringType::from_data(refresh_mode),
+            StringType::from_data(initialize),
+            UInt64Type::from_data(rows),
+            StringType::from_data(texts),
+            StringType::from_data(comments),
+            StringType::from_opt_data(owners),
+        ]))
+    }
+}
+
+impl DynamicTablesTable {
+    pub fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("refreshed_on", TableDataType::Timestamp),
+            TableField::new("target_lag", TableDataType::String),
+            TableField::new("refresh_mode", TableDataType::String),
+            TableField::new("initialize", TableDataType::String),
+            TableField::new("rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("text", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new(
+                "owner",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ])
+    }
+
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let table_info = TableInfo {
+            desc: "'system'.'dynamic_tables'".to_string(),
+            name: "dynamic_tables".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema: DynamicTablesTable::schema(),
+                engine: "SystemDynamicTables".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(DynamicTablesTable { table_info })
+    }
+}
@@ -34,6 +34,7 @@ mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod dictionaries_table;
+mod dynamic_tables_table;
 mod engines_table;
 mod functions_table;
 mod indexes_table;
@@ -48,12 +49,15 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod pipe_history_table;
+mod pipes_table;
 mod procedures_table;
 mod processes_table;
 mod queries_profiling;
 mod query_cache_table;
 mod query_log_table;
 mod roles_table;
+mod sequences_table;
 mod settings_table;
 mod stages_table;
 mod streams_table;
@@ -68,6 +72,7 @@ mod user_functions_table;
 mod users_table;
 mod util;
 mod virtual_columns_table;
+mod workload_groups_table;
 
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
@@ -85,6 +90,7 @@ pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use dictionaries_table::DictionariesTable;
+pub use dynamic_tables_table::DynamicTablesTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
@@ -102,6 +108,10 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use pipe_history_table::PipeHistoryLogElement;
+pub use pipe_history_table::PipeHistoryQueue;
+pub use pipe_history_table::PipeHistoryTable;
+pub use pipes_table::PipesTable;
 pub use procedures_table::ProceduresTable;
 pub use processes_table::ProcessesTable;
 pub use queries_profiling::ProfilesLogElement;
@@ -113,6 +123,7 @@ pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
+pub use sequences_table::SequencesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
 pub use streams_table::FullStreamsTable;
@@ -125,8 +136,10 @@ pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
 pub use tables_table::ViewsTableWithHistory;
 pub use tables_table::ViewsTableWithoutHistory;
+pub use task_history_table::parse_task_run_infos_to_datablock;
 pub use task_history_table::parse_task_runs_to_datablock;
 pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::parse_task_infos_to_datablock;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
@@ -134,3 +147,4 @@ pub use temporary_tables_table::TemporaryTablesTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
+pub use workload_groups_table::WorkloadGroupsTable;
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+#[derive(Clone)]
+pub struct PipeHistoryLogElement {
+    pub pipe_name: String,
+    pub query_id: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub files_loaded: u64,
+    pub rows_loaded: u64,
+    pub error: Option<String>,
+}
+
+impl SystemLogElement for PipeHistoryLogElement {
+    const TABLE_NAME: &'static str = "pipe_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("pipe_name", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new(
+                "files_loaded",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("rows_loaded", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "error",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.pipe_name.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.end_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.files_loaded)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.rows_loaded)).as_ref());
+        columns.next().unwrap().push(
+            self.error
+                .clone()
+                .map(Scalar::String)
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        Ok(())
+    }
+}
+
+pub type PipeHistoryQueue = SystemLogQueue<PipeHistoryLogElement>;
+pub type PipeHistoryTable = SystemLogTable<PipeHistoryLogElement>;
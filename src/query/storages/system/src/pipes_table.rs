@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct PipesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipesTable {
+    const NAME: &'static str = "system.pipes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let pipes = UserApiProvider::instance().get_pipes(&tenant).await?;
+
+        let mut names = Vec::with_capacity(pipes.len());
+        let mut auto_ingests = Vec::with_capacity(pipes.len());
+        let mut execution_pauseds = Vec::with_capacity(pipes.len());
+        let mut definitions = Vec::with_capacity(pipes.len());
+        let mut comments = Vec::with_capacity(pipes.len());
+        let mut owners = Vec::with_capacity(pipes.len());
+        let mut created_on_columns = Vec::with_capacity(pipes.len());
+        let mut updated_on_columns = Vec::with_capacity(pipes.len());
+        for pipe in pipes {
+            names.push(pipe.name);
+            auto_ingests.push(pipe.auto_ingest);
+            execution_pauseds.push(pipe.execution_paused);
+            definitions.push(pipe.copy_stmt);
+            comments.push(pipe.comment);
+            owners.push(pipe.owner);
+            created_on_columns.push(pipe.created_on.timestamp_micros());
+            updated_on_columns.push(pipe.updated_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            BooleanType::from_data(auto_ingests),
+            BooleanType::from_data(execution_pauseds),
+            StringType::from_data(definitions),
+            StringType::from_data(comments),
+            StringType::from_data(owners),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl PipesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("auto_ingest", TableDataType::Boolean),
+            TableField::new("execution_paused", TableDataType::Boolean),
+            TableField::new("definition", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipes'".to_string(),
+            name: "pipes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipes".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PipesTable { table_info })
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::ListSequencesReq;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct SequencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for SequencesTable {
+    const NAME: &'static str = "system.sequences";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_default_catalog()?;
+        let reply = catalog.list_sequences(ListSequencesReq { tenant }).await?;
+
+        let len = reply.sequences.len();
+        let mut names = Vec::with_capacity(len);
+        let mut starts = Vec::with_capacity(len);
+        let mut increments = Vec::with_capacity(len);
+        let mut caches = Vec::with_capacity(len);
+        let mut currents = Vec::with_capacity(len);
+        let mut created_on_columns = Vec::with_capacity(len);
+        let mut updated_on_columns = Vec::with_capacity(len);
+        let mut comments = Vec::with_capacity(len);
+        for (name, meta) in reply.sequences {
+            names.push(name);
+            starts.push(meta.start);
+            increments.push(meta.step);
+            caches.push(meta.cache);
+            currents.push(meta.current);
+            created_on_columns.push(meta.create_on.timestamp_micros());
+            updated_on_columns.push(meta.update_on.timestamp_micros());
+            comments.push(meta.comment);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(starts),
+            Int64Type::from_data(increments),
+            UInt64Type::from_data(caches),
+            UInt64Type::from_data(currents),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_data(updated_on_columns),
+            StringType::from_opt_data(comments),
+        ]))
+    }
+}
+
+impl SequencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("start", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("increment", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("cache", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("current", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+            TableField::new(
+                "comment",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'sequences'".to_string(),
+            name: "sequences".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSequences".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(SequencesTable { table_info })
+    }
+}
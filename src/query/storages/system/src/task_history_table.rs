@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::Utc;
 use chrono_tz::Tz::UTC;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
@@ -22,8 +24,9 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTaskRunsRequest;
 use databend_common_cloud_control::pb::TaskRun;
+use databend_common_cloud_control::pb::WarehouseOptions;
+use databend_common_cloud_control::task_utils;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::infer_table_schema;
@@ -37,10 +40,13 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::TaskRunInfo;
+use databend_common_meta_app::principal::TaskRunState;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -49,6 +55,52 @@ use crate::util::find_gt_filter;
 use crate::util::find_lt_filter;
 
 pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock> {
+    let task_runs = task_runs
+        .into_iter()
+        .map(|task_run| task_run.try_into())
+        .collect::<Result<Vec<_>>>()?;
+    task_runs_to_datablock(task_runs)
+}
+
+/// Converts the runs of the tasks scheduled by databend-query itself
+/// to a data block of `system.task_history`.
+pub fn parse_task_run_infos_to_datablock(task_runs: Vec<TaskRunInfo>) -> Result<DataBlock> {
+    let task_runs = task_runs
+        .into_iter()
+        .map(|run| task_utils::TaskRun {
+            task_id: run.task.task_id,
+            task_name: run.task.task_name,
+            query_text: run.task.query_text,
+            condition_text: run.task.when_condition.unwrap_or_default(),
+            comment: run.task.comment,
+            owner: run.task.owner,
+            run_id: run.run_id,
+            query_id: run.query_id,
+            schedule_options: run.task.schedule.map(|schedule| schedule.to_string()),
+            warehouse_options: run.task.warehouse.map(|warehouse| WarehouseOptions {
+                warehouse: Some(warehouse),
+                using_warehouse_size: None,
+            }),
+            attempt_number: 1,
+            state: match run.state {
+                TaskRunState::Scheduled => task_utils::State::SCHEDULED,
+                TaskRunState::Executing => task_utils::State::EXECUTING,
+                TaskRunState::Succeeded => task_utils::State::SUCCEEDED,
+                TaskRunState::Failed => task_utils::State::FAILED,
+                TaskRunState::Cancelled => task_utils::State::CANCELLED,
+            },
+            scheduled_at: run.scheduled_at,
+            completed_at: run.completed_at,
+            error_code: run.error_code,
+            error_message: run.error_message,
+            root_task_id: run.root_task_id.to_string(),
+            session_params: run.task.session_params,
+        })
+        .collect();
+    task_runs_to_datablock(task_runs)
+}
+
+fn task_runs_to_datablock(task_runs: Vec<task_utils::TaskRun>) -> Result<DataBlock> {
     let mut name: Vec<String> = Vec::with_capacity(task_runs.len());
     let mut id: Vec<u64> = Vec::with_capacity(task_runs.len());
     let mut owner: Vec<String> = Vec::with_capacity(task_runs.len());
@@ -68,8 +120,7 @@ pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock
     let mut root_task_id: Vec<String> = Vec::with_capacity(task_runs.len());
     let mut session_params: Vec<Option<Vec<u8>>> = Vec::with_capacity(task_runs.len());
 
-    for task_run in task_runs {
-        let tr: databend_common_cloud_control::task_utils::TaskRun = task_run.try_into()?;
+    for tr in task_runs {
         name.push(tr.task_name);
         id.push(tr.task_id);
         owner.push(tr.owner);
@@ -130,13 +181,6 @@ impl AsyncSystemTable for TaskHistoryTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
-        let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
-        }
-
         let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
@@ -163,23 +207,54 @@ impl AsyncSystemTable for TaskHistoryTable {
                 find_lt_filter(&expr, &mut |col_name, scalar| {
                     if col_name == "scheduled_time" {
                         if let Scalar::Timestamp(s) = scalar {
-                            scheduled_time_end = Some(s.to_timestamp(UTC).to_rfc3339());
+                            scheduled_time_end = Some(s.to_timestamp(UTC).with_timezone(&Utc));
                         }
                     }
                 });
                 find_gt_filter(&expr, &mut |col_name, scalar| {
                     if col_name == "scheduled_time" {
                         if let Scalar::Timestamp(s) = scalar {
-                            scheduled_time_start = Some(s.to_timestamp(UTC).to_rfc3339());
+                            scheduled_time_start = Some(s.to_timestamp(UTC).with_timezone(&Utc));
                         }
                     }
                 });
             }
         }
+
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_none() {
+            let owners = available_roles
+                .into_iter()
+                .map(|x| x.identity().to_string())
+                .collect::<HashSet<_>>();
+            let mut task_runs = UserApiProvider::instance()
+                .get_task_runs(&tenant)
+                .await?
+                .into_iter()
+                .filter(|run| {
+                    owners.contains(&run.task.owner)
+                        && task_name
+                            .as_ref()
+                            .map_or(true, |name| &run.task.task_name == name)
+                        && scheduled_time_start.map_or(true, |start| run.scheduled_at >= start)
+                        && scheduled_time_end.map_or(true, |end| run.scheduled_at <= end)
+                })
+                .collect::<Vec<_>>();
+            task_runs.sort_by(|a, b| b.scheduled_at.cmp(&a.scheduled_at));
+            if let Some(limit) = result_limit {
+                task_runs.truncate(limit as usize);
+            }
+            return parse_task_run_infos_to_datablock(task_runs);
+        }
+
         let req = ShowTaskRunsRequest {
             tenant_id: tenant.tenant_name().to_string(),
-            scheduled_time_start: scheduled_time_start.unwrap_or("".to_string()),
-            scheduled_time_end: scheduled_time_end.unwrap_or("".to_string()),
+            scheduled_time_start: scheduled_time_start
+                .map(|t| t.to_rfc3339())
+                .unwrap_or("".to_string()),
+            scheduled_time_end: scheduled_time_end
+                .map(|t| t.to_rfc3339())
+                .unwrap_or("".to_string()),
             task_name: task_name.unwrap_or("".to_string()),
             result_limit: result_limit.unwrap_or(0), // 0 means default
             error_only: false,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -22,8 +23,9 @@ use databend_common_cloud_control::client_config::make_request;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::pb::Task;
+use databend_common_cloud_control::pb::WarehouseOptions;
+use databend_common_cloud_control::task_utils;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
@@ -32,15 +34,59 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal::TaskInfo;
+use databend_common_meta_app::principal::TaskStatus;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
 pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
+    let tasks = tasks
+        .into_iter()
+        .map(|task| task.try_into())
+        .collect::<Result<Vec<_>>>()?;
+    tasks_to_datablock(tasks)
+}
+
+/// Converts the tasks scheduled by databend-query itself to a data block of `system.tasks`.
+pub fn parse_task_infos_to_datablock(tasks: Vec<TaskInfo>) -> Result<DataBlock> {
+    let tasks = tasks
+        .into_iter()
+        .map(|task| task_utils::Task {
+            task_id: task.task_id,
+            task_name: task.task_name,
+            query_text: task.query_text,
+            condition_text: task.when_condition.unwrap_or_default(),
+            after: task.after,
+            comment: task.comment,
+            owner: task.owner,
+            schedule_options: task.schedule.map(|schedule| schedule.to_string()),
+            warehouse_options: task.warehouse.map(|warehouse| WarehouseOptions {
+                warehouse: Some(warehouse),
+                using_warehouse_size: None,
+            }),
+            next_scheduled_at: task.next_scheduled_at,
+            suspend_task_after_num_failures: task.suspend_task_after_num_failures.map(|v| v as i32),
+            error_integration: task.error_integration,
+            status: match task.status {
+                TaskStatus::Suspended => task_utils::Status::Suspended,
+                TaskStatus::Started => task_utils::Status::Started,
+            },
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+            last_suspended_at: task.last_suspended_at,
+            session_params: task.session_params,
+        })
+        .collect();
+    tasks_to_datablock(tasks)
+}
+
+fn tasks_to_datablock(tasks: Vec<task_utils::Task>) -> Result<DataBlock> {
     let mut created_on: Vec<i64> = Vec::with_capacity(tasks.len());
     let mut name: Vec<String> = Vec::with_capacity(tasks.len());
     let mut id: Vec<u64> = Vec::with_capacity(tasks.len());
@@ -58,8 +104,7 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
     let mut next_schedule_time: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
     let mut last_suspended_on: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
     let mut session_params: Vec<Option<Vec<u8>>> = Vec::with_capacity(tasks.len());
-    for task in tasks {
-        let tsk: databend_common_cloud_control::task_utils::Task = task.try_into()?;
+    for tsk in tasks {
         created_on.push(tsk.created_at.timestamp_micros());
         name.push(tsk.task_name);
         id.push(tsk.task_id);
@@ -120,17 +165,25 @@ impl AsyncSystemTable for TasksTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.tasks table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let owners = available_roles
+                .into_iter()
+                .map(|x| x.identity().to_string())
+                .collect::<HashSet<_>>();
+            let tasks = UserApiProvider::instance()
+                .get_tasks(&tenant)
+                .await?
+                .into_iter()
+                .filter(|task| owners.contains(&task.owner))
+                .collect();
+            return parse_task_infos_to_datablock(tasks);
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTasksRequest {
             tenant_id: tenant.tenant_name().to_string(),
             name_like: "".to_string(),
@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct WorkloadGroupsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for WorkloadGroupsTable {
+    const NAME: &'static str = "system.workload_groups";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let workload_groups = UserApiProvider::instance()
+            .get_workload_groups(&tenant)
+            .await?;
+        // The runtime status of the groups on the current node.
+        let status = ctx.get_workload_groups_status();
+
+        let len = workload_groups.len();
+        let mut names = Vec::with_capacity(len);
+        let mut max_concurrency = Vec::with_capacity(len);
+        let mut query_memory_limits = Vec::with_capacity(len);
+        let mut memory_limits = Vec::with_capacity(len);
+        let mut cpu_weights = Vec::with_capacity(len);
+        let mut running_queries = Vec::with_capacity(len);
+        let mut queued_queries = Vec::with_capacity(len);
+        let mut memory_usages = Vec::with_capacity(len);
+        let mut comments = Vec::with_capacity(len);
+        let mut created_on_columns = Vec::with_capacity(len);
+        let mut updated_on_columns = Vec::with_capacity(len);
+        for workload_group in workload_groups {
+            let group_status = status
+                .get(&workload_group.name)
+                .cloned()
+                .unwrap_or_default();
+
+            names.push(workload_group.name);
+            max_concurrency.push(workload_group.max_concurrency);
+            query_memory_limits.push(workload_group.query_memory_limit);
+            memory_limits.push(workload_group.memory_limit);
+            cpu_weights.push(workload_group.cpu_weight);
+            running_queries.push(group_status.running_queries);
+            queued_queries.push(group_status.queued_queries);
+            memory_usages.push(group_status.memory_usage);
+            comments.push(workload_group.comment);
+            created_on_columns.push(workload_group.created_on.timestamp_micros());
+            updated_on_columns.push(workload_group.updated_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_opt_data(max_concurrency),
+            UInt64Type::from_opt_data(query_memory_limits),
+            UInt64Type::from_opt_data(memory_limits),
+            UInt64Type::from_data(cpu_weights),
+            UInt64Type::from_data(running_queries),
+            UInt64Type::from_data(queued_queries),
+            Int64Type::from_data(memory_usages),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl WorkloadGroupsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let nullable_u64 =
+            TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64)));
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("max_concurrency", nullable_u64.clone()),
+            TableField::new("query_memory_limit", nullable_u64.clone()),
+            TableField::new("memory_limit", nullable_u64),
+            TableField::new("cpu_weight", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "queued_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("memory_usage", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'workload_groups'".to_string(),
+            name: "workload_groups".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemWorkloadGroups".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(WorkloadGroupsTable { table_info })
+    }
+}
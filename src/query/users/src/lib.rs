@@ -20,7 +20,9 @@ extern crate core;
 mod jwt;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod role_mgr;
+mod task;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -28,12 +30,14 @@ mod user_setting;
 mod user_stage;
 mod user_udf;
 mod visibility_checker;
+mod workload_group;
 
 pub mod builtin;
 pub mod connection;
 pub mod file_format;
 pub mod role_cache_mgr;
 pub mod role_util;
+pub mod secret;
 
 pub use jwt::*;
 pub use password_policy::*;
@@ -43,3 +47,4 @@ pub use role_mgr::BUILTIN_ROLE_PUBLIC;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
 pub use visibility_checker::GrantObjectVisibilityChecker;
+pub use workload_group::*;
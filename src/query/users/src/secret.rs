@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserDefinedSecret;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// user secret operations.
+impl UserApiProvider {
+    // Add a new secret.
+    #[async_backtrace::framed]
+    pub async fn add_secret(
+        &self,
+        tenant: &Tenant,
+        secret: UserDefinedSecret,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let secret_api_provider = self.secret_api(tenant);
+        secret_api_provider.add(secret, create_option).await?;
+        Ok(())
+    }
+
+    // Get one secret by name.
+    #[async_backtrace::framed]
+    pub async fn get_secret(
+        &self,
+        tenant: &Tenant,
+        secret_name: &str,
+    ) -> Result<UserDefinedSecret> {
+        let secret_api_provider = self.secret_api(tenant);
+        let get_secret = secret_api_provider.get(secret_name, MatchSeq::GE(0));
+        Ok(get_secret.await?.data)
+    }
+
+    // Get all the secrets of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_secrets(&self, tenant: &Tenant) -> Result<Vec<UserDefinedSecret>> {
+        let secret_api_provider = self.secret_api(tenant);
+        match secret_api_provider.list().await {
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while get secrets)")),
+            Ok(secrets) => Ok(secrets),
+        }
+    }
+
+    // Drop a secret by name.
+    #[async_backtrace::framed]
+    pub async fn drop_secret(&self, tenant: &Tenant, name: &str, if_exists: bool) -> Result<()> {
+        let secret_api_provider = self.secret_api(tenant);
+        match secret_api_provider.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SECRET {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop secret)"))
+                }
+            }
+        }
+    }
+}
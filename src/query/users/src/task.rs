@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::principal::TaskInfo;
+use databend_common_meta_app::principal::TaskRunInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new task, the task id is allocated by the meta service.
+    #[async_backtrace::framed]
+    pub async fn add_task(
+        &self,
+        tenant: &Tenant,
+        mut task: TaskInfo,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let meta_store = self.get_meta_store_client();
+        task.task_id = fetch_id(meta_store.as_ref(), IdGenerator::task_id()).await?;
+        let client = self.task_api(tenant);
+        client.add(task, create_option).await?;
+        Ok(())
+    }
+
+    // Update a task with the function `update`, it returns `None` if the task does not exist
+    // and `if_exists` is true.
+    #[async_backtrace::framed]
+    pub async fn update_task(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        if_exists: bool,
+        update: impl FnOnce(&mut TaskInfo) -> Result<()>,
+    ) -> Result<Option<u64>> {
+        let client = self.task_api(tenant);
+        let seq_task = match client.get(name, MatchSeq::GE(0)).await {
+            Ok(seq_task) => seq_task,
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    return Err(ErrorCode::from(meta_err).add_message_back(" (while alter task)"));
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        return Ok(None);
+                    } else {
+                        return Err(
+                            ErrorCode::from(unknown).add_message_back(" (while alter task)")
+                        );
+                    }
+                }
+            },
+        };
+
+        let seq = seq_task.seq;
+        let mut task = seq_task.data;
+        update(&mut task)?;
+
+        match client.update(task, MatchSeq::Exact(seq)).await {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                Err(e.add_message_back(" (while alter task)."))
+            }
+        }
+    }
+
+    // Drop a task by name.
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, tenant: &Tenant, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.task_api(tenant);
+        match client.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Get a task by name.
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, tenant: &Tenant, name: &str) -> Result<TaskInfo> {
+        let client = self.task_api(tenant);
+        let task = client.get(name, MatchSeq::GE(0)).await?.data;
+        Ok(task)
+    }
+
+    // Get all tasks by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tasks(&self, tenant: &Tenant) -> Result<Vec<TaskInfo>> {
+        let client = self.task_api(tenant);
+        let tasks = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get tasks).")
+        })?;
+        Ok(tasks)
+    }
+
+    // Add or overwrite a run of a task, the run is removed after `ttl`.
+    #[async_backtrace::framed]
+    pub async fn upsert_task_run(
+        &self,
+        tenant: &Tenant,
+        run: TaskRunInfo,
+        ttl: Duration,
+    ) -> Result<()> {
+        let client = self.task_run_api(tenant);
+        client
+            .add_with_ttl(run, Some(ttl), &CreateOption::CreateOrReplace)
+            .await?;
+        Ok(())
+    }
+
+    // Get all the runs of the tasks by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_task_runs(&self, tenant: &Tenant) -> Result<Vec<TaskRunInfo>> {
+        let client = self.task_run_api(tenant);
+        let runs = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get task runs).")
+        })?;
+        Ok(runs)
+    }
+}
@@ -25,17 +25,22 @@ use databend_common_management::ConnectionMgr;
 use databend_common_management::FileFormatMgr;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PipeMgr;
 use databend_common_management::ProcedureMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
 use databend_common_management::RoleMgr;
+use databend_common_management::SecretMgr;
 use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TaskMgr;
+use databend_common_management::TaskRunMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
+use databend_common_management::WorkloadGroupMgr;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedFunction;
@@ -147,6 +152,26 @@ impl UserApiProvider {
         ConnectionMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn secret_api(&self, tenant: &Tenant) -> SecretMgr {
+        SecretMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn pipe_api(&self, tenant: &Tenant) -> PipeMgr {
+        PipeMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn task_api(&self, tenant: &Tenant) -> TaskMgr {
+        TaskMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn workload_group_api(&self, tenant: &Tenant) -> WorkloadGroupMgr {
+        WorkloadGroupMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn task_run_api(&self, tenant: &Tenant) -> TaskRunMgr {
+        TaskRunMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn tenant_quota_api(&self, tenant: &Tenant) -> Arc<dyn QuotaApi> {
         const WRITE_PB: bool = false;
         Arc::new(QuotaMgr::<WRITE_PB>::create(self.client.clone(), tenant))
@@ -157,6 +157,14 @@ impl UserApiProvider {
                 )));
             }
         }
+        if let Some(name) = user_info.option.workload_group() {
+            if self.get_workload_group(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                    "workload group `{}` is not exist",
+                    name
+                )));
+            }
+        }
         if self.get_configured_user(&user_info.name).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
                 "Same name with configured user `{}`",
@@ -315,6 +323,14 @@ impl UserApiProvider {
                     )));
                 }
             }
+            if let Some(name) = user_option.workload_group() {
+                if self.get_workload_group(tenant, name).await.is_err() {
+                    return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                        "workload group `{}` is not exist",
+                        name
+                    )));
+                }
+            }
         }
         if self.get_configured_user(&user.username).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
@@ -156,6 +156,8 @@ impl GrantObjectVisibilityChecker {
                             granted_read_stages.insert(stage.to_string());
                         }
                     }
+                    // The secrets are not listed by the visibility checker.
+                    GrantObject::Secret(_) => {}
                 }
             }
         }
@@ -184,6 +186,7 @@ impl GrantObjectVisibilityChecker {
                 OwnershipObject::UDF { name } => {
                     granted_udfs.insert(name.to_string());
                 }
+                OwnershipObject::Secret { .. } => {}
             }
         }
 
@@ -0,0 +1,256 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_ast::ast::AlterWorkloadGroupAction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::principal::DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+// range of the cpu weight of workload group
+pub const MIN_WORKLOAD_GROUP_CPU_WEIGHT: u64 = 1;
+pub const MAX_WORKLOAD_GROUP_CPU_WEIGHT: u64 = 10000;
+
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &Tenant,
+        workload_group: WorkloadGroup,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        check_workload_group(&workload_group)?;
+
+        let client = self.workload_group_api(tenant);
+        client.add(workload_group, create_option).await?;
+        Ok(())
+    }
+
+    // Set or unset the options of a workload group.
+    #[async_backtrace::framed]
+    pub async fn update_workload_group(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        action: &AlterWorkloadGroupAction,
+        if_exists: bool,
+    ) -> Result<Option<u64>> {
+        let client = self.workload_group_api(tenant);
+        let seq_workload_group = match client.get(name, MatchSeq::GE(0)).await {
+            Ok(seq_workload_group) => seq_workload_group,
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    return Err(
+                        ErrorCode::from(meta_err).add_message_back(" (while alter workload group)")
+                    );
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        return Ok(None);
+                    } else {
+                        return Err(ErrorCode::from(unknown)
+                            .add_message_back(" (while alter workload group)"));
+                    }
+                }
+            },
+        };
+
+        let seq = seq_workload_group.seq;
+        let mut workload_group = seq_workload_group.data;
+        match action {
+            AlterWorkloadGroupAction::SetOptions(set_options) => {
+                if let Some(max_concurrency) = set_options.max_concurrency {
+                    workload_group.max_concurrency = Some(max_concurrency);
+                }
+                if let Some(query_memory_limit) = set_options.query_memory_limit {
+                    workload_group.query_memory_limit = Some(query_memory_limit);
+                }
+                if let Some(memory_limit) = set_options.memory_limit {
+                    workload_group.memory_limit = Some(memory_limit);
+                }
+                if let Some(cpu_weight) = set_options.cpu_weight {
+                    workload_group.cpu_weight = cpu_weight;
+                }
+                if let Some(comment) = &set_options.comment {
+                    workload_group.comment = comment.clone();
+                }
+            }
+            AlterWorkloadGroupAction::UnSetOptions(unset_options) => {
+                if unset_options.max_concurrency {
+                    workload_group.max_concurrency = None;
+                }
+                if unset_options.query_memory_limit {
+                    workload_group.query_memory_limit = None;
+                }
+                if unset_options.memory_limit {
+                    workload_group.memory_limit = None;
+                }
+                if unset_options.cpu_weight {
+                    workload_group.cpu_weight = DEFAULT_WORKLOAD_GROUP_CPU_WEIGHT;
+                }
+                if unset_options.comment {
+                    workload_group.comment = "".to_string();
+                }
+            }
+        }
+        check_workload_group(&workload_group)?;
+
+        workload_group.updated_on = Utc::now();
+
+        match client.update(workload_group, MatchSeq::Exact(seq)).await {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while alter workload group).")),
+        }
+    }
+
+    // Drop a workload group by name, the group must not be bound to any user or role.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        for user_info in user_infos {
+            if user_info.option.workload_group().is_some_and(|v| v == name) {
+                return Err(ErrorCode::WorkloadGroupIsInUse(format!(
+                    "workload group `{}` is used by user `{}`",
+                    name, user_info.name,
+                )));
+            }
+        }
+        let role_infos = self.get_roles(tenant).await?;
+        for role_info in role_infos {
+            if role_info.workload_group.as_ref().is_some_and(|v| v == name) {
+                return Err(ErrorCode::WorkloadGroupIsInUse(format!(
+                    "workload group `{}` is used by role `{}`",
+                    name, role_info.name,
+                )));
+            }
+        }
+
+        let client = self.workload_group_api(tenant);
+        match client.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    Err(ErrorCode::from(meta_err).add_message_back(" (while drop workload group)"))
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        Ok(())
+                    } else {
+                        Err(ErrorCode::from(unknown)
+                            .add_message_back(" (while drop workload group)"))
+                    }
+                }
+            },
+        }
+    }
+
+    // Get a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &Tenant, name: &str) -> Result<WorkloadGroup> {
+        let client = self.workload_group_api(tenant);
+        let workload_group = client.get(name, MatchSeq::GE(0)).await?.data;
+        Ok(workload_group)
+    }
+
+    // Get all workload groups by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &Tenant) -> Result<Vec<WorkloadGroup>> {
+        let client = self.workload_group_api(tenant);
+        let workload_groups = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get workload groups).")
+        })?;
+        Ok(workload_groups)
+    }
+
+    // Bind a role to a workload group, or unbind it if `workload_group` is None.
+    #[async_backtrace::framed]
+    pub async fn update_role_workload_group(
+        &self,
+        tenant: &Tenant,
+        role: &String,
+        workload_group: Option<String>,
+    ) -> Result<Option<u64>> {
+        if let Some(name) = &workload_group {
+            if self.get_workload_group(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                    "workload group `{}` is not exist",
+                    name
+                )));
+            }
+        }
+
+        let client = self.role_api(tenant);
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.update_role_time();
+                ri.workload_group = workload_group;
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role workload group)"))
+    }
+}
+
+fn check_workload_group(workload_group: &WorkloadGroup) -> Result<()> {
+    if workload_group.max_concurrency == Some(0) {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid max_concurrency of workload group `{}`, must be greater than 0",
+            workload_group.name
+        )));
+    }
+    if workload_group.query_memory_limit == Some(0) || workload_group.memory_limit == Some(0) {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid memory limit of workload group `{}`, must be greater than 0",
+            workload_group.name
+        )));
+    }
+    if let (Some(query_memory_limit), Some(memory_limit)) = (
+        workload_group.query_memory_limit,
+        workload_group.memory_limit,
+    ) {
+        if query_memory_limit > memory_limit {
+            return Err(ErrorCode::IllegalWorkloadGroup(format!(
+                "query_memory_limit {} of workload group `{}` is greater than memory_limit {}",
+                query_memory_limit, workload_group.name, memory_limit
+            )));
+        }
+    }
+    if !(MIN_WORKLOAD_GROUP_CPU_WEIGHT..=MAX_WORKLOAD_GROUP_CPU_WEIGHT)
+        .contains(&workload_group.cpu_weight)
+    {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid cpu_weight {} of workload group `{}`, must be in range [{}, {}]",
+            workload_group.cpu_weight,
+            workload_group.name,
+            MIN_WORKLOAD_GROUP_CPU_WEIGHT,
+            MAX_WORKLOAD_GROUP_CPU_WEIGHT
+        )));
+    }
+    Ok(())
+}
@@ -85,6 +85,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 engine: Some(Engine::Fuse),
                 uri_location: None,
                 cluster_by: None,
+                ttl: None,
                 table_options: BTreeMap::new(),
                 as_query: None,
                 table_type: TableType::Normal,
@@ -290,6 +291,10 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             value: Literal::String("POINT(0 0)".to_string()),
         },
+        TypeName::Interval => Expr::Literal {
+            span: None,
+            value: Literal::String("00:00:00".to_string()),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             value: Literal::Null,
@@ -675,6 +675,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let join = Join {
             op,
             condition,
+            match_condition: None,
             left: Box::new(left_table),
             right: Box::new(right_table),
         };
@@ -167,4 +167,15 @@ for table_name, (schema, file_path) in tables.items():
     df.write.format("iceberg").mode("overwrite").save(full_table_name)
     print(f"table {full_table_name} has been created")
 
+# Empty tables for the write tests.
+spark.sql(
+    "CREATE OR REPLACE TABLE iceberg.tpch.write_test (id int, name string) USING iceberg"
+)
+spark.sql(
+    """
+    CREATE OR REPLACE TABLE iceberg.tpch.write_partitioned (id int, name string)
+    USING iceberg PARTITIONED BY (id)
+    """
+)
+
 spark.stop()
@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=test-user --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "=== test secret priv"
+echo "drop user if exists 'test-user'" | $BENDSQL_CLIENT_CONNECT
+echo "drop secret if exists s1" | $BENDSQL_CLIENT_CONNECT
+echo "create secret s1 secret_string = 'p4ssphr4se'" | $BENDSQL_CLIENT_CONNECT
+echo "create user 'test-user' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== no privilege on s1 ==="
+echo "select to_string(decrypt(encrypt('abc', secret('s1')), secret('s1')))" | $TEST_USER_CONNECT 2>&1 | grep 'privilege USAGE is required on secret s1' | wc -l
+echo "create secret s2 secret_string = 'p4ssphr4se'" | $TEST_USER_CONNECT 2>&1 | grep 'Permission denied' | wc -l
+
+echo "=== usage on s1 ==="
+echo "grant usage on secret s1 to 'test-user'" | $BENDSQL_CLIENT_CONNECT
+echo "select to_string(decrypt(encrypt('abc', secret('s1')), secret('s1')))" | $TEST_USER_CONNECT
+echo "drop secret s1" | $TEST_USER_CONNECT 2>&1 | grep 'Permission denied' | wc -l
+echo "show grants for 'test-user'" | $BENDSQL_CLIENT_CONNECT | grep -c 'SECRET s1'
+
+echo "=== revoke usage on s1 ==="
+echo "revoke usage on secret s1 from 'test-user'" | $BENDSQL_CLIENT_CONNECT
+echo "select to_string(decrypt(encrypt('abc', secret('s1')), secret('s1')))" | $TEST_USER_CONNECT 2>&1 | grep 'privilege USAGE is required on secret s1' | wc -l
+
+echo "drop secret s1" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists 'test-user'" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+stmt "create or replace database test_table_ttl_pruning"
+
+stmt "create table test_table_ttl_pruning.t(id int, ts timestamp) ttl ts + interval 30 day"
+
+# all the rows of the first block are expired
+stmt "insert into test_table_ttl_pruning.t values (1, '2000-01-01 00:00:00'), (2, '2000-01-02 00:00:00')"
+stmt "insert into test_table_ttl_pruning.t values (3, '2000-01-01 00:00:00'), (4, '2099-01-01 00:00:00')"
+stmt "insert into test_table_ttl_pruning.t values (5, '2099-01-01 00:00:00')"
+
+# the filter of the TTL holds the current time, so only the pruning of the plan is checked
+echo "the block of expired rows is pruned"
+echo "explain select id from test_table_ttl_pruning.t" | $BENDSQL_CLIENT_CONNECT | grep -oE "(partitions total|partitions scanned|pruning stats): .*"
+
+stmt "drop database test_table_ttl_pruning"
@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/deletion_vector/)
+
+stmt "drop table if exists test_delta;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+# rows 2, 5 and 7 are deleted by the deletion vector
+query "select * from test_delta order by id;"
+query "select count() from test_delta;"
+query "select id from test_delta where id > 4 order by id;"
+query "select id from test_delta where id between 2 and 7 order by id;"
+
+## time travel to the version before the delete
+query "select count() from test_delta at (version => 0);"
+query "select id from test_delta at (version => 0) where id between 2 and 7 order by id;"
+
+stmt "drop table test_delta;"
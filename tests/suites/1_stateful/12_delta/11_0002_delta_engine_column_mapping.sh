@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/column_mapping/)
+
+stmt "drop table if exists test_delta;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+# the columns are stored by physical names, p is partition column
+query "select * from test_delta order by id;"
+query "select id, value from test_delta where p = 2;"
+query "select p, value from test_delta where id > 1 order by id;"
+query "select count() from test_delta where value >= 20;"
+
+## time travel to the version before the column v is renamed to value
+query "select v from test_delta at (version => 0) order by id;"
+
+stmt "drop table test_delta;"